@@ -3,4 +3,5 @@ pub mod get_archives_project;
 pub mod has_archives_project;
 pub mod open_archives_project;
 pub mod read_archive_file;
+pub mod read_archive_file_bytes;
 pub mod unpack_archives_path;
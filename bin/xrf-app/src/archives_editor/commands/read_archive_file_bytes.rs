@@ -0,0 +1,25 @@
+use crate::archives_editor::state::ArchivesEditorState;
+use crate::types::TauriResult;
+use std::sync::MutexGuard;
+use tauri::ipc::Response;
+use tauri::State;
+use xray_archive::ArchiveProject;
+
+#[tauri::command]
+pub async fn read_archive_file_bytes(
+  path: &str,
+  state: State<'_, ArchivesEditorState>,
+) -> TauriResult<Response> {
+  let lock: MutexGuard<Option<ArchiveProject>> = state.project.lock().unwrap();
+
+  if (*lock).is_none() {
+    return Err(String::from("Failed to read file - archive is not open"));
+  }
+
+  lock
+    .as_ref()
+    .unwrap()
+    .read_file_bytes(path)
+    .map(Response::new)
+    .map_err(|error| error.to_string())
+}
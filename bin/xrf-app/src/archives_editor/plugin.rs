@@ -20,6 +20,7 @@ impl ArchivesEditorPlugin {
         crate::archives_editor::commands::has_archives_project::has_archives_project,
         crate::archives_editor::commands::open_archives_project::open_archives_project,
         crate::archives_editor::commands::read_archive_file::read_archive_file,
+        crate::archives_editor::commands::read_archive_file_bytes::read_archive_file_bytes,
         crate::archives_editor::commands::unpack_archives_path::unpack_archives_path,
       ])
       .build()
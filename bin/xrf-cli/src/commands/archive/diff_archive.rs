@@ -0,0 +1,114 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use xray_archive::{ArchiveDiffResult, ArchiveProject};
+use xray_error::XRayResult;
+
+#[derive(Default)]
+pub struct DiffArchiveCommand;
+
+impl GenericCommand for DiffArchiveCommand {
+  fn name(&self) -> &'static str {
+    "diff-archive"
+  }
+
+  /// Create command to diff archives.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to compare *.db archives with other archives or loose gamedata folder")
+      .arg(
+        Arg::new("path")
+          .help("Path to previous *.db file, folder with archives or fsgame.ltx")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("target")
+          .help("Path to next *.db file, folder with archives, fsgame.ltx or gamedata folder")
+          .short('t')
+          .long("target")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("folder")
+          .help("Treat target as loose files folder instead of archives")
+          .long("folder")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("dest")
+          .help("Path to output *.json diff file")
+          .short('d')
+          .long("dest")
+          .required(false)
+          .value_parser(value_parser!(PathBuf)),
+      )
+  }
+
+  /// Compare xray engine database archives.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let target: &PathBuf = matches
+      .get_one::<PathBuf>("target")
+      .expect("Expected valid target path to be provided");
+
+    let is_folder: bool = matches.get_flag("folder");
+
+    log::info!("Diff source: {}", path.display());
+    log::info!("Diff target: {}", target.display());
+
+    let result: ArchiveDiffResult = if is_folder {
+      Self::open_project(path)?.diff_folder(target)?
+    } else {
+      Self::open_project(path)?.diff(&Self::open_project(target)?)
+    };
+
+    for entry in &result.added {
+      println!("A {}", entry.name);
+    }
+
+    for entry in &result.removed {
+      println!("D {}", entry.name);
+    }
+
+    for entry in &result.modified {
+      println!("M {}", entry.name);
+    }
+
+    if let Some(destination) = matches.get_one::<PathBuf>("dest") {
+      let mut writer: BufWriter<File> = BufWriter::new(File::create(destination)?);
+
+      result.write_json(&mut writer)?;
+      writer.flush()?;
+    }
+
+    log::info!(
+      "Diff summary: {} added, {} removed, {} modified, {} unchanged",
+      result.added.len(),
+      result.removed.len(),
+      result.modified.len(),
+      result.unchanged,
+    );
+
+    Ok(())
+  }
+}
+
+impl DiffArchiveCommand {
+  fn open_project(path: &Path) -> XRayResult<ArchiveProject> {
+    if path.extension().is_some_and(|it| it == "ltx") {
+      ArchiveProject::from_fsgame(&path)
+    } else {
+      ArchiveProject::new(&path)
+    }
+  }
+}
@@ -0,0 +1,90 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgMatches, Command};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use xray_archive::{ArchiveManifest, ArchiveProject};
+use xray_error::XRayError;
+
+#[derive(Default)]
+pub struct ManifestArchiveCommand;
+
+impl GenericCommand for ManifestArchiveCommand {
+  fn name(&self) -> &'static str {
+    "manifest-archive"
+  }
+
+  /// Create command to export archive manifest.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to export manifest of *.db archives files and shadowed entries")
+      .arg(
+        Arg::new("path")
+          .help("Path to *.db file, folder with archives or fsgame.ltx")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help("Path to output *.json or *.csv manifest file")
+          .short('d')
+          .long("dest")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+  }
+
+  /// Export manifest of xray engine database archives.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let destination: &PathBuf = matches
+      .get_one::<PathBuf>("dest")
+      .expect("Expected valid output path to be provided");
+
+    let is_csv: bool = match destination.extension().and_then(|it| it.to_str()) {
+      Some("csv") => true,
+      Some("json") => false,
+      _ => {
+        return Err(
+          XRayError::new_invalid_error("Expected manifest destination with json or csv extension")
+            .into(),
+        )
+      }
+    };
+
+    log::info!("Manifest source: {}", path.display());
+    log::info!("Manifest destination: {}", destination.display());
+
+    let archive_project: Box<ArchiveProject> =
+      Box::new(if path.extension().is_some_and(|it| it == "ltx") {
+        ArchiveProject::from_fsgame(path)?
+      } else {
+        ArchiveProject::new(path)?
+      });
+
+    let manifest: ArchiveManifest = archive_project.get_manifest();
+    let mut writer: BufWriter<File> = BufWriter::new(File::create(destination)?);
+
+    if is_csv {
+      manifest.write_csv(&mut writer)?;
+    } else {
+      manifest.write_json(&mut writer)?;
+    }
+
+    writer.flush()?;
+
+    log::info!(
+      "Exported manifest: {} archive(s), {} file(s), {} shadowed file(s)",
+      manifest.archives.len(),
+      manifest.files.len(),
+      manifest.shadowed.len(),
+    );
+
+    Ok(())
+  }
+}
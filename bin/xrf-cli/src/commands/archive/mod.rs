@@ -1 +1,6 @@
+pub(crate) mod diff_archive;
+pub(crate) mod manifest_archive;
+pub(crate) mod pack_archive;
+pub(crate) mod patch_archive;
 pub(crate) mod unpack_archive;
+pub(crate) mod verify_archive;
@@ -0,0 +1,117 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{fs, io};
+use xray_archive::{ArchiveDescriptor, ArchiveWriteOptions, ArchiveWriter};
+
+#[derive(Default)]
+pub struct PackArchiveCommand;
+
+impl GenericCommand for PackArchiveCommand {
+  fn name(&self) -> &'static str {
+    "pack-archive"
+  }
+
+  /// Create command to pack folder into archive.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to pack provided folder into single *.db archive")
+      .arg(
+        Arg::new("path")
+          .help("Path to folder for packing")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help("Path to resulting *.db file")
+          .short('d')
+          .long("dest")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("entry-point")
+          .help("Archive header entry point, for example $game_data$\\")
+          .short('e')
+          .long("entry-point")
+          .required(false)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("uncompressed")
+          .help("Whether files should be stored without compression")
+          .long("uncompressed")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("force")
+          .help("Whether existing archive should be pruned if destination file exists")
+          .short('f')
+          .long("force")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Pack folder into xray engine database archive.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let destination: &PathBuf = matches
+      .get_one::<PathBuf>("dest")
+      .expect("Expected valid output path to be provided");
+
+    let entry_point: Option<String> = matches.get_one::<String>("entry-point").cloned();
+    let is_uncompressed: bool = matches.get_flag("uncompressed");
+    let force: bool = matches.get_flag("force");
+
+    log::info!("Pack source: {}", path.display());
+    log::info!("Pack destination: {}", destination.display());
+
+    // Apply force flag and delete existing archive output.
+    if force && destination.exists() && destination.is_file() {
+      fs::remove_file(destination)?;
+    }
+
+    // Re-validate that provided output can be used.
+    if destination.exists() {
+      return Err(
+        io::Error::new(
+          io::ErrorKind::AlreadyExists,
+          "Pack output file already exists, use --force to prune destination",
+        )
+        .into(),
+      );
+    }
+
+    let started_at: Instant = Instant::now();
+
+    let archive: ArchiveDescriptor = ArchiveWriter::from_path_utf8(destination)?.write_archive(
+      path,
+      &ArchiveWriteOptions {
+        entry_point,
+        is_compressed: !is_uncompressed,
+        ..ArchiveWriteOptions::default()
+      },
+    )?;
+
+    let duration: Duration = started_at.elapsed();
+
+    log::info!(
+      "Packed archive: {} file(s), {:.3} MB compressed, {:.3} MB real, took {} sec",
+      archive.files.len(),
+      (archive.get_compressed_size() as f64) / 1024.0 / 1024.0,
+      (archive.get_real_size() as f64) / 1024.0 / 1024.0,
+      duration.as_millis() as f64 / 1000.0,
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,134 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use std::path::PathBuf;
+use std::{fs, io};
+use xray_archive::{ArchivePatchResult, ArchiveProject, ArchiveWriteOptions};
+
+#[derive(Default)]
+pub struct PatchArchiveCommand;
+
+impl GenericCommand for PatchArchiveCommand {
+  fn name(&self) -> &'static str {
+    "patch-archive"
+  }
+
+  /// Create command to build patch archive.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to pack new and changed gamedata files into single patch *.db archive")
+      .arg(
+        Arg::new("path")
+          .help("Path to base *.db file, folder with archives or fsgame.ltx")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("source")
+          .help("Path to modified gamedata folder")
+          .short('s')
+          .long("source")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help("Path to resulting patch *.db file")
+          .short('d')
+          .long("dest")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("entry-point")
+          .help("Archive header entry point, defaults to $fs_root$\\gamedata\\")
+          .short('e')
+          .long("entry-point")
+          .required(false)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("force")
+          .help("Whether existing archive should be pruned if destination file exists")
+          .short('f')
+          .long("force")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Build patch xray engine database archive.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let source: &PathBuf = matches
+      .get_one::<PathBuf>("source")
+      .expect("Expected valid source path to be provided");
+
+    let destination: &PathBuf = matches
+      .get_one::<PathBuf>("dest")
+      .expect("Expected valid output path to be provided");
+
+    let entry_point: Option<String> = matches.get_one::<String>("entry-point").cloned();
+    let force: bool = matches.get_flag("force");
+
+    log::info!("Patch base: {}", path.display());
+    log::info!("Patch source: {}", source.display());
+    log::info!("Patch destination: {}", destination.display());
+
+    // Apply force flag and delete existing archive output, before base archives are mounted.
+    if force && destination.exists() && destination.is_file() {
+      fs::remove_file(destination)?;
+    }
+
+    // Re-validate that provided output can be used.
+    if destination.exists() {
+      return Err(
+        io::Error::new(
+          io::ErrorKind::AlreadyExists,
+          "Patch output file already exists, use --force to prune destination",
+        )
+        .into(),
+      );
+    }
+
+    let archive_project: Box<ArchiveProject> =
+      Box::new(if path.extension().is_some_and(|it| it == "ltx") {
+        ArchiveProject::from_fsgame(path)?
+      } else {
+        ArchiveProject::new(path)?
+      });
+
+    let result: ArchivePatchResult = archive_project.build_patch(
+      source,
+      destination,
+      &ArchiveWriteOptions {
+        entry_point,
+        ..ArchiveWriteOptions::default()
+      },
+    )?;
+
+    if !result.removed.is_empty() {
+      log::warn!(
+        "{} file(s) missing in source cannot be removed by patch",
+        result.removed.len()
+      );
+
+      for removed in &result.removed {
+        log::debug!("File is missing in source and cannot be removed by patch: {removed}");
+      }
+    }
+
+    log::info!(
+      "Built patch: {} file(s), {:.3} MB real, took {} sec",
+      result.files.len(),
+      (result.size as f64) / 1024.0 / 1024.0,
+      result.duration as f64 / 1000.0,
+    );
+
+    Ok(())
+  }
+}
@@ -1,9 +1,9 @@
 use crate::generic_command::{CommandResult, GenericCommand};
-use clap::{value_parser, Arg, ArgMatches, Command};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
 use std::env;
 use std::path::PathBuf;
 use tokio::runtime::Runtime;
-use xray_archive::{ArchiveProject, ArchiveUnpackResult};
+use xray_archive::{ArchiveProject, ArchiveUnpackOptions, ArchiveUnpackResult};
 
 #[derive(Default)]
 pub struct UnpackArchiveCommand;
@@ -19,7 +19,7 @@ impl GenericCommand for UnpackArchiveCommand {
       .about("Command to unpack provided *.db into separate files")
       .arg(
         Arg::new("path")
-          .help("Path to *.db file")
+          .help("Path to *.db file, folder with archives or fsgame.ltx")
           .short('p')
           .long("path")
           .required(true)
@@ -40,6 +40,36 @@ impl GenericCommand for UnpackArchiveCommand {
           .default_value("32")
           .value_parser(value_parser!(usize)),
       )
+      .arg(
+        Arg::new("include")
+          .help("Glob pattern of archive files to unpack, for example 'configs/**'")
+          .short('i')
+          .long("include")
+          .action(ArgAction::Append)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("exclude")
+          .help("Glob pattern of archive files to skip, for example '**/*.dds'")
+          .short('e')
+          .long("exclude")
+          .action(ArgAction::Append)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("dry-run")
+          .help("List files that would be unpacked without writing them")
+          .long("dry-run")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("incremental")
+          .help("Skip files already unpacked with matching size and CRC")
+          .long("incremental")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
   }
 
   /// Unpack xray engine database archive.
@@ -57,6 +87,19 @@ impl GenericCommand for UnpackArchiveCommand {
       .get_one::<usize>("parallel")
       .expect("Expected valid parallel threads count to be provided");
 
+    let options: ArchiveUnpackOptions = ArchiveUnpackOptions {
+      include: matches
+        .get_many::<String>("include")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default(),
+      exclude: matches
+        .get_many::<String>("exclude")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default(),
+      is_dry_run: matches.get_flag("dry-run"),
+      is_incremental: matches.get_flag("incremental"),
+    };
+
     if destination.is_relative() {
       destination = env::current_dir().unwrap().join(destination);
     }
@@ -64,7 +107,12 @@ impl GenericCommand for UnpackArchiveCommand {
     log::info!("Unpack source: {}", path.display());
     log::info!("Unpack destination: {}", destination.display());
 
-    let archive_project: Box<ArchiveProject> = Box::new(ArchiveProject::new(path).unwrap());
+    let archive_project: Box<ArchiveProject> =
+      Box::new(if path.extension().is_some_and(|it| it == "ltx") {
+        ArchiveProject::from_fsgame(path)?
+      } else {
+        ArchiveProject::new(path)?
+      });
 
     log::info!(
       "Summary: {} archive(s), {} file(s), {:.3} MB compressed, {:.3} MB real",
@@ -76,8 +124,21 @@ impl GenericCommand for UnpackArchiveCommand {
 
     log::info!("Unpacking files, parallel {parallel}");
 
-    let result: ArchiveUnpackResult =
-      Runtime::new()?.block_on(archive_project.unpack_parallel(&destination, parallel))?;
+    let result: ArchiveUnpackResult = Runtime::new()?
+      .block_on(archive_project.unpack_parallel_opt(&destination, parallel, &options))?;
+
+    if options.is_dry_run {
+      for file in &result.files {
+        println!("{file}");
+      }
+    }
+
+    log::info!(
+      "Selected {} file(s), {:.3} MB, skipped {} up-to-date file(s)",
+      result.files.len(),
+      (result.unpacked_size as f64) / 1024.0 / 1024.0,
+      result.skipped_files,
+    );
 
     log::info!(
       "Unpacked archive, took {} sec (preparation {} sec, unpack {} sec)",
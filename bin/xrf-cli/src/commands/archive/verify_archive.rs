@@ -0,0 +1,73 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgMatches, Command};
+use std::path::PathBuf;
+use xray_archive::{ArchiveProject, ArchiveVerifyResult};
+use xray_error::XRayError;
+
+#[derive(Default)]
+pub struct VerifyArchiveCommand;
+
+impl GenericCommand for VerifyArchiveCommand {
+  fn name(&self) -> &'static str {
+    "verify-archive"
+  }
+
+  /// Create command to verify archive.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to verify CRC and size of every file in provided *.db archives")
+      .arg(
+        Arg::new("path")
+          .help("Path to *.db file, folder with archives or fsgame.ltx")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+  }
+
+  /// Verify xray engine database archive.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    log::info!("Verify source: {}", path.display());
+
+    let archive_project: Box<ArchiveProject> =
+      Box::new(if path.extension().is_some_and(|it| it == "ltx") {
+        ArchiveProject::from_fsgame(path)?
+      } else {
+        ArchiveProject::new(path)?
+      });
+
+    let result: ArchiveVerifyResult = archive_project.verify()?;
+
+    for failure in &result.failures {
+      println!(
+        "Corrupted: {} -> {} ({})",
+        failure.archive, failure.name, failure.reason
+      );
+    }
+
+    log::info!(
+      "Verified {} archive(s), {} file(s), {:.3} MB, took {} sec",
+      result.archives.len(),
+      result.checked_files,
+      (result.checked_size as f64) / 1024.0 / 1024.0,
+      result.duration as f64 / 1000.0,
+    );
+
+    if result.is_valid() {
+      Ok(())
+    } else {
+      Err(
+        XRayError::new_verify_error(format!(
+          "Failed to verify archives, got {} corrupted files",
+          result.failures.len()
+        ))
+        .into(),
+      )
+    }
+  }
+}
@@ -0,0 +1,81 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use std::io;
+use std::path::PathBuf;
+use xray_chunk::{ChunkDiff, ChunkTreeOptions, XRayByteOrder};
+
+#[derive(Default)]
+pub struct DiffChunksCommand;
+
+impl GenericCommand for DiffChunksCommand {
+  fn name(&self) -> &'static str {
+    "diff-chunks"
+  }
+
+  /// Create command for structural comparison of chunked files.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to compare chunks of two xray binary files")
+      .arg(
+        Arg::new("path")
+          .help("Path to original chunked file")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("target")
+          .help("Path to compared chunked file")
+          .short('t')
+          .long("target")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("json")
+          .help("Print differences as JSON")
+          .long("json")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("depth")
+          .help("Maximal nesting depth of compared chunks")
+          .long("depth")
+          .required(false)
+          .value_parser(value_parser!(usize)),
+      )
+  }
+
+  /// Print differences of chunked files.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let target: &PathBuf = matches
+      .get_one::<PathBuf>("target")
+      .expect("Expected valid target path to be provided");
+
+    let defaults: ChunkTreeOptions = ChunkTreeOptions::default();
+    let options: ChunkTreeOptions = ChunkTreeOptions {
+      max_depth: *matches
+        .get_one::<usize>("depth")
+        .unwrap_or(&defaults.max_depth),
+      ..defaults
+    };
+
+    let diff: ChunkDiff = ChunkDiff::read_from_paths::<XRayByteOrder, _>(path, target, &options)?;
+
+    if matches.get_flag("json") {
+      diff.write_json(&mut io::stdout().lock())?;
+    } else {
+      println!("Chunks diff of {} and {}", path.display(), target.display());
+
+      diff.write_text(&mut io::stdout().lock())?;
+    }
+
+    Ok(())
+  }
+}
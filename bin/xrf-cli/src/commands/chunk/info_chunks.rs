@@ -0,0 +1,79 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use std::io;
+use std::path::PathBuf;
+use xray_chunk::{ChunkTree, ChunkTreeOptions, XRayByteOrder};
+
+#[derive(Default)]
+pub struct InfoChunksCommand;
+
+impl GenericCommand for InfoChunksCommand {
+  fn name(&self) -> &'static str {
+    "info-chunks"
+  }
+
+  /// Create command for printing chunks structure of any xray file.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to print guessed chunks tree of provided xray binary file")
+      .arg(
+        Arg::new("path")
+          .help("Path to chunked file")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("json")
+          .help("Print chunks tree as JSON")
+          .long("json")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("depth")
+          .help("Maximal nesting depth of inspected chunks")
+          .long("depth")
+          .required(false)
+          .value_parser(value_parser!(usize)),
+      )
+      .arg(
+        Arg::new("preview")
+          .help("Count of bytes in leaf chunks data preview")
+          .long("preview")
+          .required(false)
+          .value_parser(value_parser!(usize)),
+      )
+  }
+
+  /// Print chunks tree of file.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let defaults: ChunkTreeOptions = ChunkTreeOptions::default();
+    let options: ChunkTreeOptions = ChunkTreeOptions {
+      max_depth: *matches
+        .get_one::<usize>("depth")
+        .unwrap_or(&defaults.max_depth),
+      preview_size: *matches
+        .get_one::<usize>("preview")
+        .unwrap_or(&defaults.preview_size),
+      ..defaults
+    };
+
+    let tree: ChunkTree = ChunkTree::read_from_path::<XRayByteOrder, _>(path, &options)?;
+
+    if matches.get_flag("json") {
+      tree.write_json(&mut io::stdout().lock())?;
+    } else {
+      println!("Chunks of file {}", path.display());
+
+      tree.write_text(&mut io::stdout().lock())?;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod diff_chunks;
+pub(crate) mod info_chunks;
@@ -0,0 +1,84 @@
+use crate::generic_command::{CommandResult, GenericCommand};
+use clap::{value_parser, Arg, ArgMatches, Command};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use xray_db::{SpawnBuilder, SpawnFile, XRayByteOrder};
+
+#[derive(Default)]
+pub struct BuildSpawnCommand;
+
+impl GenericCommand for BuildSpawnCommand {
+  fn name(&self) -> &'static str {
+    "build-spawn"
+  }
+
+  /// Create command for build of spawn file.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to build all.spawn from level files and game graph")
+      .arg(
+        Arg::new("graph")
+          .help("Path to game.graph file")
+          .short('g')
+          .long("graph")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("levels")
+          .help("Path to levels folder containing level.spawn, level.game and level.ai files")
+          .short('l')
+          .long("levels")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help("Path to resulting *.spawn file")
+          .short('d')
+          .long("dest")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+  }
+
+  /// Build *.spawn file from levels of provided game graph.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let graph: &PathBuf = matches
+      .get_one::<_>("graph")
+      .expect("Expected valid game graph path to be provided");
+
+    let levels: &PathBuf = matches
+      .get_one::<_>("levels")
+      .expect("Expected valid levels path to be provided");
+
+    let destination: &PathBuf = matches
+      .get_one::<_>("dest")
+      .expect("Expected valid output path to be provided");
+
+    log::info!(
+      "Starting building spawn file from graph {}",
+      graph.display()
+    );
+    log::info!("Reading levels from {}", levels.display());
+
+    let started_at: Instant = Instant::now();
+    let builder: SpawnBuilder = SpawnBuilder::read_from_path::<XRayByteOrder, _>(graph, levels)?;
+    let read_duration: Duration = started_at.elapsed();
+
+    let spawn_file: Box<SpawnFile> = Box::new(builder.build::<XRayByteOrder>()?);
+    let build_duration: Duration = started_at.elapsed() - read_duration;
+
+    spawn_file.write_to_path::<XRayByteOrder, _>(destination)?;
+
+    let write_duration: Duration = started_at.elapsed() - read_duration - build_duration;
+
+    log::info!("Read levels took: {}ms", read_duration.as_millis());
+    log::info!("Build spawn file took: {}ms", build_duration.as_millis());
+    log::info!("Write spawn file took: {}ms", write_duration.as_millis());
+
+    log::info!("Spawn file was built into {}", destination.display());
+
+    Ok(())
+  }
+}
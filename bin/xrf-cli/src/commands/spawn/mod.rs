@@ -1,3 +1,4 @@
+pub(crate) mod build_spawn;
 pub(crate) mod info_spawn;
 pub(crate) mod pack_spawn;
 pub(crate) mod repack_spawn;
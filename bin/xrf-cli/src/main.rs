@@ -1,8 +1,16 @@
 pub(crate) mod commands;
 pub(crate) mod generic_command;
 
+use commands::archive::diff_archive::DiffArchiveCommand;
+use commands::archive::manifest_archive::ManifestArchiveCommand;
+use commands::archive::pack_archive::PackArchiveCommand;
+use commands::archive::patch_archive::PatchArchiveCommand;
 use commands::archive::unpack_archive::UnpackArchiveCommand;
+use commands::archive::verify_archive::VerifyArchiveCommand;
+use commands::chunk::diff_chunks::DiffChunksCommand;
+use commands::chunk::info_chunks::InfoChunksCommand;
 use commands::ltx::verify_ltx::VerifyLtxCommand;
+use commands::spawn::build_spawn::BuildSpawnCommand;
 use commands::spawn::info_spawn::InfoSpawnCommand;
 use commands::spawn::pack_spawn::PackSpawnFileCommand;
 use commands::spawn::repack_spawn::RepackSpawnCommand;
@@ -75,7 +83,15 @@ async fn main() -> Result<(), Box<dyn Error>> {
 pub fn setup_subcommands() -> Vec<Box<dyn GenericCommand>> {
   vec![
     // Archive:
+    DiffArchiveCommand::new_box(),
+    ManifestArchiveCommand::new_box(),
+    PackArchiveCommand::new_box(),
+    PatchArchiveCommand::new_box(),
     UnpackArchiveCommand::new_box(),
+    VerifyArchiveCommand::new_box(),
+    // Chunks:
+    InfoChunksCommand::new_box(),
+    DiffChunksCommand::new_box(),
     // Gamedata:
     VerifyGamedataCommand::new_box(),
     // LTX:
@@ -93,6 +109,7 @@ pub fn setup_subcommands() -> Vec<Box<dyn GenericCommand>> {
     UnpackParticlesCommand::new_box(),
     VerifyParticlesFileCommand::new_box(),
     // Spawn:
+    BuildSpawnCommand::new_box(),
     InfoSpawnCommand::new_box(),
     PackSpawnFileCommand::new_box(),
     RepackSpawnCommand::new_box(),
@@ -1,3 +1,10 @@
+export type TArchiveVersion =
+  | "build2215"
+  | "build2945"
+  | "shadowOfChernobylRussian"
+  | "shadowOfChernobylWorldwide"
+  | "xdb";
+
 export interface IArchiveFileReplicationDescriptor {
   crc: number;
   destination: string;
@@ -6,6 +13,7 @@ export interface IArchiveFileReplicationDescriptor {
   sizeCompressed: number;
   sizeReal: number;
   source: string;
+  version: TArchiveVersion;
 }
 
 export interface IArchiveFileDescriptor {
@@ -20,6 +28,7 @@ export interface IArchiveDescriptor {
   files: Record<string, IArchiveFileDescriptor>;
   outputRootPath: string;
   path: string;
+  version: TArchiveVersion;
 }
 
 export interface IArchivesProject {
@@ -2,7 +2,9 @@ export interface IArchiveUnpackResult {
   archives: Array<string>;
   destination: string;
   duration: number;
+  files: Array<string>;
   prepareDuration: number;
+  skippedFiles: number;
   unpackDuration: number;
   unpackedSize: number;
 }
@@ -4,6 +4,7 @@ export enum EArchivesEditorCommand {
   HAS_ARCHIVES_PROJECT = "plugin:archives-editor|has_archives_project",
   OPEN_ARCHIVES_PROJECT = "plugin:archives-editor|open_archives_project",
   READ_ARCHIVE_FILE = "plugin:archives-editor|read_archive_file",
+  READ_ARCHIVE_FILE_BYTES = "plugin:archives-editor|read_archive_file_bytes",
   UNPACK_ARCHIVES_PATH = "plugin:archives-editor|unpack_archives_path",
 }
 
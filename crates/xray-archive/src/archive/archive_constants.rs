@@ -1,2 +1,19 @@
 pub const CHUNK_ID_COMPRESSED_MASK: u32 = 1 << 31;
 pub const CHUNK_ID_MASK: u32 = !(1 << 31);
+
+pub const ARCHIVE_CHUNK_ID_DATA: u32 = 0;
+pub const ARCHIVE_CHUNK_ID_FILES: u32 = 1;
+pub const ARCHIVE_CHUNK_ID_HEADER: u32 = 666;
+
+/// Upper bound of LZH chunks decompression ratio, guards against decoding of garbage data.
+pub const ARCHIVE_CHUNK_MAX_COMPRESSION_RATIO: u64 = 256;
+
+/// Entry point of official patch archives mounted with `$arch_dir_patches$`.
+pub const ARCHIVE_PATCH_ENTRY_POINT: &str = "$fs_root$\\gamedata\\";
+
+pub const ARCHIVE_PATH_SEPARATOR: char = '\\';
+pub const ARCHIVE_FILE_DESCRIPTOR_BASE_SIZE: u16 = 16;
+pub const ARCHIVE_FILE_NAME_MAX_SIZE: usize = 520;
+
+/// Extensions of files that are already compressed and should be stored as-is.
+pub const ARCHIVE_UNCOMPRESSED_EXTENSIONS: &[&str] = &["ogg", "ogm"];
@@ -1,4 +1,6 @@
 use crate::archive::archive_file_descriptor::ArchiveFileDescriptor;
+use crate::archive::archive_version::ArchiveVersion;
+use regex::Regex;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -6,9 +8,11 @@ use std::path::{Path, PathBuf};
 #[derive(Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArchiveDescriptor {
+  pub entry_point: Option<String>,
   pub files: HashMap<String, ArchiveFileDescriptor>,
   pub output_root_path: PathBuf,
   pub path: PathBuf,
+  pub version: ArchiveVersion,
 }
 
 impl ArchiveDescriptor {
@@ -22,6 +26,15 @@ impl ArchiveDescriptor {
       }
     }
   }
+
+  /// Get output root path of archive entry point, leading `$alias$\` of entry point is dropped.
+  pub fn get_entry_point_root_path(entry_point: &str) -> PathBuf {
+    Regex::new(r"^\$\w+?\$\\")
+      .unwrap()
+      .replace(entry_point, "")
+      .to_string()
+      .into()
+  }
 }
 
 impl ArchiveDescriptor {
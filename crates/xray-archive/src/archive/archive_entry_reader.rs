@@ -0,0 +1,181 @@
+use crate::archive::archive_file_descriptor::ArchiveFileReplicationDescriptor;
+use crate::archive::archive_version::ArchiveVersion;
+use crate::archive::reader::ArchiveReader;
+use crc32fast::Hasher;
+use minilzo_rs::LZO;
+use std::fs::File;
+use std::io;
+use std::io::{Cursor, ErrorKind, Read, Seek, SeekFrom, Take};
+use xray_chunk::{ChunkDataSource, MmapChunkData, MmapChunkDataSource};
+use xray_error::{XRayError, XRayResult};
+use xray_utils::assert_equal;
+
+/// Reader of single archive entry.
+/// Compressed entries are decompressed in memory, raw entries are streamed from the archive file
+/// and their CRC is verified once the end of entry is reached.
+pub enum ArchiveEntryReader {
+  Raw {
+    crc: Option<u32>,
+    hasher: Hasher,
+    name: String,
+    reader: Take<File>,
+  },
+  Decompressed(Cursor<Vec<u8>>),
+}
+
+impl ArchiveEntryReader {
+  pub fn from_descriptor(descriptor: &ArchiveFileReplicationDescriptor) -> XRayResult<Self> {
+    let mut source_file: File = File::open(descriptor.source.as_path())?;
+
+    source_file.seek(SeekFrom::Start(descriptor.offset as u64))?;
+
+    if descriptor.size_real == descriptor.size_compressed {
+      Ok(Self::Raw {
+        crc: Some(descriptor.crc).filter(|_| descriptor.version.is_with_crc()),
+        hasher: Hasher::new(),
+        name: descriptor.name.clone(),
+        reader: source_file.take(descriptor.size_real as u64),
+      })
+    } else {
+      let mut buf: Vec<u8> = vec![0u8; descriptor.size_compressed as usize];
+
+      source_file.read_exact(&mut buf)?;
+
+      Ok(Self::Decompressed(Cursor::new(Self::decompress(
+        &LZO::init().map_err(|error| {
+          XRayError::new_unexpected_error(format!("Failed to initialize LZO: {:?}", error))
+        })?,
+        &buf,
+        descriptor,
+      )?)))
+    }
+  }
+
+  /// Open archive entry as chunk data source without temporary files.
+  /// Raw entries are memory-mapped slices of the archive file, compressed entries are
+  /// decompressed in memory. CRC of the data is verified when archive version stores it.
+  pub fn open_chunk_source(
+    descriptor: &ArchiveFileReplicationDescriptor,
+  ) -> XRayResult<MmapChunkDataSource> {
+    if descriptor.size_real != descriptor.size_compressed {
+      let mut buf: Vec<u8> = Vec::new();
+
+      Self::from_descriptor(descriptor)?.read_to_end(&mut buf)?;
+
+      return Ok(MmapChunkDataSource::from_data(MmapChunkData::Owned(buf)));
+    }
+
+    let start: u64 = descriptor.offset as u64;
+    let end: u64 = start + descriptor.size_real as u64;
+    let source: MmapChunkDataSource = MmapChunkDataSource::from_path(descriptor.source.as_path())?;
+
+    if source.as_bytes().len() < end as usize {
+      return Err(XRayError::new_read_error(format!(
+        "Archive entry '{}' is truncated",
+        descriptor.name
+      )));
+    }
+
+    let source: MmapChunkDataSource = source.slice(start..end);
+
+    if descriptor.version.is_with_crc() {
+      assert_equal(
+        descriptor.crc,
+        crc32fast::hash(source.as_bytes()),
+        "CRCs do not match",
+      )?;
+    }
+
+    Ok(source)
+  }
+
+  /// Decompress entry data and verify its CRC when archive version stores it.
+  pub fn decompress(
+    lzo: &LZO,
+    data: &[u8],
+    descriptor: &ArchiveFileReplicationDescriptor,
+  ) -> XRayResult<Vec<u8>> {
+    let decompressed: Vec<u8> =
+      Self::decompress_data(lzo, data, descriptor.version, descriptor.size_real).map_err(
+        |error| {
+          XRayError::new_read_error(format!(
+            "Failed to decompress archive entry '{}': {}",
+            descriptor.name, error
+          ))
+        },
+      )?;
+
+    assert_equal(
+      descriptor.size_real as usize,
+      decompressed.len(),
+      "Decompressed entry size does not match",
+    )?;
+
+    if descriptor.version.is_with_crc() {
+      assert_equal(
+        descriptor.crc,
+        crc32fast::hash(&decompressed),
+        "CRCs do not match",
+      )?;
+    }
+
+    Ok(decompressed)
+  }
+
+  /// Decompress entry data with compression method of archive version, without any checks.
+  pub fn decompress_data(
+    lzo: &LZO,
+    data: &[u8],
+    version: ArchiveVersion,
+    size_real: u32,
+  ) -> XRayResult<Vec<u8>> {
+    if version.is_lzo_compressed() {
+      lzo
+        .decompress_safe(data, size_real as usize)
+        .map_err(|error| XRayError::new_read_error(format!("LZO error {:?}", error)))
+    } else {
+      ArchiveReader::decompress_chunk(data)
+    }
+  }
+}
+
+impl Read for ArchiveEntryReader {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    match self {
+      Self::Raw {
+        crc,
+        hasher,
+        name,
+        reader,
+      } => {
+        let read: usize = reader.read(buf)?;
+
+        hasher.update(&buf[..read]);
+
+        if read == 0 && !buf.is_empty() {
+          if reader.limit() > 0 {
+            return Err(io::Error::new(
+              ErrorKind::UnexpectedEof,
+              format!("Archive entry '{}' is truncated", name),
+            ));
+          }
+
+          let actual: u32 = hasher.clone().finalize();
+
+          if let Some(crc) = crc.filter(|crc| *crc != actual) {
+            return Err(io::Error::new(
+              ErrorKind::InvalidData,
+              format!(
+                "Archive entry '{}' CRCs do not match, expected {}, got {}",
+                name, crc, actual
+              ),
+            ));
+          }
+        }
+
+        Ok(read)
+      }
+      Self::Decompressed(cursor) => cursor.read(buf),
+    }
+  }
+}
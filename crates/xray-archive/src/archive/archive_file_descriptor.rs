@@ -1,7 +1,8 @@
+use crate::archive::archive_version::ArchiveVersion;
 use serde::Serialize;
 use std::path::{Path, PathBuf};
 
-#[derive(Clone, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArchiveFileDescriptor {
   pub crc: u32,
@@ -11,7 +12,7 @@ pub struct ArchiveFileDescriptor {
   pub size_real: u32,
 }
 
-#[derive(Clone, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArchiveFileReplicationDescriptor {
   pub crc: u32,
@@ -21,6 +22,7 @@ pub struct ArchiveFileReplicationDescriptor {
   pub offset: u32,
   pub size_compressed: u32,
   pub size_real: u32,
+  pub version: ArchiveVersion,
 }
 
 impl ArchiveFileReplicationDescriptor {
@@ -28,6 +30,7 @@ impl ArchiveFileReplicationDescriptor {
     descriptor: &ArchiveFileDescriptor,
     source: &Path,
     destination: &Path,
+    version: ArchiveVersion,
   ) -> Self {
     Self {
       crc: descriptor.crc,
@@ -37,6 +40,7 @@ impl ArchiveFileReplicationDescriptor {
       offset: descriptor.offset,
       size_compressed: descriptor.size_compressed,
       size_real: descriptor.size_real,
+      version,
     }
   }
 }
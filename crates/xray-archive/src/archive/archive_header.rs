@@ -1,9 +1,12 @@
 use crate::archive::archive_file_descriptor::ArchiveFileDescriptor;
+use crate::archive::archive_version::ArchiveVersion;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
 pub struct ArchiveHeader {
   pub archive_path: PathBuf,
+  pub entry_point: Option<String>,
   pub output_root_path: PathBuf,
   pub files: HashMap<String, ArchiveFileDescriptor>,
+  pub version: ArchiveVersion,
 }
@@ -0,0 +1,123 @@
+/// Key of engine trivial encryptor used for Shadow of Chernobyl archives headers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArchiveScramblerKey {
+  pub table_iterations: u32,
+  pub table_seed: u32,
+  pub encrypt_seed: u32,
+}
+
+pub const ARCHIVE_SCRAMBLER_KEY_RUSSIAN: ArchiveScramblerKey = ArchiveScramblerKey {
+  table_iterations: 2048,
+  table_seed: 20091958,
+  encrypt_seed: 20031955,
+};
+
+pub const ARCHIVE_SCRAMBLER_KEY_WORLDWIDE: ArchiveScramblerKey = ArchiveScramblerKey {
+  table_iterations: 1024,
+  table_seed: 6011979,
+  encrypt_seed: 24031979,
+};
+
+/// Stream cipher of archive headers: bytes are xored with pseudo-random sequence and substituted
+/// with shuffled alphabet.
+pub struct ArchiveScrambler {
+  alphabet: [u8; 256],
+  alphabet_back: [u8; 256],
+  encrypt_seed: u32,
+}
+
+impl ArchiveScrambler {
+  pub fn new(key: &ArchiveScramblerKey) -> Self {
+    let mut alphabet: [u8; 256] = [0u8; 256];
+    let mut alphabet_back: [u8; 256] = [0u8; 256];
+    let mut random: ArchiveScramblerRandom = ArchiveScramblerRandom::new(key.table_seed);
+
+    for (index, value) in alphabet.iter_mut().enumerate() {
+      *value = index as u8;
+    }
+
+    for _ in 0..key.table_iterations {
+      let first: usize = random.next(256) as usize;
+      let mut second: usize = random.next(256) as usize;
+
+      while first == second {
+        second = random.next(256) as usize;
+      }
+
+      alphabet.swap(first, second);
+    }
+
+    for (index, value) in alphabet.iter().enumerate() {
+      alphabet_back[*value as usize] = index as u8;
+    }
+
+    Self {
+      alphabet,
+      alphabet_back,
+      encrypt_seed: key.encrypt_seed,
+    }
+  }
+
+  pub fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+    let mut random: ArchiveScramblerRandom = ArchiveScramblerRandom::new(self.encrypt_seed);
+
+    data
+      .iter()
+      .map(|it| self.alphabet_back[*it as usize] ^ random.next(256) as u8)
+      .collect()
+  }
+
+  pub fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+    let mut random: ArchiveScramblerRandom = ArchiveScramblerRandom::new(self.encrypt_seed);
+
+    data
+      .iter()
+      .map(|it| self.alphabet[(*it ^ random.next(256) as u8) as usize])
+      .collect()
+  }
+}
+
+/// Linear congruential generator matching engine `random32`.
+struct ArchiveScramblerRandom {
+  seed: u32,
+}
+
+impl ArchiveScramblerRandom {
+  fn new(seed: u32) -> Self {
+    Self { seed }
+  }
+
+  fn next(&mut self, range: u32) -> u32 {
+    self.seed = self.seed.wrapping_mul(0x08088405).wrapping_add(1);
+
+    ((self.seed as u64 * range as u64) >> 32) as u32
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_scrambler::{
+    ArchiveScrambler, ARCHIVE_SCRAMBLER_KEY_RUSSIAN, ARCHIVE_SCRAMBLER_KEY_WORLDWIDE,
+  };
+
+  #[test]
+  fn test_encrypt_decrypt() {
+    let data: Vec<u8> = (0..=255u8).cycle().take(1024).collect();
+
+    for key in [
+      ARCHIVE_SCRAMBLER_KEY_RUSSIAN,
+      ARCHIVE_SCRAMBLER_KEY_WORLDWIDE,
+    ] {
+      let scrambler: ArchiveScrambler = ArchiveScrambler::new(&key);
+      let encrypted: Vec<u8> = scrambler.encrypt(&data);
+
+      assert_ne!(encrypted, data);
+      assert_eq!(scrambler.decrypt(&encrypted), data);
+    }
+
+    assert_ne!(
+      ArchiveScrambler::new(&ARCHIVE_SCRAMBLER_KEY_RUSSIAN).encrypt(&data),
+      ArchiveScrambler::new(&ARCHIVE_SCRAMBLER_KEY_WORLDWIDE).encrypt(&data)
+    );
+  }
+}
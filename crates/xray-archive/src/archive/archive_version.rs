@@ -0,0 +1,55 @@
+use crate::archive::archive_scrambler::{
+  ArchiveScramblerKey, ARCHIVE_SCRAMBLER_KEY_RUSSIAN, ARCHIVE_SCRAMBLER_KEY_WORLDWIDE,
+};
+use serde::Serialize;
+
+/// Layout version of archive file descriptors table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveVersion {
+  /// Build 2215 era: null-terminated names, no CRC, LZH compressed entries.
+  Build2215,
+  /// Build 2945 era: null-terminated names with CRC, LZH compressed entries.
+  Build2945,
+  /// Shadow of Chernobyl russian release, descriptors table is scrambled.
+  ShadowOfChernobylRussian,
+  /// Shadow of Chernobyl worldwide release, descriptors table is scrambled.
+  ShadowOfChernobylWorldwide,
+  /// Clear Sky and Call of Pripyat archives.
+  #[default]
+  Xdb,
+}
+
+impl ArchiveVersion {
+  /// Versions in order of detection, most common layouts are checked first.
+  pub const DETECTION_ORDER: [ArchiveVersion; 5] = [
+    Self::Xdb,
+    Self::ShadowOfChernobylWorldwide,
+    Self::ShadowOfChernobylRussian,
+    Self::Build2945,
+    Self::Build2215,
+  ];
+
+  /// Whether descriptors table contains CRC32 of entries data.
+  pub fn is_with_crc(&self) -> bool {
+    !matches!(self, Self::Build2215)
+  }
+
+  /// Whether entries are compressed with LZO, older builds use LZH.
+  pub fn is_lzo_compressed(&self) -> bool {
+    !matches!(self, Self::Build2215 | Self::Build2945)
+  }
+
+  /// Whether descriptors table uses null-terminated names layout of early builds.
+  pub fn is_legacy_table(&self) -> bool {
+    matches!(self, Self::Build2215 | Self::Build2945)
+  }
+
+  pub fn get_scrambler_key(&self) -> Option<&'static ArchiveScramblerKey> {
+    match self {
+      Self::ShadowOfChernobylRussian => Some(&ARCHIVE_SCRAMBLER_KEY_RUSSIAN),
+      Self::ShadowOfChernobylWorldwide => Some(&ARCHIVE_SCRAMBLER_KEY_WORLDWIDE),
+      _ => None,
+    }
+  }
+}
@@ -0,0 +1,35 @@
+use crate::archive::archive_constants::ARCHIVE_UNCOMPRESSED_EXTENSIONS;
+
+#[derive(Clone, Debug)]
+pub struct ArchiveWriteOptions {
+  /// Value of `[header] entry_point`, header chunk is not written when it is not provided.
+  pub entry_point: Option<String>,
+  /// Whether files should be LZO-compressed when it reduces their size.
+  pub is_compressed: bool,
+  /// Extensions of files that are always stored without compression.
+  pub uncompressed_extensions: Vec<String>,
+}
+
+impl Default for ArchiveWriteOptions {
+  fn default() -> Self {
+    Self {
+      entry_point: None,
+      is_compressed: true,
+      uncompressed_extensions: ARCHIVE_UNCOMPRESSED_EXTENSIONS
+        .iter()
+        .map(|it| String::from(*it))
+        .collect(),
+    }
+  }
+}
+
+impl ArchiveWriteOptions {
+  pub fn can_compress(&self, name: &str) -> bool {
+    self.is_compressed
+      && !self.uncompressed_extensions.iter().any(|extension| {
+        name
+          .rsplit_once('.')
+          .is_some_and(|(_, it)| it.eq_ignore_ascii_case(extension))
+      })
+  }
+}
@@ -1,6 +1,11 @@
 pub(crate) mod archive_constants;
 pub(crate) mod archive_descriptor;
+pub(crate) mod archive_entry_reader;
 pub(crate) mod archive_file_descriptor;
 pub(crate) mod archive_header;
+pub(crate) mod archive_scrambler;
+pub(crate) mod archive_version;
+pub(crate) mod archive_write_options;
 pub(crate) mod io;
 pub(crate) mod reader;
+pub(crate) mod writer;
@@ -1,7 +1,12 @@
-use crate::archive::archive_constants::{CHUNK_ID_COMPRESSED_MASK, CHUNK_ID_MASK};
+use crate::archive::archive_constants::{
+  ARCHIVE_CHUNK_MAX_COMPRESSION_RATIO, ARCHIVE_FILE_DESCRIPTOR_BASE_SIZE,
+  ARCHIVE_FILE_NAME_MAX_SIZE, CHUNK_ID_COMPRESSED_MASK, CHUNK_ID_MASK,
+};
 use crate::archive::archive_descriptor::ArchiveDescriptor;
 use crate::archive::archive_file_descriptor::ArchiveFileDescriptor;
 use crate::archive::archive_header::ArchiveHeader;
+use crate::archive::archive_scrambler::ArchiveScrambler;
+use crate::archive::archive_version::ArchiveVersion;
 use crate::types::XRayByteOrder;
 use byteorder::ReadBytesExt;
 use delharc::decode::{Decoder, Lh1Decoder};
@@ -21,7 +26,6 @@ pub struct ArchiveReader {
   pub file: File,
   pub section_regex: Regex,
   pub variable_regex: Regex,
-  pub root_regex: Regex,
   pub encoding: XRayEncoding,
 }
 
@@ -33,7 +37,6 @@ impl ArchiveReader {
         encoding,
         file,
         path: path.as_ref().into(),
-        root_regex: Regex::new(r"^\$\w+?\$\\").unwrap(),
         section_regex: Regex::new(r"^.*\[(?P<name>\w*)\]$").unwrap(),
         variable_regex: Regex::new(r"^\s*(?P<name>\w+)\s*=\s*(?P<value>.+)\s*$").unwrap(),
       }),
@@ -53,20 +56,27 @@ impl ArchiveReader {
 
 impl ArchiveReader {
   pub fn read_archive(&mut self) -> XRayResult<ArchiveDescriptor> {
-    let header: ArchiveHeader = self.read_archive_header()?.unwrap();
+    let header: ArchiveHeader = self.read_archive_header()?.ok_or_else(|| {
+      XRayError::new_read_error(format!(
+        "Archive {} has no file descriptors chunk",
+        self.path.display()
+      ))
+    })?;
 
     Ok(ArchiveDescriptor {
+      entry_point: header.entry_point,
       files: header.files,
       output_root_path: header.output_root_path,
       path: header.archive_path,
+      version: header.version,
     })
   }
 }
 
 impl ArchiveReader {
   fn read_archive_header(&mut self) -> XRayResult<Option<ArchiveHeader>> {
-    let mut file_descriptors = None;
-    let mut root_path: String = String::new();
+    let mut file_descriptors_chunk: Option<(Vec<u8>, bool)> = None;
+    let mut entry_point: Option<String> = None;
 
     loop {
       let raw_chunk_id: u32 = match self.file.read_u32::<XRayByteOrder>() {
@@ -86,23 +96,23 @@ impl ArchiveReader {
       let compressed: bool = (raw_chunk_id & CHUNK_ID_COMPRESSED_MASK) != 0;
 
       match chunk_id {
-        // File descriptors list
+        // File descriptors list, decoded once archive version is detected.
         0x1 | 0x86 => {
-          let chunk_data: Vec<u8> = Self::read_chunk(&mut self.file, chunk_usize, compressed)?;
-          let mut reader: Cursor<&[u8]> = Cursor::new(chunk_data.as_slice());
+          let mut chunk_data: Vec<u8> = vec![0u8; chunk_usize];
 
-          file_descriptors = Some(
-            Self::read_file_descriptors(&mut reader, self.encoding)
-              .expect("Expecting a valid file descriptors chunk"),
-          );
+          self.file.read_exact(&mut chunk_data)?;
+
+          file_descriptors_chunk = Some((chunk_data, compressed));
         }
         // Metadata header
         666 | 1337 => {
           let chunk_data: Vec<u8> = Self::read_chunk(&mut self.file, chunk_usize, compressed)?;
 
-          root_path = self
-            .read_root_path(chunk_data.as_slice())?
-            .expect("[header].entry_point must be specified in header chunk when it exists");
+          entry_point = Some(
+            self
+              .read_entry_point(chunk_data.as_slice())?
+              .expect("[header].entry_point must be specified in header chunk when it exists"),
+          );
         }
         _ => {
           // Skip
@@ -111,15 +121,84 @@ impl ArchiveReader {
       }
     }
 
-    Ok(file_descriptors.map(|file_descriptors| ArchiveHeader {
+    let (chunk_data, compressed): (Vec<u8>, bool) = match file_descriptors_chunk {
+      Some(chunk) => chunk,
+      None => return Ok(None),
+    };
+
+    let archive_size: u64 = self.file.metadata()?.len();
+    let (version, files): (ArchiveVersion, HashMap<String, ArchiveFileDescriptor>) =
+      self.read_file_descriptors_chunk(&chunk_data, compressed, archive_size)?;
+
+    let root_path: PathBuf = match &entry_point {
+      Some(entry_point) => ArchiveDescriptor::get_entry_point_root_path(entry_point),
+      None => PathBuf::new(),
+    };
+
+    Ok(Some(ArchiveHeader {
       archive_path: self.path.clone(),
-      output_root_path: root_path.into(),
-      files: file_descriptors,
+      entry_point,
+      output_root_path: root_path,
+      files,
+      version,
     }))
   }
 
+  /// Detect archive version and read file descriptors.
+  /// Each known layout is tried in turn, descriptors are accepted only when the whole table is
+  /// parsed and every entry fits into the archive file.
+  fn read_file_descriptors_chunk(
+    &self,
+    chunk_data: &[u8],
+    compressed: bool,
+    archive_size: u64,
+  ) -> XRayResult<(ArchiveVersion, HashMap<String, ArchiveFileDescriptor>)> {
+    if chunk_data.is_empty() {
+      return Ok((ArchiveVersion::default(), HashMap::new()));
+    }
+
+    for version in ArchiveVersion::DETECTION_ORDER {
+      let data: Vec<u8> = match version.get_scrambler_key() {
+        Some(key) => ArchiveScrambler::new(key).decrypt(chunk_data),
+        None => chunk_data.to_vec(),
+      };
+
+      let data: Vec<u8> = if compressed {
+        match Self::decompress_chunk(&data) {
+          Ok(data) => data,
+          Err(_) => continue,
+        }
+      } else {
+        data
+      };
+
+      let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+
+      let files: XRayResult<HashMap<String, ArchiveFileDescriptor>> = if version.is_legacy_table() {
+        Self::read_legacy_file_descriptors(&mut reader, version, self.encoding)
+      } else {
+        Self::read_file_descriptors(&mut reader, self.encoding)
+      };
+
+      if let Ok(files) = files {
+        if !files.is_empty()
+          && files.values().all(|it| {
+            !it.name.is_empty() && it.offset as u64 + it.size_compressed as u64 <= archive_size
+          })
+        {
+          return Ok((version, files));
+        }
+      }
+    }
+
+    Err(XRayError::new_read_error(format!(
+      "Unable to detect version of archive {}, file descriptors chunk cannot be parsed",
+      self.path.display()
+    )))
+  }
+
   // Just Result instead of optional?
-  fn read_root_path(&self, chunk_data: &[u8]) -> XRayResult<Option<String>> {
+  fn read_entry_point(&self, chunk_data: &[u8]) -> XRayResult<Option<String>> {
     // let section_regex= Regex::new(r"^.*\[(?P<name>\w*)\]$").unwrap();
     // let variable_regex= Regex::new(r"^\s*(?P<name>\w+)\s*=\s*(?P<value>.+)\s*$").unwrap();
     // let root_regex = Regex::new(r"^\$\w+?\$\\").unwrap();
@@ -134,13 +213,7 @@ impl ArchiveReader {
 
           if let Some(captures) = variable_captures {
             if &captures["name"] == "entry_point" {
-              let entry_point = captures["value"].to_string();
-              return Ok(Some(
-                self
-                  .root_regex
-                  .replace(entry_point.as_str(), "")
-                  .to_string(),
-              ));
+              return Ok(Some(captures["value"].trim().to_string()));
             }
           }
         }
@@ -159,28 +232,32 @@ impl ArchiveReader {
     chunk_usize: usize,
     compressed: bool,
   ) -> XRayResult<Vec<u8>> {
-    match compressed {
-      true => {
-        let decoded_len: u32 = file.read_u32::<XRayByteOrder>()?;
-        let mut compressed_buf: Vec<u8> = vec![0u8; chunk_usize - 4usize];
+    let mut raw_buf: Vec<u8> = vec![0u8; chunk_usize];
 
-        file.read_exact(compressed_buf.as_mut_slice())?;
+    file.read_exact(raw_buf.as_mut_slice())?;
 
-        let mut res: Lh1Decoder<&[u8]> = Lh1Decoder::new(compressed_buf.as_slice());
-        let mut decompressed_buf: Vec<u8> = vec![0u8; decoded_len as usize];
+    match compressed {
+      true => Self::decompress_chunk(&raw_buf),
+      false => Ok(raw_buf),
+    }
+  }
 
-        res.fill_buffer(&mut decompressed_buf)?;
+  /// Decompress LZH data prefixed with size of decompressed data.
+  pub(crate) fn decompress_chunk(data: &[u8]) -> XRayResult<Vec<u8>> {
+    let mut reader: &[u8] = data;
+    let decoded_len: u32 = reader.read_u32::<XRayByteOrder>()?;
 
-        Ok(decompressed_buf)
-      }
-      false => {
-        let mut raw_buf: Vec<u8> = vec![0u8; chunk_usize];
+    assert(
+      decoded_len as u64 <= (data.len() as u64).max(1024) * ARCHIVE_CHUNK_MAX_COMPRESSION_RATIO,
+      "Compressed chunk decoded size is not realistic",
+    )?;
 
-        file.read_exact(raw_buf.as_mut_slice())?;
+    let mut decoder: Lh1Decoder<&[u8]> = Lh1Decoder::new(reader);
+    let mut decompressed_buf: Vec<u8> = vec![0u8; decoded_len as usize];
 
-        Ok(raw_buf)
-      }
-    }
+    decoder.fill_buffer(&mut decompressed_buf)?;
+
+    Ok(decompressed_buf)
   }
 
   fn read_file_descriptors<T: Read>(
@@ -200,14 +277,14 @@ impl ArchiveReader {
       let size_real: u32 = reader.read_u32::<XRayByteOrder>()?;
       let size_compressed: u32 = reader.read_u32::<XRayByteOrder>()?;
       let crc: u32 = reader.read_u32::<XRayByteOrder>()?;
-      let name_size: u16 = header_size - 16;
+      let name_size: u16 = header_size
+        .checked_sub(ARCHIVE_FILE_DESCRIPTOR_BASE_SIZE)
+        .ok_or_else(|| XRayError::new_read_error("Invalid file descriptor header size"))?;
 
       let name_bytes = {
         assert((name_size as usize) < name_buf.len(), "Name is too long")?;
 
-        reader
-          .read_exact(&mut name_buf[..(name_size as usize)])
-          .expect("Unable to read file name from header");
+        reader.read_exact(&mut name_buf[..(name_size as usize)])?;
 
         &name_buf[..(name_size as usize)]
       };
@@ -229,4 +306,178 @@ impl ArchiveReader {
 
     Ok(file_descriptors)
   }
+
+  /// Read descriptors table of early builds archives: null-terminated name followed by
+  /// CRC (since build 2945), offset, real size and compressed size.
+  fn read_legacy_file_descriptors(
+    reader: &mut Cursor<&[u8]>,
+    version: ArchiveVersion,
+    encoding: XRayEncoding,
+  ) -> XRayResult<HashMap<String, ArchiveFileDescriptor>> {
+    let mut file_descriptors: HashMap<String, ArchiveFileDescriptor> = HashMap::new();
+
+    while (reader.position() as usize) < reader.get_ref().len() {
+      let rest: &[u8] = &reader.get_ref()[reader.position() as usize..];
+      let name_size: usize = rest
+        .iter()
+        .position(|it| *it == 0)
+        .ok_or_else(|| XRayError::new_read_error("File name terminator is missing"))?;
+
+      assert(name_size < ARCHIVE_FILE_NAME_MAX_SIZE, "Name is too long")?;
+
+      let name: String = decode_bytes_to_string_without_bom_handling(&rest[..name_size], encoding)?;
+
+      reader.seek(SeekFrom::Current(name_size as i64 + 1))?;
+
+      let crc: u32 = if version.is_with_crc() {
+        reader.read_u32::<XRayByteOrder>()?
+      } else {
+        0
+      };
+      let offset: u32 = reader.read_u32::<XRayByteOrder>()?;
+      let size_real: u32 = reader.read_u32::<XRayByteOrder>()?;
+      let size_compressed: u32 = reader.read_u32::<XRayByteOrder>()?;
+
+      file_descriptors.insert(
+        name.clone(),
+        ArchiveFileDescriptor {
+          name,
+          offset,
+          size_real,
+          size_compressed,
+          crc,
+        },
+      );
+    }
+
+    Ok(file_descriptors)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_descriptor::ArchiveDescriptor;
+  use crate::archive::archive_scrambler::ArchiveScrambler;
+  use crate::archive::archive_version::ArchiveVersion;
+  use crate::archive::reader::ArchiveReader;
+  use crate::types::XRayByteOrder;
+  use crate::ArchiveProject;
+  use byteorder::WriteBytesExt;
+  use std::fs::File;
+  use std::io::Write;
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  const FILES: [(&str, &[u8]); 2] = [
+    ("configs\\system.ltx", b"[section]"),
+    ("scripts\\_g.script", b"function main() end"),
+  ];
+
+  /// Write archive with raw data chunk and provided descriptors table, offsets start after data
+  /// chunk header.
+  fn write_test_archive(name: &str, version: ArchiveVersion) -> XRayResult<PathBuf> {
+    let mut data: Vec<u8> = Vec::new();
+    let mut table: Vec<u8> = Vec::new();
+
+    for (name, content) in FILES {
+      let offset: u32 = 8 + data.len() as u32;
+      let size: u32 = content.len() as u32;
+      let crc: u32 = crc32fast::hash(content);
+
+      data.extend_from_slice(content);
+
+      if version.is_legacy_table() {
+        table.extend_from_slice(name.as_bytes());
+        table.push(0);
+
+        if version.is_with_crc() {
+          table.write_u32::<XRayByteOrder>(crc)?;
+        }
+
+        table.write_u32::<XRayByteOrder>(offset)?;
+        table.write_u32::<XRayByteOrder>(size)?;
+        table.write_u32::<XRayByteOrder>(size)?;
+      } else {
+        table.write_u16::<XRayByteOrder>(name.len() as u16 + 16)?;
+        table.write_u32::<XRayByteOrder>(size)?;
+        table.write_u32::<XRayByteOrder>(size)?;
+        table.write_u32::<XRayByteOrder>(crc)?;
+        table.extend_from_slice(name.as_bytes());
+        table.write_u32::<XRayByteOrder>(offset)?;
+      }
+    }
+
+    if let Some(key) = version.get_scrambler_key() {
+      table = ArchiveScrambler::new(key).encrypt(&table);
+    }
+
+    let path: String = get_relative_test_sample_file_path(file!(), name);
+    let mut file: File = overwrite_test_relative_resource_as_file(&path)?;
+
+    file.write_u32::<XRayByteOrder>(0)?;
+    file.write_u32::<XRayByteOrder>(data.len() as u32)?;
+    file.write_all(&data)?;
+    file.write_u32::<XRayByteOrder>(1)?;
+    file.write_u32::<XRayByteOrder>(table.len() as u32)?;
+    file.write_all(&table)?;
+
+    Ok(get_absolute_test_resource_path(&path))
+  }
+
+  #[test]
+  fn test_read_archive_versions() -> XRayResult {
+    for (name, version) in [
+      ("build_2215.db", ArchiveVersion::Build2215),
+      ("build_2945.db", ArchiveVersion::Build2945),
+      ("soc_ru.db", ArchiveVersion::ShadowOfChernobylRussian),
+      ("soc_ww.db", ArchiveVersion::ShadowOfChernobylWorldwide),
+      ("xdb.db", ArchiveVersion::Xdb),
+    ] {
+      let path: PathBuf = write_test_archive(name, version)?;
+      let archive: ArchiveDescriptor = ArchiveReader::from_path_utf8(&path)?.read_archive()?;
+
+      assert_eq!(archive.version, version, "Expected {name} version to match");
+      assert_eq!(archive.files.len(), FILES.len());
+      assert_eq!(
+        archive.files.get("configs\\system.ltx").unwrap().crc,
+        if version.is_with_crc() {
+          crc32fast::hash(b"[section]")
+        } else {
+          0
+        }
+      );
+
+      let project: ArchiveProject = ArchiveProject::new(&path)?;
+
+      for (name, content) in FILES {
+        assert_eq!(project.read_file_bytes(name)?, content);
+      }
+
+      assert!(project.verify()?.is_valid());
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_archive_invalid() -> XRayResult {
+    let path: String = get_relative_test_sample_file_path(file!(), "invalid.db");
+    let mut file: File = overwrite_test_relative_resource_as_file(&path)?;
+
+    file.write_u32::<XRayByteOrder>(1)?;
+    file.write_u32::<XRayByteOrder>(8)?;
+    file.write_all(&[0xFF; 8])?;
+
+    assert!(
+      ArchiveReader::from_path_utf8(&get_absolute_test_resource_path(&path))?
+        .read_archive()
+        .is_err()
+    );
+
+    Ok(())
+  }
 }
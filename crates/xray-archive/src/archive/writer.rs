@@ -0,0 +1,358 @@
+use crate::archive::archive_constants::{
+  ARCHIVE_CHUNK_ID_DATA, ARCHIVE_CHUNK_ID_FILES, ARCHIVE_CHUNK_ID_HEADER,
+  ARCHIVE_FILE_DESCRIPTOR_BASE_SIZE, ARCHIVE_FILE_NAME_MAX_SIZE, ARCHIVE_PATH_SEPARATOR,
+};
+use crate::archive::archive_descriptor::ArchiveDescriptor;
+use crate::archive::archive_file_descriptor::ArchiveFileDescriptor;
+use crate::archive::archive_write_options::ArchiveWriteOptions;
+use crate::archive::reader::ArchiveReader;
+use crate::types::XRayByteOrder;
+use byteorder::WriteBytesExt;
+use minilzo_rs::LZO;
+use std::fs;
+use std::fs::File;
+use std::io::{Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
+use xray_error::{XRayError, XRayResult};
+use xray_utils::{assert, encode_string_to_bytes, get_utf8_encoder, XRayEncoding};
+
+pub struct ArchiveWriter {
+  pub path: PathBuf,
+  pub file: File,
+  pub encoding: XRayEncoding,
+}
+
+impl ArchiveWriter {
+  /// Create archive writer based on destination file, existing file is truncated.
+  pub fn from_path<P: AsRef<Path>>(path: &P, encoding: XRayEncoding) -> XRayResult<Self> {
+    match File::options()
+      .read(true)
+      .write(true)
+      .create(true)
+      .truncate(true)
+      .open(path.as_ref())
+    {
+      Ok(file) => Ok(Self {
+        encoding,
+        file,
+        path: path.as_ref().into(),
+      }),
+      Err(error) => Err(XRayError::new_io_error(
+        format!(
+          "Failed to create archive file {}, {}",
+          path.as_ref().display(),
+          error
+        ),
+        error.kind(),
+      )),
+    }
+  }
+
+  /// Create archive writer based on destination file, existing file is truncated.
+  pub fn from_path_utf8<P: AsRef<Path>>(path: &P) -> XRayResult<Self> {
+    Self::from_path(path, get_utf8_encoder())
+  }
+}
+
+impl ArchiveWriter {
+  /// Pack all files from provided directory into archive.
+  /// Layout matches xrCompress output: optional header chunk, data chunk and file descriptors chunk.
+  pub fn write_archive<P: AsRef<Path>>(
+    &mut self,
+    source: &P,
+    options: &ArchiveWriteOptions,
+  ) -> XRayResult<ArchiveDescriptor> {
+    let entries: Vec<(String, PathBuf)> = Self::collect_entries(source.as_ref())?;
+
+    log::info!(
+      "Packing {} files from {} into {}",
+      entries.len(),
+      source.as_ref().display(),
+      self.path.display()
+    );
+
+    self.write_archive_entries(&entries, options)
+  }
+
+  /// Pack provided files into archive, entries are pairs of archive file name and source path.
+  pub fn write_archive_entries(
+    &mut self,
+    entries: &[(String, PathBuf)],
+    options: &ArchiveWriteOptions,
+  ) -> XRayResult<ArchiveDescriptor> {
+    if let Some(entry_point) = &options.entry_point {
+      self.write_header_chunk(entry_point)?;
+    }
+
+    let descriptors: Vec<ArchiveFileDescriptor> = self.write_data_chunk(entries, options)?;
+
+    self.write_file_descriptors_chunk(&descriptors)?;
+    self.file.flush()?;
+
+    ArchiveReader::from_path(&self.path, self.encoding)?.read_archive()
+  }
+}
+
+impl ArchiveWriter {
+  fn write_header_chunk(&mut self, entry_point: &str) -> XRayResult {
+    let data: Vec<u8> = encode_string_to_bytes(
+      &format!("[header]\r\nentry_point = {entry_point}\r\n"),
+      self.encoding,
+    )?;
+
+    self
+      .file
+      .write_u32::<XRayByteOrder>(ARCHIVE_CHUNK_ID_HEADER)?;
+    self.file.write_u32::<XRayByteOrder>(data.len() as u32)?;
+    self.file.write_all(&data)?;
+
+    Ok(())
+  }
+
+  fn write_data_chunk(
+    &mut self,
+    entries: &[(String, PathBuf)],
+    options: &ArchiveWriteOptions,
+  ) -> XRayResult<Vec<ArchiveFileDescriptor>> {
+    let mut lzo: LZO = LZO::init().map_err(|error| {
+      XRayError::new_unexpected_error(format!("Failed to initialize LZO: {:?}", error))
+    })?;
+    let mut descriptors: Vec<ArchiveFileDescriptor> = Vec::with_capacity(entries.len());
+
+    let chunk_start: u64 = self.file.stream_position()?;
+
+    // Size is patched once all files data is written.
+    self
+      .file
+      .write_u32::<XRayByteOrder>(ARCHIVE_CHUNK_ID_DATA)?;
+    self.file.write_u32::<XRayByteOrder>(0)?;
+
+    for (name, path) in entries {
+      let data: Vec<u8> = fs::read(path)?;
+      let offset: u32 = Self::to_u32(self.file.stream_position()?, name)?;
+      let size_real: u32 = Self::to_u32(data.len() as u64, name)?;
+
+      let compressed: Option<Vec<u8>> = if !data.is_empty() && options.can_compress(name) {
+        lzo
+          .compress(&data)
+          .ok()
+          .filter(|compressed| compressed.len() < data.len())
+      } else {
+        None
+      };
+
+      let size_compressed: u32 = match &compressed {
+        Some(compressed) => {
+          self.file.write_all(compressed)?;
+
+          compressed.len() as u32
+        }
+        None => {
+          self.file.write_all(&data)?;
+
+          size_real
+        }
+      };
+
+      descriptors.push(ArchiveFileDescriptor {
+        crc: crc32fast::hash(&data),
+        name: name.clone(),
+        offset,
+        size_compressed,
+        size_real,
+      });
+    }
+
+    let chunk_end: u64 = self.file.stream_position()?;
+    let chunk_size: u32 = Self::to_u32(chunk_end - chunk_start - 8, "data chunk")?;
+
+    self.file.seek(SeekFrom::Start(chunk_start + 4))?;
+    self.file.write_u32::<XRayByteOrder>(chunk_size)?;
+    self.file.seek(SeekFrom::Start(chunk_end))?;
+
+    Ok(descriptors)
+  }
+
+  fn write_file_descriptors_chunk(&mut self, descriptors: &[ArchiveFileDescriptor]) -> XRayResult {
+    let mut data: Vec<u8> = Vec::new();
+
+    for descriptor in descriptors {
+      let name: Vec<u8> = encode_string_to_bytes(&descriptor.name, self.encoding)?;
+
+      assert(name.len() < ARCHIVE_FILE_NAME_MAX_SIZE, "Name is too long")?;
+
+      data.write_u16::<XRayByteOrder>(name.len() as u16 + ARCHIVE_FILE_DESCRIPTOR_BASE_SIZE)?;
+      data.write_u32::<XRayByteOrder>(descriptor.size_real)?;
+      data.write_u32::<XRayByteOrder>(descriptor.size_compressed)?;
+      data.write_u32::<XRayByteOrder>(descriptor.crc)?;
+      data.write_all(&name)?;
+      data.write_u32::<XRayByteOrder>(descriptor.offset)?;
+    }
+
+    self
+      .file
+      .write_u32::<XRayByteOrder>(ARCHIVE_CHUNK_ID_FILES)?;
+    self
+      .file
+      .write_u32::<XRayByteOrder>(Self::to_u32(data.len() as u64, "file descriptors chunk")?)?;
+    self.file.write_all(&data)?;
+
+    Ok(())
+  }
+
+  /// Collect list of files to pack with names relative to source root.
+  /// Entries are sorted by name to keep produced archives reproducible.
+  pub fn collect_entries(source: &Path) -> XRayResult<Vec<(String, PathBuf)>> {
+    if !source.is_dir() {
+      return Err(XRayError::new_not_found_error(format!(
+        "Unable to pack archive, source directory {} does not exist",
+        source.display()
+      )));
+    }
+
+    let mut entries: Vec<(String, PathBuf)> = Vec::new();
+
+    for entry in WalkDir::new(source) {
+      let entry: DirEntry = entry.map_err(|error| {
+        XRayError::new_read_error(format!("Failed to read source directory entry: {}", error))
+      })?;
+
+      if !entry.file_type().is_file() {
+        continue;
+      }
+
+      let relative: &Path = entry.path().strip_prefix(source).map_err(|error| {
+        XRayError::new_unexpected_error(format!("Failed to get relative file path: {}", error))
+      })?;
+
+      let name: String = relative
+        .components()
+        .map(|component| {
+          component.as_os_str().to_str().ok_or_else(|| {
+            XRayError::new_invalid_error(format!(
+              "Unable to pack file with non UTF-8 path {}",
+              relative.display()
+            ))
+          })
+        })
+        .collect::<XRayResult<Vec<&str>>>()?
+        .join(&ARCHIVE_PATH_SEPARATOR.to_string());
+
+      entries.push((name, entry.path().into()));
+    }
+
+    entries.sort_by(|first, second| first.0.cmp(&second.0));
+
+    Ok(entries)
+  }
+
+  fn to_u32(value: u64, name: &str) -> XRayResult<u32> {
+    u32::try_from(value).map_err(|_| {
+      XRayError::new_invalid_error(format!(
+        "Unable to pack '{}', archive size limit of 4 GB is exceeded",
+        name
+      ))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_descriptor::ArchiveDescriptor;
+  use crate::archive::archive_file_descriptor::ArchiveFileDescriptor;
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use minilzo_rs::LZO;
+  use std::fs::File;
+  use std::io::{Read, Seek, SeekFrom, Write};
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    get_relative_test_sample_file_path, overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_write_read() -> XRayResult {
+    let source: String = get_relative_test_sample_file_directory(file!());
+    let text: String = "[section]\nvalue = 1\n".repeat(64);
+    let binary: Vec<u8> = (0..=255u8).collect();
+
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/configs/system.ltx"))?
+      .write_all(text.as_bytes())?;
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/scripts/binary.bin"))?
+      .write_all(&binary)?;
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/empty.txt"))?;
+
+    let path: PathBuf = get_absolute_test_resource_path(&get_relative_test_sample_file_path(
+      file!(),
+      "write_read.db",
+    ));
+
+    let descriptor: ArchiveDescriptor = ArchiveWriter::from_path_utf8(&path)?.write_archive(
+      &get_absolute_test_resource_path(&format!("{source}/source")),
+      &ArchiveWriteOptions {
+        entry_point: Some(String::from("$game_data$\\mod\\")),
+        ..ArchiveWriteOptions::default()
+      },
+    )?;
+
+    assert_eq!(descriptor.files.len(), 3);
+    assert_eq!(descriptor.output_root_path, PathBuf::from("mod\\"));
+
+    let text_file: &ArchiveFileDescriptor = descriptor.files.get("configs\\system.ltx").unwrap();
+    let binary_file: &ArchiveFileDescriptor = descriptor.files.get("scripts\\binary.bin").unwrap();
+    let empty_file: &ArchiveFileDescriptor = descriptor.files.get("empty.txt").unwrap();
+
+    assert_eq!(text_file.size_real, text.len() as u32);
+    assert!(text_file.size_compressed < text_file.size_real);
+    assert_eq!(text_file.crc, crc32fast::hash(text.as_bytes()));
+
+    assert_eq!(binary_file.size_real, 256);
+    assert_eq!(binary_file.size_compressed, 256);
+    assert_eq!(binary_file.crc, crc32fast::hash(&binary));
+
+    assert_eq!(empty_file.size_real, 0);
+    assert_eq!(empty_file.size_compressed, 0);
+
+    let mut file: File = File::open(&path)?;
+    let mut compressed: Vec<u8> = vec![0u8; text_file.size_compressed as usize];
+
+    file.seek(SeekFrom::Start(text_file.offset as u64))?;
+    file.read_exact(&mut compressed)?;
+
+    assert_eq!(
+      LZO::init()
+        .unwrap()
+        .decompress_safe(&compressed, text_file.size_real as usize)
+        .unwrap(),
+      text.as_bytes()
+    );
+
+    let mut raw: Vec<u8> = vec![0u8; binary_file.size_real as usize];
+
+    file.seek(SeekFrom::Start(binary_file.offset as u64))?;
+    file.read_exact(&mut raw)?;
+
+    assert_eq!(raw, binary);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_can_compress() {
+    let options: ArchiveWriteOptions = ArchiveWriteOptions::default();
+
+    assert!(options.can_compress("configs\\system.ltx"));
+    assert!(!options.can_compress("sounds\\ambient.ogg"));
+    assert!(!options.can_compress("sounds\\AMBIENT.OGG"));
+
+    let options: ArchiveWriteOptions = ArchiveWriteOptions {
+      is_compressed: false,
+      ..ArchiveWriteOptions::default()
+    };
+
+    assert!(!options.can_compress("configs\\system.ltx"));
+  }
+}
@@ -0,0 +1,278 @@
+use crate::fs::fsgame_alias::FsGameAlias;
+use crate::fs::fsgame_constants::{FSGAME_GAMEDATA_ENTRY_POINT, FSGAME_ROOT_ALIAS};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use xray_error::{XRayError, XRayResult};
+
+/// Parsed fsgame.ltx file describing engine filesystem mount points.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FsGame {
+  pub path: PathBuf,
+  pub root: PathBuf,
+  pub aliases: Vec<FsGameAlias>,
+}
+
+impl FsGame {
+  /// Read fsgame.ltx file, `$fs_root$` is resolved as directory of the file.
+  pub fn read_from_path<P: AsRef<Path>>(path: &P) -> XRayResult<Self> {
+    let content: Vec<u8> = fs::read(path.as_ref()).map_err(|error| {
+      XRayError::new_io_error(
+        format!(
+          "Failed to read fsgame file {}, {}",
+          path.as_ref().display(),
+          error
+        ),
+        error.kind(),
+      )
+    })?;
+
+    let root: PathBuf = match path.as_ref().parent() {
+      Some(parent) if !parent.as_os_str().is_empty() => parent.into(),
+      _ => PathBuf::from("."),
+    };
+
+    Self::read_from_str(&String::from_utf8_lossy(&content), path.as_ref(), &root)
+  }
+
+  /// Parse fsgame.ltx content, aliases are resolved in declaration order.
+  pub fn read_from_str(content: &str, path: &Path, root: &Path) -> XRayResult<Self> {
+    let mut fsgame: Self = Self {
+      path: path.into(),
+      root: root.into(),
+      aliases: Vec::new(),
+    };
+
+    for (index, line) in content.lines().enumerate() {
+      let line: &str = line.trim();
+
+      // Engine treats only whole lines as comments, filters may contain `;` separators.
+      if line.is_empty() || line.starts_with(';') {
+        continue;
+      }
+
+      let (name, value) = line.split_once('=').ok_or_else(|| {
+        XRayError::new_parsing_error(format!(
+          "Failed to parse fsgame line {}, expected alias declaration: '{}'",
+          index + 1,
+          line
+        ))
+      })?;
+
+      let alias: FsGameAlias = fsgame.parse_alias(name.trim(), value).map_err(|error| {
+        XRayError::new_parsing_error(format!(
+          "Failed to parse fsgame line {}: {}",
+          index + 1,
+          error
+        ))
+      })?;
+
+      match fsgame.aliases.iter_mut().find(|it| it.name == alias.name) {
+        Some(existing) => *existing = alias,
+        None => fsgame.aliases.push(alias),
+      }
+    }
+
+    Ok(fsgame)
+  }
+}
+
+impl FsGame {
+  pub fn get_alias(&self, name: &str) -> Option<&FsGameAlias> {
+    self.aliases.iter().find(|it| it.name == name)
+  }
+
+  /// Get resolved filesystem path of provided alias.
+  pub fn get_alias_path(&self, name: &str) -> Option<PathBuf> {
+    if name == FSGAME_ROOT_ALIAS {
+      Some(self.root.clone())
+    } else {
+      self.get_alias(name).map(|it| it.path.clone())
+    }
+  }
+
+  /// Resolve path like `$game_data$\configs\` into filesystem path.
+  pub fn resolve_path(&self, path: &str) -> XRayResult<PathBuf> {
+    if let Some(rest) = path.strip_prefix('$') {
+      match rest.find('$') {
+        Some(end) => {
+          let alias: &str = &path[..end + 2];
+
+          match self.get_alias_path(alias) {
+            Some(alias_path) => Ok(Self::join_relative(&alias_path, &path[end + 2..])),
+            None => Err(XRayError::new_not_found_error(format!(
+              "Unknown fsgame alias '{}' used in path '{}'",
+              alias, path
+            ))),
+          }
+        }
+        None => Err(XRayError::new_parsing_error(format!(
+          "Invalid fsgame path '{}', alias is not terminated",
+          path
+        ))),
+      }
+    } else {
+      Ok(Self::join_relative(&PathBuf::from(path), ""))
+    }
+  }
+
+  /// Resolve mount point of archive based on its header entry point.
+  /// Archives without header are mounted at their own path without extension.
+  pub fn resolve_entry_point(
+    &self,
+    entry_point: Option<&str>,
+    archive_path: &Path,
+  ) -> XRayResult<PathBuf> {
+    match entry_point {
+      None => Ok(archive_path.with_extension("")),
+      Some(entry_point) if entry_point.eq_ignore_ascii_case(FSGAME_GAMEDATA_ENTRY_POINT) => {
+        Ok(self.root.join(FSGAME_GAMEDATA_ENTRY_POINT))
+      }
+      Some(entry_point) => self.resolve_path(entry_point),
+    }
+  }
+}
+
+impl FsGame {
+  fn parse_alias(&self, name: &str, value: &str) -> XRayResult<FsGameAlias> {
+    if !name.starts_with('$') || !name.ends_with('$') || name.len() < 3 {
+      return Err(XRayError::new_parsing_error(format!(
+        "Invalid alias name '{}'",
+        name
+      )));
+    }
+
+    let parts: Vec<&str> = value.split('|').map(|it| it.trim()).collect();
+
+    if parts.len() < 3 {
+      return Err(XRayError::new_parsing_error(format!(
+        "Alias '{}' expects at least 3 values (recurse, notify, root), got {}",
+        name,
+        parts.len()
+      )));
+    }
+
+    let root: String = String::from(parts[2]);
+    let add: Option<String> = Self::get_optional_part(&parts, 3);
+
+    let root_path: PathBuf = if root.starts_with('$') {
+      self.get_alias_path(&root).ok_or_else(|| {
+        XRayError::new_not_found_error(format!(
+          "Alias '{}' references unknown root '{}'",
+          name, root
+        ))
+      })?
+    } else {
+      Self::join_relative(&PathBuf::from(&root), "")
+    };
+
+    Ok(FsGameAlias {
+      name: String::from(name),
+      is_recursive: Self::parse_bool(parts[0])?,
+      is_notify: Self::parse_bool(parts[1])?,
+      path: Self::join_relative(&root_path, add.as_deref().unwrap_or_default()),
+      root,
+      add,
+      filter: Self::get_optional_part(&parts, 4),
+      caption: Self::get_optional_part(&parts, 5),
+    })
+  }
+
+  fn get_optional_part(parts: &[&str], index: usize) -> Option<String> {
+    parts
+      .get(index)
+      .filter(|it| !it.is_empty())
+      .map(|it| String::from(*it))
+  }
+
+  fn parse_bool(value: &str) -> XRayResult<bool> {
+    match value.to_lowercase().as_str() {
+      "true" | "yes" | "on" | "1" => Ok(true),
+      "false" | "no" | "off" | "0" => Ok(false),
+      _ => Err(XRayError::new_parsing_error(format!(
+        "Expected boolean flag value, got '{}'",
+        value
+      ))),
+    }
+  }
+
+  /// Join engine path with mixed separators to provided base path.
+  fn join_relative(base: &Path, relative: &str) -> PathBuf {
+    relative
+      .split(['\\', '/'])
+      .filter(|it| !it.is_empty())
+      .fold(base.to_path_buf(), |path, it| path.join(it))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::fs::fsgame::FsGame;
+  use std::path::{Path, PathBuf};
+  use xray_error::XRayResult;
+
+  #[test]
+  fn test_read_from_str() -> XRayResult {
+    let fsgame: FsGame = FsGame::read_from_str(
+      "; comment line\n\
+      $arch_dir$ = false| false| $fs_root$\n\
+      $arch_dir_patches$ = false| true| $fs_root$| patches\\\n\
+      $game_data$ = false| true| $fs_root$| gamedata\\\n\
+      $game_meshes$ = true| true| $game_data$| meshes\\| *.ogf;*.omf| Game Object files\n\
+      $game_config$ = true| false| $game_data$| configs\\\n",
+      Path::new("game/fsgame.ltx"),
+      Path::new("game"),
+    )?;
+
+    assert_eq!(fsgame.aliases.len(), 5);
+
+    assert_eq!(fsgame.aliases[0].name, "$arch_dir$");
+    assert_eq!(fsgame.aliases[0].path, PathBuf::from("game"));
+    assert_eq!(fsgame.aliases[0].add, None);
+
+    assert!(!fsgame.aliases[1].is_recursive);
+    assert!(fsgame.aliases[1].is_notify);
+    assert_eq!(fsgame.aliases[1].path, PathBuf::from("game/patches"));
+
+    assert_eq!(
+      fsgame.aliases[3].path,
+      PathBuf::from("game/gamedata/meshes")
+    );
+    assert_eq!(fsgame.aliases[3].filter, Some(String::from("*.ogf;*.omf")));
+
+    assert_eq!(
+      fsgame.resolve_path("$game_config$\\creatures\\actor.ltx")?,
+      PathBuf::from("game/gamedata/configs/creatures/actor.ltx")
+    );
+
+    assert_eq!(
+      fsgame.resolve_entry_point(Some("gamedata"), Path::new("game/resources/configs.db"))?,
+      PathBuf::from("game/gamedata")
+    );
+    assert_eq!(
+      fsgame.resolve_entry_point(Some("$game_data$\\"), Path::new("game/patches/a.db"))?,
+      PathBuf::from("game/gamedata")
+    );
+    assert_eq!(
+      fsgame.resolve_entry_point(None, Path::new("game/resources/configs.db"))?,
+      PathBuf::from("game/resources/configs")
+    );
+
+    assert!(fsgame.resolve_path("$unknown$\\file").is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_from_str_invalid() {
+    assert!(FsGame::read_from_str("$a$ = true", Path::new("f"), Path::new(".")).is_err());
+    assert!(
+      FsGame::read_from_str("$a$ = x|true|$fs_root$", Path::new("f"), Path::new(".")).is_err()
+    );
+    assert!(FsGame::read_from_str("$a$ = true|true|$b$", Path::new("f"), Path::new(".")).is_err());
+    assert!(
+      FsGame::read_from_str("a = true|true|$fs_root$", Path::new("f"), Path::new(".")).is_err()
+    );
+  }
+}
@@ -0,0 +1,17 @@
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Single path alias declared in fsgame.ltx file.
+/// Format: `$alias$ = recurse | notify | root | add | filter | caption`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FsGameAlias {
+  pub name: String,
+  pub is_recursive: bool,
+  pub is_notify: bool,
+  pub root: String,
+  pub add: Option<String>,
+  pub filter: Option<String>,
+  pub caption: Option<String>,
+  pub path: PathBuf,
+}
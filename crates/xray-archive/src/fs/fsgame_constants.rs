@@ -0,0 +1,6 @@
+pub const FSGAME_ROOT_ALIAS: &str = "$fs_root$";
+pub const FSGAME_GAMEDATA_ALIAS: &str = "$game_data$";
+pub const FSGAME_GAMEDATA_ENTRY_POINT: &str = "gamedata";
+
+/// Aliases with archives that are mounted only for multiplayer sessions.
+pub const FSGAME_SKIPPED_ARCHIVE_ALIASES: &[&str] = &["$game_arch_mp$"];
@@ -0,0 +1,3 @@
+pub(crate) mod fsgame;
+pub(crate) mod fsgame_alias;
+pub(crate) mod fsgame_constants;
@@ -1,9 +1,31 @@
 pub(crate) mod archive;
+pub(crate) mod fs;
 pub(crate) mod project;
 pub(crate) mod types;
+pub(crate) mod vfs;
 
 pub use crate::archive::archive_descriptor::*;
+pub use crate::archive::archive_entry_reader::*;
 pub use crate::archive::archive_file_descriptor::*;
+pub use crate::archive::archive_version::*;
+pub use crate::archive::archive_write_options::*;
+pub use crate::archive::writer::*;
+
+pub use crate::fs::fsgame::*;
+pub use crate::fs::fsgame_alias::*;
 
 pub use crate::project::archive_project::*;
+pub use crate::project::archive_project_diff_result::*;
+pub use crate::project::archive_project_manifest::*;
+pub use crate::project::archive_project_patch_result::*;
+pub use crate::project::archive_project_read_result::*;
+pub use crate::project::archive_project_unpack_options::*;
 pub use crate::project::archive_project_unpack_result::*;
+pub use crate::project::archive_project_verify_result::*;
+
+pub use crate::vfs::archive_file_system::*;
+pub use crate::vfs::folder_file_system::*;
+pub use crate::vfs::overlay_file_system::*;
+pub use crate::vfs::virtual_file_location::*;
+pub use crate::vfs::virtual_file_system::*;
+pub use crate::vfs::virtual_file_system_utils::*;
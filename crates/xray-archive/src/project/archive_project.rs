@@ -1,19 +1,22 @@
 use crate::archive::archive_descriptor::ArchiveDescriptor;
 use crate::archive::archive_file_descriptor::ArchiveFileReplicationDescriptor;
 use crate::archive::reader::ArchiveReader;
+use crate::fs::fsgame::FsGame;
 use serde::Serialize;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 use xray_error::{XRayError, XRayResult};
 
-// todo: Add reading from fsgame.ltx file.
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArchiveProject {
   pub archives: Vec<ArchiveDescriptor>,
   pub files: HashMap<String, ArchiveFileReplicationDescriptor>,
+  /// Game file system used to mount archives, archives root paths are used when not set.
+  #[serde(skip)]
+  pub fsgame: Option<FsGame>,
 }
 
 impl ArchiveProject {
@@ -55,19 +58,14 @@ impl ArchiveProject {
     Self::sort_archives(&mut archives);
 
     for archive in &archives {
-      for (name, descriptor) in &archive.files {
-        files.insert(
-          name.clone(),
-          ArchiveFileReplicationDescriptor::from_descriptor(
-            descriptor,
-            &archive.path,
-            &archive.output_root_path,
-          ),
-        );
-      }
+      Self::insert_archive_files(&mut files, archive, &archive.output_root_path);
     }
 
-    Ok(Self { archives, files })
+    Ok(Self {
+      archives,
+      files,
+      fsgame: None,
+    })
   }
 }
 
@@ -92,6 +90,49 @@ impl ArchiveProject {
     total
   }
 
+  /// Get mount root of archive relative to game root.
+  ///
+  /// Archives of fsgame based projects are mounted by resolved entry point, mount points outside
+  /// of game root cannot be replicated relatively and archive root path is used instead.
+  pub fn get_mount_root(
+    &self,
+    entry_point: Option<&str>,
+    archive_path: &Path,
+    root_path: &Path,
+  ) -> XRayResult<PathBuf> {
+    match &self.fsgame {
+      Some(fsgame) => Ok(
+        match fsgame
+          .resolve_entry_point(entry_point, archive_path)?
+          .strip_prefix(&fsgame.root)
+        {
+          Ok(destination) => destination.into(),
+          Err(_) => root_path.into(),
+        },
+      ),
+      None => Ok(root_path.into()),
+    }
+  }
+
+  /// Register all archive files, replacing entries of previously mounted archives.
+  pub(crate) fn insert_archive_files(
+    files: &mut HashMap<String, ArchiveFileReplicationDescriptor>,
+    archive: &ArchiveDescriptor,
+    destination: &Path,
+  ) {
+    for (name, descriptor) in &archive.files {
+      files.insert(
+        name.clone(),
+        ArchiveFileReplicationDescriptor::from_descriptor(
+          descriptor,
+          &archive.path,
+          destination,
+          archive.version,
+        ),
+      );
+    }
+  }
+
   /// Sort archives list to maintain overriding of files in a correct way.
   /// Patches are exceptional case and should override all the files.
   fn sort_archives(archives: &mut [ArchiveDescriptor]) {
@@ -0,0 +1,186 @@
+use crate::project::archive_project_diff_result::{ArchiveDiffFileState, ArchiveDiffResult};
+use crate::vfs::folder_file_system::FolderFileSystem;
+use crate::vfs::virtual_file_system_utils::normalize_virtual_path;
+use crate::ArchiveProject;
+use crc32fast::Hasher;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use xray_error::XRayResult;
+
+impl ArchiveProject {
+  /// Compare files of current project with another one by path, size and CRC.
+  /// Current project is treated as previous state, provided one as the next.
+  pub fn diff(&self, other: &ArchiveProject) -> ArchiveDiffResult {
+    ArchiveDiffResult::from_states(&self.get_diff_states(), &other.get_diff_states())
+  }
+
+  /// Compare files of current project with loose files folder, for example unpacked `gamedata`.
+  /// Folder paths are matched against archive file names relative to provided root.
+  pub fn diff_folder<P: AsRef<Path>>(&self, folder: &P) -> XRayResult<ArchiveDiffResult> {
+    let folder: FolderFileSystem = FolderFileSystem::new(folder)?;
+    let mut states: BTreeMap<String, ArchiveDiffFileState> = BTreeMap::new();
+
+    for (name, path) in &folder.files {
+      states.insert(name.clone(), Self::get_file_diff_state(path)?);
+    }
+
+    Ok(ArchiveDiffResult::from_states(
+      &self.get_diff_states(),
+      &states,
+    ))
+  }
+
+  pub(crate) fn get_diff_states(&self) -> BTreeMap<String, ArchiveDiffFileState> {
+    self
+      .files
+      .iter()
+      .map(|(name, descriptor)| {
+        (
+          normalize_virtual_path(name),
+          ArchiveDiffFileState {
+            size: descriptor.size_real as u64,
+            crc: descriptor.crc,
+          },
+        )
+      })
+      .collect()
+  }
+
+  /// Get states of project files keyed by normalized mounted path (mount root and file name).
+  /// Files with same name mounted under different roots are kept separately.
+  pub(crate) fn get_mounted_diff_states(
+    &self,
+  ) -> XRayResult<BTreeMap<String, ArchiveDiffFileState>> {
+    let mut states: BTreeMap<String, ArchiveDiffFileState> = BTreeMap::new();
+
+    for archive in &self.archives {
+      let root: PathBuf = self.get_mount_root(
+        archive.entry_point.as_deref(),
+        &archive.path,
+        &archive.output_root_path,
+      )?;
+
+      for (name, descriptor) in &archive.files {
+        states.insert(
+          Self::get_mounted_path(&root, name),
+          ArchiveDiffFileState {
+            size: descriptor.size_real as u64,
+            crc: descriptor.crc,
+          },
+        );
+      }
+    }
+
+    Ok(states)
+  }
+
+  /// Get normalized mounted path of file inside of mount root.
+  pub(crate) fn get_mounted_path(root: &Path, name: &str) -> String {
+    normalize_virtual_path(&format!("{}\\{}", root.to_string_lossy(), name))
+  }
+
+  pub(crate) fn get_file_diff_state(path: &Path) -> XRayResult<ArchiveDiffFileState> {
+    let mut file: File = File::open(path)?;
+    let mut hasher: Hasher = Hasher::new();
+    let mut buf: Vec<u8> = vec![0u8; 256 * 1024];
+    let mut size: u64 = 0;
+
+    loop {
+      let read: usize = file.read(&mut buf)?;
+
+      if read == 0 {
+        break;
+      }
+
+      hasher.update(&buf[..read]);
+      size += read as u64;
+    }
+
+    Ok(ArchiveDiffFileState {
+      size,
+      crc: hasher.finalize(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::project::archive_project_diff_result::ArchiveDiffResult;
+  use crate::ArchiveProject;
+  use std::io::Write;
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_diff() -> XRayResult {
+    let root: String = get_relative_test_sample_file_directory(file!());
+
+    for (version, files) in [
+      (
+        "before",
+        vec![
+          ("configs/system.ltx", "system"),
+          ("configs/game.ltx", "game"),
+          ("scripts/old.script", "old"),
+        ],
+      ),
+      (
+        "after",
+        vec![
+          ("configs/system.ltx", "system"),
+          ("configs/game.ltx", "game changed"),
+          ("scripts/new.script", "new"),
+        ],
+      ),
+    ] {
+      for (name, content) in files {
+        overwrite_test_relative_resource_as_file(&format!("{root}/{version}/{name}"))?
+          .write_all(content.as_bytes())?;
+      }
+
+      ArchiveWriter::from_path_utf8(&get_absolute_test_resource_path(&format!(
+        "{root}/{version}.db"
+      )))?
+      .write_archive(
+        &get_absolute_test_resource_path(&format!("{root}/{version}")),
+        &ArchiveWriteOptions::default(),
+      )?;
+    }
+
+    let before: ArchiveProject = ArchiveProject::new(&get_absolute_test_resource_path(&format!(
+      "{root}/before.db"
+    )))?;
+    let after: ArchiveProject = ArchiveProject::new(&get_absolute_test_resource_path(&format!(
+      "{root}/after.db"
+    )))?;
+
+    let diff: ArchiveDiffResult = before.diff(&after);
+
+    assert_eq!(diff.unchanged, 1);
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].name, "scripts/new.script");
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.removed[0].name, "scripts/old.script");
+    assert_eq!(diff.modified.len(), 1);
+    assert_eq!(diff.modified[0].name, "configs/game.ltx");
+    assert_eq!(diff.modified[0].before.unwrap().size, 4);
+    assert_eq!(diff.modified[0].after.unwrap().size, 12);
+
+    assert!(before.diff(&before).is_empty());
+
+    let folder: PathBuf = get_absolute_test_resource_path(&format!("{root}/after"));
+
+    assert!(after.diff_folder(&folder)?.is_empty());
+    assert_eq!(before.diff_folder(&folder)?.modified, diff.modified);
+
+    Ok(())
+  }
+}
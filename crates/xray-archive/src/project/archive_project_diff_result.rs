@@ -0,0 +1,80 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::Write;
+use xray_error::XRayResult;
+
+/// Size and CRC of compared file.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveDiffFileState {
+  pub size: u64,
+  pub crc: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveDiffEntry {
+  pub name: String,
+  pub before: Option<ArchiveDiffFileState>,
+  pub after: Option<ArchiveDiffFileState>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveDiffResult {
+  pub added: Vec<ArchiveDiffEntry>,
+  pub removed: Vec<ArchiveDiffEntry>,
+  pub modified: Vec<ArchiveDiffEntry>,
+  pub unchanged: usize,
+}
+
+impl ArchiveDiffResult {
+  /// Compare files states keyed by normalized path.
+  pub fn from_states(
+    before: &BTreeMap<String, ArchiveDiffFileState>,
+    after: &BTreeMap<String, ArchiveDiffFileState>,
+  ) -> Self {
+    let mut result: Self = Self::default();
+
+    for (name, before_state) in before {
+      match after.get(name) {
+        None => result.removed.push(ArchiveDiffEntry {
+          name: name.clone(),
+          before: Some(*before_state),
+          after: None,
+        }),
+        Some(after_state) if after_state != before_state => {
+          result.modified.push(ArchiveDiffEntry {
+            name: name.clone(),
+            before: Some(*before_state),
+            after: Some(*after_state),
+          })
+        }
+        Some(_) => result.unchanged += 1,
+      }
+    }
+
+    for (name, after_state) in after {
+      if !before.contains_key(name) {
+        result.added.push(ArchiveDiffEntry {
+          name: name.clone(),
+          before: None,
+          after: Some(*after_state),
+        });
+      }
+    }
+
+    result
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+  }
+
+  pub fn write_json<W: Write>(&self, writer: &mut W) -> XRayResult {
+    serde_json::to_writer_pretty(&mut *writer, self)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+  }
+}
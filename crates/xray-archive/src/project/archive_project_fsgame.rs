@@ -0,0 +1,177 @@
+use crate::archive::archive_descriptor::ArchiveDescriptor;
+use crate::archive::archive_file_descriptor::ArchiveFileReplicationDescriptor;
+use crate::archive::reader::ArchiveReader;
+use crate::fs::fsgame::FsGame;
+use crate::fs::fsgame_alias::FsGameAlias;
+use crate::fs::fsgame_constants::FSGAME_SKIPPED_ARCHIVE_ALIASES;
+use crate::ArchiveProject;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use xray_error::{XRayError, XRayResult};
+
+impl ArchiveProject {
+  /// Create archive project based on fsgame.ltx file.
+  ///
+  /// Archives are mounted in the same order as engine does it: aliases are scanned in declaration
+  /// order (recursively when alias is marked so), entries of each folder are sorted by name and
+  /// each archive is processed only once. Files of later archives override earlier ones.
+  pub fn from_fsgame<P: AsRef<Path>>(path: &P) -> XRayResult<Self> {
+    log::info!("Reading fsgame file: {}", path.as_ref().display());
+
+    let fsgame: FsGame = FsGame::read_from_path(path)?;
+
+    let mut archives: Vec<ArchiveDescriptor> = Vec::new();
+    let mut files: HashMap<String, ArchiveFileReplicationDescriptor> = HashMap::new();
+
+    for alias in &fsgame.aliases {
+      if FSGAME_SKIPPED_ARCHIVE_ALIASES.contains(&alias.name.as_str()) {
+        continue;
+      }
+
+      for archive_path in Self::get_alias_archives(alias) {
+        if archives.iter().any(|it| it.path == archive_path) {
+          continue;
+        }
+
+        log::info!(
+          "Reading archive file: {} ({})",
+          archive_path.display(),
+          alias.name
+        );
+
+        archives.push(ArchiveReader::from_path_utf8(&archive_path)?.read_archive()?);
+      }
+    }
+
+    if archives.is_empty() {
+      return Err(XRayError::new_read_error(format!(
+        "Unable to read archives mounted by fsgame file {}",
+        path.as_ref().display()
+      )));
+    }
+
+    let mut project: Self = Self {
+      archives,
+      files: HashMap::new(),
+      fsgame: Some(fsgame),
+    };
+
+    for archive in &project.archives {
+      let destination: PathBuf = project.get_mount_root(
+        archive.entry_point.as_deref(),
+        &archive.path,
+        &archive.output_root_path,
+      )?;
+
+      Self::insert_archive_files(&mut files, archive, &destination);
+    }
+
+    project.files = files;
+
+    Ok(project)
+  }
+
+  /// Get list of archives in alias folder, in the order engine mounts them.
+  fn get_alias_archives(alias: &FsGameAlias) -> Vec<PathBuf> {
+    if !alias.path.is_dir() {
+      return Vec::new();
+    }
+
+    WalkDir::new(&alias.path)
+      .min_depth(1)
+      .max_depth(if alias.is_recursive { usize::MAX } else { 1 })
+      .sort_by_file_name()
+      .into_iter()
+      .filter_map(|entry| entry.ok())
+      .filter(|entry| entry.file_type().is_file())
+      .map(|entry| entry.into_path())
+      .filter(ArchiveDescriptor::is_valid_db_path)
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_file_descriptor::ArchiveFileReplicationDescriptor;
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::ArchiveProject;
+  use std::fs;
+  use std::io::Write;
+  use std::path::{Path, PathBuf};
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  fn write_test_archive(root: &str, name: &str, entry_point: &str, content: &str) -> XRayResult {
+    overwrite_test_relative_resource_as_file(&format!("{root}/sources/{name}/configs/system.ltx"))?
+      .write_all(content.as_bytes())?;
+
+    let path: PathBuf = get_absolute_test_resource_path(&format!("{root}/game/{name}.db"));
+
+    fs::create_dir_all(path.parent().unwrap())?;
+
+    ArchiveWriter::from_path_utf8(&path)?.write_archive(
+      &get_absolute_test_resource_path(&format!("{root}/sources/{name}")),
+      &ArchiveWriteOptions {
+        entry_point: Some(String::from(entry_point)),
+        ..ArchiveWriteOptions::default()
+      },
+    )?;
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_from_fsgame() -> XRayResult {
+    let root: String = get_relative_test_sample_file_directory(file!());
+
+    overwrite_test_relative_resource_as_file(&format!("{root}/game/fsgame.ltx"))?.write_all(
+      b"$arch_dir$ = false| false| $fs_root$\r\n\
+      $arch_dir_resources$ = false| false| $fs_root$| resources\\\r\n\
+      $arch_dir_patches$ = false| true| $fs_root$| patches\\\r\n\
+      $game_arch_mp$ = false| false| $fs_root$| mp\\\r\n\
+      $game_data$ = false| true| $fs_root$| gamedata\\\r\n",
+    )?;
+
+    write_test_archive(&root, "resources/configs", "gamedata", "base")?;
+    write_test_archive(&root, "resources/zz_patches_old", "$game_data$\\", "old")?;
+    write_test_archive(&root, "patches/xpatch_01", "$fs_root$\\gamedata\\", "patch")?;
+    write_test_archive(&root, "mp/mp_configs", "$game_data$\\", "mp")?;
+
+    let project: ArchiveProject = ArchiveProject::from_fsgame(&get_absolute_test_resource_path(
+      &format!("{root}/game/fsgame.ltx"),
+    ))?;
+
+    assert_eq!(
+      project
+        .archives
+        .iter()
+        .map(|it| it.path.file_name().unwrap().to_str().unwrap())
+        .collect::<Vec<_>>(),
+      vec!["configs.db", "zz_patches_old.db", "xpatch_01.db"]
+    );
+
+    let winner: &ArchiveFileReplicationDescriptor =
+      project.files.get("configs\\system.ltx").unwrap();
+
+    assert!(winner.source.ends_with(Path::new("patches/xpatch_01.db")));
+    assert_eq!(winner.destination, PathBuf::from("gamedata"));
+
+    let heuristic: ArchiveProject = ArchiveProject::new(&get_absolute_test_resource_path(
+      &format!("{root}/game/resources"),
+    ))?;
+
+    assert!(heuristic
+      .files
+      .get("configs\\system.ltx")
+      .unwrap()
+      .source
+      .ends_with(Path::new("resources/zz_patches_old.db")));
+
+    Ok(())
+  }
+}
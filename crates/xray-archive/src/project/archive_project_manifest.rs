@@ -0,0 +1,241 @@
+use crate::archive::archive_file_descriptor::ArchiveFileDescriptor;
+use crate::ArchiveProject;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use xray_error::XRayResult;
+
+/// Single archive entry listed in manifest.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveManifestEntry {
+  pub name: String,
+  pub archive: String,
+  pub offset: u32,
+  pub size_real: u32,
+  pub size_compressed: u32,
+  pub compression_ratio: f64,
+  pub crc: u32,
+  /// Archive mounted later and providing the same file, none for active entries.
+  pub overridden_by: Option<String>,
+}
+
+impl ArchiveManifestEntry {
+  pub fn new(archive: &Path, descriptor: &ArchiveFileDescriptor) -> Self {
+    Self {
+      name: descriptor.name.clone(),
+      archive: archive.to_string_lossy().into(),
+      offset: descriptor.offset,
+      size_real: descriptor.size_real,
+      size_compressed: descriptor.size_compressed,
+      compression_ratio: if descriptor.size_real == 0 {
+        1.0
+      } else {
+        descriptor.size_compressed as f64 / descriptor.size_real as f64
+      },
+      crc: descriptor.crc,
+      overridden_by: None,
+    }
+  }
+}
+
+/// Manifest of archive project files, including entries shadowed by later archives.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveManifest {
+  pub archives: Vec<String>,
+  pub files: Vec<ArchiveManifestEntry>,
+  pub shadowed: Vec<ArchiveManifestEntry>,
+}
+
+impl ArchiveManifest {
+  pub fn write_json<W: Write>(&self, writer: &mut W) -> XRayResult {
+    serde_json::to_writer_pretty(&mut *writer, self)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+  }
+
+  /// Write manifest as CSV table, active and shadowed entries are distinguished by status column.
+  pub fn write_csv<W: Write>(&self, writer: &mut W) -> XRayResult {
+    writeln!(
+      writer,
+      "status,name,archive,offset,size_real,size_compressed,compression_ratio,crc,overridden_by"
+    )?;
+
+    for (status, entry) in self
+      .files
+      .iter()
+      .map(|it| ("active", it))
+      .chain(self.shadowed.iter().map(|it| ("shadowed", it)))
+    {
+      writeln!(
+        writer,
+        "{},{},{},{},{},{},{:.4},{:08x},{}",
+        status,
+        Self::escape_csv(&entry.name),
+        Self::escape_csv(&entry.archive),
+        entry.offset,
+        entry.size_real,
+        entry.size_compressed,
+        entry.compression_ratio,
+        entry.crc,
+        Self::escape_csv(entry.overridden_by.as_deref().unwrap_or_default()),
+      )?;
+    }
+
+    Ok(())
+  }
+
+  fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+      format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+      value.into()
+    }
+  }
+}
+
+impl ArchiveProject {
+  /// Build manifest of project files.
+  /// Archives are replayed in mount order, so each shadowed entry references the archive that
+  /// replaced it directly.
+  pub fn get_manifest(&self) -> ArchiveManifest {
+    let mut active: HashMap<&str, ArchiveManifestEntry> = HashMap::new();
+    let mut shadowed: Vec<ArchiveManifestEntry> = Vec::new();
+
+    for archive in &self.archives {
+      for (name, descriptor) in &archive.files {
+        let entry: ArchiveManifestEntry = ArchiveManifestEntry::new(&archive.path, descriptor);
+
+        if let Some(mut previous) = active.insert(name, entry) {
+          previous.overridden_by = Some(archive.path.to_string_lossy().into());
+          shadowed.push(previous);
+        }
+      }
+    }
+
+    let mut files: Vec<ArchiveManifestEntry> = active.into_values().collect();
+
+    files.sort_by(|first, second| first.name.cmp(&second.name));
+    shadowed.sort_by(|first, second| first.name.cmp(&second.name));
+
+    ArchiveManifest {
+      archives: self
+        .archives
+        .iter()
+        .map(|it| it.path.to_string_lossy().into())
+        .collect(),
+      files,
+      shadowed,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::project::archive_project_manifest::ArchiveManifest;
+  use crate::ArchiveProject;
+  use std::fs;
+  use std::io::Write;
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_get_manifest() -> XRayResult {
+    let root: String = get_relative_test_sample_file_directory(file!());
+
+    for (archive, files) in [
+      (
+        "configs",
+        vec![("configs/system.ltx", "base"), ("configs/game.ltx", "game")],
+      ),
+      ("patches/patch_01", vec![("configs/system.ltx", "patch 1")]),
+      ("patches/patch_02", vec![("configs/system.ltx", "patch 2")]),
+    ] {
+      for (name, content) in files {
+        overwrite_test_relative_resource_as_file(&format!("{root}/sources/{archive}/{name}"))?
+          .write_all(content.as_bytes())?;
+      }
+
+      let path: PathBuf = get_absolute_test_resource_path(&format!("{root}/archives/{archive}.db"));
+
+      fs::create_dir_all(path.parent().unwrap())?;
+
+      ArchiveWriter::from_path_utf8(&path)?.write_archive(
+        &get_absolute_test_resource_path(&format!("{root}/sources/{archive}")),
+        &ArchiveWriteOptions::default(),
+      )?;
+    }
+
+    let manifest: ArchiveManifest = ArchiveProject::new(&get_absolute_test_resource_path(
+      &format!("{root}/archives"),
+    ))?
+    .get_manifest();
+
+    assert_eq!(manifest.archives.len(), 3);
+    assert_eq!(manifest.files.len(), 2);
+    assert_eq!(manifest.files[0].name, "configs\\game.ltx");
+    assert_eq!(manifest.files[1].name, "configs\\system.ltx");
+    assert!(manifest.files[1].archive.ends_with("patch_02.db"));
+    assert!(manifest.files.iter().all(|it| it.overridden_by.is_none()));
+
+    assert_eq!(manifest.shadowed.len(), 2);
+
+    let mut shadowed: Vec<(String, String)> = manifest
+      .shadowed
+      .iter()
+      .map(|it| {
+        (
+          PathBuf::from(&it.archive)
+            .file_stem()
+            .unwrap()
+            .to_string_lossy()
+            .into(),
+          PathBuf::from(it.overridden_by.as_ref().unwrap())
+            .file_stem()
+            .unwrap()
+            .to_string_lossy()
+            .into(),
+        )
+      })
+      .collect();
+
+    shadowed.sort();
+
+    assert_eq!(
+      shadowed,
+      vec![
+        (String::from("configs"), String::from("patch_01")),
+        (String::from("patch_01"), String::from("patch_02")),
+      ]
+    );
+
+    let mut csv: Vec<u8> = Vec::new();
+
+    manifest.write_csv(&mut csv)?;
+
+    let csv: String = String::from_utf8_lossy(&csv).into();
+
+    assert_eq!(csv.lines().count(), 5);
+    assert_eq!(
+      csv.lines().filter(|it| it.starts_with("shadowed,")).count(),
+      2
+    );
+
+    let mut json: Vec<u8> = Vec::new();
+
+    manifest.write_json(&mut json)?;
+
+    assert!(String::from_utf8_lossy(&json).contains("\"overriddenBy\""));
+
+    Ok(())
+  }
+}
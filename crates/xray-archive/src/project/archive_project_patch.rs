@@ -0,0 +1,210 @@
+use crate::archive::archive_constants::ARCHIVE_PATCH_ENTRY_POINT;
+use crate::archive::archive_descriptor::ArchiveDescriptor;
+use crate::archive::archive_write_options::ArchiveWriteOptions;
+use crate::archive::writer::ArchiveWriter;
+use crate::project::archive_project_diff_result::ArchiveDiffFileState;
+use crate::project::archive_project_patch_result::ArchivePatchResult;
+use crate::vfs::virtual_file_system_utils::{is_virtual_path_in_dir, normalize_virtual_path};
+use crate::ArchiveProject;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use xray_error::XRayResult;
+
+impl ArchiveProject {
+  /// Build patch archive with files of source folder that are new or changed compared to project.
+  ///
+  /// Entry point defaults to the one of official patches, so archive placed in
+  /// `$arch_dir_patches$` overrides gamedata files. Archive is not written when nothing changed.
+  ///
+  /// Files are compared by mounted path, source folder is mounted same way as resulting patch.
+  /// Removed files are reported only for base files under patch mount root.
+  pub fn build_patch<S: AsRef<Path>, D: AsRef<Path>>(
+    &self,
+    source: &S,
+    destination: &D,
+    options: &ArchiveWriteOptions,
+  ) -> XRayResult<ArchivePatchResult> {
+    let start: Instant = Instant::now();
+    let base: BTreeMap<String, ArchiveDiffFileState> = self.get_mounted_diff_states()?;
+
+    let entry_point: String = options
+      .entry_point
+      .clone()
+      .unwrap_or_else(|| String::from(ARCHIVE_PATCH_ENTRY_POINT));
+    let root: PathBuf = self.get_mount_root(
+      Some(&entry_point),
+      destination.as_ref(),
+      &ArchiveDescriptor::get_entry_point_root_path(&entry_point),
+    )?;
+    let normalized_root: String = normalize_virtual_path(&root.to_string_lossy());
+
+    let mut entries: Vec<(String, PathBuf)> = Vec::new();
+    let mut existing: HashSet<String> = HashSet::new();
+    let mut size: u64 = 0;
+
+    for (name, path) in ArchiveWriter::collect_entries(source.as_ref())? {
+      let normalized: String = Self::get_mounted_path(&root, &name);
+      let state: ArchiveDiffFileState = Self::get_file_diff_state(&path)?;
+
+      if base.get(&normalized) != Some(&state) {
+        size += state.size;
+        entries.push((name, path));
+      }
+
+      existing.insert(normalized);
+    }
+
+    let removed: Vec<String> = base
+      .keys()
+      .filter(|it| is_virtual_path_in_dir(it, &normalized_root) && !existing.contains(*it))
+      .cloned()
+      .collect();
+
+    if entries.is_empty() {
+      log::info!("No new or changed files found, skipping patch archive creation");
+    } else {
+      log::info!(
+        "Packing {} changed files into patch {}",
+        entries.len(),
+        destination.as_ref().display()
+      );
+
+      ArchiveWriter::from_path_utf8(destination)?.write_archive_entries(
+        &entries,
+        &ArchiveWriteOptions {
+          entry_point: Some(entry_point),
+          ..options.clone()
+        },
+      )?;
+    }
+
+    Ok(ArchivePatchResult {
+      destination: destination.as_ref().to_string_lossy().into(),
+      duration: start.elapsed().as_millis(),
+      files: entries.into_iter().map(|(name, _)| name).collect(),
+      removed,
+      size,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::project::archive_project_patch_result::ArchivePatchResult;
+  use crate::{ArchiveProject, FsGame};
+  use std::fs;
+  use std::io::Write;
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_build_patch() -> XRayResult {
+    let root: String = get_relative_test_sample_file_directory(file!());
+
+    overwrite_test_relative_resource_as_file(&format!("{root}/game/fsgame.ltx"))?.write_all(
+      b"$arch_dir_resources$ = false| false| $fs_root$| resources\\\r\n\
+      $arch_dir_patches$ = false| true| $fs_root$| patches\\\r\n\
+      $game_data$ = false| true| $fs_root$| gamedata\\\r\n",
+    )?;
+
+    for (name, content) in [
+      ("configs/system.ltx", "system"),
+      ("configs/game.ltx", "game"),
+      ("scripts/old.script", "old"),
+    ] {
+      overwrite_test_relative_resource_as_file(&format!("{root}/base/{name}"))?
+        .write_all(content.as_bytes())?;
+    }
+
+    for (name, content) in [
+      ("configs/system.ltx", "system"),
+      ("configs/game.ltx", "game changed"),
+      ("scripts/new.script", "new"),
+    ] {
+      overwrite_test_relative_resource_as_file(&format!("{root}/modified/{name}"))?
+        .write_all(content.as_bytes())?;
+    }
+
+    overwrite_test_relative_resource_as_file(&format!("{root}/levels/configs/game.ltx"))?
+      .write_all(b"game changed")?;
+
+    let resources: PathBuf = get_absolute_test_resource_path(&format!("{root}/game/resources"));
+    let patches: PathBuf = get_absolute_test_resource_path(&format!("{root}/game/patches"));
+
+    fs::create_dir_all(&resources)?;
+    fs::create_dir_all(&patches)?;
+
+    ArchiveWriter::from_path_utf8(&resources.join("configs.db"))?.write_archive(
+      &get_absolute_test_resource_path(&format!("{root}/base")),
+      &ArchiveWriteOptions {
+        entry_point: Some(String::from("gamedata")),
+        ..ArchiveWriteOptions::default()
+      },
+    )?;
+
+    ArchiveWriter::from_path_utf8(&resources.join("levels.db"))?.write_archive(
+      &get_absolute_test_resource_path(&format!("{root}/levels")),
+      &ArchiveWriteOptions {
+        entry_point: Some(String::from("$fs_root$\\levels\\")),
+        ..ArchiveWriteOptions::default()
+      },
+    )?;
+
+    let fsgame: PathBuf = get_absolute_test_resource_path(&format!("{root}/game/fsgame.ltx"));
+    let modified: PathBuf = get_absolute_test_resource_path(&format!("{root}/modified"));
+    let patch: PathBuf = patches.join("xpatch_01.db");
+
+    if patch.exists() {
+      fs::remove_file(&patch)?;
+    }
+
+    let result: ArchivePatchResult = ArchiveProject::from_fsgame(&fsgame)?.build_patch(
+      &modified,
+      &patch,
+      &ArchiveWriteOptions::default(),
+    )?;
+
+    assert_eq!(
+      result.files,
+      vec!["configs\\game.ltx", "scripts\\new.script"]
+    );
+    assert_eq!(result.removed, vec!["gamedata/scripts/old.script"]);
+    assert_eq!(result.size, 15);
+
+    let project: ArchiveProject = ArchiveProject::from_fsgame(&fsgame)?;
+
+    assert_eq!(project.archives.len(), 3);
+    assert_eq!(
+      project.read_file_bytes("configs\\game.ltx")?,
+      b"game changed"
+    );
+    assert!(project
+      .get_file_descriptor("configs\\game.ltx")
+      .unwrap()
+      .source
+      .ends_with("xpatch_01.db"));
+    assert_eq!(
+      FsGame::read_from_path(&fsgame)?.resolve_entry_point(
+        project.archives[2].entry_point.as_deref(),
+        &project.archives[2].path
+      )?,
+      get_absolute_test_resource_path(&format!("{root}/game/gamedata"))
+    );
+
+    assert!(project.diff_folder(&modified)?.modified.is_empty());
+
+    let result: ArchivePatchResult =
+      project.build_patch(&modified, &patch, &ArchiveWriteOptions::default())?;
+
+    assert!(result.files.is_empty());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivePatchResult {
+  pub destination: String,
+  pub duration: u128,
+  pub files: Vec<String>,
+  /// Files missing in source folder, archives cannot express removal so they are only reported.
+  pub removed: Vec<String>,
+  pub size: u64,
+}
@@ -1,14 +1,65 @@
+use crate::archive::archive_constants::ARCHIVE_PATH_SEPARATOR;
+use crate::archive::archive_entry_reader::ArchiveEntryReader;
+use crate::archive::archive_file_descriptor::ArchiveFileReplicationDescriptor;
 use crate::project::archive_project_constants::{
   ALLOWED_PROJECT_READ_EXTENSIONS, ALLOWED_PROJECT_READ_SIZE,
 };
 use crate::project::archive_project_read_result::ProjectReadResult;
 use crate::ArchiveProject;
-use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::Read;
 use std::path::PathBuf;
+use xray_chunk::{ChunkReader, MmapChunkDataSource};
 use xray_error::{XRayError, XRayResult};
 
 impl ArchiveProject {
+  /// Get descriptor of project file.
+  /// Both engine (`\`) and unix (`/`) separators are accepted in provided name.
+  pub fn get_file_descriptor(&self, filename: &str) -> Option<&ArchiveFileReplicationDescriptor> {
+    self.files.get(filename).or_else(|| {
+      self
+        .files
+        .get(&filename.replace('/', &ARCHIVE_PATH_SEPARATOR.to_string()))
+    })
+  }
+
+  /// Open single file from project for reading.
+  /// Compressed files are decompressed on demand, CRC of the data is verified.
+  pub fn open_file(&self, filename: &str) -> XRayResult<ArchiveEntryReader> {
+    match self.get_file_descriptor(filename) {
+      None => Err(XRayError::new_not_found_error(format!(
+        "File '{}' is not found in the archive project",
+        filename
+      ))),
+      Some(file_descriptor) => ArchiveEntryReader::from_descriptor(file_descriptor),
+    }
+  }
+
+  /// Open single file from project as chunk reader.
+  /// Raw files are mapped directly from the archive, compressed files are decompressed in memory.
+  pub fn open_chunk_reader(&self, filename: &str) -> XRayResult<ChunkReader<MmapChunkDataSource>> {
+    match self.get_file_descriptor(filename) {
+      None => Err(XRayError::new_not_found_error(format!(
+        "File '{}' is not found in the archive project",
+        filename
+      ))),
+      Some(file_descriptor) => {
+        ChunkReader::from_mmap_source(ArchiveEntryReader::open_chunk_source(file_descriptor)?)
+      }
+    }
+  }
+
+  /// Read single file from project as bytes.
+  pub fn read_file_bytes(&self, filename: &str) -> XRayResult<Vec<u8>> {
+    log::info!("Trying to read file bytes from archive: {}", filename);
+
+    let mut reader: ArchiveEntryReader = self.open_file(filename)?;
+    let mut buf: Vec<u8> = Vec::new();
+
+    reader.read_to_end(&mut buf)?;
+
+    Ok(buf)
+  }
+
   /// Read single file from project as string.
   pub fn read_file_as_string(&self, filename: &str) -> XRayResult<ProjectReadResult> {
     log::info!("Trying to read file from archive: {}", filename);
@@ -20,7 +71,7 @@ impl ArchiveProject {
       )));
     }
 
-    match self.files.get(filename) {
+    match self.get_file_descriptor(filename) {
       None => Err(XRayError::new_read_error(format!(
         "File '{}' is not found in the archive project",
         filename
@@ -31,21 +82,9 @@ impl ArchiveProject {
             "File '{}' is too big to be read - {}, {} is maximum allowed",
             filename, file_descriptor.size_real, ALLOWED_PROJECT_READ_SIZE
           )));
-        } else if file_descriptor.size_real != file_descriptor.size_compressed {
-          return Err(XRayError::new_read_error(format!(
-            "File '{}' is compressed, reading compressed files is not supported yet",
-            filename
-          )));
         }
 
-        let mut source_file: File = File::open(file_descriptor.source.as_path())?;
-        let mut buf: Vec<u8> = vec![0u8; file_descriptor.size_real as usize];
-
-        source_file
-          .seek(SeekFrom::Start(file_descriptor.offset as u64))
-          .expect("Expected to be able to seek to start of the source file");
-
-        source_file.read_exact(&mut buf)?;
+        let buf: Vec<u8> = self.read_file_bytes(filename)?;
 
         Ok(ProjectReadResult::new(
           filename,
@@ -68,3 +107,146 @@ impl ArchiveProject {
     false
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::ArchiveProject;
+  use byteorder::ReadBytesExt;
+  use std::io::{Read, Write};
+  use std::path::PathBuf;
+  use xray_chunk::{ChunkReader, ChunkWriter, MmapChunkDataSource, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    get_relative_test_sample_file_path, overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_read_file() -> XRayResult {
+    let source: String = get_relative_test_sample_file_directory(file!());
+    let text: String = "[section]\nvalue = 1\n".repeat(64);
+    let binary: Vec<u8> = (0..=255u8).collect();
+
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/configs/system.ltx"))?
+      .write_all(text.as_bytes())?;
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/meshes/model.ogf"))?
+      .write_all(&binary)?;
+
+    let path: PathBuf =
+      get_absolute_test_resource_path(&get_relative_test_sample_file_path(file!(), "read.db"));
+
+    ArchiveWriter::from_path_utf8(&path)?.write_archive(
+      &get_absolute_test_resource_path(&format!("{source}/source")),
+      &ArchiveWriteOptions::default(),
+    )?;
+
+    let project: ArchiveProject = ArchiveProject::new(&path)?;
+
+    assert!(
+      project
+        .files
+        .get("configs\\system.ltx")
+        .unwrap()
+        .size_compressed
+        < text.len() as u32
+    );
+
+    assert_eq!(
+      project.read_file_bytes("configs\\system.ltx")?,
+      text.as_bytes()
+    );
+    assert_eq!(project.read_file_bytes("meshes/model.ogf")?, binary);
+    assert_eq!(
+      project.read_file_as_string("configs/system.ltx")?.content,
+      text
+    );
+
+    let mut partial: [u8; 16] = [0u8; 16];
+
+    project
+      .open_file("meshes\\model.ogf")?
+      .read_exact(&mut partial)?;
+
+    assert_eq!(partial.as_slice(), &binary[..16]);
+
+    assert!(project.read_file_bytes("meshes\\unknown.ogf").is_err());
+    assert!(project.read_file_as_string("meshes\\model.ogf").is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_open_chunk_reader() -> XRayResult {
+    let source: String = get_relative_test_sample_file_directory(file!());
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    writer.write_all(&[1, 2, 3, 4])?;
+
+    let raw: Vec<u8> = writer.flush_chunk_into_buffer::<XRayByteOrder>(3)?;
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    writer.write_all(&[7; 1024])?;
+
+    let compressed: Vec<u8> = writer.flush_chunk_into_buffer::<XRayByteOrder>(5)?;
+
+    overwrite_test_relative_resource_as_file(&format!("{source}/chunks/spawns/raw.spawn"))?
+      .write_all(&raw)?;
+    overwrite_test_relative_resource_as_file(&format!("{source}/chunks/spawns/compressed.spawn"))?
+      .write_all(&compressed)?;
+
+    let path: PathBuf =
+      get_absolute_test_resource_path(&get_relative_test_sample_file_path(file!(), "chunks.db"));
+
+    ArchiveWriter::from_path_utf8(&path)?.write_archive(
+      &get_absolute_test_resource_path(&format!("{source}/chunks")),
+      &ArchiveWriteOptions::default(),
+    )?;
+
+    let project: ArchiveProject = ArchiveProject::new(&path)?;
+
+    assert_eq!(
+      project
+        .files
+        .get("spawns\\raw.spawn")
+        .unwrap()
+        .size_compressed,
+      raw.len() as u32
+    );
+    assert!(
+      project
+        .files
+        .get("spawns\\compressed.spawn")
+        .unwrap()
+        .size_compressed
+        < compressed.len() as u32
+    );
+
+    let mut reader: ChunkReader<MmapChunkDataSource> =
+      project.open_chunk_reader("spawns/raw.spawn")?;
+
+    assert_eq!(reader.size, raw.len() as u64);
+
+    let mut chunk: ChunkReader<MmapChunkDataSource> =
+      reader.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(chunk.id, 3);
+    assert_eq!(chunk.read_u32::<XRayByteOrder>()?, 0x04030201);
+    assert!(chunk.is_ended());
+
+    let mut reader: ChunkReader<MmapChunkDataSource> =
+      project.open_chunk_reader("spawns\\compressed.spawn")?;
+    let mut chunk: ChunkReader<MmapChunkDataSource> =
+      reader.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(chunk.id, 5);
+    assert_eq!(chunk.size, 1024);
+    assert_eq!(chunk.read_u8()?, 7);
+
+    assert!(project.open_chunk_reader("spawns\\unknown.spawn").is_err());
+
+    Ok(())
+  }
+}
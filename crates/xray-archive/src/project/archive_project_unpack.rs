@@ -1,6 +1,10 @@
+use crate::archive::archive_constants::ARCHIVE_PATH_SEPARATOR;
+use crate::archive::archive_entry_reader::ArchiveEntryReader;
 use crate::archive::archive_file_descriptor::ArchiveFileReplicationDescriptor;
+use crate::project::archive_project_unpack_options::{ArchiveUnpackFilter, ArchiveUnpackOptions};
 use crate::project::archive_project_unpack_result::ArchiveUnpackResult;
 use crate::ArchiveProject;
+use crc32fast::Hasher;
 use minilzo_rs::LZO;
 use std::cmp::{max, min};
 use std::collections::HashSet;
@@ -11,107 +15,206 @@ use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 use xray_error::XRayResult;
-use xray_utils::{assert, assert_equal, assert_not_equal};
+use xray_utils::{assert, assert_not_equal};
 
 impl ArchiveProject {
   pub fn unpack<P: AsRef<Path>>(&self, destination: P) -> XRayResult<ArchiveUnpackResult> {
+    self.unpack_opt(destination, &ArchiveUnpackOptions::default())
+  }
+
+  pub fn unpack_opt<P: AsRef<Path>>(
+    &self,
+    destination: P,
+    options: &ArchiveUnpackOptions,
+  ) -> XRayResult<ArchiveUnpackResult> {
     let start: Instant = Instant::now();
     let lzo: LZO = LZO::init().unwrap();
 
+    let (files, skipped_files): (Vec<&ArchiveFileReplicationDescriptor>, usize) =
+      self.get_unpack_files(destination.as_ref(), options)?;
+
     let mut unpacked_files_count: usize = 0;
-    let unpacked_files_chunk: usize = max(self.files.len() / 100 * 5, 5);
+    let unpacked_files_chunk: usize = max(files.len() / 100 * 5, 5);
 
     // Prepare structure of folders for further unpacking.
-    self.unpack_dirs(destination.as_ref())?;
+    if !options.is_dry_run {
+      Self::unpack_dirs(destination.as_ref(), &files)?;
+    }
 
     let prepared_at: Duration = start.elapsed();
 
     // Unpack each separate file.
-    for file_descriptor in self.files.values() {
-      if file_descriptor.size_real > 0 {
-        Self::unpack_file(&lzo, destination.as_ref(), file_descriptor)?;
-      }
+    if !options.is_dry_run {
+      for file_descriptor in &files {
+        if file_descriptor.size_real > 0 {
+          Self::unpack_file(&lzo, destination.as_ref(), file_descriptor)?;
+        }
 
-      unpacked_files_count += 1;
+        unpacked_files_count += 1;
 
-      if unpacked_files_count % unpacked_files_chunk == 0 {
-        log::info!(
-          "Unpacked {}/{} files",
-          unpacked_files_count,
-          self.files.len()
-        )
+        if unpacked_files_count % unpacked_files_chunk == 0 {
+          log::info!("Unpacked {}/{} files", unpacked_files_count, files.len())
+        }
       }
     }
 
     let unpacked_at: Duration = start.elapsed();
 
-    Ok(ArchiveUnpackResult {
-      archives: self
-        .archives
-        .iter()
-        .map(|it| it.path.to_str().unwrap().into())
-        .collect(),
-      destination: destination.as_ref().to_str().unwrap().into(),
-      duration: unpacked_at.as_millis(),
-      prepare_duration: prepared_at.as_millis(),
-      unpack_duration: unpacked_at.as_millis() - prepared_at.as_millis(),
-      unpacked_size: self.get_real_size(),
-    })
+    Ok(self.get_unpack_result(
+      destination.as_ref(),
+      &files,
+      skipped_files,
+      prepared_at,
+      unpacked_at,
+    ))
   }
 
   pub async fn unpack_parallel<P: AsRef<Path>>(
     &self,
     destination: P,
     concurrency: usize,
+  ) -> XRayResult<ArchiveUnpackResult> {
+    self
+      .unpack_parallel_opt(destination, concurrency, &ArchiveUnpackOptions::default())
+      .await
+  }
+
+  pub async fn unpack_parallel_opt<P: AsRef<Path>>(
+    &self,
+    destination: P,
+    concurrency: usize,
+    options: &ArchiveUnpackOptions,
   ) -> XRayResult<ArchiveUnpackResult> {
     let start: Instant = Instant::now();
 
+    let (files, skipped_files): (Vec<&ArchiveFileReplicationDescriptor>, usize) =
+      self.get_unpack_files(destination.as_ref(), options)?;
+
     let mut unpacked_files_count: usize = 0;
-    let unpacked_files_chunk: usize = max(self.files.len() / 100 * 5, 5);
+    let unpacked_files_chunk: usize = max(files.len() / 100 * 5, 5);
 
     // Prepare structure of folders for further unpacking.
-    self.unpack_dirs(destination.as_ref())?;
+    if !options.is_dry_run {
+      Self::unpack_dirs(destination.as_ref(), &files)?;
+    }
 
     let prepared_at: Duration = start.elapsed();
 
-    let mut tasks_set = bounded_join_set::JoinSet::new(concurrency);
+    if !options.is_dry_run {
+      let mut tasks_set = bounded_join_set::JoinSet::new(concurrency);
 
-    // Unpack each separate file.
-    for file_descriptor in self.files.values() {
-      if file_descriptor.size_real > 0 {
-        let descriptor: ArchiveFileReplicationDescriptor = file_descriptor.clone();
-        let destination: PathBuf = destination.as_ref().into();
+      // Unpack each separate file.
+      for file_descriptor in &files {
+        if file_descriptor.size_real > 0 {
+          let descriptor: ArchiveFileReplicationDescriptor = (*file_descriptor).clone();
+          let destination: PathBuf = destination.as_ref().into();
 
-        tasks_set
-          .spawn(async move { Self::unpack_file(&LZO::init().unwrap(), destination, &descriptor) });
+          tasks_set.spawn(async move {
+            Self::unpack_file(&LZO::init().unwrap(), destination, &descriptor)
+          });
+        }
       }
-    }
 
-    while tasks_set.join_next().await.is_some() {
-      unpacked_files_count += 1;
+      while tasks_set.join_next().await.is_some() {
+        unpacked_files_count += 1;
 
-      if unpacked_files_count % unpacked_files_chunk == 0 {
-        log::info!(
-          "Unpacked {unpacked_files_count} / {} files",
-          self.files.len()
-        )
+        if unpacked_files_count % unpacked_files_chunk == 0 {
+          log::info!("Unpacked {unpacked_files_count} / {} files", files.len())
+        }
       }
     }
 
     let unpacked_at: Duration = start.elapsed();
 
-    Ok(ArchiveUnpackResult {
+    Ok(self.get_unpack_result(
+      destination.as_ref(),
+      &files,
+      skipped_files,
+      prepared_at,
+      unpacked_at,
+    ))
+  }
+
+  /// Get list of files to unpack based on provided options, sorted by name.
+  /// Returns selected files and count of files skipped as already up-to-date.
+  fn get_unpack_files(
+    &self,
+    destination: &Path,
+    options: &ArchiveUnpackOptions,
+  ) -> XRayResult<(Vec<&ArchiveFileReplicationDescriptor>, usize)> {
+    let filter: ArchiveUnpackFilter = options.get_filter()?;
+
+    let mut files: Vec<&ArchiveFileReplicationDescriptor> = Vec::new();
+    let mut skipped_files: usize = 0;
+
+    for (name, descriptor) in &self.files {
+      if !filter.is_matching(name) {
+        continue;
+      }
+
+      if options.is_incremental && Self::is_unpacked_file(destination, descriptor)? {
+        skipped_files += 1;
+      } else {
+        files.push(descriptor);
+      }
+    }
+
+    files.sort_by(|first, second| first.name.cmp(&second.name));
+
+    Ok((files, skipped_files))
+  }
+
+  /// Check whether destination file already exists with matching size and CRC.
+  fn is_unpacked_file(
+    destination: &Path,
+    file_descriptor: &ArchiveFileReplicationDescriptor,
+  ) -> XRayResult<bool> {
+    let file_path: PathBuf = Self::get_unpack_path(destination, file_descriptor);
+
+    match fs::metadata(&file_path) {
+      Ok(metadata) if metadata.is_file() && metadata.len() == file_descriptor.size_real as u64 => {}
+      _ => return Ok(false),
+    }
+
+    let mut file: File = File::open(&file_path)?;
+    let mut hasher: Hasher = Hasher::new();
+    let mut buf: Vec<u8> = vec![0u8; 256 * 1024];
+
+    loop {
+      let read: usize = file.read(&mut buf)?;
+
+      if read == 0 {
+        break;
+      }
+
+      hasher.update(&buf[..read]);
+    }
+
+    Ok(hasher.finalize() == file_descriptor.crc)
+  }
+
+  fn get_unpack_result(
+    &self,
+    destination: &Path,
+    files: &[&ArchiveFileReplicationDescriptor],
+    skipped_files: usize,
+    prepared_at: Duration,
+    unpacked_at: Duration,
+  ) -> ArchiveUnpackResult {
+    ArchiveUnpackResult {
       archives: self
         .archives
         .iter()
         .map(|it| it.path.to_str().unwrap().into())
         .collect(),
-      destination: destination.as_ref().to_str().unwrap().into(),
+      destination: destination.to_str().unwrap().into(),
       duration: unpacked_at.as_millis(),
+      files: files.iter().map(|it| it.name.clone()).collect(),
       prepare_duration: prepared_at.as_millis(),
+      skipped_files,
       unpack_duration: unpacked_at.as_millis() - prepared_at.as_millis(),
-      unpacked_size: self.get_real_size(),
-    })
+      unpacked_size: files.iter().map(|it| it.size_real as u64).sum(),
+    }
   }
 
   fn unpack_file<P: AsRef<Path>>(
@@ -119,10 +222,7 @@ impl ArchiveProject {
     destination: P,
     file_descriptor: &ArchiveFileReplicationDescriptor,
   ) -> XRayResult {
-    let mut file_path: PathBuf = destination.as_ref().into();
-
-    file_path.push(&file_descriptor.destination);
-    file_path.push(&file_descriptor.name);
+    let file_path: PathBuf = Self::get_unpack_path(destination.as_ref(), file_descriptor);
 
     let mut source_file: File = File::open(file_descriptor.source.as_path())?;
 
@@ -142,13 +242,8 @@ impl ArchiveProject {
       let mut buf: Vec<u8> = vec![0u8; file_descriptor.size_compressed as usize];
       source_file.read_exact(buf.as_mut_slice())?;
 
-      let decompressed_buf: Vec<u8> = lzo
-        .decompress_safe(buf.as_slice(), file_descriptor.size_real as usize)
-        .expect("Valid LZO data");
-
-      let actual_crc: u32 = crc32fast::hash(decompressed_buf.as_slice());
-
-      assert_equal(file_descriptor.crc, actual_crc, "CRCs do not match")?;
+      let decompressed_buf: Vec<u8> =
+        ArchiveEntryReader::decompress(lzo, buf.as_slice(), file_descriptor)?;
 
       dest_file
         .write_all(decompressed_buf.as_slice())
@@ -182,15 +277,24 @@ impl ArchiveProject {
     Ok(())
   }
 
-  fn unpack_dirs<P: AsRef<Path>>(&self, destination: P) -> XRayResult {
+  /// Get path of unpacked file, archive separators are converted to platform ones.
+  fn get_unpack_path(
+    destination: &Path,
+    file_descriptor: &ArchiveFileReplicationDescriptor,
+  ) -> PathBuf {
+    let mut file_path: PathBuf = destination.join(&file_descriptor.destination);
+
+    file_path.extend(file_descriptor.name.split(ARCHIVE_PATH_SEPARATOR));
+
+    file_path
+  }
+
+  fn unpack_dirs(destination: &Path, files: &[&ArchiveFileReplicationDescriptor]) -> XRayResult {
     let mut set: HashSet<PathBuf> = HashSet::new();
 
-    for descriptor in self.files.values() {
+    for descriptor in files {
       set.insert(
-        destination
-          .as_ref()
-          .join(&descriptor.destination)
-          .join(&descriptor.name)
+        Self::get_unpack_path(destination, descriptor)
           .parent()
           .expect("Unpacked archive dire parent expected")
           .into(),
@@ -208,3 +312,104 @@ impl ArchiveProject {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::project::archive_project_unpack_options::ArchiveUnpackOptions;
+  use crate::project::archive_project_unpack_result::ArchiveUnpackResult;
+  use crate::ArchiveProject;
+  use std::fs;
+  use std::io::Write;
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    get_relative_test_sample_file_path, overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_unpack_opt() -> XRayResult {
+    let source: String = get_relative_test_sample_file_directory(file!());
+
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/configs/system.ltx"))?
+      .write_all(b"[section]\nvalue = 1\n")?;
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/scripts/_g.script"))?
+      .write_all(b"function main() end")?;
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/textures/face.dds"))?
+      .write_all(&[0u8; 1024])?;
+
+    let path: PathBuf =
+      get_absolute_test_resource_path(&get_relative_test_sample_file_path(file!(), "unpack.db"));
+
+    ArchiveWriter::from_path_utf8(&path)?.write_archive(
+      &get_absolute_test_resource_path(&format!("{source}/source")),
+      &ArchiveWriteOptions {
+        entry_point: Some(String::from("gamedata")),
+        ..ArchiveWriteOptions::default()
+      },
+    )?;
+
+    let project: ArchiveProject = ArchiveProject::new(&path)?;
+    let destination: PathBuf = get_absolute_test_resource_path(&format!("{source}/unpacked"));
+    let gamedata: PathBuf = destination.join("gamedata");
+
+    if destination.exists() {
+      fs::remove_dir_all(&destination)?;
+    }
+
+    let options: ArchiveUnpackOptions = ArchiveUnpackOptions {
+      include: vec![String::from("configs/**"), String::from("scripts/**")],
+      is_dry_run: true,
+      ..ArchiveUnpackOptions::default()
+    };
+
+    let result: ArchiveUnpackResult = project.unpack_opt(&destination, &options)?;
+
+    assert_eq!(
+      result.files,
+      vec!["configs\\system.ltx", "scripts\\_g.script"]
+    );
+    assert!(!destination.exists());
+
+    let result: ArchiveUnpackResult = project.unpack_opt(
+      &destination,
+      &ArchiveUnpackOptions {
+        is_dry_run: false,
+        ..options
+      },
+    )?;
+
+    assert_eq!(result.files.len(), 2);
+    assert_eq!(result.unpacked_size, 20 + 19);
+    assert!(gamedata.join("configs").join("system.ltx").is_file());
+    assert!(gamedata.join("scripts").join("_g.script").is_file());
+    assert!(!gamedata.join("textures").exists());
+
+    fs::write(
+      gamedata.join("scripts").join("_g.script"),
+      b"function main() return end",
+    )?;
+
+    let result: ArchiveUnpackResult = project.unpack_opt(
+      &destination,
+      &ArchiveUnpackOptions {
+        exclude: vec![String::from("textures/**")],
+        is_incremental: true,
+        ..ArchiveUnpackOptions::default()
+      },
+    )?;
+
+    assert_eq!(result.files, vec!["scripts\\_g.script"]);
+    assert_eq!(result.skipped_files, 1);
+    assert_eq!(
+      fs::read(gamedata.join("scripts").join("_g.script"))?,
+      b"function main() end"
+    );
+
+    fs::remove_dir_all(&destination)?;
+
+    Ok(())
+  }
+}
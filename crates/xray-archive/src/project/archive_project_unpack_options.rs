@@ -0,0 +1,105 @@
+use glob::{MatchOptions, Pattern};
+use xray_error::{XRayError, XRayResult};
+
+/// Options of archive project unpacking.
+///
+/// Include and exclude patterns are globs matched case-insensitively against archive file names
+/// with `/` separators, for example `configs/**` or `**/*.script`.
+#[derive(Clone, Debug, Default)]
+pub struct ArchiveUnpackOptions {
+  /// Unpack only files matching any of patterns, all files are unpacked when empty.
+  pub include: Vec<String>,
+  /// Skip files matching any of patterns.
+  pub exclude: Vec<String>,
+  /// Only list files that would be unpacked without writing anything.
+  pub is_dry_run: bool,
+  /// Skip files already existing in destination with matching size and CRC.
+  pub is_incremental: bool,
+}
+
+impl ArchiveUnpackOptions {
+  /// Create filter checking archive file names against include/exclude patterns.
+  pub fn get_filter(&self) -> XRayResult<ArchiveUnpackFilter> {
+    Ok(ArchiveUnpackFilter {
+      include: Self::compile_patterns(&self.include)?,
+      exclude: Self::compile_patterns(&self.exclude)?,
+    })
+  }
+
+  fn compile_patterns(patterns: &[String]) -> XRayResult<Vec<Pattern>> {
+    patterns
+      .iter()
+      .map(|pattern| {
+        Pattern::new(&pattern.replace('\\', "/")).map_err(|error| {
+          XRayError::new_parsing_error(format!("Invalid unpack pattern '{}': {}", pattern, error))
+        })
+      })
+      .collect()
+  }
+}
+
+pub struct ArchiveUnpackFilter {
+  include: Vec<Pattern>,
+  exclude: Vec<Pattern>,
+}
+
+impl ArchiveUnpackFilter {
+  const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+  };
+
+  pub fn is_matching(&self, name: &str) -> bool {
+    let name: String = name.replace('\\', "/");
+
+    (self.include.is_empty()
+      || self
+        .include
+        .iter()
+        .any(|it| it.matches_with(&name, Self::MATCH_OPTIONS)))
+      && !self
+        .exclude
+        .iter()
+        .any(|it| it.matches_with(&name, Self::MATCH_OPTIONS))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::project::archive_project_unpack_options::{ArchiveUnpackFilter, ArchiveUnpackOptions};
+  use xray_error::XRayResult;
+
+  #[test]
+  fn test_filter() -> XRayResult {
+    let filter: ArchiveUnpackFilter = ArchiveUnpackOptions {
+      include: vec![
+        String::from("configs/**"),
+        String::from("scripts\\*.script"),
+      ],
+      exclude: vec![String::from("configs/text/**")],
+      ..ArchiveUnpackOptions::default()
+    }
+    .get_filter()?;
+
+    assert!(filter.is_matching("configs\\system.ltx"));
+    assert!(filter.is_matching("Configs\\Creatures\\actor.ltx"));
+    assert!(filter.is_matching("scripts\\_g.script"));
+    assert!(!filter.is_matching("scripts\\nested\\_g.script"));
+    assert!(!filter.is_matching("configs\\text\\rus\\st_items.xml"));
+    assert!(!filter.is_matching("textures\\act\\act_face.dds"));
+
+    let filter: ArchiveUnpackFilter = ArchiveUnpackOptions::default().get_filter()?;
+
+    assert!(filter.is_matching("textures\\act\\act_face.dds"));
+
+    assert!(ArchiveUnpackOptions {
+      include: vec![String::from("configs/[")],
+      ..ArchiveUnpackOptions::default()
+    }
+    .get_filter()
+    .is_err());
+
+    Ok(())
+  }
+}
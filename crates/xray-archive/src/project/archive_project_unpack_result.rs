@@ -6,7 +6,9 @@ pub struct ArchiveUnpackResult {
   pub archives: Vec<String>,
   pub duration: u128,
   pub destination: String,
+  pub files: Vec<String>,
   pub prepare_duration: u128,
+  pub skipped_files: usize,
   pub unpacked_size: u64,
   pub unpack_duration: u128,
 }
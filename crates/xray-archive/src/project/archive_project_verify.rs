@@ -0,0 +1,228 @@
+use crate::archive::archive_descriptor::ArchiveDescriptor;
+use crate::archive::archive_entry_reader::ArchiveEntryReader;
+use crate::archive::archive_file_descriptor::ArchiveFileDescriptor;
+use crate::archive::archive_version::ArchiveVersion;
+use crate::project::archive_project_verify_result::{
+  ArchiveVerifyFailure, ArchiveVerifyFailureReason, ArchiveVerifyResult,
+};
+use crate::ArchiveProject;
+use crc32fast::Hasher;
+use minilzo_rs::LZO;
+use std::cmp::min;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Instant;
+use xray_error::{XRayError, XRayResult};
+
+impl ArchiveProject {
+  /// Verify data of every entry in project archives without writing anything to disk.
+  ///
+  /// Entries shadowed by later archives are checked as well. Corrupted or truncated entries
+  /// are collected in the result instead of interrupting verification.
+  pub fn verify(&self) -> XRayResult<ArchiveVerifyResult> {
+    let start: Instant = Instant::now();
+    let lzo: LZO = LZO::init().map_err(|error| {
+      XRayError::new_unexpected_error(format!("Failed to initialize LZO: {:?}", error))
+    })?;
+
+    let mut result: ArchiveVerifyResult = ArchiveVerifyResult {
+      archives: Vec::new(),
+      checked_files: 0,
+      checked_size: 0,
+      duration: 0,
+      failures: Vec::new(),
+    };
+
+    for archive in &self.archives {
+      log::info!("Verifying archive: {}", archive.path.display());
+
+      Self::verify_archive(&lzo, archive, &mut result)?;
+
+      result.archives.push(archive.path.to_string_lossy().into());
+    }
+
+    result.duration = start.elapsed().as_millis();
+
+    Ok(result)
+  }
+
+  fn verify_archive(
+    lzo: &LZO,
+    archive: &ArchiveDescriptor,
+    result: &mut ArchiveVerifyResult,
+  ) -> XRayResult {
+    let mut file: File = File::open(&archive.path)?;
+    let file_size: u64 = file.metadata()?.len();
+
+    let mut descriptors: Vec<&ArchiveFileDescriptor> = archive.files.values().collect();
+
+    // Read entries sequentially to keep seeking cheap.
+    descriptors.sort_by_key(|it| it.offset);
+
+    for descriptor in descriptors {
+      if let Some(reason) =
+        Self::verify_entry(lzo, &mut file, file_size, archive.version, descriptor)?
+      {
+        log::warn!(
+          "Corrupted archive entry {} in {}: {}",
+          descriptor.name,
+          archive.path.display(),
+          reason
+        );
+
+        result.failures.push(ArchiveVerifyFailure {
+          archive: archive.path.to_string_lossy().into(),
+          name: descriptor.name.clone(),
+          reason,
+        });
+      }
+
+      result.checked_files += 1;
+      result.checked_size += descriptor.size_real as u64;
+    }
+
+    Ok(())
+  }
+
+  fn verify_entry(
+    lzo: &LZO,
+    file: &mut File,
+    file_size: u64,
+    version: ArchiveVersion,
+    descriptor: &ArchiveFileDescriptor,
+  ) -> XRayResult<Option<ArchiveVerifyFailureReason>> {
+    let end: u64 = descriptor.offset as u64 + descriptor.size_compressed as u64;
+
+    if end > file_size {
+      return Ok(Some(ArchiveVerifyFailureReason::Truncated {
+        expected: descriptor.size_compressed as u64,
+        available: file_size.saturating_sub(descriptor.offset as u64),
+      }));
+    }
+
+    file.seek(SeekFrom::Start(descriptor.offset as u64))?;
+
+    let (size, crc): (u32, u32) = if descriptor.size_real == descriptor.size_compressed {
+      let mut hasher: Hasher = Hasher::new();
+      let mut remaining: usize = descriptor.size_real as usize;
+      let mut buf: Vec<u8> = vec![0u8; min(256 * 1024, remaining)];
+
+      while remaining > 0 {
+        let to_read: usize = min(buf.len(), remaining);
+
+        file.read_exact(&mut buf[..to_read])?;
+        hasher.update(&buf[..to_read]);
+
+        remaining -= to_read;
+      }
+
+      (descriptor.size_real, hasher.finalize())
+    } else {
+      let mut buf: Vec<u8> = vec![0u8; descriptor.size_compressed as usize];
+
+      file.read_exact(&mut buf)?;
+
+      match ArchiveEntryReader::decompress_data(lzo, &buf, version, descriptor.size_real) {
+        Ok(decompressed) => (decompressed.len() as u32, crc32fast::hash(&decompressed)),
+        Err(error) => {
+          return Ok(Some(ArchiveVerifyFailureReason::Decompression {
+            message: error.to_string(),
+          }))
+        }
+      }
+    };
+
+    if size != descriptor.size_real {
+      Ok(Some(ArchiveVerifyFailureReason::SizeMismatch {
+        expected: descriptor.size_real,
+        actual: size,
+      }))
+    } else if version.is_with_crc() && crc != descriptor.crc {
+      Ok(Some(ArchiveVerifyFailureReason::CrcMismatch {
+        expected: descriptor.crc,
+        actual: crc,
+      }))
+    } else {
+      Ok(None)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::project::archive_project_verify_result::{
+    ArchiveVerifyFailureReason, ArchiveVerifyResult,
+  };
+  use crate::ArchiveProject;
+  use std::fs;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    get_relative_test_sample_file_path, overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_verify() -> XRayResult {
+    let source: String = get_relative_test_sample_file_directory(file!());
+
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/configs/system.ltx"))?
+      .write_all("[section]\nvalue = 1\n".repeat(64).as_bytes())?;
+    overwrite_test_relative_resource_as_file(&format!("{source}/source/sounds/music.ogg"))?
+      .write_all(&(0..=255u8).collect::<Vec<u8>>())?;
+
+    let path: PathBuf =
+      get_absolute_test_resource_path(&get_relative_test_sample_file_path(file!(), "verify.db"));
+
+    ArchiveWriter::from_path_utf8(&path)?.write_archive(
+      &get_absolute_test_resource_path(&format!("{source}/source")),
+      &ArchiveWriteOptions::default(),
+    )?;
+
+    let result: ArchiveVerifyResult = ArchiveProject::new(&path)?.verify()?;
+
+    assert!(result.is_valid());
+    assert_eq!(result.checked_files, 2);
+    assert_eq!(result.checked_size, 64 * 20 + 256);
+
+    let corrupted: PathBuf =
+      get_absolute_test_resource_path(&get_relative_test_sample_file_path(file!(), "corrupted.db"));
+
+    fs::copy(&path, &corrupted)?;
+
+    let project: ArchiveProject = ArchiveProject::new(&corrupted)?;
+    let music_offset: u32 = project.files.get("sounds\\music.ogg").unwrap().offset;
+    let mut file: File = File::options().write(true).open(&corrupted)?;
+
+    file.seek(SeekFrom::Start(music_offset as u64 + 10))?;
+    file.write_all(&[0xFF])?;
+
+    let result: ArchiveVerifyResult = project.verify()?;
+
+    assert!(!result.is_valid());
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].name, "sounds\\music.ogg");
+    assert!(matches!(
+      result.failures[0].reason,
+      ArchiveVerifyFailureReason::CrcMismatch { .. }
+    ));
+
+    let system_offset: u32 = project.files.get("configs\\system.ltx").unwrap().offset;
+
+    file.set_len(system_offset as u64 + 4)?;
+
+    let result: ArchiveVerifyResult = project.verify()?;
+
+    assert_eq!(result.failures.len(), 2);
+    assert!(result
+      .failures
+      .iter()
+      .all(|it| matches!(it.reason, ArchiveVerifyFailureReason::Truncated { .. })));
+
+    Ok(())
+  }
+}
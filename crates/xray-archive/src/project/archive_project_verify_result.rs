@@ -0,0 +1,67 @@
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// Reason of archive entry verification failure.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ArchiveVerifyFailureReason {
+  /// Entry data goes beyond the end of archive file.
+  Truncated { expected: u64, available: u64 },
+  /// Compressed entry data cannot be decompressed.
+  Decompression { message: String },
+  /// Size of the entry data does not match descriptor.
+  SizeMismatch { expected: u32, actual: u32 },
+  /// CRC32 of the entry data does not match descriptor.
+  CrcMismatch { expected: u32, actual: u32 },
+}
+
+impl Display for ArchiveVerifyFailureReason {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Truncated {
+        expected,
+        available,
+      } => write!(
+        formatter,
+        "truncated, expected {} bytes, {} available",
+        expected, available
+      ),
+      Self::Decompression { message } => write!(formatter, "decompression failed, {}", message),
+      Self::SizeMismatch { expected, actual } => write!(
+        formatter,
+        "size mismatch, expected {}, got {}",
+        expected, actual
+      ),
+      Self::CrcMismatch { expected, actual } => write!(
+        formatter,
+        "crc mismatch, expected {:#010x}, got {:#010x}",
+        expected, actual
+      ),
+    }
+  }
+}
+
+/// Single corrupted archive entry.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveVerifyFailure {
+  pub archive: String,
+  pub name: String,
+  pub reason: ArchiveVerifyFailureReason,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveVerifyResult {
+  pub archives: Vec<String>,
+  pub checked_files: usize,
+  pub checked_size: u64,
+  pub duration: u128,
+  pub failures: Vec<ArchiveVerifyFailure>,
+}
+
+impl ArchiveVerifyResult {
+  pub fn is_valid(&self) -> bool {
+    self.failures.is_empty()
+  }
+}
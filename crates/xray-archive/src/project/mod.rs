@@ -1,6 +1,15 @@
 pub(crate) mod archive_project;
 pub(crate) mod archive_project_constants;
+pub(crate) mod archive_project_diff;
+pub(crate) mod archive_project_diff_result;
+pub(crate) mod archive_project_fsgame;
+pub(crate) mod archive_project_manifest;
+pub(crate) mod archive_project_patch;
+pub(crate) mod archive_project_patch_result;
 pub(crate) mod archive_project_read;
 pub(crate) mod archive_project_read_result;
 pub(crate) mod archive_project_unpack;
+pub(crate) mod archive_project_unpack_options;
 pub(crate) mod archive_project_unpack_result;
+pub(crate) mod archive_project_verify;
+pub(crate) mod archive_project_verify_result;
@@ -0,0 +1,65 @@
+use crate::archive::archive_entry_reader::ArchiveEntryReader;
+use crate::vfs::virtual_file_location::VirtualFileLocation;
+use crate::vfs::virtual_file_system::VirtualFileSystem;
+use crate::vfs::virtual_file_system_utils::{is_virtual_path_in_dir, normalize_virtual_path};
+use crate::ArchiveProject;
+use std::collections::BTreeMap;
+use std::io::Read;
+use xray_error::{XRayError, XRayResult};
+
+/// Virtual file system over mounted archives of archive project.
+pub struct ArchiveFileSystem {
+  pub project: ArchiveProject,
+  /// Normalized path to archive project file name mapping.
+  pub files: BTreeMap<String, String>,
+}
+
+impl ArchiveFileSystem {
+  pub fn new(project: ArchiveProject) -> Self {
+    Self {
+      files: project
+        .files
+        .keys()
+        .map(|name| (normalize_virtual_path(name), name.clone()))
+        .collect(),
+      project,
+    }
+  }
+}
+
+impl VirtualFileSystem for ArchiveFileSystem {
+  fn exists(&self, path: &str) -> bool {
+    self.files.contains_key(&normalize_virtual_path(path))
+  }
+
+  fn open(&self, path: &str) -> XRayResult<Box<dyn Read + Send>> {
+    match self.resolve(path) {
+      Some(VirtualFileLocation::Archive(descriptor)) => {
+        Ok(Box::new(ArchiveEntryReader::from_descriptor(&descriptor)?))
+      }
+      _ => Err(XRayError::new_not_found_error(format!(
+        "File '{}' is not found in the archive project",
+        path
+      ))),
+    }
+  }
+
+  fn list(&self, dir: &str) -> Vec<String> {
+    let dir: String = normalize_virtual_path(dir);
+
+    self
+      .files
+      .keys()
+      .filter(|it| is_virtual_path_in_dir(it, &dir))
+      .cloned()
+      .collect()
+  }
+
+  fn resolve(&self, path: &str) -> Option<VirtualFileLocation> {
+    self
+      .files
+      .get(&normalize_virtual_path(path))
+      .and_then(|name| self.project.files.get(name))
+      .map(|descriptor| VirtualFileLocation::Archive(descriptor.clone()))
+  }
+}
@@ -0,0 +1,86 @@
+use crate::vfs::virtual_file_location::VirtualFileLocation;
+use crate::vfs::virtual_file_system::VirtualFileSystem;
+use crate::vfs::virtual_file_system_utils::{is_virtual_path_in_dir, normalize_virtual_path};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use xray_error::{XRayError, XRayResult};
+
+/// Virtual file system over loose files folder, for example unpacked `gamedata`.
+pub struct FolderFileSystem {
+  pub root: PathBuf,
+  pub files: BTreeMap<String, PathBuf>,
+}
+
+impl FolderFileSystem {
+  /// Index all files of provided folder.
+  pub fn new<P: AsRef<Path>>(root: &P) -> XRayResult<Self> {
+    if !root.as_ref().is_dir() {
+      return Err(XRayError::new_not_found_error(format!(
+        "Unable to open folder file system, {} is not a directory",
+        root.as_ref().display()
+      )));
+    }
+
+    let mut files: BTreeMap<String, PathBuf> = BTreeMap::new();
+
+    for entry in WalkDir::new(root)
+      .into_iter()
+      .filter_map(|entry| entry.ok())
+      .filter(|entry| entry.file_type().is_file())
+    {
+      if let Some(relative) = entry
+        .path()
+        .strip_prefix(root)
+        .ok()
+        .and_then(|it| it.to_str())
+      {
+        files.insert(normalize_virtual_path(relative), entry.path().into());
+      } else {
+        log::warn!("Skipping not indexable file: {}", entry.path().display());
+      }
+    }
+
+    Ok(Self {
+      root: root.as_ref().into(),
+      files,
+    })
+  }
+}
+
+impl VirtualFileSystem for FolderFileSystem {
+  fn exists(&self, path: &str) -> bool {
+    self.files.contains_key(&normalize_virtual_path(path))
+  }
+
+  fn open(&self, path: &str) -> XRayResult<Box<dyn Read + Send>> {
+    match self.files.get(&normalize_virtual_path(path)) {
+      Some(path) => Ok(Box::new(File::open(path)?)),
+      None => Err(XRayError::new_not_found_error(format!(
+        "File '{}' is not found in folder {}",
+        path,
+        self.root.display()
+      ))),
+    }
+  }
+
+  fn list(&self, dir: &str) -> Vec<String> {
+    let dir: String = normalize_virtual_path(dir);
+
+    self
+      .files
+      .keys()
+      .filter(|it| is_virtual_path_in_dir(it, &dir))
+      .cloned()
+      .collect()
+  }
+
+  fn resolve(&self, path: &str) -> Option<VirtualFileLocation> {
+    self
+      .files
+      .get(&normalize_virtual_path(path))
+      .map(|it| VirtualFileLocation::File(it.clone()))
+  }
+}
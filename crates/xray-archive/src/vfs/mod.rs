@@ -0,0 +1,6 @@
+pub(crate) mod archive_file_system;
+pub(crate) mod folder_file_system;
+pub(crate) mod overlay_file_system;
+pub(crate) mod virtual_file_location;
+pub(crate) mod virtual_file_system;
+pub(crate) mod virtual_file_system_utils;
@@ -0,0 +1,175 @@
+use crate::fs::fsgame::FsGame;
+use crate::fs::fsgame_constants::FSGAME_GAMEDATA_ALIAS;
+use crate::vfs::archive_file_system::ArchiveFileSystem;
+use crate::vfs::folder_file_system::FolderFileSystem;
+use crate::vfs::virtual_file_location::VirtualFileLocation;
+use crate::vfs::virtual_file_system::VirtualFileSystem;
+use crate::ArchiveProject;
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use xray_error::{XRayError, XRayResult};
+
+/// Stack of virtual file systems.
+/// Layers are ordered the same way engine mounts them: files of later layers override earlier ones.
+#[derive(Default)]
+pub struct OverlayFileSystem {
+  pub layers: Vec<Box<dyn VirtualFileSystem>>,
+}
+
+impl OverlayFileSystem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Create overlay with archives mounted by fsgame.ltx and loose `$game_data$` files on top.
+  pub fn from_fsgame<P: AsRef<Path>>(path: &P) -> XRayResult<Self> {
+    let fsgame: FsGame = FsGame::read_from_path(path)?;
+    let mut overlay: Self = Self::new();
+
+    overlay.push(ArchiveFileSystem::new(ArchiveProject::from_fsgame(path)?));
+
+    if let Some(gamedata) = fsgame
+      .get_alias_path(FSGAME_GAMEDATA_ALIAS)
+      .filter(|it| it.is_dir())
+    {
+      overlay.push(FolderFileSystem::new(&gamedata)?);
+    }
+
+    Ok(overlay)
+  }
+
+  /// Create overlay of provided loose folders, later folders override earlier ones.
+  pub fn from_folders(folders: &[PathBuf]) -> XRayResult<Self> {
+    let mut overlay: Self = Self::new();
+
+    for folder in folders {
+      overlay.push(FolderFileSystem::new(folder)?);
+    }
+
+    Ok(overlay)
+  }
+
+  /// Add layer on top of existing ones.
+  pub fn push<T: VirtualFileSystem + 'static>(&mut self, layer: T) {
+    self.layers.push(Box::new(layer));
+  }
+
+  /// Get top-most layer containing provided file.
+  pub fn get_owning_layer(&self, path: &str) -> Option<&dyn VirtualFileSystem> {
+    self
+      .layers
+      .iter()
+      .rev()
+      .find(|it| it.exists(path))
+      .map(|it| it.as_ref())
+  }
+}
+
+impl VirtualFileSystem for OverlayFileSystem {
+  fn exists(&self, path: &str) -> bool {
+    self.layers.iter().any(|it| it.exists(path))
+  }
+
+  fn open(&self, path: &str) -> XRayResult<Box<dyn Read + Send>> {
+    match self.get_owning_layer(path) {
+      Some(layer) => layer.open(path),
+      None => Err(XRayError::new_not_found_error(format!(
+        "File '{}' is not found in any of file system layers",
+        path
+      ))),
+    }
+  }
+
+  fn list(&self, dir: &str) -> Vec<String> {
+    self
+      .layers
+      .iter()
+      .flat_map(|it| it.list(dir))
+      .collect::<BTreeSet<String>>()
+      .into_iter()
+      .collect()
+  }
+
+  fn resolve(&self, path: &str) -> Option<VirtualFileLocation> {
+    self
+      .get_owning_layer(path)
+      .and_then(|layer| layer.resolve(path))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::archive::archive_write_options::ArchiveWriteOptions;
+  use crate::archive::writer::ArchiveWriter;
+  use crate::vfs::archive_file_system::ArchiveFileSystem;
+  use crate::vfs::folder_file_system::FolderFileSystem;
+  use crate::vfs::overlay_file_system::OverlayFileSystem;
+  use crate::vfs::virtual_file_location::VirtualFileLocation;
+  use crate::vfs::virtual_file_system::VirtualFileSystem;
+  use crate::ArchiveProject;
+  use std::io::Write;
+  use std::path::PathBuf;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_directory,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_overlay() -> XRayResult {
+    let root: String = get_relative_test_sample_file_directory(file!());
+
+    overwrite_test_relative_resource_as_file(&format!("{root}/source/configs/system.ltx"))?
+      .write_all(b"vanilla system")?;
+    overwrite_test_relative_resource_as_file(&format!("{root}/source/configs/game.ltx"))?
+      .write_all(b"vanilla game")?;
+    overwrite_test_relative_resource_as_file(&format!("{root}/gamedata/configs/System.ltx"))?
+      .write_all(b"mod system")?;
+    overwrite_test_relative_resource_as_file(&format!("{root}/gamedata/scripts/mod.script"))?
+      .write_all(b"mod script")?;
+
+    let archive: PathBuf = get_absolute_test_resource_path(&format!("{root}/vanilla.db"));
+
+    ArchiveWriter::from_path_utf8(&archive)?.write_archive(
+      &get_absolute_test_resource_path(&format!("{root}/source")),
+      &ArchiveWriteOptions::default(),
+    )?;
+
+    let mut overlay: OverlayFileSystem = OverlayFileSystem::new();
+
+    overlay.push(ArchiveFileSystem::new(ArchiveProject::new(&archive)?));
+    overlay.push(FolderFileSystem::new(&get_absolute_test_resource_path(
+      &format!("{root}/gamedata"),
+    ))?);
+
+    assert!(overlay.exists("configs\\game.ltx"));
+    assert!(overlay.exists("CONFIGS/system.ltx"));
+    assert!(!overlay.exists("configs/unknown.ltx"));
+
+    assert_eq!(overlay.read("configs\\system.ltx")?, b"mod system");
+    assert_eq!(overlay.read("configs/game.ltx")?, b"vanilla game");
+    assert_eq!(overlay.read("scripts/mod.script")?, b"mod script");
+
+    assert!(matches!(
+      overlay.resolve("configs/game.ltx"),
+      Some(VirtualFileLocation::Archive(_))
+    ));
+    assert!(matches!(
+      overlay.resolve("configs/system.ltx"),
+      Some(VirtualFileLocation::File(_))
+    ));
+
+    assert_eq!(
+      overlay.list("configs"),
+      vec![
+        String::from("configs/game.ltx"),
+        String::from("configs/system.ltx")
+      ]
+    );
+    assert_eq!(overlay.list("").len(), 3);
+    assert!(overlay.list("config").is_empty());
+
+    Ok(())
+  }
+}
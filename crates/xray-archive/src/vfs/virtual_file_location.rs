@@ -0,0 +1,11 @@
+use crate::archive::archive_file_descriptor::ArchiveFileReplicationDescriptor;
+use std::path::PathBuf;
+
+/// Physical location of file resolved by virtual file system.
+#[derive(Clone, Debug)]
+pub enum VirtualFileLocation {
+  /// Loose file on disk.
+  File(PathBuf),
+  /// Entry of mounted archive.
+  Archive(ArchiveFileReplicationDescriptor),
+}
@@ -0,0 +1,28 @@
+use crate::vfs::virtual_file_location::VirtualFileLocation;
+use std::io::Read;
+use xray_error::XRayResult;
+
+/// Read-only file system addressed by gamedata-relative paths like `configs\system.ltx`.
+/// Paths are case-insensitive, both `\` and `/` separators are accepted.
+pub trait VirtualFileSystem: Send + Sync {
+  /// Check whether file exists.
+  fn exists(&self, path: &str) -> bool;
+
+  /// Open file for reading.
+  fn open(&self, path: &str) -> XRayResult<Box<dyn Read + Send>>;
+
+  /// List normalized paths of all files located in provided directory, recursively.
+  fn list(&self, dir: &str) -> Vec<String>;
+
+  /// Resolve physical location of file.
+  fn resolve(&self, path: &str) -> Option<VirtualFileLocation>;
+
+  /// Read whole file as bytes.
+  fn read(&self, path: &str) -> XRayResult<Vec<u8>> {
+    let mut buf: Vec<u8> = Vec::new();
+
+    self.open(path)?.read_to_end(&mut buf)?;
+
+    Ok(buf)
+  }
+}
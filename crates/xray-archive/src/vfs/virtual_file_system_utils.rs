@@ -0,0 +1,17 @@
+/// Normalize virtual path: engine paths are case-insensitive and use both `\` and `/` separators.
+pub fn normalize_virtual_path(path: &str) -> String {
+  path
+    .split(['\\', '/'])
+    .filter(|it| !it.is_empty())
+    .collect::<Vec<_>>()
+    .join("/")
+    .to_lowercase()
+}
+
+/// Check whether normalized path is located inside of normalized directory prefix.
+pub fn is_virtual_path_in_dir(path: &str, dir: &str) -> bool {
+  dir.is_empty()
+    || path
+      .strip_prefix(dir)
+      .is_some_and(|rest| rest.starts_with('/'))
+}
@@ -1,7 +1,7 @@
 use crate::reader::chunk_reader::ChunkReader;
 use crate::source::chunk_data_source::ChunkDataSource;
+use crate::source::chunk_slice_source::FileChunkDataSource;
 use bytes::Bytes;
-use fileslice::FileSlice;
 use parquet::file::reader::{ChunkReader as ParquetChunkReader, Length};
 use std::io::Read;
 
@@ -17,10 +17,10 @@ impl<T: ChunkDataSource> Read for ChunkReader<T> {
   }
 }
 
-impl ParquetChunkReader for ChunkReader<FileSlice> {
-  type T = FileSlice;
+impl ParquetChunkReader for ChunkReader<FileChunkDataSource> {
+  type T = FileChunkDataSource;
 
-  fn get_read(&self, start: u64) -> parquet::errors::Result<FileSlice> {
+  fn get_read(&self, start: u64) -> parquet::errors::Result<FileChunkDataSource> {
     Ok(self.data.slice(start..self.data.end_pos()))
   }
 
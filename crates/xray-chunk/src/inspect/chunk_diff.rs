@@ -0,0 +1,497 @@
+use crate::inspect::chunk_tree::{ChunkHeader, ChunkTree, CHUNK_HEADER_SIZE};
+use crate::inspect::chunk_tree_options::ChunkTreeOptions;
+use crate::utils::chunk_utils_compression::{
+  decompress_chunk_data, is_compressed_chunk_id, CHUNK_COMPRESSED_FLAG,
+};
+use byteorder::ByteOrder;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use xray_error::XRayResult;
+
+/// Kind of difference between matching chunks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChunkDiffKind {
+  /// Chunk exists only in right file.
+  Added,
+  /// Chunk exists only in left file.
+  Removed,
+  /// Leaf chunk data has different size.
+  Resized,
+  /// Leaf chunk data has same size, but different content.
+  Changed,
+  /// Chunk is placed at different position among matched siblings.
+  Moved,
+}
+
+/// Single difference of compared chunk trees.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkDiffEntry {
+  /// IDs of chunks from the root to the differing chunk, empty for non-chunked root data.
+  pub path: Vec<u32>,
+  pub kind: ChunkDiffKind,
+  pub left_size: Option<u64>,
+  pub right_size: Option<u64>,
+  /// Offset of first differing byte inside of leaf chunk data.
+  pub first_difference: Option<u64>,
+  /// Indices of chunk among siblings of left and right files, set for moved chunks.
+  pub left_index: Option<u64>,
+  pub right_index: Option<u64>,
+}
+
+/// Structural difference of two chunked files.
+///
+/// Chunks are aligned by IDs path, sibling chunks with duplicate IDs are matched in order of
+/// appearance. Matched chunks with different order of siblings are reported as moved, since
+/// order of chunks is significant for readers. Compressed chunks are compared by decompressed data.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkDiff {
+  pub left_size: u64,
+  pub right_size: u64,
+  pub entries: Vec<ChunkDiffEntry>,
+}
+
+impl ChunkDiff {
+  /// Read files and compare their chunks structure.
+  pub fn read_from_paths<T: ByteOrder, P: AsRef<Path>>(
+    left: P,
+    right: P,
+    options: &ChunkTreeOptions,
+  ) -> XRayResult<Self> {
+    Ok(Self::from_bytes::<T>(
+      &fs::read(left)?,
+      &fs::read(right)?,
+      options,
+    ))
+  }
+
+  /// Compare chunks structure of provided data.
+  pub fn from_bytes<T: ByteOrder>(left: &[u8], right: &[u8], options: &ChunkTreeOptions) -> Self {
+    let mut entries: Vec<ChunkDiffEntry> = Vec::new();
+
+    Self::compare_data::<T>(left, right, &mut Vec::new(), options, &mut entries);
+
+    Self {
+      left_size: left.len() as u64,
+      right_size: right.len() as u64,
+      entries,
+    }
+  }
+
+  /// Whether compared data is equal.
+  pub fn is_equal(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Write diff as pretty JSON.
+  pub fn write_json<W: Write>(&self, writer: &mut W) -> XRayResult {
+    serde_json::to_writer_pretty(&mut *writer, self)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+  }
+
+  /// Write diff as text, one line per difference.
+  pub fn write_text<W: Write>(&self, writer: &mut W) -> XRayResult {
+    writeln!(
+      writer,
+      "size: {} -> {}, differences: {}",
+      self.left_size,
+      self.right_size,
+      self.entries.len()
+    )?;
+
+    for entry in &self.entries {
+      writeln!(writer, "{}", entry)?;
+    }
+
+    Ok(())
+  }
+
+  fn compare_data<T: ByteOrder>(
+    left: &[u8],
+    right: &[u8],
+    path: &mut Vec<u32>,
+    options: &ChunkTreeOptions,
+    entries: &mut Vec<ChunkDiffEntry>,
+  ) {
+    let headers: Option<(Vec<ChunkHeader>, Vec<ChunkHeader>)> = if path.len() < options.max_depth {
+      ChunkTree::get_chunk_headers::<T>(left, options)
+        .zip(ChunkTree::get_chunk_headers::<T>(right, options))
+    } else {
+      None
+    };
+
+    match headers {
+      Some((left_headers, right_headers)) => {
+        Self::compare_chunks::<T>(
+          left,
+          &left_headers,
+          right,
+          &right_headers,
+          path,
+          options,
+          entries,
+        );
+      }
+      None => {
+        if left != right {
+          entries.push(ChunkDiffEntry {
+            path: path.clone(),
+            kind: if left.len() == right.len() {
+              ChunkDiffKind::Changed
+            } else {
+              ChunkDiffKind::Resized
+            },
+            left_size: Some(left.len() as u64),
+            right_size: Some(right.len() as u64),
+            first_difference: Some(
+              left
+                .iter()
+                .zip(right)
+                .position(|(first, second)| first != second)
+                .unwrap_or(left.len().min(right.len())) as u64,
+            ),
+            left_index: None,
+            right_index: None,
+          });
+        }
+      }
+    }
+  }
+
+  fn compare_chunks<T: ByteOrder>(
+    left: &[u8],
+    left_headers: &[ChunkHeader],
+    right: &[u8],
+    right_headers: &[ChunkHeader],
+    path: &mut Vec<u32>,
+    options: &ChunkTreeOptions,
+    entries: &mut Vec<ChunkDiffEntry>,
+  ) {
+    let mut is_right_matched: Vec<bool> = vec![false; right_headers.len()];
+    let mut matched: Vec<Option<usize>> = vec![None; left_headers.len()];
+
+    for (left_index, &(left_id, _, _)) in left_headers.iter().enumerate() {
+      let id: u32 = left_id & !CHUNK_COMPRESSED_FLAG;
+
+      if let Some(right_index) =
+        right_headers
+          .iter()
+          .enumerate()
+          .position(|(index, (right_id, _, _))| {
+            !is_right_matched[index] && right_id & !CHUNK_COMPRESSED_FLAG == id
+          })
+      {
+        is_right_matched[right_index] = true;
+        matched[left_index] = Some(right_index);
+      }
+    }
+
+    // Chunk is moved if its order among matched chunks of right file differs from left one.
+    let mut right_order: Vec<usize> = matched.iter().flatten().copied().collect();
+    let mut order: usize = 0;
+
+    right_order.sort_unstable();
+
+    for (left_index, &(left_id, left_offset, left_size)) in left_headers.iter().enumerate() {
+      let id: u32 = left_id & !CHUNK_COMPRESSED_FLAG;
+
+      path.push(id);
+
+      match matched[left_index] {
+        Some(right_index) => {
+          let (right_id, right_offset, right_size) = right_headers[right_index];
+
+          if right_order[order] != right_index {
+            entries.push(ChunkDiffEntry {
+              path: path.clone(),
+              kind: ChunkDiffKind::Moved,
+              left_size: Some(left_size as u64),
+              right_size: Some(right_size as u64),
+              first_difference: None,
+              left_index: Some(left_index as u64),
+              right_index: Some(right_index as u64),
+            });
+          }
+
+          order += 1;
+
+          Self::compare_data::<T>(
+            &Self::get_chunk_data(left, left_id, left_offset, left_size),
+            &Self::get_chunk_data(right, right_id, right_offset, right_size),
+            path,
+            options,
+            entries,
+          );
+        }
+        None => entries.push(ChunkDiffEntry {
+          path: path.clone(),
+          kind: ChunkDiffKind::Removed,
+          left_size: Some(left_size as u64),
+          right_size: None,
+          first_difference: None,
+          left_index: None,
+          right_index: None,
+        }),
+      }
+
+      path.pop();
+    }
+
+    for (index, &(right_id, _, right_size)) in right_headers.iter().enumerate() {
+      if !is_right_matched[index] {
+        let mut added_path: Vec<u32> = path.clone();
+
+        added_path.push(right_id & !CHUNK_COMPRESSED_FLAG);
+
+        entries.push(ChunkDiffEntry {
+          path: added_path,
+          kind: ChunkDiffKind::Added,
+          left_size: None,
+          right_size: Some(right_size as u64),
+          first_difference: None,
+          left_index: None,
+          right_index: None,
+        });
+      }
+    }
+  }
+
+  /// Get chunk data, compressed chunks are decompressed or compared as raw bytes if broken.
+  fn get_chunk_data(data: &[u8], id: u32, offset: usize, size: usize) -> Cow<'_, [u8]> {
+    let raw: &[u8] = &data[offset + CHUNK_HEADER_SIZE..offset + CHUNK_HEADER_SIZE + size];
+
+    if is_compressed_chunk_id(id) {
+      match decompress_chunk_data(raw) {
+        Ok(decompressed) => Cow::Owned(decompressed),
+        Err(_) => Cow::Borrowed(raw),
+      }
+    } else {
+      Cow::Borrowed(raw)
+    }
+  }
+}
+
+impl fmt::Display for ChunkDiffEntry {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    if self.path.is_empty() {
+      write!(formatter, "/")?;
+    } else {
+      for id in &self.path {
+        write!(formatter, "/{}", id)?;
+      }
+    }
+
+    match self.kind {
+      ChunkDiffKind::Added => write!(formatter, " added, size: {}", self.right_size.unwrap_or(0)),
+      ChunkDiffKind::Removed => {
+        write!(formatter, " removed, size: {}", self.left_size.unwrap_or(0))
+      }
+      ChunkDiffKind::Moved => write!(
+        formatter,
+        " moved, index: {} -> {}",
+        self.left_index.unwrap_or(0),
+        self.right_index.unwrap_or(0)
+      ),
+      ChunkDiffKind::Resized | ChunkDiffKind::Changed => write!(
+        formatter,
+        " {}, size: {} -> {}, first difference at {:#x}",
+        if self.kind == ChunkDiffKind::Resized {
+          "resized"
+        } else {
+          "changed"
+        },
+        self.left_size.unwrap_or(0),
+        self.right_size.unwrap_or(0),
+        self.first_difference.unwrap_or(0)
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::inspect::chunk_diff::{ChunkDiff, ChunkDiffEntry, ChunkDiffKind};
+  use crate::{ChunkWriter, XRayByteOrder};
+  use byteorder::{ByteOrder, WriteBytesExt};
+  use std::io::Write;
+  use xray_error::XRayResult;
+
+  fn get_sample<T: ByteOrder>(leaf: &[u8], is_with_extra: bool) -> XRayResult<Vec<u8>> {
+    let mut nested: ChunkWriter = ChunkWriter::new();
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    writer.write_all(leaf)?;
+    writer.flush_chunk_into::<T>(&mut nested, 1)?;
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    writer.write_u32::<T>(1024)?;
+    writer.flush_chunk_into::<T>(&mut nested, 2)?;
+
+    if is_with_extra {
+      let mut writer: ChunkWriter = ChunkWriter::new();
+
+      writer.write_u16::<T>(16)?;
+      writer.flush_chunk_into::<T>(&mut nested, 3)?;
+    }
+
+    let mut buffer: Vec<u8> = Vec::new();
+
+    nested.flush_chunk_into::<T>(&mut buffer, 0)?;
+
+    let mut compressed: ChunkWriter = ChunkWriter::new();
+
+    compressed.write_all(&leaf.repeat(16))?;
+    compressed.flush_compressed_chunk_into::<T>(&mut buffer, 4)?;
+
+    Ok(buffer)
+  }
+
+  #[test]
+  fn test_diff_equal() -> XRayResult {
+    let data: Vec<u8> = get_sample::<XRayByteOrder>(b"leaf data", false)?;
+    let diff: ChunkDiff = ChunkDiff::from_bytes::<XRayByteOrder>(&data, &data, &Default::default());
+
+    assert!(diff.is_equal());
+    assert_eq!(diff.left_size, diff.right_size);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_diff_changed() -> XRayResult {
+    let diff: ChunkDiff = ChunkDiff::from_bytes::<XRayByteOrder>(
+      &get_sample::<XRayByteOrder>(b"leaf data", false)?,
+      &get_sample::<XRayByteOrder>(b"leaf dama", true)?,
+      &Default::default(),
+    );
+
+    assert_eq!(
+      diff.entries,
+      vec![
+        ChunkDiffEntry {
+          path: vec![0, 1],
+          kind: ChunkDiffKind::Changed,
+          left_size: Some(9),
+          right_size: Some(9),
+          first_difference: Some(7),
+          left_index: None,
+          right_index: None,
+        },
+        ChunkDiffEntry {
+          path: vec![0, 3],
+          kind: ChunkDiffKind::Added,
+          left_size: None,
+          right_size: Some(2),
+          first_difference: None,
+          left_index: None,
+          right_index: None,
+        },
+        ChunkDiffEntry {
+          path: vec![4],
+          kind: ChunkDiffKind::Changed,
+          left_size: Some(144),
+          right_size: Some(144),
+          first_difference: Some(7),
+          left_index: None,
+          right_index: None,
+        },
+      ]
+    );
+
+    let mut text: Vec<u8> = Vec::new();
+
+    diff.write_text(&mut text)?;
+
+    assert_eq!(
+      String::from_utf8_lossy(&text),
+      "size: 66 -> 76, differences: 3\n\
+      /0/1 changed, size: 9 -> 9, first difference at 0x7\n\
+      /0/3 added, size: 2\n\
+      /4 changed, size: 144 -> 144, first difference at 0x7\n"
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_diff_resized() -> XRayResult {
+    let diff: ChunkDiff = ChunkDiff::from_bytes::<XRayByteOrder>(
+      &get_sample::<XRayByteOrder>(b"leaf data", true)?,
+      &get_sample::<XRayByteOrder>(b"leaf", false)?,
+      &Default::default(),
+    );
+
+    assert_eq!(diff.entries.len(), 3);
+    assert_eq!(diff.entries[0].path, vec![0, 1]);
+    assert_eq!(diff.entries[0].kind, ChunkDiffKind::Resized);
+    assert_eq!(diff.entries[0].first_difference, Some(4));
+    assert_eq!(diff.entries[1].path, vec![0, 3]);
+    assert_eq!(diff.entries[1].kind, ChunkDiffKind::Removed);
+    assert_eq!(diff.entries[2].path, vec![4]);
+    assert_eq!(diff.entries[2].kind, ChunkDiffKind::Resized);
+    assert_eq!(diff.entries[2].left_size, Some(144));
+    assert_eq!(diff.entries[2].right_size, Some(64));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_diff_moved() -> XRayResult {
+    let mut left: Vec<u8> = Vec::new();
+    let mut right: Vec<u8> = Vec::new();
+
+    for (id, value) in [(1u32, 10u32), (2, 20), (3, 30)] {
+      let mut writer: ChunkWriter = ChunkWriter::new();
+
+      writer.write_u32::<XRayByteOrder>(value)?;
+      writer.flush_chunk_into::<XRayByteOrder>(&mut left, id)?;
+    }
+
+    for (id, value) in [(2u32, 20u32), (1, 10), (3, 30)] {
+      let mut writer: ChunkWriter = ChunkWriter::new();
+
+      writer.write_u32::<XRayByteOrder>(value)?;
+      writer.flush_chunk_into::<XRayByteOrder>(&mut right, id)?;
+    }
+
+    let diff: ChunkDiff =
+      ChunkDiff::from_bytes::<XRayByteOrder>(&left, &right, &Default::default());
+
+    assert!(!diff.is_equal());
+    assert_eq!(diff.entries.len(), 2);
+    assert_eq!(diff.entries[0].path, vec![1]);
+    assert_eq!(diff.entries[0].kind, ChunkDiffKind::Moved);
+    assert_eq!(diff.entries[0].left_index, Some(0));
+    assert_eq!(diff.entries[0].right_index, Some(1));
+    assert_eq!(diff.entries[1].path, vec![2]);
+    assert_eq!(diff.entries[1].kind, ChunkDiffKind::Moved);
+    assert_eq!(diff.entries[1].to_string(), "/2 moved, index: 1 -> 0");
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_diff_not_chunked() -> XRayResult {
+    let diff: ChunkDiff =
+      ChunkDiff::from_bytes::<XRayByteOrder>(b"abc", b"abd", &Default::default());
+
+    assert_eq!(diff.entries.len(), 1);
+    assert!(diff.entries[0].path.is_empty());
+    assert_eq!(diff.entries[0].first_difference, Some(2));
+    assert_eq!(
+      diff.entries[0].to_string(),
+      "/ changed, size: 3 -> 3, first difference at 0x2"
+    );
+
+    Ok(())
+  }
+}
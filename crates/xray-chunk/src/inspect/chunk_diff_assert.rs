@@ -0,0 +1,39 @@
+use crate::inspect::chunk_diff::ChunkDiff;
+use crate::types::XRayByteOrder;
+use std::path::Path;
+
+/// Assert chunked data is equal, panic with list of differing chunks otherwise.
+pub fn assert_chunks_equal(first: &[u8], second: &[u8]) {
+  assert_chunk_diff_empty(&ChunkDiff::from_bytes::<XRayByteOrder>(
+    first,
+    second,
+    &Default::default(),
+  ));
+}
+
+/// Assert chunked files are equal, panic with list of differing chunks otherwise.
+pub fn assert_chunk_files_equal_by_path<P: AsRef<Path>>(first_path: P, second_path: P) {
+  assert_chunk_diff_empty(
+    &ChunkDiff::read_from_paths::<XRayByteOrder, _>(
+      first_path.as_ref(),
+      second_path.as_ref(),
+      &Default::default(),
+    )
+    .expect("Chunked files to be read"),
+  );
+}
+
+fn assert_chunk_diff_empty(diff: &ChunkDiff) {
+  if !diff.is_equal() {
+    let mut text: Vec<u8> = Vec::new();
+
+    diff
+      .write_text(&mut text)
+      .expect("Chunks diff to be written");
+
+    panic!(
+      "Expected chunked data to be equal, {}",
+      String::from_utf8_lossy(&text)
+    );
+  }
+}
@@ -0,0 +1,477 @@
+use crate::inspect::chunk_tree_options::ChunkTreeOptions;
+use crate::utils::chunk_utils_compression::{
+  decompress_chunk_data, is_compressed_chunk_id, CHUNK_COMPRESSED_FLAG,
+};
+use byteorder::ByteOrder;
+use serde::Serialize;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use xray_error::XRayResult;
+
+/// Size of chunk header: u32 ID followed by u32 size.
+pub(crate) const CHUNK_HEADER_SIZE: usize = 8;
+
+/// Parsed chunk header: raw ID, offset of header and size of data.
+pub(crate) type ChunkHeader = (u32, usize, usize);
+
+/// Structure of chunked file guessed without knowledge of its format.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkTree {
+  pub size: u64,
+  /// Top level chunks, empty if file data is not a list of chunks.
+  pub children: Vec<ChunkTreeNode>,
+  pub preview_hex: String,
+  pub preview_ascii: String,
+}
+
+/// Single chunk of inspected tree.
+///
+/// Offsets point to chunk headers and are absolute file offsets. Chunks nested in compressed
+/// chunks do not exist in file, their offsets are counted in decompressed data of closest
+/// compressed parent.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkTreeNode {
+  pub id: u32,
+  pub offset: u64,
+  /// Whether offset is relative to decompressed data of parent chunk instead of file.
+  pub is_decompressed_offset: bool,
+  pub size: u64,
+  pub is_compressed: bool,
+  /// Size of chunk data after decompression, same as size for plain chunks.
+  pub data_size: u64,
+  pub children: Vec<ChunkTreeNode>,
+  pub preview_hex: String,
+  pub preview_ascii: String,
+  pub error: Option<String>,
+}
+
+impl ChunkTree {
+  /// Read file and inspect its chunks structure.
+  pub fn read_from_path<T: ByteOrder, P: AsRef<Path>>(
+    path: P,
+    options: &ChunkTreeOptions,
+  ) -> XRayResult<Self> {
+    Ok(Self::from_bytes::<T>(&fs::read(path)?, options))
+  }
+
+  /// Inspect chunks structure of provided data.
+  pub fn from_bytes<T: ByteOrder>(data: &[u8], options: &ChunkTreeOptions) -> Self {
+    let children: Vec<ChunkTreeNode> = Self::read_nodes::<T>(data, 0, false, 0, options);
+    let (preview_hex, preview_ascii) = if children.is_empty() {
+      Self::get_preview(data, options.preview_size)
+    } else {
+      (String::new(), String::new())
+    };
+
+    Self {
+      size: data.len() as u64,
+      children,
+      preview_hex,
+      preview_ascii,
+    }
+  }
+
+  /// Get count of chunks in the tree, including nested ones.
+  pub fn get_nodes_count(&self) -> usize {
+    fn count(nodes: &[ChunkTreeNode]) -> usize {
+      nodes.iter().map(|it| 1 + count(&it.children)).sum()
+    }
+
+    count(&self.children)
+  }
+
+  /// Write tree as pretty JSON.
+  pub fn write_json<W: Write>(&self, writer: &mut W) -> XRayResult {
+    serde_json::to_writer_pretty(&mut *writer, self)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+  }
+
+  /// Write tree as indented text, one line per chunk.
+  pub fn write_text<W: Write>(&self, writer: &mut W) -> XRayResult {
+    writeln!(
+      writer,
+      "size: {}, chunks: {}",
+      self.size,
+      self.get_nodes_count()
+    )?;
+
+    if self.children.is_empty() {
+      writeln!(
+        writer,
+        "not a chunks list | {} | {}",
+        self.preview_hex, self.preview_ascii
+      )?;
+    }
+
+    for node in &self.children {
+      node.write_text(writer, 0)?;
+    }
+
+    Ok(())
+  }
+
+  /// Parse list of chunk headers if data exactly consists of chunks, otherwise treat it as leaf data.
+  pub(crate) fn get_chunk_headers<T: ByteOrder>(
+    data: &[u8],
+    options: &ChunkTreeOptions,
+  ) -> Option<Vec<ChunkHeader>> {
+    if data.len() < CHUNK_HEADER_SIZE {
+      return None;
+    }
+
+    let mut headers: Vec<ChunkHeader> = Vec::new();
+    let mut position: usize = 0;
+
+    while position < data.len() {
+      if data.len() - position < CHUNK_HEADER_SIZE {
+        return None;
+      }
+
+      let id: u32 = T::read_u32(&data[position..position + 4]);
+      let size: usize = T::read_u32(&data[position + 4..position + 8]) as usize;
+
+      if id & !CHUNK_COMPRESSED_FLAG > options.max_chunk_id
+        || size > data.len() - position - CHUNK_HEADER_SIZE
+        || (is_compressed_chunk_id(id) && size < 4)
+      {
+        return None;
+      }
+
+      headers.push((id, position, size));
+      position += CHUNK_HEADER_SIZE + size;
+    }
+
+    Some(headers)
+  }
+
+  /// Read chunks of data, offsets of nodes are counted from provided base offset.
+  fn read_nodes<T: ByteOrder>(
+    data: &[u8],
+    base: usize,
+    is_decompressed: bool,
+    depth: usize,
+    options: &ChunkTreeOptions,
+  ) -> Vec<ChunkTreeNode> {
+    if depth >= options.max_depth {
+      return Vec::new();
+    }
+
+    match Self::get_chunk_headers::<T>(data, options) {
+      None => Vec::new(),
+      Some(headers) => headers
+        .into_iter()
+        .map(|(id, offset, size)| {
+          let raw: &[u8] = &data[offset + CHUNK_HEADER_SIZE..offset + CHUNK_HEADER_SIZE + size];
+          let header: ChunkHeader = (id, base + offset, size);
+
+          if is_compressed_chunk_id(id) {
+            match decompress_chunk_data(raw) {
+              Ok(decompressed) => {
+                Self::read_node::<T>(header, is_decompressed, &decompressed, depth, options)
+              }
+              Err(error) => {
+                let mut node: ChunkTreeNode =
+                  Self::read_node::<T>(header, is_decompressed, &[], depth, options);
+
+                node.data_size = 0;
+                node.error = Some(error.to_string());
+
+                node
+              }
+            }
+          } else {
+            Self::read_node::<T>(header, is_decompressed, raw, depth, options)
+          }
+        })
+        .collect(),
+    }
+  }
+
+  /// Read node of chunk, nested chunks of compressed data are positioned in decompressed data.
+  fn read_node<T: ByteOrder>(
+    (id, offset, size): ChunkHeader,
+    is_decompressed: bool,
+    data: &[u8],
+    depth: usize,
+    options: &ChunkTreeOptions,
+  ) -> ChunkTreeNode {
+    let is_compressed: bool = is_compressed_chunk_id(id);
+    let children: Vec<ChunkTreeNode> = if is_compressed {
+      Self::read_nodes::<T>(data, 0, true, depth + 1, options)
+    } else {
+      Self::read_nodes::<T>(
+        data,
+        offset + CHUNK_HEADER_SIZE,
+        is_decompressed,
+        depth + 1,
+        options,
+      )
+    };
+    let (preview_hex, preview_ascii) = if children.is_empty() {
+      Self::get_preview(data, options.preview_size)
+    } else {
+      (String::new(), String::new())
+    };
+
+    ChunkTreeNode {
+      id: id & !CHUNK_COMPRESSED_FLAG,
+      offset: offset as u64,
+      is_decompressed_offset: is_decompressed,
+      size: size as u64,
+      is_compressed,
+      data_size: data.len() as u64,
+      children,
+      preview_hex,
+      preview_ascii,
+      error: None,
+    }
+  }
+
+  fn get_preview(data: &[u8], size: usize) -> (String, String) {
+    let preview: &[u8] = &data[..data.len().min(size)];
+
+    (
+      preview
+        .iter()
+        .map(|it| format!("{:02x}", it))
+        .collect::<Vec<_>>()
+        .join(" "),
+      preview
+        .iter()
+        .map(|it| {
+          if it.is_ascii_graphic() || *it == b' ' {
+            *it as char
+          } else {
+            '.'
+          }
+        })
+        .collect(),
+    )
+  }
+}
+
+impl ChunkTreeNode {
+  fn write_text<W: Write>(&self, writer: &mut W, depth: usize) -> XRayResult {
+    write!(
+      writer,
+      "{}[{}] offset: {:#010x}{}, size: {}",
+      "  ".repeat(depth),
+      self.id,
+      self.offset,
+      if self.is_decompressed_offset {
+        " (decompressed)"
+      } else {
+        ""
+      },
+      self.size
+    )?;
+
+    if self.is_compressed {
+      write!(writer, ", compressed -> {}", self.data_size)?;
+    }
+
+    if let Some(error) = &self.error {
+      write!(writer, ", error: {}", error)?;
+    }
+
+    if self.children.is_empty() {
+      writeln!(writer, " | {} | {}", self.preview_hex, self.preview_ascii)?;
+    } else {
+      writeln!(writer, ", children: {}", self.children.len())?;
+
+      for child in &self.children {
+        child.write_text(writer, depth + 1)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::inspect::chunk_tree::ChunkTree;
+  use crate::inspect::chunk_tree_options::ChunkTreeOptions;
+  use crate::{ChunkWriter, XRayByteOrder};
+  use byteorder::{BigEndian, ByteOrder, WriteBytesExt};
+  use std::io::Write;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    get_relative_test_sample_sub_dir, overwrite_test_relative_resource_as_file,
+  };
+
+  fn get_sample<T: ByteOrder>() -> XRayResult<Vec<u8>> {
+    let mut nested: ChunkWriter = ChunkWriter::new();
+    let mut leaf: ChunkWriter = ChunkWriter::new();
+
+    leaf.write_all(b"leaf data")?;
+    leaf.flush_chunk_into::<T>(&mut nested, 1)?;
+
+    let mut leaf: ChunkWriter = ChunkWriter::new();
+
+    leaf.write_u32::<T>(0xFFFFFFFF)?;
+    leaf.flush_chunk_into::<T>(&mut nested, 2)?;
+
+    let mut buffer: Vec<u8> = Vec::new();
+
+    nested.flush_chunk_into::<T>(&mut buffer, 0)?;
+
+    let mut compressed: ChunkWriter = ChunkWriter::new();
+    let mut leaf: ChunkWriter = ChunkWriter::new();
+
+    leaf.write_all(&b"packed ".repeat(32))?;
+    leaf.flush_chunk_into::<T>(&mut compressed, 5)?;
+    compressed.flush_compressed_chunk_into::<T>(&mut buffer, 3)?;
+
+    Ok(buffer)
+  }
+
+  #[test]
+  fn test_inspect_nested() -> XRayResult {
+    let tree: ChunkTree =
+      ChunkTree::from_bytes::<XRayByteOrder>(&get_sample::<XRayByteOrder>()?, &Default::default());
+
+    assert_eq!(tree.children.len(), 2);
+    assert_eq!(tree.get_nodes_count(), 5);
+
+    let nested = &tree.children[0];
+
+    assert_eq!(nested.id, 0);
+    assert_eq!(nested.offset, 0);
+    assert_eq!(nested.size, 29);
+    assert!(!nested.is_compressed);
+    assert_eq!(nested.children.len(), 2);
+    assert_eq!(nested.children[0].id, 1);
+    assert_eq!(nested.children[0].offset, 8);
+    assert!(!nested.children[0].is_decompressed_offset);
+    assert_eq!(nested.children[0].preview_ascii, "leaf data");
+    assert_eq!(nested.children[1].id, 2);
+    assert_eq!(nested.children[1].offset, 25);
+    assert_eq!(nested.children[1].preview_hex, "ff ff ff ff");
+
+    let compressed = &tree.children[1];
+
+    assert_eq!(compressed.id, 3);
+    assert_eq!(compressed.offset, 37);
+    assert!(!compressed.is_decompressed_offset);
+    assert!(compressed.is_compressed);
+    assert_eq!(compressed.data_size, 8 + 7 * 32);
+    assert_eq!(compressed.children.len(), 1);
+    assert_eq!(compressed.children[0].id, 5);
+    assert_eq!(compressed.children[0].offset, 0);
+    assert!(compressed.children[0].is_decompressed_offset);
+    assert_eq!(compressed.children[0].preview_ascii, "packed packed pa");
+    assert!(compressed.error.is_none());
+
+    let mut text: Vec<u8> = Vec::new();
+
+    tree.write_text(&mut text)?;
+
+    let text: String = String::from_utf8_lossy(&text).into();
+
+    assert!(text.starts_with("size: "));
+    assert!(text.contains("  [1] offset: 0x00000008, size: 9 | 6c 65 61 66"));
+    assert!(text.contains("  [5] offset: 0x00000000 (decompressed), size: 224"));
+    assert!(text.contains("[3] offset: 0x00000025, size: "));
+
+    let mut json: Vec<u8> = Vec::new();
+
+    tree.write_json(&mut json)?;
+
+    assert!(String::from_utf8_lossy(&json).contains("\"isCompressed\": true"));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_inspect_big_endian() -> XRayResult {
+    let data: Vec<u8> = get_sample::<BigEndian>()?;
+    let tree: ChunkTree = ChunkTree::from_bytes::<BigEndian>(&data, &Default::default());
+
+    assert_eq!(tree.get_nodes_count(), 5);
+    assert_eq!(tree.children[1].children[0].id, 5);
+
+    let tree: ChunkTree = ChunkTree::from_bytes::<XRayByteOrder>(&data, &Default::default());
+
+    assert!(tree.children.is_empty());
+    assert_eq!(
+      tree.preview_hex,
+      "00 00 00 00 00 00 00 1d 00 00 00 01 00 00 00 09"
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_inspect_options() -> XRayResult {
+    let data: Vec<u8> = get_sample::<XRayByteOrder>()?;
+    let tree: ChunkTree = ChunkTree::from_bytes::<XRayByteOrder>(
+      &data,
+      &ChunkTreeOptions {
+        max_depth: 1,
+        preview_size: 4,
+        ..Default::default()
+      },
+    );
+
+    assert_eq!(tree.get_nodes_count(), 2);
+    assert_eq!(tree.children[0].preview_hex, "01 00 00 00");
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_inspect_broken() -> XRayResult {
+    let mut data: Vec<u8> = get_sample::<XRayByteOrder>()?;
+
+    // Corrupt decompressed size of compressed chunk.
+    data[45..49].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+
+    let tree: ChunkTree = ChunkTree::from_bytes::<XRayByteOrder>(&data, &Default::default());
+
+    assert_eq!(tree.children.len(), 2);
+    assert!(tree.children[1].error.is_some());
+    assert!(tree.children[1].children.is_empty());
+
+    let tree: ChunkTree = ChunkTree::from_bytes::<XRayByteOrder>(&data[..20], &Default::default());
+
+    assert!(tree.children.is_empty());
+    assert_eq!(tree.size, 20);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_inspect_file() -> XRayResult {
+    let filename: String = get_relative_test_sample_file_path(file!(), "sample.chunk");
+
+    overwrite_test_relative_resource_as_file(&filename)?
+      .write_all(&get_sample::<XRayByteOrder>()?)?;
+
+    let tree: ChunkTree = ChunkTree::read_from_path::<XRayByteOrder, _>(
+      get_absolute_test_resource_path(&filename),
+      &Default::default(),
+    )?;
+
+    assert_eq!(tree.get_nodes_count(), 5);
+
+    let tree: ChunkTree = ChunkTree::read_from_path::<XRayByteOrder, _>(
+      get_absolute_test_resource_path(&get_relative_test_sample_sub_dir("dummy_nested_five.chunk")),
+      &Default::default(),
+    )?;
+
+    assert_eq!(tree.children.len(), 5);
+    assert_eq!(
+      tree.children.iter().map(|it| it.size).collect::<Vec<_>>(),
+      vec![8, 24, 16, 0, 40]
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,20 @@
+/// Options of chunk tree inspection.
+#[derive(Clone, Debug)]
+pub struct ChunkTreeOptions {
+  /// Nesting level after which chunks are always treated as leaf data.
+  pub max_depth: usize,
+  /// Count of leading bytes included in hex/ASCII previews of leaf chunks.
+  pub preview_size: usize,
+  /// Biggest chunk ID considered valid when guessing whether data is nested chunks list.
+  pub max_chunk_id: u32,
+}
+
+impl Default for ChunkTreeOptions {
+  fn default() -> Self {
+    Self {
+      max_depth: 32,
+      preview_size: 16,
+      max_chunk_id: 0xFFFF,
+    }
+  }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod chunk_diff;
+#[cfg(feature = "test-utils")]
+pub(crate) mod chunk_diff_assert;
+pub(crate) mod chunk_tree;
+pub(crate) mod chunk_tree_options;
@@ -1,66 +1,266 @@
 use crate::reader::chunk_reader::ChunkReader;
-use crate::{ChunkDataSource, XRayByteOrder};
-use byteorder::ReadBytesExt;
-use fileslice::FileSlice;
+use crate::utils::chunk_utils_compression::{
+  decompress_chunk_data, is_compressed_chunk_id, CHUNK_COMPRESSED_FLAG,
+};
+use crate::{ChunkDataSource, FileChunkDataSource};
+use byteorder::{ByteOrder, ReadBytesExt};
 use std::io::SeekFrom;
+use std::marker::PhantomData;
+use xray_error::{XRayError, XRayResult};
 
 /// Iterate over samples in provided file slice.
 /// Mutates parent object to keep track of what was read during execution.
-pub struct ChunkIterator<'a, T: ChunkDataSource = FileSlice> {
-  pub reader: &'a mut ChunkReader<T>,
+/// Chunk IDs and sizes are read with `T` byte order.
+/// Iteration stops after first malformed chunk, error of it is yielded as last item.
+pub struct ChunkIterator<'a, T: ByteOrder, D: ChunkDataSource = FileChunkDataSource> {
+  pub reader: &'a mut ChunkReader<D>,
+  is_failed: bool,
+  byte_order: PhantomData<T>,
 }
 
-impl<T: ChunkDataSource> ChunkIterator<'_, T> {
-  pub fn from_start(reader: &mut ChunkReader<T>) -> ChunkIterator<T> {
+impl<T: ByteOrder, D: ChunkDataSource> ChunkIterator<'_, T, D> {
+  pub fn from_start(reader: &mut ChunkReader<D>) -> ChunkIterator<T, D> {
     reader
       .reset_pos()
       .expect("Iterator reader position reset expected");
 
-    ChunkIterator { reader }
+    ChunkIterator {
+      reader,
+      is_failed: false,
+      byte_order: PhantomData,
+    }
   }
 
-  pub fn from_current(reader: &mut ChunkReader<T>) -> ChunkIterator<T> {
-    ChunkIterator { reader }
+  pub fn from_current(reader: &mut ChunkReader<D>) -> ChunkIterator<T, D> {
+    ChunkIterator {
+      reader,
+      is_failed: false,
+      byte_order: PhantomData,
+    }
+  }
+
+  /// Read header of next chunk and create reader of its data.
+  fn read_next(&mut self) -> XRayResult<ChunkReader<D>> {
+    let id: u32 = self.reader.read_u32::<T>()?;
+    let size: u32 = self.reader.read_u32::<T>()?;
+
+    if size as u64 > self.reader.read_bytes_remain() {
+      return Err(self.reader.with_location(XRayError::new_read_error(format!(
+        "Chunk {} size {} exceeds remaining {} bytes of parent chunk",
+        id & !CHUNK_COMPRESSED_FLAG,
+        size,
+        self.reader.read_bytes_remain()
+      ))));
+    }
+
+    let position: u64 = self.reader.data.get_seek()?;
+
+    // Compressed chunks are unpacked in memory, ID is exposed without compression flag same as engine does.
+    if is_compressed_chunk_id(id) {
+      let data: Vec<u8> = decompress_chunk_data(&self.reader.data.read_bytes(size as usize)?)
+        .map_err(|error| self.reader.with_location(error))?;
+
+      return Ok(self.reader.create_child(
+        id & !CHUNK_COMPRESSED_FLAG,
+        data.len() as u64,
+        position,
+        D::from_bytes(data)?,
+      ));
+    }
+
+    self.reader.data.set_seek(SeekFrom::Current(size as i64))?;
+
+    Ok(self.reader.create_child(
+      id,
+      size as u64,
+      position,
+      self.reader.data.slice(position..(position + size as u64)),
+    ))
   }
 }
 
 /// Iterates over chunk and read child samples.
-impl<T: ChunkDataSource> Iterator for ChunkIterator<'_, T> {
-  type Item = ChunkReader<T>;
+impl<T: ByteOrder, D: ChunkDataSource> Iterator for ChunkIterator<'_, T, D> {
+  type Item = XRayResult<ChunkReader<D>>;
 
-  fn next(&mut self) -> Option<ChunkReader<T>> {
-    if self.reader.is_ended() {
+  fn next(&mut self) -> Option<XRayResult<ChunkReader<D>>> {
+    if self.is_failed || self.reader.is_ended() {
       return None;
     }
 
-    let id: u32 = self
-      .reader
-      // todo: Hardcoded byte order, should be part of generics.
-      .read_u32::<XRayByteOrder>()
-      .expect("Chunk ID read");
-    let size: u32 = self
-      .reader
-      // todo: Hardcoded byte order, should be part of generics.
-      .read_u32::<XRayByteOrder>()
-      .expect("Chunk size read");
-
-    let position: u64 = self.reader.data.get_seek().expect("Iterator seek position");
-
-    if id & (1 << 31) != 0 {
-      todo!("Parsing not implemented compressed chunk");
+    let result: XRayResult<ChunkReader<D>> = self.read_next();
+
+    self.is_failed = result.is_err();
+
+    Some(result)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    ChunkIterator, ChunkReader, ChunkWriter, FileChunkDataSource, InMemoryChunkDataSource,
+    XRayByteOrder,
+  };
+  use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+  use fileslice::FileSlice;
+  use std::io::Write;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_relative_test_sample_file_path, open_test_resource_as_slice,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  fn get_compressed_sample() -> XRayResult<Vec<u8>> {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    let mut plain: ChunkWriter = ChunkWriter::new();
+
+    plain.write_u32::<XRayByteOrder>(42)?;
+    plain.flush_chunk_into::<XRayByteOrder>(&mut buffer, 1)?;
+
+    let mut compressed: ChunkWriter = ChunkWriter::new();
+
+    for index in 0..1024u32 {
+      compressed.write_u32::<XRayByteOrder>(index % 16)?;
+    }
+
+    compressed.flush_compressed_chunk_into::<XRayByteOrder>(&mut buffer, 2)?;
+
+    Ok(buffer)
+  }
+
+  #[test]
+  fn test_iterate_compressed_in_memory() -> XRayResult {
+    let buffer: Vec<u8> = get_compressed_sample()?;
+
+    assert_eq!(&buffer[12..16], &(2u32 | 1 << 31).to_le_bytes());
+
+    let mut reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&buffer)?;
+    let mut chunks: Vec<ChunkReader<InMemoryChunkDataSource>> =
+      ChunkIterator::<XRayByteOrder, _>::from_start(&mut reader).collect::<XRayResult<_>>()?;
+
+    assert!(reader.is_ended());
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].id, 1);
+    assert_eq!(chunks[0].read_u32::<XRayByteOrder>()?, 42);
+
+    assert_eq!(chunks[1].id, 2);
+    assert_eq!(chunks[1].size, 4096);
+    assert_eq!(chunks[1].position, 20);
+
+    for index in 0..1024u32 {
+      assert_eq!(chunks[1].read_u32::<XRayByteOrder>()?, index % 16);
     }
 
-    self
-      .reader
-      .data
-      .set_seek(SeekFrom::Current(size as i64))
-      .unwrap();
+    chunks[1].assert_read("Expect decompressed chunk to be read")?;
 
-    Some(Self::Item {
-      id,
-      size: size as u64,
-      position,
-      data: Box::new(self.reader.data.slice(position..(position + size as u64))),
-    })
+    Ok(())
+  }
+
+  #[test]
+  fn test_iterate_corrupted_size() -> XRayResult {
+    let mut buffer: Vec<u8> = get_compressed_sample()?;
+
+    buffer[4..8].copy_from_slice(&1024u32.to_le_bytes());
+
+    let mut reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&buffer)?;
+    let mut iterator: ChunkIterator<XRayByteOrder, InMemoryChunkDataSource> =
+      ChunkIterator::from_start(&mut reader);
+
+    assert!(iterator.next().is_some_and(|it| it.is_err()));
+    assert!(iterator.next().is_none());
+
+    let mut reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&buffer[0..6])?;
+
+    assert!(reader.read_children::<XRayByteOrder>().is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_iterate_corrupted_compressed() -> XRayResult {
+    let mut buffer: Vec<u8> = get_compressed_sample()?;
+    let length: usize = buffer.len();
+
+    buffer[24..length].fill(0xFF);
+
+    let mut reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&buffer)?;
+    let chunks: Vec<XRayResult<ChunkReader<InMemoryChunkDataSource>>> =
+      ChunkIterator::<XRayByteOrder, _>::from_start(&mut reader).collect();
+
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks[0].is_ok());
+    assert!(chunks[1].is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_iterate_compressed_file() -> XRayResult {
+    let filename: String = get_relative_test_sample_file_path(file!(), "compressed.chunk");
+
+    overwrite_test_relative_resource_as_file(&filename)?.write_all(&get_compressed_sample()?)?;
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+    let mut chunks: Vec<ChunkReader> =
+      ChunkReader::from_slice(file)?.read_children::<XRayByteOrder>()?;
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].id, 1);
+    assert_eq!(chunks[1].id, 2);
+    assert_eq!(chunks[1].size, 4096);
+    assert_eq!(chunks[1].read_bytes_remain(), 4096);
+    assert!(matches!(*chunks[1].data, FileChunkDataSource::Owned(_)));
+
+    for index in 0..1024u32 {
+      assert_eq!(chunks[1].read_u32::<XRayByteOrder>()?, index % 16);
+    }
+
+    assert!(chunks[1].is_ended());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_iterate_big_endian() -> XRayResult {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    for (id, value) in [(3u32, 0x01020304u32), (7, 0x0A0B0C0D)] {
+      let mut writer: ChunkWriter = ChunkWriter::new();
+
+      writer.write_u32::<BigEndian>(value)?;
+      writer.flush_chunk_into::<BigEndian>(&mut buffer, id)?;
+    }
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    writer.write_all(&[1, 2, 3].repeat(100))?;
+    writer.flush_compressed_chunk_into::<BigEndian>(&mut buffer, 9)?;
+
+    assert_eq!(&buffer[0..8], &[0, 0, 0, 3, 0, 0, 0, 4]);
+
+    let reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&buffer)?;
+    let mut chunks: Vec<ChunkReader<InMemoryChunkDataSource>> =
+      reader.get_children_cloned::<BigEndian>()?;
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0].id, 3);
+    assert_eq!(chunks[0].read_u32::<BigEndian>()?, 0x01020304);
+    assert_eq!(chunks[1].id, 7);
+    assert_eq!(chunks[1].read_u32::<BigEndian>()?, 0x0A0B0C0D);
+    assert_eq!(chunks[2].id, 9);
+    assert_eq!(chunks[2].read_bytes(300)?, [1, 2, 3].repeat(100));
+
+    let mut reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&buffer)?;
+
+    assert_eq!(
+      reader
+        .read_child_by_index::<BigEndian>(1)?
+        .read_u32::<BigEndian>()?,
+      0x0A0B0C0D
+    );
+
+    Ok(())
   }
 }
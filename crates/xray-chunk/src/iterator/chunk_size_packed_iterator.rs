@@ -1,32 +1,42 @@
-use crate::{ChunkDataSource, ChunkReader, XRayByteOrder};
-use byteorder::ReadBytesExt;
-use fileslice::FileSlice;
+use crate::{ChunkDataSource, ChunkReader, FileChunkDataSource};
+use byteorder::{ByteOrder, ReadBytesExt};
 use std::io::SeekFrom;
+use std::marker::PhantomData;
 
 /// Iterate over data in chunk slice, which is stored like [(size)(content)(size)(content)].
-pub struct ChunkSizePackedIterator<'a, T: ChunkDataSource = FileSlice> {
+/// Sizes are read with `T` byte order.
+pub struct ChunkSizePackedIterator<'a, T: ByteOrder, D: ChunkDataSource = FileChunkDataSource> {
   pub index: u32,
-  pub reader: &'a mut ChunkReader<T>,
+  pub reader: &'a mut ChunkReader<D>,
+  byte_order: PhantomData<T>,
 }
 
-impl<T: ChunkDataSource> ChunkSizePackedIterator<'_, T> {
-  pub fn from_start(reader: &mut ChunkReader<T>) -> ChunkSizePackedIterator<T> {
+impl<T: ByteOrder, D: ChunkDataSource> ChunkSizePackedIterator<'_, T, D> {
+  pub fn from_start(reader: &mut ChunkReader<D>) -> ChunkSizePackedIterator<T, D> {
     reader
       .reset_pos()
       .expect("Iterator reader position reset expected");
 
-    ChunkSizePackedIterator { index: 0, reader }
+    ChunkSizePackedIterator {
+      index: 0,
+      reader,
+      byte_order: PhantomData,
+    }
   }
 
-  pub fn from_current(reader: &mut ChunkReader<T>) -> ChunkSizePackedIterator<T> {
-    ChunkSizePackedIterator { index: 0, reader }
+  pub fn from_current(reader: &mut ChunkReader<D>) -> ChunkSizePackedIterator<T, D> {
+    ChunkSizePackedIterator {
+      index: 0,
+      reader,
+      byte_order: PhantomData,
+    }
   }
 }
 
-impl<T: ChunkDataSource> Iterator for ChunkSizePackedIterator<'_, T> {
-  type Item = ChunkReader<T>;
+impl<T: ByteOrder, D: ChunkDataSource> Iterator for ChunkSizePackedIterator<'_, T, D> {
+  type Item = ChunkReader<D>;
 
-  fn next(&mut self) -> Option<ChunkReader<T>> {
+  fn next(&mut self) -> Option<ChunkReader<D>> {
     if self.reader.is_ended() {
       return None;
     }
@@ -35,8 +45,7 @@ impl<T: ChunkDataSource> Iterator for ChunkSizePackedIterator<'_, T> {
 
     let size: u64 = self
       .reader
-      // todo: Hardcoded byte order, should be part of generics.
-      .read_u32::<XRayByteOrder>()
+      .read_u32::<T>()
       .expect("Packed iterator size expected") as u64;
 
     let id: u32 = self.index;
@@ -48,18 +57,26 @@ impl<T: ChunkDataSource> Iterator for ChunkSizePackedIterator<'_, T> {
       .set_seek(SeekFrom::Current(size as i64 - 4))
       .expect("Iterator seek position");
 
-    Some(Self::Item {
+    let mut chunk: ChunkReader<D> = self.reader.create_child(
       id,
       size,
       position,
-      data: Box::new(self.reader.data.slice(position + 4..position + size)),
-    })
+      self.reader.data.slice(position + 4..position + size),
+    );
+
+    // Packed chunk data starts right after its size.
+    chunk.offset += 4;
+
+    Some(chunk)
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::{ChunkDataSource, ChunkReader, ChunkSizePackedIterator, InMemoryChunkDataSource};
+  use crate::{
+    ChunkDataSource, ChunkReader, ChunkSizePackedIterator, InMemoryChunkDataSource, XRayByteOrder,
+  };
+  use byteorder::BigEndian;
   use std::io::SeekFrom;
   use xray_error::XRayResult;
 
@@ -68,14 +85,14 @@ mod tests {
     let mut chunk_reader: ChunkReader<InMemoryChunkDataSource> =
       ChunkReader::from_source(InMemoryChunkDataSource::from_buffer(&[]))?;
 
-    if ChunkSizePackedIterator::from_start(&mut chunk_reader)
+    if ChunkSizePackedIterator::<XRayByteOrder, _>::from_start(&mut chunk_reader)
       .next()
       .is_some()
     {
       panic!("No iterations expected in empty data");
     }
 
-    if ChunkSizePackedIterator::from_current(&mut chunk_reader)
+    if ChunkSizePackedIterator::<XRayByteOrder, _>::from_current(&mut chunk_reader)
       .next()
       .is_some()
     {
@@ -92,7 +109,7 @@ mod tests {
 
     let mut vec: Vec<ChunkReader<InMemoryChunkDataSource>> = Vec::new();
 
-    for it in ChunkSizePackedIterator::from_start(&mut chunk_reader) {
+    for it in ChunkSizePackedIterator::<XRayByteOrder, _>::from_start(&mut chunk_reader) {
       vec.push(it);
     }
 
@@ -113,7 +130,7 @@ mod tests {
 
     let mut vec: Vec<ChunkReader<InMemoryChunkDataSource>> = Vec::new();
 
-    for it in ChunkSizePackedIterator::from_start(&mut chunk_reader) {
+    for it in ChunkSizePackedIterator::<XRayByteOrder, _>::from_start(&mut chunk_reader) {
       vec.push(it);
     }
 
@@ -139,7 +156,7 @@ mod tests {
 
     let mut vec: Vec<ChunkReader<InMemoryChunkDataSource>> = Vec::new();
 
-    for it in ChunkSizePackedIterator::from_current(&mut chunk_reader) {
+    for it in ChunkSizePackedIterator::<XRayByteOrder, _>::from_current(&mut chunk_reader) {
       vec.push(it);
     }
 
@@ -150,4 +167,25 @@ mod tests {
 
     Ok(())
   }
+
+  #[test]
+  fn test_iterate_big_endian() -> XRayResult {
+    let mut chunk_reader: ChunkReader<InMemoryChunkDataSource> =
+      ChunkReader::from_source(InMemoryChunkDataSource::from_buffer(&[
+        0, 0, 0, 6, 255, 255, 0, 0, 0, 8, 255, 255, 255, 255,
+      ]))?;
+
+    let vec: Vec<ChunkReader<InMemoryChunkDataSource>> =
+      ChunkSizePackedIterator::<BigEndian, _>::from_start(&mut chunk_reader).collect();
+
+    assert_eq!(vec.len(), 2, "Expected count to be 2");
+    assert_eq!(vec[0].size, 6, "Expected [0] size to be 6");
+    assert_eq!(vec[0].position, 0, "Expected [0] position to be 0");
+    assert_eq!(vec[1].id, 1, "Expected [1] id to be 1");
+    assert_eq!(vec[1].size, 8, "Expected [1] size to be 8");
+    assert_eq!(vec[1].position, 6, "Expected [1] position to be 6");
+    assert!(chunk_reader.is_ended(), "Expected data to be read");
+
+    Ok(())
+  }
 }
@@ -1,5 +1,6 @@
 pub(crate) mod chunk_io;
 pub(crate) mod chunk_trait;
+pub(crate) mod inspect;
 pub(crate) mod iterator;
 pub(crate) mod reader;
 pub(crate) mod source;
@@ -8,13 +9,22 @@ pub(crate) mod utils;
 pub(crate) mod writer;
 
 pub use crate::chunk_trait::*;
+pub use crate::inspect::chunk_diff::*;
+#[cfg(feature = "test-utils")]
+pub use crate::inspect::chunk_diff_assert::*;
+pub use crate::inspect::chunk_tree::*;
+pub use crate::inspect::chunk_tree_options::*;
 pub use crate::iterator::chunk_iterator::*;
 pub use crate::iterator::chunk_size_packed_iterator::*;
 pub use crate::reader::chunk_reader::*;
 pub use crate::source::chunk_data_source::*;
 pub use crate::source::chunk_memory_source::*;
+pub use crate::source::chunk_mmap_source::*;
+pub use crate::source::chunk_slice_source::*;
 pub use crate::types::*;
 pub use crate::utils::chunk_utils_assert::*;
+pub use crate::utils::chunk_utils_compression::*;
 pub use crate::utils::chunk_utils_find::*;
 pub use crate::utils::chunk_utils_read::*;
+pub use crate::writer::chunk_stream_writer::*;
 pub use crate::writer::chunk_writer::*;
@@ -1,22 +1,34 @@
 use crate::iterator::chunk_iterator::ChunkIterator;
 use crate::source::chunk_data_source::ChunkDataSource;
 use crate::source::chunk_memory_source::InMemoryChunkDataSource;
+use crate::source::chunk_mmap_source::MmapChunkDataSource;
+use crate::source::chunk_slice_source::FileChunkDataSource;
+use byteorder::ByteOrder;
 use fileslice::FileSlice;
 use parquet::file::reader::Length;
 use std::fmt;
 use std::fs::File;
 use std::io::SeekFrom;
+use std::path::Path;
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Clone)]
-pub struct ChunkReader<T: ChunkDataSource = FileSlice> {
+pub struct ChunkReader<T: ChunkDataSource = FileChunkDataSource> {
   pub id: u32,
   pub size: u64,
   pub position: u64,
+  /// IDs of chunks from root source to current chunk.
+  pub path: Vec<u32>,
+  /// Absolute offset of chunk data in root source.
+  /// Offsets inside of compressed chunks are counted in decompressed data.
+  pub offset: u64,
+  /// Format version of serialized data, set by versioned containers and inherited by nested chunks.
+  /// Readers without version treat data as the latest format.
+  pub version: Option<u16>,
   pub data: Box<T>,
 }
 
-impl ChunkReader<FileSlice> {
+impl ChunkReader<FileChunkDataSource> {
   /// Create chunk based on whole file.
   pub fn from_file(file: File) -> XRayResult<Self> {
     Self::from_slice(FileSlice::new(file))
@@ -34,7 +46,10 @@ impl ChunkReader<FileSlice> {
       id: 0,
       size: slice.len() as u64,
       position: slice.start_pos(),
-      data: Box::new(slice),
+      path: Vec::new(),
+      offset: slice.start_pos(),
+      version: None,
+      data: Box::new(slice.into()),
     })
   }
 }
@@ -51,21 +66,99 @@ impl ChunkReader<InMemoryChunkDataSource> {
       id: 0,
       size: source.len(),
       position: 0,
+      path: Vec::new(),
+      offset: 0,
+      version: None,
       data: Box::new(source),
     })
   }
 }
 
-impl<T: ChunkDataSource> ChunkReader<T> {
+impl ChunkReader<MmapChunkDataSource> {
+  /// Create chunk based on memory-mapped file by provided path.
+  pub fn from_mmap_path<P: AsRef<Path>>(path: P) -> XRayResult<Self> {
+    Self::from_mmap_source(MmapChunkDataSource::from_path(path)?)
+  }
+
+  /// Create chunk based on memory-mapped file.
+  pub fn from_mmap_file(file: &File) -> XRayResult<Self> {
+    Self::from_mmap_source(MmapChunkDataSource::from_file(file)?)
+  }
+
+  /// Create chunk based on memory-mapped source boundaries.
+  pub fn from_mmap_source(source: MmapChunkDataSource) -> XRayResult<Self> {
+    if source.is_empty() {
+      return Err(XRayError::new_invalid_error(
+        "Failed to create chunk from empty source",
+      ));
+    }
+
+    Ok(Self {
+      id: 0,
+      size: source.len(),
+      position: source.start_pos(),
+      path: Vec::new(),
+      offset: source.start_pos(),
+      version: None,
+      data: Box::new(source),
+    })
+  }
+}
+
+impl<D: ChunkDataSource> ChunkReader<D> {
   /// Assert data in chink is read and nothing remains to read.
   pub fn assert_read(&self, message: &str) -> XRayResult {
     if self.is_ended() {
       Ok(())
     } else {
-      Err(XRayError::new_chunk_not_ended_error(
+      Err(self.with_location(XRayError::new_chunk_not_ended_error(
         message,
         self.read_bytes_remain(),
-      ))
+      )))
+    }
+  }
+
+  /// Get absolute offset of current seek position in root source.
+  pub fn cursor_offset(&self) -> u64 {
+    self.offset + self.read_bytes_len()
+  }
+
+  /// Get description of chunk path and current seek offset, like `chunk 1/342/0 @0x3F2A10`.
+  pub fn get_location(&self) -> String {
+    let path: String = if self.path.is_empty() {
+      String::from("root")
+    } else {
+      self
+        .path
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+    };
+
+    format!("chunk {} @0x{:X}", path, self.cursor_offset())
+  }
+
+  /// Attach current chunk location to error, already located errors are kept as is.
+  pub fn with_location(&self, error: XRayError) -> XRayError {
+    error.with_chunk_location(self.get_location())
+  }
+
+  /// Create reader of nested chunk with data starting at provided position of current chunk.
+  pub(crate) fn create_child(&self, id: u32, size: u64, position: u64, data: D) -> Self {
+    let mut path: Vec<u32> = Vec::with_capacity(self.path.len() + 1);
+
+    path.extend_from_slice(&self.path);
+    path.push(id);
+
+    Self {
+      id,
+      size,
+      position,
+      path,
+      offset: self.offset + position,
+      version: self.version,
+      data: Box::new(data),
     }
   }
 
@@ -79,6 +172,11 @@ impl<T: ChunkDataSource> ChunkReader<T> {
     self.data.end_pos()
   }
 
+  /// Whether data is serialized in provided format version or newer one.
+  pub fn is_version_at_least(&self, version: u16) -> bool {
+    self.version.is_none_or(|it| it >= version)
+  }
+
   /// Whether chunk is ended and contains no more data to read.
   pub fn is_ended(&self) -> bool {
     self.data.cursor_pos() == self.data.end_pos()
@@ -105,36 +203,38 @@ impl<T: ChunkDataSource> ChunkReader<T> {
   }
 
   /// Navigates to chunk with index and constructs chunk representation.
-  pub fn read_child_by_index(&mut self, id: u32) -> XRayResult<Self> {
-    for (iteration, chunk) in ChunkIterator::from_start(self).enumerate() {
+  pub fn read_child_by_index<T: ByteOrder>(&mut self, id: u32) -> XRayResult<Self> {
+    for (iteration, chunk) in ChunkIterator::<T, D>::from_start(self).enumerate() {
+      let chunk: Self = chunk?;
+
       if id as usize == iteration {
         return Ok(chunk);
       }
     }
 
-    Err(XRayError::new_invalid_error(format!(
+    Err(self.with_location(XRayError::new_invalid_error(format!(
       "Attempt to read not existing chunk with id {} in chunk {}",
       id, self.id
-    )))
+    ))))
   }
 
   /// Get list of all child samples in current chunk, do not mutate current chunk.
-  pub fn get_children_cloned(&self) -> Vec<Self> {
-    ChunkIterator::from_start(&mut self.clone()).collect()
+  pub fn get_children_cloned<T: ByteOrder>(&self) -> XRayResult<Vec<Self>> {
+    ChunkIterator::<T, D>::from_start(&mut self.clone()).collect()
   }
 
   /// Read list of all child samples in current chunk and advance further.
-  pub fn read_children(&mut self) -> Vec<Self> {
-    ChunkIterator::<T>::from_start(self).collect()
+  pub fn read_children<T: ByteOrder>(&mut self) -> XRayResult<Vec<Self>> {
+    ChunkIterator::<T, D>::from_start(self).collect()
   }
 }
 
-impl fmt::Debug for ChunkReader {
+impl<D: ChunkDataSource> fmt::Debug for ChunkReader<D> {
   fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
     write!(
       formatter,
-      "Chunk {{ index: {}, size: {}, position: {} }}",
-      self.id, self.size, self.position
+      "Chunk {{ index: {}, size: {}, position: {}, offset: {} }}",
+      self.id, self.size, self.position, self.offset
     )
   }
 }
@@ -142,8 +242,11 @@ impl fmt::Debug for ChunkReader {
 #[cfg(test)]
 mod tests {
   use crate::reader::chunk_reader::ChunkReader;
+  use crate::{ChunkWriter, InMemoryChunkDataSource, XRayByteOrder};
+  use byteorder::{ReadBytesExt, WriteBytesExt};
   use fileslice::FileSlice;
-  use xray_error::XRayResult;
+  use std::io::Write;
+  use xray_error::{XRayError, XRayResult};
   use xray_test_utils::utils::{get_relative_test_sample_sub_dir, open_test_resource_as_slice};
 
   #[test]
@@ -176,7 +279,8 @@ mod tests {
     assert_eq!(file.start_pos(), 0);
     assert_eq!(file.end_pos(), 8);
 
-    let reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert!(reader.is_ended(), "Expect empty chunk");
 
@@ -187,14 +291,16 @@ mod tests {
   fn test_read_empty_children() -> XRayResult {
     let filename: String = get_relative_test_sample_sub_dir("empty_nested_single.chunk");
     let file: FileSlice = open_test_resource_as_slice(&filename)?;
-    let chunks: Vec<ChunkReader> = ChunkReader::from_slice(file)?.get_children_cloned();
+    let chunks: Vec<ChunkReader> =
+      ChunkReader::from_slice(file)?.get_children_cloned::<XRayByteOrder>()?;
 
     assert_eq!(chunks.len(), 1, "Expect single chunk");
     assert_eq!(chunks.first().unwrap().size, 0);
 
     let filename: String = get_relative_test_sample_sub_dir("empty_nested_five.chunk");
     let file: FileSlice = open_test_resource_as_slice(&filename)?;
-    let chunks: Vec<ChunkReader> = ChunkReader::from_slice(file)?.get_children_cloned();
+    let chunks: Vec<ChunkReader> =
+      ChunkReader::from_slice(file)?.get_children_cloned::<XRayByteOrder>()?;
 
     assert_eq!(chunks.len(), 5, "Expect five chunks");
     assert_eq!(chunks[0].size, 0);
@@ -210,7 +316,8 @@ mod tests {
   fn test_read_empty_unordered_children() -> XRayResult {
     let filename: String = get_relative_test_sample_sub_dir("empty_nested_five_unordered.chunk");
     let file: FileSlice = open_test_resource_as_slice(&filename)?;
-    let chunks: Vec<ChunkReader> = ChunkReader::from_slice(file)?.get_children_cloned();
+    let chunks: Vec<ChunkReader> =
+      ChunkReader::from_slice(file)?.get_children_cloned::<XRayByteOrder>()?;
 
     assert_eq!(chunks.len(), 5, "Expect five chunks");
     assert_eq!(chunks[0].size, 0);
@@ -231,14 +338,16 @@ mod tests {
   fn test_read_dummy_children() -> XRayResult {
     let filename: String = get_relative_test_sample_sub_dir("dummy_nested_single.chunk");
     let file: FileSlice = open_test_resource_as_slice(&filename)?;
-    let chunks: Vec<ChunkReader> = ChunkReader::from_slice(file)?.get_children_cloned();
+    let chunks: Vec<ChunkReader> =
+      ChunkReader::from_slice(file)?.get_children_cloned::<XRayByteOrder>()?;
 
     assert_eq!(chunks.len(), 1, "Expect single chunk");
     assert_eq!(chunks.first().unwrap().size, 8);
 
     let filename: String = get_relative_test_sample_sub_dir("dummy_nested_five.chunk");
     let file: FileSlice = open_test_resource_as_slice(&filename)?;
-    let chunks: Vec<ChunkReader> = ChunkReader::from_slice(file)?.get_children_cloned();
+    let chunks: Vec<ChunkReader> =
+      ChunkReader::from_slice(file)?.get_children_cloned::<XRayByteOrder>()?;
 
     assert_eq!(chunks.len(), 5, "Expect five chunks");
     assert_eq!(chunks[0].size, 8);
@@ -249,4 +358,76 @@ mod tests {
 
     Ok(())
   }
+
+  #[test]
+  fn test_read_location() -> XRayResult {
+    let mut leaf: ChunkWriter = ChunkWriter::new();
+    let mut middle: ChunkWriter = ChunkWriter::new();
+    let mut root: ChunkWriter = ChunkWriter::new();
+
+    leaf.write_u32::<XRayByteOrder>(42)?;
+    middle.write_all(&leaf.flush_chunk_into_buffer::<XRayByteOrder>(0)?)?;
+    root.write_all(&middle.flush_chunk_into_buffer::<XRayByteOrder>(342)?)?;
+
+    let data: Vec<u8> = root.flush_chunk_into_buffer::<XRayByteOrder>(1)?;
+    let mut reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(reader.get_location(), "chunk root @0x0");
+
+    let mut chunk: ChunkReader<InMemoryChunkDataSource> = reader
+      .read_child_by_index::<XRayByteOrder>(0)?
+      .read_child_by_index::<XRayByteOrder>(0)?
+      .read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(chunk.path, vec![1, 342, 0]);
+    assert_eq!(chunk.offset, 24);
+    assert_eq!(chunk.read_u16::<XRayByteOrder>()?, 42);
+    assert_eq!(chunk.cursor_offset(), 26);
+    assert_eq!(chunk.get_location(), "chunk 1/342/0 @0x1A");
+
+    let error: XRayError = chunk.assert_read("Expect leaf to be read").unwrap_err();
+
+    assert_eq!(error.get_chunk_location(), Some("chunk 1/342/0 @0x1A"));
+    assert_eq!(
+      error.to_string(),
+      "Chunk is not ended, 2 bytes remain: Expect leaf to be read, at chunk 1/342/0 @0x1A"
+    );
+    assert_eq!(
+      reader
+        .read_child_by_index::<XRayByteOrder>(1)
+        .unwrap_err()
+        .to_string(),
+      "Invalid error: Attempt to read not existing chunk with id 1 in chunk 0, at chunk root @0x1C"
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_version() -> XRayResult {
+    let mut leaf: ChunkWriter = ChunkWriter::new();
+    let mut root: ChunkWriter = ChunkWriter::new();
+
+    leaf.write_u32::<XRayByteOrder>(42)?;
+    root.write_all(&leaf.flush_chunk_into_buffer::<XRayByteOrder>(0)?)?;
+
+    let data: Vec<u8> = root.flush_chunk_into_buffer::<XRayByteOrder>(1)?;
+    let mut reader: ChunkReader<InMemoryChunkDataSource> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(reader.version, None);
+    assert!(reader.is_version_at_least(u16::MAX));
+
+    reader.version = Some(122);
+
+    let chunk: ChunkReader<InMemoryChunkDataSource> = reader
+      .read_child_by_index::<XRayByteOrder>(0)?
+      .read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(chunk.version, Some(122));
+    assert!(chunk.is_version_at_least(118));
+    assert!(chunk.is_version_at_least(122));
+    assert!(!chunk.is_version_at_least(123));
+
+    Ok(())
+  }
 }
@@ -4,26 +4,24 @@ use crate::{ChunkReadWriteList, ChunkReadWriteOptional, ChunkReader};
 use byteorder::{ByteOrder, ReadBytesExt};
 use xray_error::XRayResult;
 
-impl ChunkReader {
+impl<D: ChunkDataSource> ChunkReader<D> {
   #[inline(always)]
   pub fn read_xr<T: ByteOrder, C: ChunkReadWrite>(&mut self) -> XRayResult<C> {
-    C::read::<T>(self)
+    C::read::<T, D>(self).map_err(|error| self.with_location(error))
   }
 
   #[inline(always)]
   pub fn read_xr_optional<T: ByteOrder, C: ChunkReadWriteOptional>(
     &mut self,
   ) -> XRayResult<Option<C>> {
-    C::read_optional::<T>(self)
+    C::read_optional::<T, D>(self).map_err(|error| self.with_location(error))
   }
 
   #[inline(always)]
   pub fn read_xr_list<T: ByteOrder, C: ChunkReadWriteList>(&mut self) -> XRayResult<Vec<C>> {
-    C::read_list::<T>(self)
+    C::read_list::<T, D>(self).map_err(|error| self.with_location(error))
   }
-}
 
-impl<D: ChunkDataSource> ChunkReader<D> {
   /// Read serialized vector from chunk, where u32 count N is followed by N u16 entries.
   pub fn read_u16_vector<T: ByteOrder>(&mut self) -> XRayResult<Vec<u16>> {
     let count: u32 = self.read_u32::<T>()?;
@@ -46,8 +44,25 @@ impl<D: ChunkDataSource> ChunkReader<D> {
 mod tests {
   use crate::reader::chunk_reader::ChunkReader;
   use crate::source::chunk_memory_source::InMemoryChunkDataSource;
-  use crate::XRayByteOrder;
-  use xray_error::XRayResult;
+  use crate::{ChunkDataSource, ChunkReadWrite, ChunkWriter, XRayByteOrder};
+  use byteorder::{ByteOrder, ReadBytesExt};
+  use xray_error::{XRayError, XRayResult};
+
+  struct Sample {
+    value: u32,
+  }
+
+  impl ChunkReadWrite for Sample {
+    fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+      Ok(Self {
+        value: reader.read_u32::<T>()?,
+      })
+    }
+
+    fn write<T: ByteOrder>(&self, _: &mut ChunkWriter) -> XRayResult {
+      Ok(())
+    }
+  }
 
   #[test]
   fn test_read_u16_vector() -> XRayResult {
@@ -84,4 +99,22 @@ mod tests {
 
     Ok(())
   }
+
+  #[test]
+  fn test_read_xr_location() -> XRayResult {
+    let mut chunk: ChunkReader<InMemoryChunkDataSource> =
+      ChunkReader::from_bytes(&[1, 0, 0, 0, 2, 0])?;
+
+    assert_eq!(chunk.read_xr::<XRayByteOrder, Sample>()?.value, 1);
+
+    let error: XRayError = chunk
+      .read_xr::<XRayByteOrder, Sample>()
+      .map(|_| ())
+      .unwrap_err();
+
+    assert_eq!(error.get_chunk_location(), Some("chunk root @0x6"));
+    assert!(matches!(error.get_cause(), XRayError::Io { .. }));
+
+    Ok(())
+  }
 }
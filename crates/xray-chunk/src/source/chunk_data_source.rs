@@ -3,6 +3,9 @@ use std::io::{Read, Result as IoResult, SeekFrom};
 use std::ops::RangeBounds;
 
 pub trait ChunkDataSource: ReadBytesExt + Read + Clone {
+  /// Create source over detached data, used for decompressed chunks.
+  fn from_bytes(data: Vec<u8>) -> IoResult<Self>;
+
   fn start_pos(&self) -> u64;
 
   fn cursor_pos(&self) -> u64;
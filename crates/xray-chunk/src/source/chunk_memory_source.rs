@@ -22,6 +22,12 @@ impl InMemoryChunkDataSource {
 }
 
 impl ChunkDataSource for InMemoryChunkDataSource {
+  fn from_bytes(data: Vec<u8>) -> IoResult<Self> {
+    Ok(Self {
+      cursor: Cursor::new(data),
+    })
+  }
+
   fn start_pos(&self) -> u64 {
     0
   }
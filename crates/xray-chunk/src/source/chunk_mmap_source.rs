@@ -0,0 +1,232 @@
+use crate::source::chunk_data_source::ChunkDataSource;
+use memmap2::Mmap;
+use parquet::file::reader::Length;
+use std::fs::File;
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult, SeekFrom};
+use std::ops::{Bound, Deref, RangeBounds};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Shared bytes backing memory-mapped chunk data sources.
+pub enum MmapChunkData {
+  Mapped(Mmap),
+  Owned(Vec<u8>),
+}
+
+impl Deref for MmapChunkData {
+  type Target = [u8];
+
+  fn deref(&self) -> &[u8] {
+    match self {
+      Self::Mapped(mmap) => mmap,
+      Self::Owned(data) => data,
+    }
+  }
+}
+
+/// Chunk data source over memory-mapped file.
+///
+/// Slices share mapping of the whole file, so nested chunks are read without copying or
+/// additional file handles. Positions are absolute offsets in the mapped file, same as for
+/// file slices.
+#[derive(Clone)]
+pub struct MmapChunkDataSource {
+  data: Arc<MmapChunkData>,
+  start: u64,
+  end: u64,
+  cursor: u64,
+}
+
+impl MmapChunkDataSource {
+  /// Map file by provided path.
+  pub fn from_path<P: AsRef<Path>>(path: P) -> IoResult<Self> {
+    Self::from_file(&File::open(path)?)
+  }
+
+  /// Map whole file, file is expected not to be modified while mapping is used.
+  pub fn from_file(file: &File) -> IoResult<Self> {
+    // Safety: mapped files are treated as read-only game assets and are not modified externally.
+    let mmap: Mmap = unsafe { Mmap::map(file)? };
+
+    Ok(Self::from_data(MmapChunkData::Mapped(mmap)))
+  }
+
+  /// Create source over whole shared data.
+  pub fn from_data(data: MmapChunkData) -> Self {
+    let end: u64 = data.len() as u64;
+
+    Self {
+      data: Arc::new(data),
+      start: 0,
+      end,
+      cursor: 0,
+    }
+  }
+
+  /// Get bytes of the source from its start to end.
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.data[self.start as usize..self.end as usize]
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.start == self.end
+  }
+}
+
+impl ChunkDataSource for MmapChunkDataSource {
+  fn from_bytes(data: Vec<u8>) -> IoResult<Self> {
+    Ok(Self::from_data(MmapChunkData::Owned(data)))
+  }
+
+  fn start_pos(&self) -> u64 {
+    self.start
+  }
+
+  fn cursor_pos(&self) -> u64 {
+    self.cursor
+  }
+
+  fn end_pos(&self) -> u64 {
+    self.end
+  }
+
+  fn set_seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+    let cursor: i128 = match pos {
+      SeekFrom::Start(offset) => self.start as i128 + offset as i128,
+      SeekFrom::Current(offset) => self.cursor as i128 + offset as i128,
+      SeekFrom::End(offset) => self.end as i128 + offset as i128,
+    };
+
+    if cursor < self.start as i128 {
+      return Err(IoError::new(
+        ErrorKind::InvalidInput,
+        "Invalid seek to a position before start of mapped data",
+      ));
+    }
+
+    self.cursor = (cursor as u64).min(self.end);
+
+    Ok(self.cursor - self.start)
+  }
+
+  fn get_seek(&mut self) -> IoResult<u64> {
+    Ok(self.cursor - self.start)
+  }
+
+  fn slice<T: RangeBounds<u64>>(&self, range: T) -> Self {
+    let start: u64 = match range.start_bound() {
+      Bound::Included(&start) => self.start + start,
+      Bound::Excluded(&start) => self.start + start + 1,
+      Bound::Unbounded => self.start,
+    };
+
+    let end: u64 = match range.end_bound() {
+      Bound::Included(&end) => self.start + end + 1,
+      Bound::Excluded(&end) => self.start + end,
+      Bound::Unbounded => self.end,
+    };
+
+    let end: u64 = end.min(self.end);
+    let start: u64 = start.min(end);
+
+    Self {
+      data: self.data.clone(),
+      start,
+      end,
+      cursor: start,
+    }
+  }
+}
+
+impl Length for MmapChunkDataSource {
+  fn len(&self) -> u64 {
+    self.end - self.start
+  }
+}
+
+impl Read for MmapChunkDataSource {
+  fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+    let remaining: &[u8] = &self.data[self.cursor as usize..self.end as usize];
+    let count: usize = remaining.len().min(buf.len());
+
+    buf[..count].copy_from_slice(&remaining[..count]);
+    self.cursor += count as u64;
+
+    Ok(count)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    ChunkDataSource, ChunkIterator, ChunkReader, ChunkWriter, MmapChunkDataSource, XRayByteOrder,
+  };
+  use byteorder::{ReadBytesExt, WriteBytesExt};
+  use std::fs::File;
+  use std::io::{SeekFrom, Write};
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  #[test]
+  fn test_seek_and_slice() -> XRayResult {
+    let mut source: MmapChunkDataSource = MmapChunkDataSource::from_bytes((0..16).collect())?;
+
+    assert_eq!(source.read_u8()?, 0);
+    assert_eq!(source.get_seek()?, 1);
+
+    let mut slice: MmapChunkDataSource = source.slice(4..8);
+
+    assert_eq!(slice.start_pos(), 4);
+    assert_eq!(slice.end_pos(), 8);
+    assert_eq!(slice.as_bytes(), [4, 5, 6, 7]);
+    assert_eq!(slice.read_u16::<XRayByteOrder>()?, 0x0504);
+    assert_eq!(slice.cursor_pos(), 6);
+    assert_eq!(slice.get_seek()?, 2);
+
+    let mut nested: MmapChunkDataSource = slice.slice(1..);
+
+    assert_eq!(nested.as_bytes(), [5, 6, 7]);
+    assert_eq!(nested.set_seek(SeekFrom::End(-1))?, 2);
+    assert_eq!(nested.read_u8()?, 7);
+    assert!(nested.read_u8().is_err());
+    assert!(nested.set_seek(SeekFrom::Current(-4)).is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_mapped_file() -> XRayResult {
+    let filename: String = get_relative_test_sample_file_path(file!(), "mapped.chunk");
+    let mut file: File = overwrite_test_relative_resource_as_file(&filename)?;
+
+    for id in 0..4u32 {
+      let mut writer: ChunkWriter = ChunkWriter::new();
+
+      writer.write_u32::<XRayByteOrder>(id * 100)?;
+      writer.flush_chunk_into::<XRayByteOrder>(&mut file, id)?;
+    }
+
+    file.flush()?;
+
+    let mut reader: ChunkReader<MmapChunkDataSource> =
+      ChunkReader::from_mmap_path(get_absolute_test_resource_path(&filename))?;
+
+    assert_eq!(reader.size, 48);
+
+    for (index, chunk) in ChunkIterator::<XRayByteOrder, _>::from_start(&mut reader).enumerate() {
+      let mut chunk: ChunkReader<MmapChunkDataSource> = chunk?;
+
+      assert_eq!(chunk.id, index as u32);
+      assert_eq!(chunk.position, index as u64 * 12 + 8);
+      assert_eq!(chunk.read_u32::<XRayByteOrder>()?, index as u32 * 100);
+      assert!(chunk.is_ended());
+    }
+
+    assert!(reader.is_ended());
+
+    Ok(())
+  }
+}
@@ -1,30 +1,122 @@
 use crate::source::chunk_data_source::ChunkDataSource;
+use crate::source::chunk_mmap_source::{MmapChunkData, MmapChunkDataSource};
 use fileslice::FileSlice;
-use std::io::{Seek, SeekFrom};
+use parquet::file::reader::Length;
+use std::io::{Read, Result as IoResult, Seek, SeekFrom};
 use std::ops::RangeBounds;
 
-impl ChunkDataSource for FileSlice {
+/// Chunk data source over file slice.
+///
+/// Detached data (decompressed chunks) is kept in memory, positions of such data are counted
+/// from its start same as for owned memory-mapped sources.
+#[derive(Clone)]
+pub enum FileChunkDataSource {
+  Slice(FileSlice),
+  Owned(MmapChunkDataSource),
+}
+
+impl FileChunkDataSource {
+  pub fn is_empty(&self) -> bool {
+    self.start_pos() == self.end_pos()
+  }
+}
+
+impl From<FileSlice> for FileChunkDataSource {
+  fn from(slice: FileSlice) -> Self {
+    Self::Slice(slice)
+  }
+}
+
+impl ChunkDataSource for FileChunkDataSource {
+  fn from_bytes(data: Vec<u8>) -> IoResult<Self> {
+    Ok(Self::Owned(MmapChunkDataSource::from_data(
+      MmapChunkData::Owned(data),
+    )))
+  }
+
   fn start_pos(&self) -> u64 {
-    self.start_pos()
+    match self {
+      Self::Slice(slice) => slice.start_pos(),
+      Self::Owned(source) => source.start_pos(),
+    }
   }
 
   fn cursor_pos(&self) -> u64 {
-    self.cursor_pos()
+    match self {
+      Self::Slice(slice) => slice.cursor_pos(),
+      Self::Owned(source) => source.cursor_pos(),
+    }
   }
 
   fn end_pos(&self) -> u64 {
-    self.end_pos()
+    match self {
+      Self::Slice(slice) => slice.end_pos(),
+      Self::Owned(source) => source.end_pos(),
+    }
   }
 
-  fn set_seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
-    self.seek(pos)
+  fn set_seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+    match self {
+      Self::Slice(slice) => slice.seek(pos),
+      Self::Owned(source) => source.set_seek(pos),
+    }
   }
 
-  fn get_seek(&mut self) -> std::io::Result<u64> {
-    self.stream_position()
+  fn get_seek(&mut self) -> IoResult<u64> {
+    match self {
+      Self::Slice(slice) => slice.stream_position(),
+      Self::Owned(source) => source.get_seek(),
+    }
   }
 
   fn slice<T: RangeBounds<u64>>(&self, range: T) -> Self {
-    self.slice(range)
+    match self {
+      Self::Slice(slice) => Self::Slice(slice.slice(range)),
+      Self::Owned(source) => Self::Owned(source.slice(range)),
+    }
+  }
+}
+
+impl Length for FileChunkDataSource {
+  fn len(&self) -> u64 {
+    self.end_pos() - self.start_pos()
+  }
+}
+
+impl Read for FileChunkDataSource {
+  fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+    match self {
+      Self::Slice(slice) => slice.read(buf),
+      Self::Owned(source) => source.read(buf),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{ChunkDataSource, FileChunkDataSource, XRayByteOrder};
+  use byteorder::ReadBytesExt;
+  use std::io::SeekFrom;
+  use xray_error::XRayResult;
+
+  #[test]
+  fn test_from_bytes_in_memory() -> XRayResult {
+    let mut source: FileChunkDataSource = FileChunkDataSource::from_bytes((0..16).collect())?;
+
+    assert!(matches!(source, FileChunkDataSource::Owned(_)));
+    assert_eq!(source.start_pos(), 0);
+    assert_eq!(source.end_pos(), 16);
+    assert_eq!(source.read_u8()?, 0);
+
+    let mut slice: FileChunkDataSource = source.slice(4..8);
+
+    assert_eq!(slice.start_pos(), 4);
+    assert_eq!(slice.read_u16::<XRayByteOrder>()?, 0x0504);
+    assert_eq!(slice.get_seek()?, 2);
+    assert_eq!(slice.set_seek(SeekFrom::End(-1))?, 3);
+    assert_eq!(slice.read_u8()?, 7);
+    assert!(slice.read_u8().is_err());
+
+    Ok(())
   }
 }
@@ -1,3 +1,4 @@
 pub(crate) mod chunk_data_source;
 pub(crate) mod chunk_memory_source;
+pub(crate) mod chunk_mmap_source;
 pub(crate) mod chunk_slice_source;
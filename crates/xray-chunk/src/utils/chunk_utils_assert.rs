@@ -1,16 +1,17 @@
 use crate::reader::chunk_reader::ChunkReader;
+use crate::source::chunk_data_source::ChunkDataSource;
 use xray_error::{XRayError, XRayResult};
 
 /// Assert chunk ended and has no remaining data or fail with error.
 #[inline(always)]
-pub fn assert_chunk_read(chunk: &ChunkReader, message: &str) -> XRayResult {
+pub fn assert_chunk_read<D: ChunkDataSource>(chunk: &ChunkReader<D>, message: &str) -> XRayResult {
   if chunk.is_ended() {
     Ok(())
   } else {
-    Err(XRayError::new_chunk_not_ended_error(
+    Err(chunk.with_location(XRayError::new_chunk_not_ended_error(
       message,
       chunk.read_bytes_remain(),
-    ))
+    )))
   }
 }
 
@@ -0,0 +1,85 @@
+use crate::types::XRayByteOrder;
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use delharc::decode::{Decoder, Lh1Decoder};
+use delharc::encode::Lh1Encoder;
+use xray_error::{XRayError, XRayResult};
+
+/// Flag of chunk ID marking chunk data as compressed.
+pub const CHUNK_COMPRESSED_FLAG: u32 = 1 << 31;
+
+/// Upper bound of compression ratio used to reject corrupted size headers before allocation.
+pub const CHUNK_MAX_COMPRESSION_RATIO: u64 = 256;
+
+/// Whether chunk ID is marked as compressed.
+pub fn is_compressed_chunk_id(id: u32) -> bool {
+  id & CHUNK_COMPRESSED_FLAG != 0
+}
+
+/// Decompress chunk data same way as engine `_decompressLZ` does.
+/// Data is LZH (lh1) stream prefixed with size of decompressed data.
+pub fn decompress_chunk_data(data: &[u8]) -> XRayResult<Vec<u8>> {
+  let mut reader: &[u8] = data;
+  let decoded_len: u32 = reader.read_u32::<XRayByteOrder>()?;
+
+  if decoded_len as u64 > (data.len() as u64).max(1024) * CHUNK_MAX_COMPRESSION_RATIO {
+    return Err(XRayError::new_read_error(format!(
+      "Compressed chunk decoded size {} is not realistic for {} bytes of data",
+      decoded_len,
+      data.len()
+    )));
+  }
+
+  let mut decoder: Lh1Decoder<&[u8]> = Lh1Decoder::new(reader);
+  let mut decompressed: Vec<u8> = vec![0u8; decoded_len as usize];
+
+  decoder.fill_buffer(&mut decompressed)?;
+
+  Ok(decompressed)
+}
+
+/// Compress chunk data same way as engine `_compressLZ` does.
+pub fn compress_chunk_data(data: &[u8]) -> XRayResult<Vec<u8>> {
+  let mut compressed: Vec<u8> = Vec::with_capacity(size_of::<u32>() + data.len() / 2);
+
+  compressed.write_u32::<XRayByteOrder>(data.len() as u32)?;
+
+  let mut encoder: Lh1Encoder<Vec<u8>> = Lh1Encoder::new(compressed);
+
+  encoder.encode(data)?;
+
+  Ok(encoder.finish()?)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::chunk_utils_compression::{compress_chunk_data, decompress_chunk_data};
+  use xray_error::XRayResult;
+
+  #[test]
+  fn test_compress_decompress() -> XRayResult {
+    let data: Vec<u8> = b"level.spawn chunk data, level.spawn chunk data ".repeat(64);
+    let compressed: Vec<u8> = compress_chunk_data(&data)?;
+
+    assert!(compressed.len() < data.len() / 4);
+    assert_eq!(&compressed[0..4], &(data.len() as u32).to_le_bytes());
+    assert_eq!(decompress_chunk_data(&compressed)?, data);
+
+    assert_eq!(
+      decompress_chunk_data(&compress_chunk_data(&[])?)?,
+      Vec::<u8>::new()
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_decompress_invalid() {
+    assert!(decompress_chunk_data(&[1, 2]).is_err());
+    assert_eq!(
+      decompress_chunk_data(&[255, 255, 255, 255, 0])
+        .unwrap_err()
+        .to_string(),
+      "Read error: Compressed chunk decoded size 4294967295 is not realistic for 5 bytes of data"
+    );
+  }
+}
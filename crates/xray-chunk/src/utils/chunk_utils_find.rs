@@ -1,16 +1,20 @@
 use crate::reader::chunk_reader::ChunkReader;
+use crate::source::chunk_data_source::ChunkDataSource;
 use xray_error::{XRayError, XRayResult};
 
 /// Find chink in list by id.
-pub fn find_optional_chunk_by_id(chunks: &[ChunkReader], id: u32) -> Option<ChunkReader> {
+pub fn find_optional_chunk_by_id<D: ChunkDataSource>(
+  chunks: &[ChunkReader<D>],
+  id: u32,
+) -> Option<ChunkReader<D>> {
   chunks.iter().find(|it| it.id == id).cloned()
 }
 
 /// Find chink in list by id.
-pub fn find_one_of_optional_chunk_by_id(
-  chunks: &[ChunkReader],
+pub fn find_one_of_optional_chunk_by_id<D: ChunkDataSource>(
+  chunks: &[ChunkReader<D>],
   ids: &[u32],
-) -> Option<(u32, ChunkReader)> {
+) -> Option<(u32, ChunkReader<D>)> {
   for id in ids {
     if let Some(chunk) = chunks.iter().find(|it| it.id == *id).cloned() {
       return Some((*id, chunk));
@@ -21,7 +25,10 @@ pub fn find_one_of_optional_chunk_by_id(
 }
 
 /// Find required chunk in list by id.
-pub fn find_required_chunk_by_id(chunks: &[ChunkReader], id: u32) -> XRayResult<ChunkReader> {
+pub fn find_required_chunk_by_id<D: ChunkDataSource>(
+  chunks: &[ChunkReader<D>],
+  id: u32,
+) -> XRayResult<ChunkReader<D>> {
   match chunks.iter().find(|it| it.id == id).cloned() {
     None => Err(XRayError::new_not_found_error(format!(
       "Chunk with ID {} was not found",
@@ -32,10 +39,10 @@ pub fn find_required_chunk_by_id(chunks: &[ChunkReader], id: u32) -> XRayResult<
 }
 
 /// Find required chunk in list by one of ids.
-pub fn find_one_of_required_chunks_by_id(
-  chunks: &[ChunkReader],
+pub fn find_one_of_required_chunks_by_id<D: ChunkDataSource>(
+  chunks: &[ChunkReader<D>],
   ids: &[u32],
-) -> XRayResult<(u32, ChunkReader)> {
+) -> XRayResult<(u32, ChunkReader<D>)> {
   for id in ids {
     if let Some(chunk) = chunks.iter().find(|it| it.id == *id).cloned() {
       return Ok((*id, chunk));
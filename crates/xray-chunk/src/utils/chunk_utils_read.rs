@@ -1,10 +1,12 @@
-use crate::{assert_chunk_read, ChunkReader};
+use crate::{assert_chunk_read, ChunkDataSource, ChunkReader};
 use byteorder::{ByteOrder, ReadBytesExt};
 use std::io::Read;
 use xray_error::XRayResult;
 
 /// Read chunk as u16 value, verify remaining data is 0.
-pub fn read_u16_chunk<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<u16> {
+pub fn read_u16_chunk<T: ByteOrder, D: ChunkDataSource>(
+  reader: &mut ChunkReader<D>,
+) -> XRayResult<u16> {
   let data: u16 = reader.read_u16::<T>()?;
 
   assert_chunk_read(reader, "All data should be read in u16 chunk")?;
@@ -13,7 +15,9 @@ pub fn read_u16_chunk<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<u16>
 }
 
 /// Read chunk as u32 value, verify remaining data is 0.
-pub fn read_u32_chunk<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<u32> {
+pub fn read_u32_chunk<T: ByteOrder, D: ChunkDataSource>(
+  reader: &mut ChunkReader<D>,
+) -> XRayResult<u32> {
   let data: u32 = reader.read_u32::<T>()?;
 
   assert_chunk_read(reader, "All data should be read in u32 chunk")?;
@@ -22,7 +26,9 @@ pub fn read_u32_chunk<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<u32>
 }
 
 /// Read chunk as f32 value, verify remaining data is 0.
-pub fn read_f32_chunk<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<f32> {
+pub fn read_f32_chunk<T: ByteOrder, D: ChunkDataSource>(
+  reader: &mut ChunkReader<D>,
+) -> XRayResult<f32> {
   let data: f32 = reader.read_f32::<T>()?;
 
   assert_chunk_read(reader, "All data should be read in f32 chunk")?;
@@ -31,8 +37,8 @@ pub fn read_f32_chunk<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<f32>
 }
 
 /// Read chunk as f32 vector value, verify remaining data is 0.
-pub fn read_f32_vector_chunk<T: ByteOrder>(
-  reader: &mut ChunkReader,
+pub fn read_f32_vector_chunk<T: ByteOrder, D: ChunkDataSource>(
+  reader: &mut ChunkReader<D>,
 ) -> XRayResult<(f32, f32, f32)> {
   let data: (f32, f32, f32) = (
     reader.read_f32::<T>()?,
@@ -46,7 +52,9 @@ pub fn read_f32_vector_chunk<T: ByteOrder>(
 }
 
 /// Read chunk as binary data till reader end, verify remaining data is 0.
-pub fn read_till_end_binary_chunk(reader: &mut ChunkReader) -> XRayResult<Vec<u8>> {
+pub fn read_till_end_binary_chunk<D: ChunkDataSource>(
+  reader: &mut ChunkReader<D>,
+) -> XRayResult<Vec<u8>> {
   let mut data: Vec<u8> = vec![0; reader.read_bytes_remain() as usize];
 
   reader.read_exact(&mut data)?;
@@ -57,7 +65,9 @@ pub fn read_till_end_binary_chunk(reader: &mut ChunkReader) -> XRayResult<Vec<u8
 }
 
 /// Read chunk as containing string, verify remaining data is 0.
-pub fn read_w1251_string_chunk(reader: &mut ChunkReader) -> XRayResult<String> {
+pub fn read_w1251_string_chunk<D: ChunkDataSource>(
+  reader: &mut ChunkReader<D>,
+) -> XRayResult<String> {
   let data: String = reader.read_w1251_string()?;
 
   assert_chunk_read(reader, "All data should be read in string chunk")?;
@@ -1,3 +1,4 @@
 pub(crate) mod chunk_utils_assert;
+pub(crate) mod chunk_utils_compression;
 pub(crate) mod chunk_utils_find;
 pub(crate) mod chunk_utils_read;
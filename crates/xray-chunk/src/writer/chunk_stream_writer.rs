@@ -0,0 +1,294 @@
+use crate::chunk_trait::ChunkReadWrite;
+use crate::writer::chunk_writer::ChunkWriter;
+use byteorder::{ByteOrder, WriteBytesExt};
+use std::io;
+use std::io::{Seek, SeekFrom, Write};
+use xray_error::{XRayError, XRayResult};
+
+/// Header of chunk opened in stream writer and waiting for size patching.
+#[derive(Clone, Copy, Debug)]
+struct ChunkStreamHeader {
+  id: u32,
+  position: u64,
+}
+
+/// Chunk writer streaming data directly into destination without buffering.
+///
+/// Chunk headers are written with placeholder size when chunk is opened, size is patched in
+/// place when chunk is closed. Chunks can be nested, payload of leaf chunks can be written
+/// directly or buffered with `ChunkReadWrite` implementations.
+pub struct ChunkStreamWriter<W: Write + Seek> {
+  destination: W,
+  position: u64,
+  opened: Vec<ChunkStreamHeader>,
+  buffer: ChunkWriter,
+}
+
+impl<W: Write + Seek> ChunkStreamWriter<W> {
+  /// Create stream writer starting at current position of destination.
+  pub fn new(mut destination: W) -> XRayResult<Self> {
+    Ok(Self {
+      position: destination.stream_position()?,
+      destination,
+      opened: Vec::new(),
+      buffer: ChunkWriter::new(),
+    })
+  }
+
+  /// Open nested chunk, all following data is written as its payload until it is closed.
+  pub fn open_chunk<T: ByteOrder>(&mut self, id: u32) -> XRayResult {
+    self.opened.push(ChunkStreamHeader {
+      id,
+      position: self.position,
+    });
+
+    self.write_u32::<T>(id)?;
+    self.write_u32::<T>(0)?;
+
+    Ok(())
+  }
+
+  /// Close last opened chunk and patch its header with written payload size.
+  pub fn close_chunk<T: ByteOrder>(&mut self) -> XRayResult<u32> {
+    let header: ChunkStreamHeader = self.opened.pop().ok_or_else(|| {
+      XRayError::new_unexpected_error("Failed to close chunk, no chunks are opened in stream")
+    })?;
+
+    let size: u64 = self.position - header.position - (size_of::<u32>() * 2) as u64;
+    let size: u32 = u32::try_from(size).map_err(|_| {
+      XRayError::new_invalid_error(format!(
+        "Chunk {} size {} exceeds maximal chunk size",
+        header.id, size
+      ))
+    })?;
+
+    self
+      .destination
+      .seek(SeekFrom::Start(header.position + size_of::<u32>() as u64))?;
+    self.destination.write_u32::<T>(size)?;
+    self.destination.seek(SeekFrom::Start(self.position))?;
+
+    Ok(size)
+  }
+
+  /// Write chunk with payload produced by provided callback.
+  pub fn write_chunk<T: ByteOrder, F>(&mut self, id: u32, callback: F) -> XRayResult<u32>
+  where
+    F: FnOnce(&mut Self) -> XRayResult,
+  {
+    self.open_chunk::<T>(id)?;
+
+    callback(self)?;
+
+    self.close_chunk::<T>()
+  }
+
+  /// Write serializable value as part of current chunk payload.
+  pub fn write_xr<T: ByteOrder, C: ChunkReadWrite>(&mut self, value: &C) -> XRayResult {
+    let mut buffer: ChunkWriter = std::mem::take(&mut self.buffer);
+
+    buffer.buffer.clear();
+    value.write::<T>(&mut buffer)?;
+
+    let result: io::Result<()> = self.write_all(&buffer.buffer);
+
+    self.buffer = buffer;
+
+    Ok(result?)
+  }
+
+  /// Write serializable value as separate chunk with provided id.
+  pub fn write_xr_chunk<T: ByteOrder, C: ChunkReadWrite>(
+    &mut self,
+    id: u32,
+    value: &C,
+  ) -> XRayResult<u32> {
+    self.write_chunk::<T, _>(id, |writer| writer.write_xr::<T, C>(value))
+  }
+
+  /// Get count of currently opened nested chunks.
+  pub fn depth(&self) -> usize {
+    self.opened.len()
+  }
+
+  /// Get current position of writer in destination.
+  pub fn position(&self) -> u64 {
+    self.position
+  }
+
+  /// Flush written data and return destination, all opened chunks are expected to be closed.
+  pub fn into_inner(mut self) -> XRayResult<W> {
+    if let Some(header) = self.opened.last() {
+      return Err(XRayError::new_unexpected_error(format!(
+        "Failed to finish chunk stream, chunk {} is not closed",
+        header.id
+      )));
+    }
+
+    self.destination.flush()?;
+
+    Ok(self.destination)
+  }
+}
+
+impl<W: Write + Seek> Write for ChunkStreamWriter<W> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    let written: usize = self.destination.write(buf)?;
+
+    self.position += written as u64;
+
+    Ok(written)
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    self.destination.flush()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    ChunkDataSource, ChunkIterator, ChunkReadWrite, ChunkReader, ChunkStreamWriter, ChunkWriter,
+    InMemoryChunkDataSource, XRayByteOrder,
+  };
+  use byteorder::{BigEndian, ByteOrder, ReadBytesExt, WriteBytesExt};
+  use std::io::{Cursor, Write};
+  use xray_error::XRayResult;
+
+  struct Sample {
+    first: u32,
+    second: u16,
+  }
+
+  impl ChunkReadWrite for Sample {
+    fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+      Ok(Self {
+        first: reader.read_u32::<T>()?,
+        second: reader.read_u16::<T>()?,
+      })
+    }
+
+    fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+      writer.write_u32::<T>(self.first)?;
+      writer.write_u16::<T>(self.second)?;
+
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_write_flat() -> XRayResult {
+    let mut writer: ChunkStreamWriter<Cursor<Vec<u8>>> =
+      ChunkStreamWriter::new(Cursor::new(Vec::new()))?;
+
+    assert_eq!(
+      writer.write_chunk::<XRayByteOrder, _>(1, |it| Ok(it.write_all(&[1, 2, 3])?))?,
+      3
+    );
+    assert_eq!(writer.write_chunk::<XRayByteOrder, _>(2, |_| Ok(()))?, 0);
+    assert_eq!(writer.position(), 19);
+
+    let mut expected: ChunkWriter = ChunkWriter::new();
+
+    expected.write_all(&[1, 2, 3])?;
+
+    let mut expected_buffer: Vec<u8> = expected.flush_chunk_into_buffer::<XRayByteOrder>(1)?;
+
+    expected_buffer.extend(ChunkWriter::new().flush_chunk_into_buffer::<XRayByteOrder>(2)?);
+
+    assert_eq!(writer.into_inner()?.into_inner(), expected_buffer);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_write_nested() -> XRayResult {
+    let mut writer: ChunkStreamWriter<Cursor<Vec<u8>>> =
+      ChunkStreamWriter::new(Cursor::new(Vec::new()))?;
+
+    writer.open_chunk::<XRayByteOrder>(0)?;
+
+    for index in 0..3 {
+      writer.write_xr_chunk::<XRayByteOrder, _>(
+        index,
+        &Sample {
+          first: index * 10,
+          second: index as u16,
+        },
+      )?;
+    }
+
+    assert_eq!(writer.depth(), 1);
+    assert_eq!(writer.close_chunk::<XRayByteOrder>()?, 42);
+    assert_eq!(writer.depth(), 0);
+
+    let data: Vec<u8> = writer.into_inner()?.into_inner();
+    let mut reader: ChunkReader<InMemoryChunkDataSource> =
+      ChunkReader::from_bytes(&data)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(reader.id, 0);
+    assert_eq!(reader.size, 42);
+
+    for (index, child) in ChunkIterator::<XRayByteOrder, _>::from_start(&mut reader).enumerate() {
+      let mut child: ChunkReader<InMemoryChunkDataSource> = child?;
+
+      assert_eq!(child.id, index as u32);
+      assert_eq!(child.read_u32::<XRayByteOrder>()?, index as u32 * 10);
+      assert_eq!(child.read_u16::<XRayByteOrder>()?, index as u16);
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_write_big_endian() -> XRayResult {
+    let mut writer: ChunkStreamWriter<Cursor<Vec<u8>>> =
+      ChunkStreamWriter::new(Cursor::new(Vec::new()))?;
+
+    writer.write_chunk::<BigEndian, _>(1, |it| {
+      it.write_chunk::<BigEndian, _>(2, |it| Ok(it.write_u8(255)?))?;
+
+      Ok(())
+    })?;
+
+    assert_eq!(
+      writer.into_inner()?.into_inner(),
+      [0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0, 2, 0, 0, 0, 1, 255]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_write_with_offset() -> XRayResult {
+    let mut cursor: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+    cursor.write_all(&[7, 7])?;
+
+    let mut writer: ChunkStreamWriter<&mut Cursor<Vec<u8>>> = ChunkStreamWriter::new(&mut cursor)?;
+
+    writer.write_chunk::<XRayByteOrder, _>(5, |it| Ok(it.write_u16::<XRayByteOrder>(1)?))?;
+    writer.into_inner()?;
+
+    assert_eq!(cursor.into_inner(), [7, 7, 5, 0, 0, 0, 2, 0, 0, 0, 1, 0]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_not_closed() -> XRayResult {
+    let mut writer: ChunkStreamWriter<Cursor<Vec<u8>>> =
+      ChunkStreamWriter::new(Cursor::new(Vec::new()))?;
+
+    assert!(writer.close_chunk::<XRayByteOrder>().is_err());
+
+    writer.open_chunk::<XRayByteOrder>(1)?;
+
+    assert_eq!(
+      writer.into_inner().map(|_| ()).unwrap_err().to_string(),
+      "Unexpected error: Failed to finish chunk stream, chunk 1 is not closed"
+    );
+
+    Ok(())
+  }
+}
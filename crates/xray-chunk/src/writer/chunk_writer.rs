@@ -1,3 +1,4 @@
+use crate::utils::chunk_utils_compression::{compress_chunk_data, CHUNK_COMPRESSED_FLAG};
 use byteorder::{ByteOrder, WriteBytesExt};
 use std::io;
 use std::io::Write;
@@ -6,6 +7,8 @@ use xray_error::XRayResult;
 #[derive(Default)]
 pub struct ChunkWriter {
   pub buffer: Vec<u8>,
+  /// Format version of written data, writers without version produce the latest format.
+  pub version: Option<u16>,
 }
 
 impl ChunkWriter {
@@ -13,6 +16,19 @@ impl ChunkWriter {
     Self::default()
   }
 
+  /// Create writer producing data in provided format version.
+  pub fn new_versioned(version: u16) -> Self {
+    Self {
+      buffer: Vec::new(),
+      version: Some(version),
+    }
+  }
+
+  /// Whether data is written in provided format version or newer one.
+  pub fn is_version_at_least(&self, version: u16) -> bool {
+    self.version.is_none_or(|it| it >= version)
+  }
+
   /// Flush all the written data as chunk into the writable object.
   pub fn flush_chunk_into<T: ByteOrder>(
     &mut self,
@@ -21,8 +37,24 @@ impl ChunkWriter {
   ) -> XRayResult<usize> {
     destination.write_u32::<T>(id)?;
     destination.write_u32::<T>(self.buffer.len() as u32)?;
+    destination.write_all(&self.buffer)?;
 
-    Ok(destination.write(&self.buffer)?)
+    Ok(self.buffer.len())
+  }
+
+  /// Flush all the written data as compressed chunk into the writable object.
+  pub fn flush_compressed_chunk_into<T: ByteOrder>(
+    &mut self,
+    destination: &mut dyn Write,
+    id: u32,
+  ) -> XRayResult<usize> {
+    let compressed: Vec<u8> = compress_chunk_data(&self.buffer)?;
+
+    destination.write_u32::<T>(id | CHUNK_COMPRESSED_FLAG)?;
+    destination.write_u32::<T>(compressed.len() as u32)?;
+    destination.write_all(&compressed)?;
+
+    Ok(compressed.len())
   }
 
   /// Flush all the written data as raw buffer into writable.
@@ -41,6 +73,18 @@ impl ChunkWriter {
     Ok(buffer)
   }
 
+  /// Flush all the written data as compressed chunk into the buffer.
+  pub fn flush_compressed_chunk_into_buffer<T: ByteOrder>(
+    &mut self,
+    id: u32,
+  ) -> XRayResult<Vec<u8>> {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    self.flush_compressed_chunk_into::<T>(&mut buffer, id)?;
+
+    Ok(buffer)
+  }
+
   /// Flush all the written data as chunk into the file.
   pub fn flush_raw_into_buffer(&mut self) -> XRayResult<Vec<u8>> {
     let mut buffer: Vec<u8> = Vec::with_capacity(self.buffer.len());
@@ -1,2 +1,3 @@
+pub(crate) mod chunk_stream_writer;
 pub(crate) mod chunk_writer;
 pub(crate) mod chunk_writer_extension;
@@ -0,0 +1,241 @@
+use crate::utils::{
+  get_named_fields, get_type_ident, get_wrapped_type, is_numeric_primitive, is_single_byte,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Field, LitInt, LitStr, Type};
+
+/// Storage format of single struct field.
+enum ChunkFieldKind {
+  /// Numeric primitive, optionally stored as another primitive type.
+  Primitive { ident: Ident, repr: Option<Ident> },
+  /// Unsigned value stored as 3 bytes.
+  U24,
+  /// Null-terminated windows-1251 string.
+  String,
+  /// Optional nested value.
+  Optional(Type),
+  /// List of nested values.
+  List(Type),
+  /// Nested value implementing `ChunkReadWrite`.
+  Nested(Type),
+}
+
+/// Parsed struct field with its storage format.
+struct ChunkField<'a> {
+  ident: &'a Ident,
+  kind: ChunkFieldKind,
+  /// Minimal data format version where field is serialized.
+  min_version: Option<LitInt>,
+}
+
+pub fn derive(input: DeriveInput) -> TokenStream {
+  let name: &Ident = &input.ident;
+  let fields: Vec<ChunkField> = get_named_fields(&input, "ChunkReadWrite")
+    .named
+    .iter()
+    .map(parse_field)
+    .collect();
+
+  let reads: Vec<TokenStream> = fields
+    .iter()
+    .map(|field| {
+      let ident: &Ident = field.ident;
+      let read: TokenStream = get_field_read(&field.kind);
+
+      match &field.min_version {
+        Some(version) => quote! {
+          #ident: if reader.is_version_at_least(#version) {
+            #read
+          } else {
+            ::std::default::Default::default()
+          }
+        },
+        None => quote! { #ident: #read },
+      }
+    })
+    .collect();
+
+  let writes: Vec<TokenStream> = fields
+    .iter()
+    .map(|field| {
+      let write: TokenStream = get_field_write(field.ident, &field.kind);
+
+      match &field.min_version {
+        Some(version) => quote! {
+          if writer.is_version_at_least(#version) {
+            #write
+          }
+        },
+        None => write,
+      }
+    })
+    .collect();
+
+  quote! {
+    impl ::xray_chunk::ChunkReadWrite for #name {
+      fn read<T: ::byteorder::ByteOrder, D: ::xray_chunk::ChunkDataSource>(
+        reader: &mut ::xray_chunk::ChunkReader<D>,
+      ) -> ::xray_error::XRayResult<Self> {
+        Ok(Self {
+          #(#reads,)*
+        })
+      }
+
+      fn write<T: ::byteorder::ByteOrder>(
+        &self,
+        writer: &mut ::xray_chunk::ChunkWriter,
+      ) -> ::xray_error::XRayResult {
+        #(#writes)*
+
+        Ok(())
+      }
+    }
+  }
+}
+
+fn parse_field(field: &Field) -> ChunkField<'_> {
+  let mut is_u24: bool = false;
+  let mut repr: Option<Ident> = None;
+  let mut min_version: Option<LitInt> = None;
+
+  for attribute in field.attrs.iter().filter(|it| it.path().is_ident("chunk")) {
+    attribute
+      .parse_nested_meta(|meta| {
+        if meta.path.is_ident("u24") {
+          is_u24 = true;
+
+          Ok(())
+        } else if meta.path.is_ident("repr") {
+          let value: LitStr = meta.value()?.parse()?;
+
+          repr = Some(Ident::new(&value.value(), value.span()));
+
+          Ok(())
+        } else if meta.path.is_ident("min_version") {
+          min_version = Some(meta.value()?.parse()?);
+
+          Ok(())
+        } else {
+          Err(meta.error(
+            "unsupported chunk attribute, expected `u24`, `repr = \"..\"` or `min_version = N`",
+          ))
+        }
+      })
+      .unwrap_or_else(|error| panic!("Failed to parse #[chunk] attribute: {error}"));
+  }
+
+  ChunkField {
+    ident: field.ident.as_ref().unwrap(),
+    kind: parse_field_kind(field, is_u24, repr),
+    min_version,
+  }
+}
+
+fn parse_field_kind(field: &Field, is_u24: bool, repr: Option<Ident>) -> ChunkFieldKind {
+  let ident: Option<&Ident> = get_type_ident(&field.ty);
+
+  if is_u24 {
+    if ident.is_none_or(|it| it != "u32") {
+      panic!("#[chunk(u24)] can only be used with u32 fields");
+    }
+
+    return ChunkFieldKind::U24;
+  }
+
+  if let Some(repr) = repr {
+    if !is_numeric_primitive(&repr) {
+      panic!("#[chunk(repr = \"{repr}\")] should be numeric primitive type");
+    }
+
+    return match ident {
+      Some(ident) if ident == "bool" || is_numeric_primitive(ident) => ChunkFieldKind::Primitive {
+        ident: ident.clone(),
+        repr: Some(repr),
+      },
+      _ => panic!("#[chunk(repr)] can only be used with bool and numeric fields"),
+    };
+  }
+
+  if let Some(inner) = get_wrapped_type(&field.ty, "Option") {
+    return ChunkFieldKind::Optional(inner.clone());
+  }
+
+  if let Some(inner) = get_wrapped_type(&field.ty, "Vec") {
+    return ChunkFieldKind::List(inner.clone());
+  }
+
+  match ident {
+    Some(ident) if ident == "String" => ChunkFieldKind::String,
+    Some(ident) if is_numeric_primitive(ident) => ChunkFieldKind::Primitive {
+      ident: ident.clone(),
+      repr: None,
+    },
+    _ => ChunkFieldKind::Nested(field.ty.clone()),
+  }
+}
+
+fn get_primitive_read(ident: &Ident) -> TokenStream {
+  let method: Ident = format_ident!("read_{}", ident);
+
+  if is_single_byte(ident) {
+    quote! { ::byteorder::ReadBytesExt::#method(reader)? }
+  } else {
+    quote! { ::byteorder::ReadBytesExt::#method::<T>(reader)? }
+  }
+}
+
+fn get_primitive_write(ident: &Ident, value: TokenStream) -> TokenStream {
+  let method: Ident = format_ident!("write_{}", ident);
+
+  if is_single_byte(ident) {
+    quote! { ::byteorder::WriteBytesExt::#method(writer, #value)?; }
+  } else {
+    quote! { ::byteorder::WriteBytesExt::#method::<T>(writer, #value)?; }
+  }
+}
+
+fn get_field_read(kind: &ChunkFieldKind) -> TokenStream {
+  match kind {
+    ChunkFieldKind::Primitive { ident, repr: None } => get_primitive_read(ident),
+    ChunkFieldKind::Primitive {
+      ident,
+      repr: Some(repr),
+    } => {
+      let read: TokenStream = get_primitive_read(repr);
+
+      if ident == "bool" {
+        quote! { #read != 0 }
+      } else {
+        quote! { #read as #ident }
+      }
+    }
+    ChunkFieldKind::U24 => {
+      quote! { ::byteorder::ReadBytesExt::read_u24::<T>(reader)? }
+    }
+    ChunkFieldKind::String => quote! { reader.read_w1251_string()? },
+    ChunkFieldKind::Optional(ty) => quote! { reader.read_xr_optional::<T, #ty>()? },
+    ChunkFieldKind::List(ty) => quote! { reader.read_xr_list::<T, #ty>()? },
+    ChunkFieldKind::Nested(ty) => quote! { reader.read_xr::<T, #ty>()? },
+  }
+}
+
+fn get_field_write(field: &Ident, kind: &ChunkFieldKind) -> TokenStream {
+  match kind {
+    ChunkFieldKind::Primitive { ident, repr: None } => {
+      get_primitive_write(ident, quote! { self.#field })
+    }
+    ChunkFieldKind::Primitive {
+      repr: Some(repr), ..
+    } => get_primitive_write(repr, quote! { self.#field as #repr }),
+    ChunkFieldKind::U24 => {
+      quote! { ::byteorder::WriteBytesExt::write_u24::<T>(writer, self.#field)?; }
+    }
+    ChunkFieldKind::String => quote! { writer.write_w1251_string(&self.#field)?; },
+    ChunkFieldKind::Optional(ty) => {
+      quote! { writer.write_xr_optional::<T, #ty>(self.#field.as_ref())?; }
+    }
+    ChunkFieldKind::List(ty) => quote! { writer.write_xr_list::<T, #ty>(&self.#field)?; },
+    ChunkFieldKind::Nested(_) => quote! { writer.write_xr::<T, _>(&self.#field)?; },
+  }
+}
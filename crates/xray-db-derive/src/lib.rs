@@ -0,0 +1,41 @@
+extern crate proc_macro;
+
+mod chunk_read_write;
+mod ltx_import_export;
+mod utils;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive `xray_chunk::ChunkReadWrite` reading and writing fields in declaration order.
+///
+/// Field encoding is picked based on its type:
+/// - numeric primitives are read with generic byte order, `u8` and `i8` as single bytes
+/// - `String` is read as null-terminated windows-1251 string
+/// - `Option<C>` is read with `read_xr_optional`, `Vec<C>` with `read_xr_list`
+/// - any other type is read as nested `read_xr`
+///
+/// Supported field attributes:
+/// - `#[chunk(u24)]` - `u32` value stored as 3 bytes
+/// - `#[chunk(repr = "u8")]` - value stored as another primitive type, including `bool` fields
+/// - `#[chunk(min_version = 128)]` - value stored only in data of provided format version or newer,
+///   older data falls back to `Default` value
+#[proc_macro_derive(ChunkReadWrite, attributes(chunk))]
+pub fn chunk_read_write_derive(input: TokenStream) -> TokenStream {
+  chunk_read_write::derive(parse_macro_input!(input as DeriveInput)).into()
+}
+
+/// Derive `LtxImportExport` of xray-db, reading and writing fields as section values.
+///
+/// Fields are parsed with `FromStr` and exported with `ToString`.
+///
+/// Supported struct attributes:
+/// - `#[ltx(prefix = "name.")]` - prefix of all fields keys in section
+///
+/// Supported field attributes:
+/// - `#[ltx(inherit)]` - field is parent class imported from and exported into the same section
+/// - `#[ltx(name = "key")]` - custom key of field value, prefix is not applied
+#[proc_macro_derive(LtxImportExport, attributes(ltx))]
+pub fn ltx_import_export_derive(input: TokenStream) -> TokenStream {
+  ltx_import_export::derive(parse_macro_input!(input as DeriveInput)).into()
+}
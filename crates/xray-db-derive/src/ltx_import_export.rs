@@ -0,0 +1,149 @@
+use crate::utils::get_named_fields;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Attribute, DeriveInput, Field, LitStr};
+
+/// Section mapping of single struct field.
+enum LtxFieldKind {
+  /// Parent class data stored in the same section.
+  Inherit,
+  /// Value stored under provided key.
+  Value(String),
+}
+
+pub fn derive(input: DeriveInput) -> TokenStream {
+  let name: &Ident = &input.ident;
+  let prefix: String = parse_struct_prefix(&input.attrs);
+  let fields: Vec<(&Ident, &Field, LtxFieldKind)> = get_named_fields(&input, "LtxImportExport")
+    .named
+    .iter()
+    .map(|field| {
+      let ident: &Ident = field.ident.as_ref().unwrap();
+
+      (ident, field, parse_field_kind(field, ident, &prefix))
+    })
+    .collect();
+
+  let imports: Vec<TokenStream> = fields
+    .iter()
+    .map(|(ident, field, kind)| {
+      let ty: &syn::Type = &field.ty;
+
+      match kind {
+        LtxFieldKind::Inherit => quote! { #ident: <#ty>::import(section_name, ltx)? },
+        LtxFieldKind::Value(key) => {
+          quote! { #ident: crate::file_import::read_ltx_field(#key, section)? }
+        }
+      }
+    })
+    .collect();
+
+  let inherited_exports: Vec<TokenStream> = fields
+    .iter()
+    .filter(|(_, _, kind)| matches!(kind, LtxFieldKind::Inherit))
+    .map(|(ident, _, _)| quote! { self.#ident.export(section_name, ltx)?; })
+    .collect();
+
+  let value_exports: Vec<TokenStream> = fields
+    .iter()
+    .filter_map(|(ident, _, kind)| match kind {
+      LtxFieldKind::Value(key) => Some(quote! { .set(#key, self.#ident.to_string()) }),
+      LtxFieldKind::Inherit => None,
+    })
+    .collect();
+
+  let error: String = format!("{name} section '{{}}' should be defined in ltx file ({{}})");
+
+  // Sections of fully inherited structs are checked and written by parent classes.
+  let section_import: TokenStream = if value_exports.is_empty() {
+    quote! {}
+  } else {
+    quote! {
+      let section: &::xray_ltx::Section = ltx.section(section_name).ok_or_else(|| {
+        ::xray_error::XRayError::new_parsing_error(format!(#error, section_name, file!()))
+      })?;
+    }
+  };
+
+  let section_export: TokenStream = if value_exports.is_empty() {
+    quote! {}
+  } else {
+    quote! {
+      ltx
+        .with_section(section_name)
+        #(#value_exports)*;
+    }
+  };
+
+  quote! {
+    impl crate::export::LtxImportExport for #name {
+      fn import(section_name: &str, ltx: &::xray_ltx::Ltx) -> ::xray_error::XRayResult<Self> {
+        use crate::export::LtxImportExport;
+
+        #section_import
+
+        Ok(Self {
+          #(#imports,)*
+        })
+      }
+
+      fn export(
+        &self,
+        section_name: &str,
+        ltx: &mut ::xray_ltx::Ltx,
+      ) -> ::xray_error::XRayResult {
+        use crate::export::LtxImportExport;
+
+        #(#inherited_exports)*
+
+        #section_export
+
+        Ok(())
+      }
+    }
+  }
+}
+
+fn parse_struct_prefix(attributes: &[Attribute]) -> String {
+  let mut prefix: String = String::new();
+
+  for attribute in attributes.iter().filter(|it| it.path().is_ident("ltx")) {
+    attribute
+      .parse_nested_meta(|meta| {
+        if meta.path.is_ident("prefix") {
+          prefix = meta.value()?.parse::<LitStr>()?.value();
+
+          Ok(())
+        } else {
+          Err(meta.error("unsupported ltx attribute, expected `prefix = \"..\"`"))
+        }
+      })
+      .unwrap_or_else(|error| panic!("Failed to parse #[ltx] attribute: {error}"));
+  }
+
+  prefix
+}
+
+fn parse_field_kind(field: &Field, ident: &Ident, prefix: &str) -> LtxFieldKind {
+  let mut kind: LtxFieldKind = LtxFieldKind::Value(format!("{prefix}{ident}"));
+
+  for attribute in field.attrs.iter().filter(|it| it.path().is_ident("ltx")) {
+    attribute
+      .parse_nested_meta(|meta| {
+        if meta.path.is_ident("inherit") {
+          kind = LtxFieldKind::Inherit;
+
+          Ok(())
+        } else if meta.path.is_ident("name") {
+          kind = LtxFieldKind::Value(meta.value()?.parse::<LitStr>()?.value());
+
+          Ok(())
+        } else {
+          Err(meta.error("unsupported ltx attribute, expected `inherit` or `name = \"..\"`"))
+        }
+      })
+      .unwrap_or_else(|error| panic!("Failed to parse #[ltx] attribute: {error}"));
+  }
+
+  kind
+}
@@ -0,0 +1,63 @@
+use proc_macro2::Ident;
+use syn::{
+  Data, DataStruct, DeriveInput, Fields, FieldsNamed, GenericArgument, PathArguments, Type,
+};
+
+/// Get named fields of derived struct, other items are not supported.
+pub fn get_named_fields<'a>(input: &'a DeriveInput, derive: &str) -> &'a FieldsNamed {
+  if !input.generics.params.is_empty() {
+    panic!("#[derive({derive})] does not support generic structs");
+  }
+
+  match &input.data {
+    Data::Struct(DataStruct {
+      fields: Fields::Named(fields),
+      ..
+    }) => fields,
+    _ => panic!("#[derive({derive})] can only be used on structs with named fields"),
+  }
+}
+
+/// Get last path segment identifier of the type, e.g. `u32` or `Vec`.
+pub fn get_type_ident(ty: &Type) -> Option<&Ident> {
+  match ty {
+    Type::Path(path) if path.qself.is_none() => path.path.segments.last().map(|it| &it.ident),
+    _ => None,
+  }
+}
+
+/// Get single generic argument of `Option<T>` or `Vec<T>` like types.
+pub fn get_wrapped_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+  let Type::Path(path) = ty else {
+    return None;
+  };
+
+  let segment = path.path.segments.last()?;
+
+  if segment.ident != wrapper {
+    return None;
+  }
+
+  match &segment.arguments {
+    PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+      match arguments.args.first() {
+        Some(GenericArgument::Type(ty)) => Some(ty),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Whether identifier names numeric primitive supported by byteorder read/write methods.
+pub fn is_numeric_primitive(ident: &Ident) -> bool {
+  matches!(
+    ident.to_string().as_str(),
+    "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "f32" | "f64"
+  )
+}
+
+/// Whether numeric primitive is single byte and does not require byte order.
+pub fn is_single_byte(ident: &Ident) -> bool {
+  matches!(ident.to_string().as_str(), "u8" | "i8")
+}
@@ -3,7 +3,10 @@
 pub const NIL: &str = "nil";
 pub const META_TYPE_FIELD: &str = "$type";
 
-pub const MINIMAL_SUPPORTED_SPAWN_VERSION: u16 = 120;
+pub const MINIMAL_SUPPORTED_SPAWN_VERSION: u16 = 118;
+pub const SPAWN_VERSION_SOC: u16 = 118;
+pub const SPAWN_VERSION_CS: u16 = 122;
+pub const SPAWN_VERSION_COP: u16 = 128;
 
 pub const FLAG_SPAWN_ENABLED: u16 = 1;
 pub const FLAG_SPAWN_ON_SURGE_ONLY: u16 = 2;
@@ -17,6 +20,10 @@ pub const FLAG_SKELETON_SPAWN_COPY: u8 = 2;
 pub const FLAG_SKELETON_SAVED_DATA: u8 = 4;
 pub const FLAG_SKELETON_NOT_SAVE: u8 = 8;
 
+pub const FLAG_PHYSIC_STATE_ENABLED: u8 = 32;
+pub const FLAG_PHYSIC_STATE_ANGULAR_NULL: u8 = 64;
+pub const FLAG_PHYSIC_STATE_LINEAR_NULL: u8 = 128;
+
 // Graph related chunk sizing.
 pub const DEFAULT_LEVEL_BLOCK_SIZE: usize = 4096;
 pub const DEFAULT_VERTEX_BLOCK_SIZE: usize = 42;
@@ -1,7 +1,8 @@
 use crate::constants::{
-  FLAG_SPAWN_DESTROY_ON_SPAWN, MINIMAL_SUPPORTED_SPAWN_VERSION, NET_ACTION_SPAWN,
+  FLAG_SPAWN_DESTROY_ON_SPAWN, MINIMAL_SUPPORTED_SPAWN_VERSION, NET_ACTION_SPAWN, NET_ACTION_UPDATE,
 };
 use crate::data::alife::alife_object_inherited::AlifeObjectInherited;
+use crate::data::alife::alife_object_update::AlifeObjectUpdate;
 use crate::data::generic::vector_3d::Vector3d;
 use crate::data::meta::alife_class::AlifeClass;
 use crate::data::meta::cls_id::ClsId;
@@ -9,13 +10,10 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use std::io::Write;
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
-use xray_utils::{
-  assert, assert_equal, assert_not_equal, decode_bytes_from_base64, encode_bytes_to_base64,
-};
+use xray_utils::{assert, assert_equal, assert_not_equal};
 
 /// Generic abstract ALife object base.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -40,28 +38,20 @@ pub struct AlifeObject {
   pub client_data_size: u16,
   pub spawn_id: u16,
   pub inherited: AlifeObjectInherited,
-  pub update_data: Vec<u8>, // todo: Parse.
+  pub update: AlifeObjectUpdate,
 }
 
 impl AlifeObject {
   pub const DATA_SPAWN_CHUNK_ID: u32 = 0;
   pub const DATA_UPDATE_CHUNK_ID: u32 = 1;
-}
-
-impl ChunkReadWrite for AlifeObject {
-  /// Read generic ALife object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    let mut spawn_reader: ChunkReader = reader.read_child_by_index(Self::DATA_SPAWN_CHUNK_ID)?;
-
-    let data_length: u16 = spawn_reader.read_u16::<T>()?;
-
-    assert_equal(
-      data_length as u64 + 2,
-      spawn_reader.size,
-      "Expect correct data size for ALife object",
-    )?;
 
-    let net_action: u16 = spawn_reader.read_u16::<T>()?;
+  /// Read ALife object spawn packet, represents Spawn_Read of xray implementation.
+  /// Same packet is stored in level.spawn chunks and in spawn data of all.spawn objects.
+  /// Update data is not part of spawn packet and is created from spawn state.
+  pub fn read_spawn_packet<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Self> {
+    let net_action: u16 = reader.read_u16::<T>()?;
 
     assert_equal(
       net_action,
@@ -69,33 +59,36 @@ impl ChunkReadWrite for AlifeObject {
       "Expect only net_spawn actions in ALife object data",
     )?;
 
-    let section: String = spawn_reader.read_w1251_string()?;
+    let section: String = reader.read_w1251_string()?;
     let clsid: ClsId = ClsId::from_section(&section);
     let class: AlifeClass = AlifeClass::from_cls_id(&clsid);
-    let name: String = spawn_reader.read_w1251_string()?;
-    let script_game_id: u8 = spawn_reader.read_u8()?;
-    let script_rp: u8 = spawn_reader.read_u8()?;
-    let position: Vector3d = spawn_reader.read_xr::<T, _>()?;
-    let direction: Vector3d = spawn_reader.read_xr::<T, _>()?;
-    let respawn_time: u16 = spawn_reader.read_u16::<T>()?;
-    let id: u16 = spawn_reader.read_u16::<T>()?;
-    let parent_id: u16 = spawn_reader.read_u16::<T>()?;
-    let phantom_id: u16 = spawn_reader.read_u16::<T>()?;
-    let script_flags: u16 = spawn_reader.read_u16::<T>()?;
+    let name: String = reader.read_w1251_string()?;
+    let script_game_id: u8 = reader.read_u8()?;
+    let script_rp: u8 = reader.read_u8()?;
+    let position: Vector3d = reader.read_xr::<T, _>()?;
+    let direction: Vector3d = reader.read_xr::<T, _>()?;
+    let respawn_time: u16 = reader.read_u16::<T>()?;
+    let id: u16 = reader.read_u16::<T>()?;
+    let parent_id: u16 = reader.read_u16::<T>()?;
+    let phantom_id: u16 = reader.read_u16::<T>()?;
+    let script_flags: u16 = reader.read_u16::<T>()?;
     let version: u16 = if script_flags & FLAG_SPAWN_DESTROY_ON_SPAWN == 0 {
       0
     } else {
-      spawn_reader.read_u16::<T>()?
+      reader.read_u16::<T>()?
     };
 
     assert(
-      version > MINIMAL_SUPPORTED_SPAWN_VERSION,
+      version >= MINIMAL_SUPPORTED_SPAWN_VERSION,
       "Unexpected version of ALife object in spawn file, flag is {script_flags}",
     )?;
 
-    let game_type: u16 = spawn_reader.read_u16::<T>()?;
-    let script_version: u16 = spawn_reader.read_u16::<T>()?;
-    let client_data_size: u16 = spawn_reader.read_u16::<T>()?;
+    // Nested data layout depends on object version, same as STATE_Read / UPDATE_Read of xray.
+    reader.version = Some(version);
+
+    let game_type: u16 = reader.read_u16::<T>()?;
+    let script_version: u16 = reader.read_u16::<T>()?;
+    let client_data_size: u16 = reader.read_u16::<T>()?;
 
     assert_equal(
       client_data_size,
@@ -103,12 +96,12 @@ impl ChunkReadWrite for AlifeObject {
       "Client data is not expected in ALife object",
     )?; // Or read client data?
 
-    let spawn_id: u16 = spawn_reader.read_u16::<T>()?;
-    let inherited_size: u16 = spawn_reader.read_u16::<T>()?;
+    let spawn_id: u16 = reader.read_u16::<T>()?;
+    let inherited_size: u16 = reader.read_u16::<T>()?;
 
     assert_equal(
       inherited_size as u64 - 2,
-      spawn_reader.end_pos() - spawn_reader.cursor_pos(),
+      reader.end_pos() - reader.cursor_pos(),
       "Expect correct size of inherited data for ALife object",
     )?;
 
@@ -118,30 +111,11 @@ impl ChunkReadWrite for AlifeObject {
       "Expect known ALife object clsid",
     )?;
 
-    let inherited: AlifeObjectInherited =
-      AlifeObjectInherited::read::<T>(&mut spawn_reader, &class)?;
-
-    let mut update_reader: ChunkReader = reader.read_child_by_index(Self::DATA_UPDATE_CHUNK_ID)?;
-    let update_data_length: u16 = update_reader.read_u16::<T>()?;
-    let update_size: u16 = update_reader.read_u16::<T>()?;
-
-    assert_equal(
-      update_data_length as u64 + 2,
-      update_reader.size,
-      "Expect correct size of ALife object update data",
-    )?;
-    assert_equal(
-      update_size,
-      0,
-      "Expect no ALife update data in object chunk",
-    )?;
-
-    let update_data: Vec<u8> =
-      update_reader.read_bytes(update_reader.read_bytes_remain() as usize)?;
+    let inherited: AlifeObjectInherited = AlifeObjectInherited::read::<T, _>(reader, &class)?;
+    let update: AlifeObjectUpdate =
+      AlifeObjectUpdate::from_spawn(&class, &inherited, &position, &direction);
 
-    assert_chunk_read(&spawn_reader, "Expect ALife object spawn data to be read")?;
-    assert_chunk_read(&update_reader, "Expect ALife object update data to be read")?;
-    assert_chunk_read(reader, "Expect ALife object chunk to be read")?;
+    assert_chunk_read(reader, "Expect ALife object spawn data to be read")?;
 
     Ok(Self {
       net_action,
@@ -163,64 +137,108 @@ impl ChunkReadWrite for AlifeObject {
       client_data_size,
       spawn_id,
       inherited,
-      update_data,
+      update,
     })
   }
 
+  /// Write ALife object spawn packet, represents Spawn_Write of xray implementation.
+  pub fn write_spawn_packet<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    let mut inherited_data_writer: ChunkWriter = ChunkWriter::new_versioned(self.version);
+
+    writer.write_u16::<T>(self.net_action)?;
+
+    writer.write_w1251_string(&self.section)?;
+    writer.write_w1251_string(&self.name)?;
+    writer.write_u8(self.script_game_id)?;
+    writer.write_u8(self.script_rp)?;
+
+    writer.write_xr::<T, Vector3d>(&self.position)?;
+    writer.write_xr::<T, Vector3d>(&self.direction)?;
+
+    writer.write_u16::<T>(self.respawn_time)?;
+    writer.write_u16::<T>(self.id)?;
+    writer.write_u16::<T>(self.parent_id)?;
+    writer.write_u16::<T>(self.phantom_id)?;
+    writer.write_u16::<T>(self.script_flags)?;
+    writer.write_u16::<T>(self.version)?;
+    writer.write_u16::<T>(self.game_type)?;
+    writer.write_u16::<T>(self.script_version)?;
+    writer.write_u16::<T>(self.client_data_size)?;
+    writer.write_u16::<T>(self.spawn_id)?;
+
+    self.inherited.write::<T>(&mut inherited_data_writer)?;
+
+    writer.write_u16::<T>(inherited_data_writer.bytes_written() as u16 + 2)?;
+    inherited_data_writer.flush_raw_into(writer)?;
+
+    Ok(())
+  }
+}
+
+impl ChunkReadWrite for AlifeObject {
+  /// Read generic ALife object data from the chunk.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let mut spawn_reader: ChunkReader<D> =
+      reader.read_child_by_index::<T>(Self::DATA_SPAWN_CHUNK_ID)?;
+
+    let data_length: u16 = spawn_reader.read_u16::<T>()?;
+
+    assert_equal(
+      data_length as u64 + 2,
+      spawn_reader.size,
+      "Expect correct data size for ALife object",
+    )?;
+
+    let mut object: Self = Self::read_spawn_packet::<T, _>(&mut spawn_reader)?;
+
+    let mut update_reader: ChunkReader<D> =
+      reader.read_child_by_index::<T>(Self::DATA_UPDATE_CHUNK_ID)?;
+    update_reader.version = Some(object.version);
+
+    let update_data_length: u16 = update_reader.read_u16::<T>()?;
+    let update_net_action: u16 = update_reader.read_u16::<T>()?;
+
+    assert_equal(
+      update_data_length as u64 + 2,
+      update_reader.size,
+      "Expect correct size of ALife object update data",
+    )?;
+    assert_equal(
+      update_net_action,
+      NET_ACTION_UPDATE,
+      "Expect only net_update actions in ALife object update data",
+    )?;
+
+    object.update =
+      AlifeObjectUpdate::read::<T, _>(&mut update_reader, &AlifeClass::from_cls_id(&object.clsid))?;
+
+    assert_chunk_read(&update_reader, "Expect ALife object update data to be read")?;
+    assert_chunk_read(reader, "Expect ALife object chunk to be read")?;
+
+    Ok(object)
+  }
+
   /// Write ALife object data into the writer.
   fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
     let mut data_spawn_writer: ChunkWriter = ChunkWriter::new();
     let mut data_update_writer: ChunkWriter = ChunkWriter::new();
 
     let mut object_data_writer: ChunkWriter = ChunkWriter::new();
-    let mut inherited_data_writer: ChunkWriter = ChunkWriter::new();
-    let mut updated_data_writer: ChunkWriter = ChunkWriter::new();
-
-    object_data_writer.write_u16::<T>(self.net_action)?;
-
-    object_data_writer.write_w1251_string(&self.section)?;
-    object_data_writer.write_w1251_string(&self.name)?;
-    object_data_writer.write_u8(self.script_game_id)?;
-    object_data_writer.write_u8(self.script_rp)?;
-
-    object_data_writer.write_xr::<T, Vector3d>(&self.position)?;
-    object_data_writer.write_xr::<T, Vector3d>(&self.direction)?;
-
-    object_data_writer.write_u16::<T>(self.respawn_time)?;
-    object_data_writer.write_u16::<T>(self.id)?;
-    object_data_writer.write_u16::<T>(self.parent_id)?;
-    object_data_writer.write_u16::<T>(self.phantom_id)?;
-    object_data_writer.write_u16::<T>(self.script_flags)?;
-    object_data_writer.write_u16::<T>(self.version)?;
-    object_data_writer.write_u16::<T>(self.game_type)?;
-    object_data_writer.write_u16::<T>(self.script_version)?;
-    object_data_writer.write_u16::<T>(self.client_data_size)?;
-    object_data_writer.write_u16::<T>(self.spawn_id)?;
+    let mut updated_data_writer: ChunkWriter = ChunkWriter::new_versioned(self.version);
 
-    self.inherited.write::<T>(&mut inherited_data_writer)?;
-
-    object_data_writer.write_u16::<T>(inherited_data_writer.bytes_written() as u16 + 2)?;
-    object_data_writer.write_all(inherited_data_writer.flush_raw_into_buffer()?.as_slice())?;
+    self.write_spawn_packet::<T>(&mut object_data_writer)?;
 
     data_spawn_writer.write_u16::<T>(object_data_writer.bytes_written() as u16)?;
-    data_spawn_writer.write_all(object_data_writer.flush_raw_into_buffer()?.as_slice())?;
+    object_data_writer.flush_raw_into(&mut data_spawn_writer)?;
 
-    updated_data_writer.write_u16::<T>(0)?;
-    updated_data_writer.write_all(&self.update_data)?;
+    updated_data_writer.write_u16::<T>(NET_ACTION_UPDATE)?;
+    self.update.write::<T>(&mut updated_data_writer)?;
 
     data_update_writer.write_u16::<T>(updated_data_writer.bytes_written() as u16)?;
-    data_update_writer.write_all(updated_data_writer.flush_raw_into_buffer()?.as_slice())?;
+    updated_data_writer.flush_raw_into(&mut data_update_writer)?;
 
-    writer.write_all(
-      data_spawn_writer
-        .flush_chunk_into_buffer::<T>(Self::DATA_SPAWN_CHUNK_ID)?
-        .as_slice(),
-    )?;
-    writer.write_all(
-      data_update_writer
-        .flush_chunk_into_buffer::<T>(Self::DATA_UPDATE_CHUNK_ID)?
-        .as_slice(),
-    )?;
+    data_spawn_writer.flush_chunk_into::<T>(writer, Self::DATA_SPAWN_CHUNK_ID)?;
+    data_update_writer.flush_chunk_into::<T>(writer, Self::DATA_UPDATE_CHUNK_ID)?;
 
     Ok(())
   }
@@ -239,6 +257,7 @@ impl LtxImportExport for AlifeObject {
 
     let object_section: String = read_ltx_field("section", section)?;
     let clsid: ClsId = ClsId::from_section(&object_section);
+    let class: AlifeClass = AlifeClass::from_cls_id(&clsid);
 
     Ok(Self {
       id: read_ltx_field("id", section)?,
@@ -259,8 +278,8 @@ impl LtxImportExport for AlifeObject {
       script_version: read_ltx_field("script_version", section)?,
       client_data_size: read_ltx_field("client_data_size", section)?,
       spawn_id: read_ltx_field("spawn_id", section)?,
-      inherited: AlifeObjectInherited::import(section_name, ltx, &AlifeClass::from_cls_id(&clsid))?,
-      update_data: decode_bytes_from_base64(&read_ltx_field::<String>("update_data", section)?)?,
+      inherited: AlifeObjectInherited::import(section_name, ltx, &class)?,
+      update: AlifeObjectUpdate::import(section_name, ltx, &class)?,
     })
   }
 
@@ -287,10 +306,7 @@ impl LtxImportExport for AlifeObject {
       .set("spawn_id", self.spawn_id.to_string());
 
     self.inherited.export(section_name, ltx)?;
-
-    ltx
-      .with_section(section_name)
-      .set("update_data", encode_bytes_to_base64(&self.update_data));
+    self.update.export(section_name, ltx)?;
 
     Ok(())
   }
@@ -298,12 +314,16 @@ impl LtxImportExport for AlifeObject {
 
 #[cfg(test)]
 mod tests {
+  use crate::constants::{SPAWN_VERSION_CS, SPAWN_VERSION_SOC};
   use crate::data::alife::alife_object::AlifeObject;
   use crate::data::alife::alife_object_inherited::AlifeObjectInherited;
+  use crate::data::alife::alife_object_update::AlifeObjectUpdate;
   use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
   use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
   use crate::data::alife::inherited::alife_object_item::AlifeObjectItem;
   use crate::data::alife::inherited::alife_object_item_custom_outfit::AlifeObjectItemCustomOutfit;
+  use crate::data::alife::update::alife_object_item_custom_outfit_update::AlifeObjectItemCustomOutfitUpdate;
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
   use crate::data::generic::vector_3d::Vector3d;
   use crate::data::meta::cls_id::ClsId;
   use crate::export::LtxImportExport;
@@ -366,27 +386,208 @@ mod tests {
           },
         },
       )),
-      update_data: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+      update: AlifeObjectUpdate::ItemCustomOutfit(Box::new(AlifeObjectItemCustomOutfitUpdate {
+        base: AlifeObjectPhysicStateUpdate {
+          num_items: 0,
+          position: None,
+          quaternion: vec![],
+          angular_velocity: vec![],
+          linear_velocity: vec![],
+        },
+        condition: 255,
+      })),
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 170);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 170);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 170 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObject::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_write_cs_version() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write_cs.chunk");
+
+    let original: AlifeObject = AlifeObject {
+      id: 340,
+      net_action: 1,
+      section: String::from("dolg_heavy_outfit"),
+      clsid: ClsId::EStlk,
+      name: String::from("test-outfit-object"),
+      script_game_id: 2,
+      script_rp: 3,
+      position: Vector3d::new(1.0, 2.0, 3.0),
+      direction: Vector3d::new(3.0, 2.0, 1.0),
+      respawn_time: 50000,
+      parent_id: 2143,
+      phantom_id: 0,
+      script_flags: 33,
+      version: SPAWN_VERSION_CS,
+      game_type: 1,
+      script_version: 10,
+      client_data_size: 0,
+      spawn_id: 2354,
+      inherited: AlifeObjectInherited::CseAlifeItemCustomOutfit(Box::new(
+        AlifeObjectItemCustomOutfit {
+          base: AlifeObjectItem {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 12434,
+                distance: 124.33,
+                direct_control: 624345,
+                level_vertex_id: 48528,
+                flags: 34,
+                custom_data: String::from("custom-data"),
+                story_id: 523,
+                spawn_story_id: 2865268,
+              },
+              visual_name: String::from("visual-name"),
+              visual_flags: 0,
+            },
+            condition: 1.0,
+            upgrades_count: 0,
+          },
+        },
+      )),
+      update: AlifeObjectUpdate::ItemCustomOutfit(Box::new(AlifeObjectItemCustomOutfitUpdate {
+        base: AlifeObjectPhysicStateUpdate {
+          num_items: 0,
+          position: None,
+          quaternion: vec![],
+          angular_velocity: vec![],
+          linear_velocity: vec![],
+        },
+        condition: 255,
+      })),
     };
 
     original.write::<XRayByteOrder>(&mut writer)?;
 
-    assert_eq!(writer.bytes_written(), 185);
+    assert_eq!(writer.bytes_written(), 166);
 
     let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
       &mut overwrite_test_relative_resource_as_file(&filename)?,
       0,
     )?;
 
-    assert_eq!(bytes_written, 185);
+    assert_eq!(bytes_written, 166);
 
     let file: FileSlice = open_test_resource_as_slice(&filename)?;
 
-    assert_eq!(file.bytes_remaining(), 185 + 8);
+    assert_eq!(file.bytes_remaining(), 166 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
-    assert_eq!(AlifeObject::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(
+      AlifeObject::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_write_soc_version() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write_soc.chunk");
+
+    let original: AlifeObject = AlifeObject {
+      id: 340,
+      net_action: 1,
+      section: String::from("dolg_heavy_outfit"),
+      clsid: ClsId::EStlk,
+      name: String::from("test-outfit-object"),
+      script_game_id: 2,
+      script_rp: 3,
+      position: Vector3d::new(1.0, 2.0, 3.0),
+      direction: Vector3d::new(3.0, 2.0, 1.0),
+      respawn_time: 50000,
+      parent_id: 2143,
+      phantom_id: 0,
+      script_flags: 33,
+      version: SPAWN_VERSION_SOC,
+      game_type: 1,
+      script_version: 10,
+      client_data_size: 0,
+      spawn_id: 2354,
+      inherited: AlifeObjectInherited::CseAlifeItemCustomOutfit(Box::new(
+        AlifeObjectItemCustomOutfit {
+          base: AlifeObjectItem {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 12434,
+                distance: 124.33,
+                direct_control: 624345,
+                level_vertex_id: 48528,
+                flags: 34,
+                custom_data: String::from("custom-data"),
+                story_id: 523,
+                spawn_story_id: 2865268,
+              },
+              visual_name: String::from("visual-name"),
+              visual_flags: 0,
+            },
+            condition: 1.0,
+            upgrades_count: 0,
+          },
+        },
+      )),
+      update: AlifeObjectUpdate::ItemCustomOutfit(Box::new(AlifeObjectItemCustomOutfitUpdate {
+        base: AlifeObjectPhysicStateUpdate {
+          num_items: 0,
+          position: None,
+          quaternion: vec![],
+          angular_velocity: vec![],
+          linear_velocity: vec![],
+        },
+        condition: 255,
+      })),
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 166);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 166);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 166 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObject::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
 
     Ok(())
   }
@@ -437,7 +638,16 @@ mod tests {
           },
         },
       )),
-      update_data: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+      update: AlifeObjectUpdate::ItemCustomOutfit(Box::new(AlifeObjectItemCustomOutfitUpdate {
+        base: AlifeObjectPhysicStateUpdate {
+          num_items: 0,
+          position: None,
+          quaternion: vec![],
+          angular_velocity: vec![],
+          linear_velocity: vec![],
+        },
+        condition: 255,
+      })),
     };
 
     original.export("data", &mut ltx)?;
@@ -496,7 +706,16 @@ mod tests {
           },
         },
       )),
-      update_data: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+      update: AlifeObjectUpdate::ItemCustomOutfit(Box::new(AlifeObjectItemCustomOutfitUpdate {
+        base: AlifeObjectPhysicStateUpdate {
+          num_items: 0,
+          position: None,
+          quaternion: vec![],
+          angular_velocity: vec![],
+          linear_velocity: vec![],
+        },
+        condition: 255,
+      })),
     };
 
     let mut file: File = overwrite_test_relative_resource_as_file(
@@ -2,9 +2,16 @@ use crate::data::alife::inherited::alife_actor::AlifeActor;
 use crate::data::alife::inherited::alife_anomalous_zone::AlifeAnomalousZone;
 use crate::data::alife::inherited::alife_graph_point::AlifeGraphPoint;
 use crate::data::alife::inherited::alife_level_changer::AlifeLevelChanger;
+use crate::data::alife::inherited::alife_monster::AlifeMonster;
+use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
 use crate::data::alife::inherited::alife_object_anomaly_zone::AlifeObjectAnomalyZone;
 use crate::data::alife::inherited::alife_object_breakable::AlifeObjectBreakable;
+use crate::data::alife::inherited::alife_object_car::AlifeObjectCar;
 use crate::data::alife::inherited::alife_object_climable::AlifeObjectClimable;
+use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+use crate::data::alife::inherited::alife_object_dynamic::AlifeObjectDynamic;
+use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+use crate::data::alife::inherited::alife_object_flesh_group::AlifeObjectFleshGroup;
 use crate::data::alife::inherited::alife_object_hanging_lamp::AlifeObjectHangingLamp;
 use crate::data::alife::inherited::alife_object_helicopter::AlifeObjectHelicopter;
 use crate::data::alife::inherited::alife_object_inventory_box::AlifeObjectInventoryBox;
@@ -13,6 +20,7 @@ use crate::data::alife::inherited::alife_object_item_ammo::AlifeObjectItemAmmo;
 use crate::data::alife::inherited::alife_object_item_artefact::AlifeObjectItemArtefact;
 use crate::data::alife::inherited::alife_object_item_custom_outfit::AlifeObjectItemCustomOutfit;
 use crate::data::alife::inherited::alife_object_item_detector::AlifeObjectItemDetector;
+use crate::data::alife::inherited::alife_object_item_document::AlifeObjectItemDocument;
 use crate::data::alife::inherited::alife_object_item_explosive::AlifeObjectItemExplosive;
 use crate::data::alife::inherited::alife_object_item_grenade::AlifeObjectItemGrenade;
 use crate::data::alife::inherited::alife_object_item_helmet::AlifeObjectItemHelmet;
@@ -21,18 +29,27 @@ use crate::data::alife::inherited::alife_object_item_weapon::AlifeObjectItemWeap
 use crate::data::alife::inherited::alife_object_item_weapon_magazined::AlifeObjectItemWeaponMagazined;
 use crate::data::alife::inherited::alife_object_item_weapon_magazined_wgl::AlifeObjectItemWeaponMagazinedWgl;
 use crate::data::alife::inherited::alife_object_item_weapon_shotgun::AlifeObjectItemWeaponShotgun;
+use crate::data::alife::inherited::alife_object_monster_base::AlifeObjectMonsterBase;
+use crate::data::alife::inherited::alife_object_ph_skeleton::AlifeObjectPhSkeleton;
 use crate::data::alife::inherited::alife_object_physic::AlifeObjectPhysic;
+use crate::data::alife::inherited::alife_object_rat_group::AlifeObjectRatGroup;
 use crate::data::alife::inherited::alife_object_space_restrictor::AlifeObjectSpaceRestrictor;
+use crate::data::alife::inherited::alife_object_spawn_group::AlifeObjectSpawnGroup;
+use crate::data::alife::inherited::alife_object_spectator::AlifeObjectSpectator;
+use crate::data::alife::inherited::alife_object_team_base_zone::AlifeObjectTeamBaseZone;
 use crate::data::alife::inherited::alife_object_torrid_zone::AlifeObjectTorridZone;
+use crate::data::alife::inherited::alife_object_trader::AlifeObjectTrader;
 use crate::data::alife::inherited::alife_smart_cover::AlifeSmartCover;
 use crate::data::alife::inherited::alife_smart_terrain::AlifeSmartTerrain;
+use crate::data::alife::inherited::alife_smart_zone::AlifeSmartZone;
+use crate::data::alife::inherited::alife_stalker::AlifeStalker;
 use crate::data::alife::inherited::alife_zone_visual::AlifeZoneVisual;
 use crate::data::meta::alife_class::AlifeClass;
 use crate::export::LtxImportExport;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
-use xray_chunk::{ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::Ltx;
 
@@ -68,14 +85,35 @@ pub enum AlifeObjectInherited {
   CseAlifeItemWeaponShotgun(Box<AlifeObjectItemWeaponShotgun>),
   CseAlifeItemWeaponMagazined(Box<AlifeObjectItemWeaponMagazined>),
   CseAlifeItemWeaponMagazinedWGl(Box<AlifeObjectItemWeaponMagazinedWgl>),
+  CseAlifeCreatureCrow(Box<AlifeObjectCreature>),
+  CseAlifeMonsterBase(Box<AlifeObjectMonsterBase>),
+  CseAlifeTrader(Box<AlifeObjectTrader>),
+  SeMonster(Box<AlifeMonster>),
+  SeStalker(Box<AlifeStalker>),
+  CseAlifeCar(Box<AlifeObjectCar>),
+  CseAlifeItemTorch(Box<AlifeObjectItem>),
+  CseAlifeItemBolt(Box<AlifeObjectItem>),
+  CseAlifeItemDocument(Box<AlifeObjectItemDocument>),
+  CseAlifeMountedWeapon(Box<AlifeObjectDynamicVisual>),
+  CseAlifeStationaryMGun(Box<AlifeObjectDynamicVisual>),
+  CseAlifeObjectProjector(Box<AlifeObjectDynamicVisual>),
+  CseAlifeDynamicObjectVisual(Box<AlifeObjectDynamicVisual>),
+  CseAlifePhSkeletonObject(Box<AlifeObjectPhSkeleton>),
+  CseAlifeFleshGroup(Box<AlifeObjectFleshGroup>),
+  CseAlifeRatGroup(Box<AlifeObjectRatGroup>),
+  CseAlifeSpawnGroup(Box<AlifeObjectSpawnGroup>),
+  CseAlifeTeamBaseZone(Box<AlifeObjectTeamBaseZone>),
+  CseSpectator(Box<AlifeObjectSpectator>),
+  SeSimFaction(Box<AlifeSmartZone>),
+  SimSquadScripted(Box<AlifeObjectDynamic>),
 }
 
 impl AlifeObjectInherited {
   /// Read custom save data based on serialized clsid.
   /// Represents STATE_Read of each separate object in xray implementation.
   /// Additionally, should respect script extension.
-  pub fn read<T: ByteOrder>(
-    reader: &mut ChunkReader,
+  pub fn read<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
     alife_class: &AlifeClass,
   ) -> XRayResult<Self> {
     Ok(match alife_class {
@@ -146,6 +184,49 @@ impl AlifeObjectInherited {
       AlifeClass::CseAlifeItemWeaponMagazinedWGl => {
         Self::CseAlifeItemWeaponMagazinedWGl(Box::new(reader.read_xr::<T, _>()?))
       }
+      AlifeClass::CseAlifeCreatureCrow => {
+        Self::CseAlifeCreatureCrow(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeMonsterBase => {
+        Self::CseAlifeMonsterBase(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeTrader => Self::CseAlifeTrader(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::SeMonster => Self::SeMonster(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::SeStalker => Self::SeStalker(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeCar => Self::CseAlifeCar(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeItemTorch => Self::CseAlifeItemTorch(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeItemBolt => Self::CseAlifeItemBolt(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeItemDocument => {
+        Self::CseAlifeItemDocument(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeMountedWeapon => {
+        Self::CseAlifeMountedWeapon(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeStationaryMGun => {
+        Self::CseAlifeStationaryMGun(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeObjectProjector => {
+        Self::CseAlifeObjectProjector(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeDynamicObjectVisual => {
+        Self::CseAlifeDynamicObjectVisual(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifePhSkeletonObject => {
+        Self::CseAlifePhSkeletonObject(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeFleshGroup => {
+        Self::CseAlifeFleshGroup(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeRatGroup => Self::CseAlifeRatGroup(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeSpawnGroup => {
+        Self::CseAlifeSpawnGroup(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeTeamBaseZone => {
+        Self::CseAlifeTeamBaseZone(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseSpectator => Self::CseSpectator(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::SeSimFaction => Self::SeSimFaction(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::SimSquadScripted => Self::SimSquadScripted(Box::new(reader.read_xr::<T, _>()?)),
       _ => {
         return Err(XRayError::new_parsing_error(format!(
           "Not implemented parser for {}",
@@ -224,6 +305,49 @@ impl AlifeObjectInherited {
       AlifeObjectInherited::CseAlifeItemWeaponMagazinedWGl(object) => {
         writer.write_xr::<T, _>(object.deref())?
       }
+      AlifeObjectInherited::CseAlifeCreatureCrow(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeMonsterBase(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeTrader(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::SeMonster(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::SeStalker(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::CseAlifeCar(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::CseAlifeItemTorch(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::CseAlifeItemBolt(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::CseAlifeItemDocument(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeMountedWeapon(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeStationaryMGun(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeObjectProjector(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeDynamicObjectVisual(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifePhSkeletonObject(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeFleshGroup(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeRatGroup(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::CseAlifeSpawnGroup(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseAlifeTeamBaseZone(object) => {
+        writer.write_xr::<T, _>(object.deref())?
+      }
+      AlifeObjectInherited::CseSpectator(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::SeSimFaction(object) => writer.write_xr::<T, _>(object.deref())?,
+      AlifeObjectInherited::SimSquadScripted(object) => writer.write_xr::<T, _>(object.deref())?,
     }
 
     Ok(())
@@ -316,6 +440,65 @@ impl AlifeObjectInherited {
       AlifeClass::CseAlifeItemWeaponMagazinedWGl => Self::CseAlifeItemWeaponMagazinedWGl(Box::new(
         AlifeObjectItemWeaponMagazinedWgl::import(section_name, ltx)?,
       )),
+      AlifeClass::CseAlifeCreatureCrow => {
+        Self::CseAlifeCreatureCrow(Box::new(AlifeObjectCreature::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeMonsterBase => {
+        Self::CseAlifeMonsterBase(Box::new(AlifeObjectMonsterBase::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeTrader => {
+        Self::CseAlifeTrader(Box::new(AlifeObjectTrader::import(section_name, ltx)?))
+      }
+      AlifeClass::SeMonster => Self::SeMonster(Box::new(AlifeMonster::import(section_name, ltx)?)),
+      AlifeClass::SeStalker => Self::SeStalker(Box::new(AlifeStalker::import(section_name, ltx)?)),
+      AlifeClass::CseAlifeCar => {
+        Self::CseAlifeCar(Box::new(AlifeObjectCar::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeItemTorch => {
+        Self::CseAlifeItemTorch(Box::new(AlifeObjectItem::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeItemBolt => {
+        Self::CseAlifeItemBolt(Box::new(AlifeObjectItem::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeItemDocument => Self::CseAlifeItemDocument(Box::new(
+        AlifeObjectItemDocument::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeMountedWeapon => Self::CseAlifeMountedWeapon(Box::new(
+        AlifeObjectDynamicVisual::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeStationaryMGun => Self::CseAlifeStationaryMGun(Box::new(
+        AlifeObjectDynamicVisual::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeObjectProjector => Self::CseAlifeObjectProjector(Box::new(
+        AlifeObjectDynamicVisual::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeDynamicObjectVisual => Self::CseAlifeDynamicObjectVisual(Box::new(
+        AlifeObjectDynamicVisual::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifePhSkeletonObject => {
+        Self::CseAlifePhSkeletonObject(Box::new(AlifeObjectPhSkeleton::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeFleshGroup => {
+        Self::CseAlifeFleshGroup(Box::new(AlifeObjectFleshGroup::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeRatGroup => {
+        Self::CseAlifeRatGroup(Box::new(AlifeObjectRatGroup::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeSpawnGroup => {
+        Self::CseAlifeSpawnGroup(Box::new(AlifeObjectSpawnGroup::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeTeamBaseZone => Self::CseAlifeTeamBaseZone(Box::new(
+        AlifeObjectTeamBaseZone::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseSpectator => {
+        Self::CseSpectator(Box::new(AlifeObjectSpectator::import(section_name, ltx)?))
+      }
+      AlifeClass::SeSimFaction => {
+        Self::SeSimFaction(Box::new(AlifeSmartZone::import(section_name, ltx)?))
+      }
+      AlifeClass::SimSquadScripted => {
+        Self::SimSquadScripted(Box::new(AlifeObjectDynamic::import(section_name, ltx)?))
+      }
       _ => {
         return Err(XRayError::new_parsing_error(format!(
           "Not implemented parser for {}",
@@ -358,6 +541,142 @@ impl AlifeObjectInherited {
       AlifeObjectInherited::CseAlifeItemWeaponMagazinedWGl(object) => {
         object.export(section_name, ltx)
       }
+      AlifeObjectInherited::CseAlifeCreatureCrow(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeMonsterBase(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeTrader(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::SeMonster(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::SeStalker(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeCar(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeItemTorch(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeItemBolt(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeItemDocument(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeMountedWeapon(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeStationaryMGun(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeObjectProjector(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeDynamicObjectVisual(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifePhSkeletonObject(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeFleshGroup(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeRatGroup(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeSpawnGroup(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseAlifeTeamBaseZone(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::CseSpectator(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::SeSimFaction(object) => object.export(section_name, ltx),
+      AlifeObjectInherited::SimSquadScripted(object) => object.export(section_name, ltx),
+    }
+  }
+
+  /// Get mutable base ALife object data, shared by all objects except graph points,
+  /// spawn groups and spectators.
+  pub fn get_abstract_mut(&mut self) -> Option<&mut AlifeObjectAbstract> {
+    Some(match self {
+      Self::SeActor(object) => &mut object.base.base.base.base,
+      Self::CseAlifeObjectBreakable(object) => &mut object.base.base,
+      Self::CseAlifeObjectClimable(object) => &mut object.base.base,
+      Self::CseAlifeSpaceRestrictor(object) => &mut object.base,
+      Self::SeSmartCover(object) => &mut object.base.base.base,
+      Self::CseAlifeAnomalousZone(object) => &mut object.base.base.base,
+      Self::SeZoneAnom(object) => &mut object.base.base.base.base,
+      Self::SeZoneTorrid(object) => &mut object.base.base.base,
+      Self::SeSmartTerrain(object) => &mut object.base.base.base,
+      Self::SeLevelChanger(object) => &mut object.base.base,
+      Self::SeZoneVisual(object) => &mut object.base.base.base.base,
+      Self::CseAlifeObjectPhysic(object) => &mut object.base.base,
+      Self::CseAlifeHelicopter(object) => &mut object.base.base,
+      Self::CseAlifeInventoryBox(object) => &mut object.base.base,
+      Self::CseAlifeObjectHangingLamp(object) => &mut object.base.base,
+      Self::CseAlifeItem(object) => &mut object.base.base,
+      Self::CseAlifeItemExplosive(object) => &mut object.base.base.base,
+      Self::CseAlifeItemPda(object) => &mut object.base.base.base,
+      Self::CseAlifeItemAmmo(object) => &mut object.base.base.base,
+      Self::CseAlifeItemGrenade(object) => &mut object.base.base.base,
+      Self::CseAlifeItemArtefact(object) => &mut object.base.base.base,
+      Self::CseAlifeItemWeapon(object) => &mut object.base.base.base,
+      Self::CseAlifeItemDetector(object) => &mut object.base.base.base,
+      Self::CseAlifeItemHelmet(object) => &mut object.base.base.base,
+      Self::CseAlifeItemCustomOutfit(object) => &mut object.base.base.base,
+      Self::CseAlifeItemWeaponShotgun(object) => &mut object.base.base.base.base,
+      Self::CseAlifeItemWeaponMagazined(object) => &mut object.base.base.base.base,
+      Self::CseAlifeItemWeaponMagazinedWGl(object) => &mut object.base.base.base.base.base,
+      Self::CseAlifeCreatureCrow(object) => &mut object.base.base,
+      Self::CseAlifeMonsterBase(object) => &mut object.base.base.base.base,
+      Self::CseAlifeTrader(object) => &mut object.base.base,
+      Self::SeMonster(object) => &mut object.base.base.base.base.base,
+      Self::SeStalker(object) => &mut object.base.base.base.base.base.base,
+      Self::CseAlifeCar(object) => &mut object.base.base,
+      Self::CseAlifeItemTorch(object) => &mut object.base.base,
+      Self::CseAlifeItemBolt(object) => &mut object.base.base,
+      Self::CseAlifeItemDocument(object) => &mut object.base.base.base,
+      Self::CseAlifeMountedWeapon(object) => &mut object.base,
+      Self::CseAlifeStationaryMGun(object) => &mut object.base,
+      Self::CseAlifeObjectProjector(object) => &mut object.base,
+      Self::CseAlifeDynamicObjectVisual(object) => &mut object.base,
+      Self::CseAlifePhSkeletonObject(object) => &mut object.base.base,
+      Self::CseAlifeFleshGroup(object) => &mut object.base.base.base.base.base,
+      Self::CseAlifeRatGroup(object) => &mut object.base.base.base.base.base,
+      Self::CseAlifeTeamBaseZone(object) => &mut object.base.base,
+      Self::SeSimFaction(object) => &mut object.base.base,
+      Self::SimSquadScripted(object) => &mut object.base,
+      Self::CseAlifeGraphPoint(_) | Self::CseAlifeSpawnGroup(_) | Self::CseSpectator(_) => {
+        return None
+      }
+    })
+  }
+
+  /// Get creature data of living objects.
+  pub fn get_creature(&self) -> Option<&AlifeObjectCreature> {
+    match self {
+      Self::SeActor(object) => Some(&object.base.base),
+      Self::CseAlifeCreatureCrow(object) => Some(object),
+      Self::CseAlifeMonsterBase(object) => Some(&object.base.base),
+      Self::SeMonster(object) => Some(&object.base.base.base),
+      Self::SeStalker(object) => Some(&object.base.base.base.base),
+      Self::CseAlifeFleshGroup(object) => Some(&object.base.base.base),
+      Self::CseAlifeRatGroup(object) => Some(&object.base.base.base),
+      _ => None,
+    }
+  }
+
+  /// Get inventory item data of item objects.
+  pub fn get_item(&self) -> Option<&AlifeObjectItem> {
+    match self {
+      Self::CseAlifeItem(object)
+      | Self::CseAlifeItemTorch(object)
+      | Self::CseAlifeItemBolt(object) => Some(object),
+      Self::CseAlifeItemExplosive(object) => Some(&object.base),
+      Self::CseAlifeItemPda(object) => Some(&object.base),
+      Self::CseAlifeItemAmmo(object) => Some(&object.base),
+      Self::CseAlifeItemGrenade(object) => Some(&object.base),
+      Self::CseAlifeItemArtefact(object) => Some(&object.base),
+      Self::CseAlifeItemDetector(object) => Some(&object.base),
+      Self::CseAlifeItemHelmet(object) => Some(&object.base),
+      Self::CseAlifeItemCustomOutfit(object) => Some(&object.base),
+      Self::CseAlifeItemDocument(object) => Some(&object.base),
+      Self::CseAlifeItemWeapon(object) => Some(&object.base),
+      Self::CseAlifeItemWeaponShotgun(object) => Some(&object.base.base),
+      Self::CseAlifeItemWeaponMagazined(object) => Some(&object.base.base),
+      Self::CseAlifeItemWeaponMagazinedWGl(object) => Some(&object.base.base.base),
+      _ => None,
+    }
+  }
+
+  /// Get weapon data of weapon objects.
+  pub fn get_item_weapon(&self) -> Option<&AlifeObjectItemWeapon> {
+    match self {
+      Self::CseAlifeItemWeapon(object) => Some(object),
+      Self::CseAlifeItemWeaponShotgun(object) => Some(&object.base),
+      Self::CseAlifeItemWeaponMagazined(object) => Some(&object.base),
+      Self::CseAlifeItemWeaponMagazinedWGl(object) => Some(&object.base.base),
+      _ => None,
+    }
+  }
+
+  /// Get mutable anomaly zone data of zones able to spawn artefacts.
+  pub fn get_anomaly_zone_mut(&mut self) -> Option<&mut AlifeObjectAnomalyZone> {
+    match self {
+      Self::CseAlifeAnomalousZone(object) => Some(object),
+      Self::SeZoneAnom(object) => Some(&mut object.base),
+      Self::SeZoneVisual(object) => Some(&mut object.base),
+      _ => None,
     }
   }
 }
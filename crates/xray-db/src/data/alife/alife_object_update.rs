@@ -0,0 +1,378 @@
+use crate::data::alife::alife_object_inherited::AlifeObjectInherited;
+use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+use crate::data::alife::inherited::alife_object_item_weapon::AlifeObjectItemWeapon;
+use crate::data::alife::update::alife_object_actor_update::AlifeObjectActorUpdate;
+use crate::data::alife::update::alife_object_creature_update::AlifeObjectCreatureUpdate;
+use crate::data::alife::update::alife_object_human_stalker_update::AlifeObjectHumanStalkerUpdate;
+use crate::data::alife::update::alife_object_item_ammo_update::AlifeObjectItemAmmoUpdate;
+use crate::data::alife::update::alife_object_item_custom_outfit_update::AlifeObjectItemCustomOutfitUpdate;
+use crate::data::alife::update::alife_object_item_torch_update::AlifeObjectItemTorchUpdate;
+use crate::data::alife::update::alife_object_item_weapon_magazined_update::AlifeObjectItemWeaponMagazinedUpdate;
+use crate::data::alife::update::alife_object_item_weapon_magazined_wgl_update::AlifeObjectItemWeaponMagazinedWglUpdate;
+use crate::data::alife::update::alife_object_item_weapon_shotgun_update::AlifeObjectItemWeaponShotgunUpdate;
+use crate::data::alife::update::alife_object_item_weapon_update::AlifeObjectItemWeaponUpdate;
+use crate::data::alife::update::alife_object_monster_rat_update::AlifeObjectMonsterRatUpdate;
+use crate::data::alife::update::alife_object_monster_update::AlifeObjectMonsterUpdate;
+use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+use crate::data::alife::update::alife_object_stationary_mgun_update::AlifeObjectStationaryMgunUpdate;
+use crate::data::generic::vector_3d::Vector3d;
+use crate::data::meta::alife_class::AlifeClass;
+use crate::export::LtxImportExport;
+use byteorder::ByteOrder;
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use xray_chunk::{ChunkDataSource, ChunkReader, ChunkWriter};
+use xray_error::XRayResult;
+use xray_ltx::Ltx;
+
+/// Update packet data of ALife object.
+/// Represents UPDATE_Read/UPDATE_Write data, layout is picked based on object class.
+/// Classes without own update data are represented with `None` variant.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum AlifeObjectUpdate {
+  None,
+  Item(Box<AlifeObjectPhysicStateUpdate>),
+  ItemAmmo(Box<AlifeObjectItemAmmoUpdate>),
+  ItemCustomOutfit(Box<AlifeObjectItemCustomOutfitUpdate>),
+  ItemTorch(Box<AlifeObjectItemTorchUpdate>),
+  ItemWeapon(Box<AlifeObjectItemWeaponUpdate>),
+  ItemWeaponMagazined(Box<AlifeObjectItemWeaponMagazinedUpdate>),
+  ItemWeaponMagazinedWGl(Box<AlifeObjectItemWeaponMagazinedWglUpdate>),
+  ItemWeaponShotgun(Box<AlifeObjectItemWeaponShotgunUpdate>),
+  ObjectPhysic(Box<AlifeObjectPhysicStateUpdate>),
+  Creature(Box<AlifeObjectCreatureUpdate>),
+  Monster(Box<AlifeObjectMonsterUpdate>),
+  MonsterRat(Box<AlifeObjectMonsterRatUpdate>),
+  HumanStalker(Box<AlifeObjectHumanStalkerUpdate>),
+  Actor(Box<AlifeObjectActorUpdate>),
+  StationaryMgun(Box<AlifeObjectStationaryMgunUpdate>),
+}
+
+impl AlifeObjectUpdate {
+  /// Read update data based on serialized clsid.
+  /// Represents UPDATE_Read of each separate object in xray implementation.
+  pub fn read<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+    alife_class: &AlifeClass,
+  ) -> XRayResult<Self> {
+    Ok(match alife_class {
+      AlifeClass::CseAlifeItem
+      | AlifeClass::CseAlifeItemArtefact
+      | AlifeClass::CseAlifeItemBolt
+      | AlifeClass::CseAlifeItemDetector
+      | AlifeClass::CseAlifeItemDocument
+      | AlifeClass::CseAlifeItemExplosive
+      | AlifeClass::CseAlifeItemGrenade
+      | AlifeClass::CseAlifeItemPda => Self::Item(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeItemAmmo => Self::ItemAmmo(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeItemCustomOutfit | AlifeClass::CseAlifeItemHelmet => {
+        Self::ItemCustomOutfit(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeItemTorch => Self::ItemTorch(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeItemWeapon => Self::ItemWeapon(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeItemWeaponMagazined => {
+        Self::ItemWeaponMagazined(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeItemWeaponMagazinedWGl => {
+        Self::ItemWeaponMagazinedWGl(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeItemWeaponShotgun => {
+        Self::ItemWeaponShotgun(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeObjectPhysic => Self::ObjectPhysic(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeCreatureCrow => Self::Creature(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeFleshGroup | AlifeClass::CseAlifeMonsterBase | AlifeClass::SeMonster => {
+        Self::Monster(Box::new(reader.read_xr::<T, _>()?))
+      }
+      AlifeClass::CseAlifeRatGroup => Self::MonsterRat(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::SeStalker => Self::HumanStalker(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::SeActor => Self::Actor(Box::new(reader.read_xr::<T, _>()?)),
+      AlifeClass::CseAlifeStationaryMGun => {
+        Self::StationaryMgun(Box::new(reader.read_xr::<T, _>()?))
+      }
+      _ => Self::None,
+    })
+  }
+
+  /// Create update data of freshly spawned object, as UPDATE_Write of xray right after STATE_Read.
+  /// Values not stored in spawn state are filled with defaults of xray object constructors.
+  pub fn from_spawn(
+    alife_class: &AlifeClass,
+    inherited: &AlifeObjectInherited,
+    position: &Vector3d,
+    direction: &Vector3d,
+  ) -> Self {
+    let condition: u8 = inherited
+      .get_item()
+      .map_or(u8::MAX, |item| Self::quantize_condition(item.condition));
+
+    match alife_class {
+      AlifeClass::CseAlifeItem
+      | AlifeClass::CseAlifeItemArtefact
+      | AlifeClass::CseAlifeItemBolt
+      | AlifeClass::CseAlifeItemDetector
+      | AlifeClass::CseAlifeItemDocument
+      | AlifeClass::CseAlifeItemExplosive
+      | AlifeClass::CseAlifeItemGrenade
+      | AlifeClass::CseAlifeItemPda => Self::Item(Box::new(AlifeObjectPhysicStateUpdate::new())),
+      AlifeClass::CseAlifeItemAmmo => Self::ItemAmmo(Box::new(AlifeObjectItemAmmoUpdate {
+        base: AlifeObjectPhysicStateUpdate::new(),
+        ammo_left: match inherited {
+          AlifeObjectInherited::CseAlifeItemAmmo(ammo) => ammo.ammo_left,
+          _ => 0,
+        },
+      })),
+      AlifeClass::CseAlifeItemCustomOutfit | AlifeClass::CseAlifeItemHelmet => {
+        Self::ItemCustomOutfit(Box::new(AlifeObjectItemCustomOutfitUpdate {
+          base: AlifeObjectPhysicStateUpdate::new(),
+          condition,
+        }))
+      }
+      AlifeClass::CseAlifeItemTorch => Self::ItemTorch(Box::new(AlifeObjectItemTorchUpdate {
+        base: AlifeObjectPhysicStateUpdate::new(),
+        flags: 0,
+      })),
+      AlifeClass::CseAlifeItemWeapon => {
+        Self::ItemWeapon(Box::new(Self::weapon_from_spawn(inherited, condition)))
+      }
+      AlifeClass::CseAlifeItemWeaponMagazined => {
+        Self::ItemWeaponMagazined(Box::new(AlifeObjectItemWeaponMagazinedUpdate {
+          base: Self::weapon_from_spawn(inherited, condition),
+          current_fire_mode: 0,
+        }))
+      }
+      AlifeClass::CseAlifeItemWeaponMagazinedWGl => {
+        Self::ItemWeaponMagazinedWGl(Box::new(AlifeObjectItemWeaponMagazinedWglUpdate {
+          base: AlifeObjectItemWeaponMagazinedUpdate {
+            base: Self::weapon_from_spawn(inherited, condition),
+            current_fire_mode: 0,
+          },
+          grenade_mode: 0,
+        }))
+      }
+      AlifeClass::CseAlifeItemWeaponShotgun => {
+        Self::ItemWeaponShotgun(Box::new(AlifeObjectItemWeaponShotgunUpdate {
+          base: AlifeObjectItemWeaponMagazinedUpdate {
+            base: Self::weapon_from_spawn(inherited, condition),
+            current_fire_mode: 0,
+          },
+          ammo_ids: Vec::new(),
+        }))
+      }
+      AlifeClass::CseAlifeObjectPhysic => {
+        Self::ObjectPhysic(Box::new(AlifeObjectPhysicStateUpdate::new()))
+      }
+      AlifeClass::CseAlifeCreatureCrow => Self::Creature(Box::new(Self::creature_from_spawn(
+        inherited, position, direction,
+      ))),
+      AlifeClass::CseAlifeFleshGroup | AlifeClass::CseAlifeMonsterBase | AlifeClass::SeMonster => {
+        Self::Monster(Box::new(Self::monster_from_spawn(
+          inherited, position, direction,
+        )))
+      }
+      AlifeClass::CseAlifeRatGroup => Self::MonsterRat(Box::new(AlifeObjectMonsterRatUpdate {
+        base: Self::monster_from_spawn(inherited, position, direction),
+        state: AlifeObjectPhysicStateUpdate::new(),
+      })),
+      AlifeClass::SeStalker => Self::HumanStalker(Box::new(AlifeObjectHumanStalkerUpdate {
+        base: Self::monster_from_spawn(inherited, position, direction),
+        start_dialog: String::new(),
+      })),
+      AlifeClass::SeActor => Self::Actor(Box::new(AlifeObjectActorUpdate {
+        base: Self::creature_from_spawn(inherited, position, direction),
+        movement_state: 0,
+        acceleration_direction: 0,
+        acceleration_magnitude: 0.0,
+        velocity_direction: 0,
+        velocity_magnitude: 0.0,
+        radiation: 0.0,
+        weapon: 0,
+        num_items: 0,
+      })),
+      AlifeClass::CseAlifeStationaryMGun => {
+        Self::StationaryMgun(Box::new(AlifeObjectStationaryMgunUpdate {
+          working: 0,
+          destination_enemy_direction: Vector3d::new(0.0, 0.0, 0.0),
+        }))
+      }
+      _ => Self::None,
+    }
+  }
+
+  /// Quantize item condition into single byte, same as w_float_q8 in 0..1 range.
+  fn quantize_condition(condition: f32) -> u8 {
+    (condition.clamp(0.0, 1.0) * 255.0 + 0.5).floor() as u8
+  }
+
+  fn weapon_from_spawn(
+    inherited: &AlifeObjectInherited,
+    condition: u8,
+  ) -> AlifeObjectItemWeaponUpdate {
+    let weapon: Option<&AlifeObjectItemWeapon> = inherited.get_item_weapon();
+
+    AlifeObjectItemWeaponUpdate {
+      base: AlifeObjectPhysicStateUpdate::new(),
+      condition,
+      weapon_flags: 0,
+      ammo_elapsed: weapon.map_or(0, |weapon| weapon.ammo_elapsed),
+      addon_flags: weapon.map_or(0, |weapon| weapon.addon_flags),
+      ammo_type: weapon.map_or(0, |weapon| weapon.ammo_type),
+      weapon_state: weapon.map_or(0, |weapon| weapon.weapon_state),
+      weapon_zoom: 0,
+    }
+  }
+
+  fn creature_from_spawn(
+    inherited: &AlifeObjectInherited,
+    position: &Vector3d,
+    direction: &Vector3d,
+  ) -> AlifeObjectCreatureUpdate {
+    let creature: Option<&AlifeObjectCreature> = inherited.get_creature();
+
+    AlifeObjectCreatureUpdate {
+      health: creature.map_or(1.0, |creature| creature.health),
+      timestamp: 0,
+      flags: 0,
+      position: position.clone(),
+      model_yaw: direction.y,
+      torso_yaw: direction.y,
+      torso_pitch: 0.0,
+      torso_roll: 0.0,
+      team: creature.map_or(0, |creature| creature.team),
+      squad: creature.map_or(0, |creature| creature.squad),
+      group: creature.map_or(0, |creature| creature.group),
+    }
+  }
+
+  fn monster_from_spawn(
+    inherited: &AlifeObjectInherited,
+    position: &Vector3d,
+    direction: &Vector3d,
+  ) -> AlifeObjectMonsterUpdate {
+    let game_vertex_id: u16 = inherited
+      .get_creature()
+      .map_or(u16::MAX, |creature| creature.base.base.game_vertex_id);
+
+    AlifeObjectMonsterUpdate {
+      base: Self::creature_from_spawn(inherited, position, direction),
+      next_game_vertex_id: game_vertex_id,
+      previous_game_vertex_id: game_vertex_id,
+      distance_from_point: 0.0,
+      distance_to_point: 0.0,
+    }
+  }
+
+  pub fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    match self {
+      AlifeObjectUpdate::None => {}
+      AlifeObjectUpdate::Item(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::ItemAmmo(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::ItemCustomOutfit(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::ItemTorch(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::ItemWeapon(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::ItemWeaponMagazined(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::ItemWeaponMagazinedWGl(update) => {
+        writer.write_xr::<T, _>(update.deref())?
+      }
+      AlifeObjectUpdate::ItemWeaponShotgun(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::ObjectPhysic(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::Creature(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::Monster(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::MonsterRat(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::HumanStalker(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::Actor(update) => writer.write_xr::<T, _>(update.deref())?,
+      AlifeObjectUpdate::StationaryMgun(update) => writer.write_xr::<T, _>(update.deref())?,
+    }
+
+    Ok(())
+  }
+
+  pub fn import(section_name: &str, ltx: &Ltx, alife_class: &AlifeClass) -> XRayResult<Self> {
+    Ok(match alife_class {
+      AlifeClass::CseAlifeItem
+      | AlifeClass::CseAlifeItemArtefact
+      | AlifeClass::CseAlifeItemBolt
+      | AlifeClass::CseAlifeItemDetector
+      | AlifeClass::CseAlifeItemDocument
+      | AlifeClass::CseAlifeItemExplosive
+      | AlifeClass::CseAlifeItemGrenade
+      | AlifeClass::CseAlifeItemPda => Self::Item(Box::new(AlifeObjectPhysicStateUpdate::import(
+        section_name,
+        ltx,
+      )?)),
+      AlifeClass::CseAlifeItemAmmo => Self::ItemAmmo(Box::new(AlifeObjectItemAmmoUpdate::import(
+        section_name,
+        ltx,
+      )?)),
+      AlifeClass::CseAlifeItemCustomOutfit | AlifeClass::CseAlifeItemHelmet => {
+        Self::ItemCustomOutfit(Box::new(AlifeObjectItemCustomOutfitUpdate::import(
+          section_name,
+          ltx,
+        )?))
+      }
+      AlifeClass::CseAlifeItemTorch => Self::ItemTorch(Box::new(
+        AlifeObjectItemTorchUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeItemWeapon => Self::ItemWeapon(Box::new(
+        AlifeObjectItemWeaponUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeItemWeaponMagazined => Self::ItemWeaponMagazined(Box::new(
+        AlifeObjectItemWeaponMagazinedUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeItemWeaponMagazinedWGl => Self::ItemWeaponMagazinedWGl(Box::new(
+        AlifeObjectItemWeaponMagazinedWglUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeItemWeaponShotgun => Self::ItemWeaponShotgun(Box::new(
+        AlifeObjectItemWeaponShotgunUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeObjectPhysic => Self::ObjectPhysic(Box::new(
+        AlifeObjectPhysicStateUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeCreatureCrow => Self::Creature(Box::new(
+        AlifeObjectCreatureUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::CseAlifeFleshGroup | AlifeClass::CseAlifeMonsterBase | AlifeClass::SeMonster => {
+        Self::Monster(Box::new(AlifeObjectMonsterUpdate::import(
+          section_name,
+          ltx,
+        )?))
+      }
+      AlifeClass::CseAlifeRatGroup => Self::MonsterRat(Box::new(
+        AlifeObjectMonsterRatUpdate::import(section_name, ltx)?,
+      )),
+      AlifeClass::SeStalker => Self::HumanStalker(Box::new(AlifeObjectHumanStalkerUpdate::import(
+        section_name,
+        ltx,
+      )?)),
+      AlifeClass::SeActor => {
+        Self::Actor(Box::new(AlifeObjectActorUpdate::import(section_name, ltx)?))
+      }
+      AlifeClass::CseAlifeStationaryMGun => Self::StationaryMgun(Box::new(
+        AlifeObjectStationaryMgunUpdate::import(section_name, ltx)?,
+      )),
+      _ => Self::None,
+    })
+  }
+
+  pub fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    match self {
+      AlifeObjectUpdate::None => Ok(()),
+      AlifeObjectUpdate::Item(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ItemAmmo(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ItemCustomOutfit(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ItemTorch(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ItemWeapon(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ItemWeaponMagazined(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ItemWeaponMagazinedWGl(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ItemWeaponShotgun(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::ObjectPhysic(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::Creature(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::Monster(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::MonsterRat(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::HumanStalker(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::Actor(update) => update.export(section_name, ltx),
+      AlifeObjectUpdate::StationaryMgun(update) => update.export(section_name, ltx),
+    }
+  }
+}
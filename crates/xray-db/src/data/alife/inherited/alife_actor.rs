@@ -4,7 +4,7 @@ use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use typetag::serde;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -19,7 +19,7 @@ pub struct AlifeActor {
 
 impl ChunkReadWrite for AlifeActor {
   /// Read actor data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let object: Self = Self {
       base: reader.read_xr::<T, _>()?,
       start_position_filled: reader.read_u8()?,
@@ -149,6 +149,7 @@ mod tests {
           name: String::from("skeleton-name-0"),
           flags: 98,
           source_id: 12,
+          bones: None,
         },
         holder_id: 0,
       },
@@ -171,9 +172,10 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 196 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
-    assert_eq!(AlifeActor::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(AlifeActor::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -225,6 +227,7 @@ mod tests {
           name: String::from("skeleton-name-0"),
           flags: 25,
           source_id: 12,
+          bones: None,
         },
         holder_id: 0,
       },
@@ -289,6 +292,7 @@ mod tests {
           name: String::from("skeleton-name-0"),
           flags: 27,
           source_id: 12,
+          bones: None,
         },
         holder_id: 0,
       },
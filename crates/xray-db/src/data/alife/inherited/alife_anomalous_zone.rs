@@ -4,7 +4,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -17,7 +17,7 @@ pub struct AlifeAnomalousZone {
 
 impl ChunkReadWrite for AlifeAnomalousZone {
   /// Read anomalous zone object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       last_spawn_time: reader.read_xr_optional::<T, Time>()?,
@@ -156,10 +156,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 145 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeAnomalousZone::read::<XRayByteOrder>(&mut reader)?,
+      AlifeAnomalousZone::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
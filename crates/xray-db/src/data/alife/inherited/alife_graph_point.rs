@@ -2,7 +2,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -22,7 +22,7 @@ pub struct AlifeGraphPoint {
 
 impl ChunkReadWrite for AlifeGraphPoint {
   /// Read graph point data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       connection_point_name: reader.read_w1251_string()?,
       connection_level_name: reader.read_w1251_string()?,
@@ -134,10 +134,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 26 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeGraphPoint::read::<XRayByteOrder>(&mut reader)?,
+      AlifeGraphPoint::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -4,7 +4,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -28,7 +28,7 @@ pub struct AlifeLevelChanger {
 
 impl ChunkReadWrite for AlifeLevelChanger {
   /// Read ALife level changer object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let object: Self = Self {
       base: reader.read_xr::<T, _>()?,
       dest_game_vertex_id: reader.read_u16::<T>()?,
@@ -220,8 +220,9 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 177 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
-    let read_object: AlifeLevelChanger = AlifeLevelChanger::read::<XRayByteOrder>(&mut reader)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+    let read_object: AlifeLevelChanger = AlifeLevelChanger::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read_object, original);
 
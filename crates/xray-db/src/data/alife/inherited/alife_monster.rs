@@ -0,0 +1,396 @@
+use crate::data::alife::inherited::alife_object_monster_base::AlifeObjectMonsterBase;
+use crate::export::LtxImportExport;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::assert_equal;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeMonster {
+  pub base: AlifeObjectMonsterBase,
+  pub old_level_vertex_id: String,
+  pub active_section: String,
+  pub save_marker: u16,
+}
+
+impl ChunkReadWrite for AlifeMonster {
+  /// Read monster data from the chunk.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let base: AlifeObjectMonsterBase = reader.read_xr::<T, _>()?;
+    let script_data_start: u64 = reader.read_bytes_len();
+
+    let old_level_vertex_id: String = reader.read_w1251_string()?;
+    let active_section: String = reader.read_w1251_string()?;
+
+    let script_data_size: u64 = reader.read_bytes_len() - script_data_start;
+    let save_marker: u16 = reader.read_u16::<T>()?;
+
+    assert_equal(
+      save_marker as u64,
+      script_data_size,
+      "Unexpected script data provided for monster",
+    )?;
+
+    Ok(Self {
+      base,
+      old_level_vertex_id,
+      active_section,
+      save_marker,
+    })
+  }
+
+  /// Write monster data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_xr::<T, _>(&self.base)?;
+    writer.write_w1251_string(&self.old_level_vertex_id)?;
+    writer.write_w1251_string(&self.active_section)?;
+    writer.write_u16::<T>(self.save_marker)?;
+
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeMonster {
+  /// Import monster data from ltx config section.
+  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
+    let section: &Section = ltx.section(section_name).ok_or_else(|| {
+      XRayError::new_parsing_error(format!(
+        "ALife object '{}' should be defined in ltx file ({})",
+        section_name,
+        file!()
+      ))
+    })?;
+
+    Ok(Self {
+      base: AlifeObjectMonsterBase::import(section_name, ltx)?,
+      old_level_vertex_id: read_ltx_field("monster.old_level_vertex_id", section)?,
+      active_section: read_ltx_field("monster.active_section", section)?,
+      save_marker: read_ltx_field("monster.save_marker", section)?,
+    })
+  }
+
+  /// Export object data into ltx file.
+  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    self.base.export(section_name, ltx)?;
+
+    ltx
+      .with_section(section_name)
+      .set("monster.old_level_vertex_id", &self.old_level_vertex_id)
+      .set("monster.active_section", &self.active_section)
+      .set("monster.save_marker", self.save_marker.to_string());
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_monster::AlifeMonster;
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+  use crate::data::alife::inherited::alife_object_monster_base::AlifeObjectMonsterBase;
+  use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+  use crate::export::LtxImportExport;
+  use byteorder::WriteBytesExt;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeMonster = AlifeMonster {
+      base: AlifeObjectMonsterBase {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 4321,
+                distance: 4.5,
+                direct_control: 1,
+                level_vertex_id: 3245,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 4322,
+                spawn_story_id: 4323,
+              },
+              visual_name: String::from("monsters\\snork\\snork"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 0,
+            group: 0,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        skeleton: AlifeObjectSkeleton {
+          name: String::from("$editor"),
+          flags: 0,
+          source_id: 65535,
+          bones: None,
+        },
+        spec_object_id: 65535,
+      },
+      old_level_vertex_id: String::from("3245"),
+      active_section: String::from("mob_home@base"),
+      save_marker: 19,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 151);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 151);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 151 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeMonster::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  /// Monster spawn packet data written field by field in order of engine `STATE_Write`:
+  /// object, visual, creature, monster abstract, monster base, skeleton and se_monster script data.
+  fn get_packet() -> XRayResult<Vec<u8>> {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    // CSE_ALifeObject:
+    writer.write_u16::<XRayByteOrder>(2211)?;
+    writer.write_f32::<XRayByteOrder>(3.5)?;
+    writer.write_u32::<XRayByteOrder>(1)?;
+    writer.write_u32::<XRayByteOrder>(84211)?;
+    writer.write_u32::<XRayByteOrder>(7)?;
+    writer.write_w1251_string("")?;
+    writer.write_u32::<XRayByteOrder>(u32::MAX)?;
+    writer.write_u32::<XRayByteOrder>(u32::MAX)?;
+    // CSE_Visual:
+    writer.write_w1251_string("monsters\\bloodsucker\\bloodsucker_strong")?;
+    writer.write_u8(0)?;
+    // CSE_ALifeCreatureAbstract:
+    writer.write_u8(0)?;
+    writer.write_u8(0)?;
+    writer.write_u8(0)?;
+    writer.write_f32::<XRayByteOrder>(1.0)?;
+    writer.write_u32::<XRayByteOrder>(0)?;
+    writer.write_u32::<XRayByteOrder>(2)?;
+    writer.write_u16::<XRayByteOrder>(304)?;
+    writer.write_u16::<XRayByteOrder>(305)?;
+    writer.write_u16::<XRayByteOrder>(u16::MAX)?;
+    writer.write_u64::<XRayByteOrder>(0)?;
+    // CSE_ALifeMonsterAbstract:
+    writer.write_w1251_string("")?;
+    writer.write_w1251_string("jup_b211_lair")?;
+    writer.write_u16::<XRayByteOrder>(640)?;
+    writer.write_u8(1)?;
+    // CSE_ALifeMonsterBase:
+    writer.write_u16::<XRayByteOrder>(u16::MAX)?;
+    // CSE_PHSkeleton:
+    writer.write_w1251_string("$editor")?;
+    writer.write_u8(0)?;
+    writer.write_u16::<XRayByteOrder>(u16::MAX)?;
+    // se_monster:
+    writer.write_w1251_string("84211")?;
+    writer.write_w1251_string("mob_home@lair")?;
+    writer.write_u16::<XRayByteOrder>(20)?;
+
+    writer.flush_raw_into_buffer()
+  }
+
+  #[test]
+  fn test_read_packet() -> XRayResult {
+    let data: Vec<u8> = get_packet()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    let monster: AlifeMonster = AlifeMonster::read::<XRayByteOrder, _>(&mut reader)?;
+
+    assert!(reader.is_ended());
+
+    let base: &AlifeObjectMonsterBase = &monster.base;
+
+    assert_eq!(base.base.base.base.base.game_vertex_id, 2211);
+    assert_eq!(base.base.base.base.base.level_vertex_id, 84211);
+    assert_eq!(
+      base.base.base.base.visual_name,
+      "monsters\\bloodsucker\\bloodsucker_strong"
+    );
+    assert_eq!(base.base.base.dynamic_in_restrictions, vec![304, 305]);
+    assert_eq!(base.base.in_space_restrictors, "jup_b211_lair");
+    assert_eq!(base.base.smart_terrain_id, 640);
+    assert_eq!(base.base.smart_terrain_task_active, 1);
+    assert_eq!(base.spec_object_id, u16::MAX);
+    assert_eq!(base.skeleton.name, "$editor");
+    assert_eq!(monster.old_level_vertex_id, "84211");
+    assert_eq!(monster.active_section, "mob_home@lair");
+    assert_eq!(monster.save_marker, 20);
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    monster.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.flush_raw_into_buffer()?, data);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeMonster = AlifeMonster {
+      base: AlifeObjectMonsterBase {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 25,
+                distance: 4.5,
+                direct_control: 1,
+                level_vertex_id: 3245,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 26,
+                spawn_story_id: 27,
+              },
+              visual_name: String::from("monsters\\snork\\snork"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 0,
+            group: 0,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        skeleton: AlifeObjectSkeleton {
+          name: String::from("$editor"),
+          flags: 0,
+          source_id: 65535,
+          bones: None,
+        },
+        spec_object_id: 65535,
+      },
+      old_level_vertex_id: String::from("3245"),
+      active_section: String::from("mob_home@base"),
+      save_marker: 19,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeMonster::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeMonster = AlifeMonster {
+      base: AlifeObjectMonsterBase {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 36,
+                distance: 4.5,
+                direct_control: 1,
+                level_vertex_id: 3245,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 37,
+                spawn_story_id: 38,
+              },
+              visual_name: String::from("monsters\\snork\\snork"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 0,
+            group: 0,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        skeleton: AlifeObjectSkeleton {
+          name: String::from("$editor"),
+          flags: 0,
+          source_id: 65535,
+          bones: None,
+        },
+        spec_object_id: 65535,
+      },
+      old_level_vertex_id: String::from("3245"),
+      active_section: String::from("mob_home@base"),
+      save_marker: 19,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(serde_json::from_str::<AlifeMonster>(&serialized)?, original);
+
+    Ok(())
+  }
+}
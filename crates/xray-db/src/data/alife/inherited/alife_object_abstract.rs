@@ -2,7 +2,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::{decode_string_from_base64, encode_string_to_base64};
@@ -23,7 +23,7 @@ pub struct AlifeObjectAbstract {
 
 impl ChunkReadWrite for AlifeObjectAbstract {
   /// Read generic ALife object base data from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       game_vertex_id: reader.read_u16::<T>()?,
       distance: reader.read_f32::<T>()?,
@@ -145,10 +145,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 38 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectAbstract::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectAbstract::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
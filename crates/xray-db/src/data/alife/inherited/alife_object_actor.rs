@@ -5,7 +5,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -20,7 +20,7 @@ pub struct AlifeObjectActor {
 
 impl ChunkReadWrite for AlifeObjectActor {
   /// Read actor data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       trader: reader.read_xr::<T, _>()?,
@@ -142,6 +142,7 @@ mod tests {
         name: String::from("skeleton-name"),
         flags: 98,
         source_id: 12,
+        bones: None,
       },
       holder_id: 0,
     };
@@ -161,10 +162,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 185 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectActor::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectActor::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -216,8 +218,9 @@ mod tests {
       },
       skeleton: AlifeObjectSkeleton {
         name: String::from("skeleton-name"),
-        flags: 45,
+        flags: 41,
         source_id: 10,
+        bones: None,
       },
       holder_id: 4,
     };
@@ -276,6 +279,7 @@ mod tests {
         name: String::from("skeleton-name"),
         flags: 35,
         source_id: 67,
+        bones: None,
       },
       holder_id: 0,
     };
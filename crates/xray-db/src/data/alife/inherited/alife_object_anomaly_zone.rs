@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -18,7 +18,7 @@ pub struct AlifeObjectAnomalyZone {
 
 impl ChunkReadWrite for AlifeObjectAnomalyZone {
   /// Read anomaly zone object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       offline_interactive_radius: reader.read_f32::<T>()?,
@@ -160,10 +160,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 125 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectAnomalyZone::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectAnomalyZone::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -16,7 +16,7 @@ pub struct AlifeObjectBreakable {
 
 impl ChunkReadWrite for AlifeObjectBreakable {
   /// Read ALife breakable object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       health: reader.read_f32::<T>()?,
@@ -118,10 +118,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 55 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectBreakable::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectBreakable::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
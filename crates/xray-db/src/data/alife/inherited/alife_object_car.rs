@@ -0,0 +1,176 @@
+use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "car.")]
+pub struct AlifeObjectCar {
+  #[ltx(inherit)]
+  pub base: AlifeObjectDynamicVisual,
+  #[ltx(inherit)]
+  pub skeleton: AlifeObjectSkeleton,
+  pub health: f32,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_car::AlifeObjectCar;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectCar = AlifeObjectCar {
+      base: AlifeObjectDynamicVisual {
+        base: AlifeObjectAbstract {
+          game_vertex_id: 37,
+          distance: 2.5,
+          direct_control: 1,
+          level_vertex_id: 4562,
+          flags: 63,
+          custom_data: String::from("custom-data"),
+          story_id: 38,
+          spawn_story_id: 39,
+        },
+        visual_name: String::from("physics\\vehicles\\niva\\veh_niva_u_01"),
+        visual_flags: 0,
+      },
+      skeleton: AlifeObjectSkeleton {
+        name: String::from("$editor"),
+        flags: 0,
+        source_id: 65535,
+        bones: None,
+      },
+      health: 0.75,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 90);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 90);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 90 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectCar::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectCar = AlifeObjectCar {
+      base: AlifeObjectDynamicVisual {
+        base: AlifeObjectAbstract {
+          game_vertex_id: 25,
+          distance: 2.5,
+          direct_control: 1,
+          level_vertex_id: 4562,
+          flags: 63,
+          custom_data: String::from("custom-data"),
+          story_id: 26,
+          spawn_story_id: 27,
+        },
+        visual_name: String::from("physics\\vehicles\\niva\\veh_niva_u_01"),
+        visual_flags: 0,
+      },
+      skeleton: AlifeObjectSkeleton {
+        name: String::from("$editor"),
+        flags: 0,
+        source_id: 65535,
+        bones: None,
+      },
+      health: 0.75,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectCar::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectCar = AlifeObjectCar {
+      base: AlifeObjectDynamicVisual {
+        base: AlifeObjectAbstract {
+          game_vertex_id: 36,
+          distance: 2.5,
+          direct_control: 1,
+          level_vertex_id: 4562,
+          flags: 63,
+          custom_data: String::from("custom-data"),
+          story_id: 37,
+          spawn_story_id: 38,
+        },
+        visual_name: String::from("physics\\vehicles\\niva\\veh_niva_u_01"),
+        visual_flags: 0,
+      },
+      skeleton: AlifeObjectSkeleton {
+        name: String::from("$editor"),
+        flags: 0,
+        source_id: 65535,
+        bones: None,
+      },
+      health: 0.75,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectCar>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -16,17 +16,25 @@ pub struct AlifeObjectClimable {
 
 impl ChunkReadWrite for AlifeObjectClimable {
   /// Read climable object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  /// Game material is stored since CoP spawn format (128).
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
-      game_material: reader.read_w1251_string()?,
+      game_material: if reader.is_version_at_least(128) {
+        reader.read_w1251_string()?
+      } else {
+        String::new()
+      },
     })
   }
 
   /// Write climable object data into the chunk.
   fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
     writer.write_xr::<T, _>(&self.base)?;
-    writer.write_w1251_string(&self.game_material)?;
+
+    if writer.is_version_at_least(128) {
+      writer.write_w1251_string(&self.game_material)?;
+    }
 
     Ok(())
   }
@@ -127,10 +135,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 119 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectClimable::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectClimable::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
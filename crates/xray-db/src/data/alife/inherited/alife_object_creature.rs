@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::{vector_from_string, vector_to_string};
@@ -24,7 +24,7 @@ pub struct AlifeObjectCreature {
 
 impl ChunkReadWrite for AlifeObjectCreature {
   /// Read ALife creature object data from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       team: reader.read_u8()?,
@@ -176,8 +176,10 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 87 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
-    let read_object: AlifeObjectCreature = AlifeObjectCreature::read::<XRayByteOrder>(&mut reader)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+    let read_object: AlifeObjectCreature =
+      AlifeObjectCreature::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read_object, original);
 
@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -17,7 +17,7 @@ pub struct AlifeObjectDynamicVisual {
 
 impl ChunkReadWrite for AlifeObjectDynamicVisual {
   /// Read visual object data from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       visual_name: reader.read_w1251_string()?,
@@ -119,9 +119,10 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 51 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
     let read_object: AlifeObjectDynamicVisual =
-      AlifeObjectDynamicVisual::read::<XRayByteOrder>(&mut reader)?;
+      AlifeObjectDynamicVisual::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read_object, original);
 
@@ -0,0 +1,178 @@
+use crate::export::LtxImportExport;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::{vector_from_string, vector_to_string};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectGroupAbstract {
+  pub create_spawn_positions: u32,
+  pub count: u16,
+  pub members: Vec<u16>,
+}
+
+impl ChunkReadWrite for AlifeObjectGroupAbstract {
+  /// Read group data from the chunk.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    Ok(Self {
+      create_spawn_positions: reader.read_u32::<T>()?,
+      count: reader.read_u16::<T>()?,
+      members: reader.read_u16_vector::<T>()?,
+    })
+  }
+
+  /// Write group data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u32::<T>(self.create_spawn_positions)?;
+    writer.write_u16::<T>(self.count)?;
+    writer.write_u16_vector::<T>(&self.members)?;
+
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeObjectGroupAbstract {
+  /// Import group data from ltx config section.
+  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
+    let section: &Section = ltx.section(section_name).ok_or_else(|| {
+      XRayError::new_parsing_error(format!(
+        "ALife object '{}' should be defined in ltx file ({})",
+        section_name,
+        file!()
+      ))
+    })?;
+
+    Ok(Self {
+      create_spawn_positions: read_ltx_field("group_abstract.create_spawn_positions", section)?,
+      count: read_ltx_field("group_abstract.count", section)?,
+      members: vector_from_string(&read_ltx_field::<String>(
+        "group_abstract.members",
+        section,
+      )?)?,
+    })
+  }
+
+  /// Export group data into ltx file.
+  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    ltx
+      .with_section(section_name)
+      .set(
+        "group_abstract.create_spawn_positions",
+        self.create_spawn_positions.to_string(),
+      )
+      .set("group_abstract.count", self.count.to_string())
+      .set("group_abstract.members", vector_to_string(&self.members));
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_group_abstract::AlifeObjectGroupAbstract;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectGroupAbstract = AlifeObjectGroupAbstract {
+      create_spawn_positions: 1,
+      count: 3,
+      members: vec![25, 26, 27],
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 16);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 16);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 16 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectGroupAbstract::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectGroupAbstract = AlifeObjectGroupAbstract {
+      create_spawn_positions: 0,
+      count: 2,
+      members: vec![1000, 1001],
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectGroupAbstract::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectGroupAbstract = AlifeObjectGroupAbstract {
+      create_spawn_positions: 1,
+      count: 0,
+      members: vec![],
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(serialized.to_string(), serialized);
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectGroupAbstract>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
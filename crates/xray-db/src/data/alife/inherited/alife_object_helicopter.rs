@@ -5,7 +5,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -21,7 +21,7 @@ pub struct AlifeObjectHelicopter {
 
 impl ChunkReadWrite for AlifeObjectHelicopter {
   /// Read helicopter data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       motion: reader.read_xr::<T, _>()?,
@@ -124,6 +124,7 @@ mod tests {
         name: String::from("skeleton-name"),
         flags: 0,
         source_id: 235,
+        bones: None,
       },
       motion: AlifeObjectMotion {
         motion_name: String::from("motion-name"),
@@ -147,10 +148,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 111 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectHelicopter::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectHelicopter::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -181,6 +183,7 @@ mod tests {
         name: String::from("skeleton-name"),
         flags: 32,
         source_id: 235,
+        bones: None,
       },
       motion: AlifeObjectMotion {
         motion_name: String::from("motion-name"),
@@ -223,6 +226,7 @@ mod tests {
         name: String::from("skeleton-name"),
         flags: 14,
         source_id: 253,
+        bones: None,
       },
       motion: AlifeObjectMotion {
         motion_name: String::from("motion-name"),
@@ -0,0 +1,346 @@
+use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+use crate::data::alife::inherited::alife_object_trader_abstract::AlifeObjectTraderAbstract;
+use crate::export::LtxImportExport;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::{vector_from_string, vector_to_string};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectHumanAbstract {
+  pub base: AlifeObjectMonsterAbstract,
+  pub trader: AlifeObjectTraderAbstract,
+  pub equipment_preferences: Vec<u8>,
+  pub main_weapon_preferences: Vec<u8>,
+}
+
+impl AlifeObjectHumanAbstract {
+  /// Read serialized preferences, where u32 count N is followed by N u8 entries.
+  fn read_preferences<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<u8>> {
+    let count: u32 = reader.read_u32::<T>()?;
+
+    reader.read_bytes(count as usize)
+  }
+
+  /// Write serialized preferences, where u32 count N is followed by N u8 entries.
+  fn write_preferences<T: ByteOrder>(writer: &mut ChunkWriter, preferences: &[u8]) -> XRayResult {
+    writer.write_u32::<T>(preferences.len() as u32)?;
+
+    for preference in preferences {
+      writer.write_u8(*preference)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl ChunkReadWrite for AlifeObjectHumanAbstract {
+  /// Read human data from the chunk.
+  /// Trader data goes first, as human abstract inherits trader abstract before monster abstract.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let trader: AlifeObjectTraderAbstract = reader.read_xr::<T, _>()?;
+
+    Ok(Self {
+      base: reader.read_xr::<T, _>()?,
+      trader,
+      equipment_preferences: Self::read_preferences::<T, D>(reader)?,
+      main_weapon_preferences: Self::read_preferences::<T, D>(reader)?,
+    })
+  }
+
+  /// Write human data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_xr::<T, _>(&self.trader)?;
+    writer.write_xr::<T, _>(&self.base)?;
+
+    Self::write_preferences::<T>(writer, &self.equipment_preferences)?;
+    Self::write_preferences::<T>(writer, &self.main_weapon_preferences)?;
+
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeObjectHumanAbstract {
+  /// Import human data from ltx config section.
+  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
+    let section: &Section = ltx.section(section_name).ok_or_else(|| {
+      XRayError::new_parsing_error(format!(
+        "ALife object '{}' should be defined in ltx file ({})",
+        section_name,
+        file!()
+      ))
+    })?;
+
+    let trader: AlifeObjectTraderAbstract = AlifeObjectTraderAbstract::import(section_name, ltx)?;
+
+    Ok(Self {
+      base: AlifeObjectMonsterAbstract::import(section_name, ltx)?,
+      trader,
+      equipment_preferences: vector_from_string(&read_ltx_field::<String>(
+        "human_abstract.equipment_preferences",
+        section,
+      )?)?,
+      main_weapon_preferences: vector_from_string(&read_ltx_field::<String>(
+        "human_abstract.main_weapon_preferences",
+        section,
+      )?)?,
+    })
+  }
+
+  /// Export object data into ltx file.
+  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    self.trader.export(section_name, ltx)?;
+    self.base.export(section_name, ltx)?;
+
+    ltx
+      .with_section(section_name)
+      .set(
+        "human_abstract.equipment_preferences",
+        vector_to_string(&self.equipment_preferences),
+      )
+      .set(
+        "human_abstract.main_weapon_preferences",
+        vector_to_string(&self.main_weapon_preferences),
+      );
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_human_abstract::AlifeObjectHumanAbstract;
+  use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+  use crate::data::alife::inherited::alife_object_trader_abstract::AlifeObjectTraderAbstract;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectHumanAbstract = AlifeObjectHumanAbstract {
+      base: AlifeObjectMonsterAbstract {
+        base: AlifeObjectCreature {
+          base: AlifeObjectDynamicVisual {
+            base: AlifeObjectAbstract {
+              game_vertex_id: 620,
+              distance: 42.5,
+              direct_control: 14,
+              level_vertex_id: 52234,
+              flags: 71,
+              custom_data: String::from("custom-data"),
+              story_id: 621,
+              spawn_story_id: 622,
+            },
+            visual_name: String::from("visual-name"),
+            visual_flags: 12,
+          },
+          team: 1,
+          squad: 2,
+          group: 3,
+          health: 1.0,
+          dynamic_out_restrictions: vec![1, 2],
+          dynamic_in_restrictions: vec![3, 4],
+          killer_id: 65535,
+          game_death_time: 0,
+        },
+        out_space_restrictors: String::from("out-restrictor"),
+        in_space_restrictors: String::from("in-restrictor"),
+        smart_terrain_id: 65535,
+        smart_terrain_task_active: 0,
+      },
+      trader: AlifeObjectTraderAbstract {
+        money: 2500,
+        specific_character: String::from("specific-character"),
+        trader_flags: 1,
+        character_profile: String::from("character-profile"),
+        community_index: 4,
+        rank: 250,
+        reputation: 10,
+        character_name: String::from("character-name"),
+        dead_body_can_take: 1,
+        dead_body_closed: 0,
+      },
+      equipment_preferences: vec![0, 1, 2, 1, 0],
+      main_weapon_preferences: vec![3, 2, 1, 0],
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 207);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 207);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 207 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectHumanAbstract::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectHumanAbstract = AlifeObjectHumanAbstract {
+      base: AlifeObjectMonsterAbstract {
+        base: AlifeObjectCreature {
+          base: AlifeObjectDynamicVisual {
+            base: AlifeObjectAbstract {
+              game_vertex_id: 25,
+              distance: 42.5,
+              direct_control: 14,
+              level_vertex_id: 52234,
+              flags: 71,
+              custom_data: String::from("custom-data"),
+              story_id: 26,
+              spawn_story_id: 27,
+            },
+            visual_name: String::from("visual-name"),
+            visual_flags: 12,
+          },
+          team: 1,
+          squad: 2,
+          group: 3,
+          health: 1.0,
+          dynamic_out_restrictions: vec![1, 2],
+          dynamic_in_restrictions: vec![3, 4],
+          killer_id: 65535,
+          game_death_time: 0,
+        },
+        out_space_restrictors: String::from("out-restrictor"),
+        in_space_restrictors: String::from("in-restrictor"),
+        smart_terrain_id: 65535,
+        smart_terrain_task_active: 0,
+      },
+      trader: AlifeObjectTraderAbstract {
+        money: 2500,
+        specific_character: String::from("specific-character"),
+        trader_flags: 1,
+        character_profile: String::from("character-profile"),
+        community_index: 4,
+        rank: 250,
+        reputation: 10,
+        character_name: String::from("character-name"),
+        dead_body_can_take: 1,
+        dead_body_closed: 0,
+      },
+      equipment_preferences: vec![0, 1, 2, 1, 0],
+      main_weapon_preferences: vec![3, 2, 1, 0],
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectHumanAbstract::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectHumanAbstract = AlifeObjectHumanAbstract {
+      base: AlifeObjectMonsterAbstract {
+        base: AlifeObjectCreature {
+          base: AlifeObjectDynamicVisual {
+            base: AlifeObjectAbstract {
+              game_vertex_id: 36,
+              distance: 42.5,
+              direct_control: 14,
+              level_vertex_id: 52234,
+              flags: 71,
+              custom_data: String::from("custom-data"),
+              story_id: 37,
+              spawn_story_id: 38,
+            },
+            visual_name: String::from("visual-name"),
+            visual_flags: 12,
+          },
+          team: 1,
+          squad: 2,
+          group: 3,
+          health: 1.0,
+          dynamic_out_restrictions: vec![1, 2],
+          dynamic_in_restrictions: vec![3, 4],
+          killer_id: 65535,
+          game_death_time: 0,
+        },
+        out_space_restrictors: String::from("out-restrictor"),
+        in_space_restrictors: String::from("in-restrictor"),
+        smart_terrain_id: 65535,
+        smart_terrain_task_active: 0,
+      },
+      trader: AlifeObjectTraderAbstract {
+        money: 2500,
+        specific_character: String::from("specific-character"),
+        trader_flags: 1,
+        character_profile: String::from("character-profile"),
+        community_index: 4,
+        rank: 250,
+        reputation: 10,
+        character_name: String::from("character-name"),
+        dead_body_can_take: 1,
+        dead_body_closed: 0,
+      },
+      equipment_preferences: vec![0, 1, 2, 1, 0],
+      main_weapon_preferences: vec![3, 2, 1, 0],
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectHumanAbstract>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,378 @@
+use crate::data::alife::inherited::alife_object_human_abstract::AlifeObjectHumanAbstract;
+use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "human_stalker.")]
+pub struct AlifeObjectHumanStalker {
+  #[ltx(inherit)]
+  pub base: AlifeObjectHumanAbstract,
+  #[ltx(inherit)]
+  pub skeleton: AlifeObjectSkeleton,
+  pub start_dialog: String,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_human_abstract::AlifeObjectHumanAbstract;
+  use crate::data::alife::inherited::alife_object_human_stalker::AlifeObjectHumanStalker;
+  use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+  use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+  use crate::data::alife::inherited::alife_object_trader_abstract::AlifeObjectTraderAbstract;
+  use crate::export::LtxImportExport;
+  use byteorder::WriteBytesExt;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectHumanStalker = AlifeObjectHumanStalker {
+      base: AlifeObjectHumanAbstract {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 54,
+                distance: 12.5,
+                direct_control: 1,
+                level_vertex_id: 2436,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 55,
+                spawn_story_id: 56,
+              },
+              visual_name: String::from("actors\\stalker_neutral\\stalker_neutral_1"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 1,
+            group: 2,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![16],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        trader: AlifeObjectTraderAbstract {
+          money: 750,
+          specific_character: String::from("sim_default_stalker_1"),
+          trader_flags: 0,
+          character_profile: String::from("sim_default_stalker"),
+          community_index: 1,
+          rank: 300,
+          reputation: 0,
+          character_name: String::from("character-name"),
+          dead_body_can_take: 1,
+          dead_body_closed: 0,
+        },
+        equipment_preferences: vec![1, 1, 1, 1, 1],
+        main_weapon_preferences: vec![2, 2, 2, 2],
+      },
+      skeleton: AlifeObjectSkeleton {
+        name: String::from("$editor"),
+        flags: 0,
+        source_id: 65535,
+        bones: None,
+      },
+      start_dialog: String::from("start-dialog"),
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 259);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 259);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 259 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectHumanStalker::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_write_trader_first() -> XRayResult {
+    let original: AlifeObjectHumanStalker = AlifeObjectHumanStalker {
+      base: AlifeObjectHumanAbstract {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 1202,
+                distance: 12.5,
+                direct_control: 1,
+                level_vertex_id: 2436,
+                flags: 63,
+                custom_data: String::new(),
+                story_id: 4294967295,
+                spawn_story_id: 4294967295,
+              },
+              visual_name: String::from("actors\\stalker_neutral\\stalker_neutral_1"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 1,
+            group: 2,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![16],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        trader: AlifeObjectTraderAbstract {
+          money: 750,
+          specific_character: String::from("sim_default_stalker_1"),
+          trader_flags: 0,
+          character_profile: String::from("sim_default_stalker"),
+          community_index: 1,
+          rank: 300,
+          reputation: 0,
+          character_name: String::from("character-name"),
+          dead_body_can_take: 1,
+          dead_body_closed: 0,
+        },
+        equipment_preferences: vec![1, 1, 1, 1, 1],
+        main_weapon_preferences: vec![2, 2, 2, 2],
+      },
+      skeleton: AlifeObjectSkeleton {
+        name: String::from("$editor"),
+        flags: 0,
+        source_id: 65535,
+        bones: None,
+      },
+      start_dialog: String::from("start-dialog"),
+    };
+
+    // Human stalker STATE_Write order: trader abstract, monster abstract, human preferences,
+    // skeleton and start dialog.
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    writer.write_u32::<XRayByteOrder>(750)?;
+    writer.write_w1251_string("sim_default_stalker_1")?;
+    writer.write_u32::<XRayByteOrder>(0)?;
+    writer.write_w1251_string("sim_default_stalker")?;
+    writer.write_u32::<XRayByteOrder>(1)?;
+    writer.write_u32::<XRayByteOrder>(300)?;
+    writer.write_u32::<XRayByteOrder>(0)?;
+    writer.write_w1251_string("character-name")?;
+    writer.write_u8(1)?;
+    writer.write_u8(0)?;
+    writer.write_xr::<XRayByteOrder, _>(&original.base.base)?;
+    writer.write_u32::<XRayByteOrder>(5)?;
+    writer.write_all(&[1, 1, 1, 1, 1])?;
+    writer.write_u32::<XRayByteOrder>(4)?;
+    writer.write_all(&[2, 2, 2, 2])?;
+    writer.write_w1251_string("$editor")?;
+    writer.write_u8(0)?;
+    writer.write_u16::<XRayByteOrder>(65535)?;
+    writer.write_w1251_string("start-dialog")?;
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(&data[0..4], &750u32.to_le_bytes());
+    assert_eq!(
+      AlifeObjectHumanStalker::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+    assert!(reader.is_ended());
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.flush_raw_into_buffer()?, data);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectHumanStalker = AlifeObjectHumanStalker {
+      base: AlifeObjectHumanAbstract {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 25,
+                distance: 12.5,
+                direct_control: 1,
+                level_vertex_id: 2436,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 26,
+                spawn_story_id: 27,
+              },
+              visual_name: String::from("actors\\stalker_neutral\\stalker_neutral_1"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 1,
+            group: 2,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![16],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        trader: AlifeObjectTraderAbstract {
+          money: 750,
+          specific_character: String::from("sim_default_stalker_1"),
+          trader_flags: 0,
+          character_profile: String::from("sim_default_stalker"),
+          community_index: 1,
+          rank: 300,
+          reputation: 0,
+          character_name: String::from("character-name"),
+          dead_body_can_take: 1,
+          dead_body_closed: 0,
+        },
+        equipment_preferences: vec![1, 1, 1, 1, 1],
+        main_weapon_preferences: vec![2, 2, 2, 2],
+      },
+      skeleton: AlifeObjectSkeleton {
+        name: String::from("$editor"),
+        flags: 0,
+        source_id: 65535,
+        bones: None,
+      },
+      start_dialog: String::from("start-dialog"),
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectHumanStalker::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectHumanStalker = AlifeObjectHumanStalker {
+      base: AlifeObjectHumanAbstract {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 36,
+                distance: 12.5,
+                direct_control: 1,
+                level_vertex_id: 2436,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 37,
+                spawn_story_id: 38,
+              },
+              visual_name: String::from("actors\\stalker_neutral\\stalker_neutral_1"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 1,
+            group: 2,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![16],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        trader: AlifeObjectTraderAbstract {
+          money: 750,
+          specific_character: String::from("sim_default_stalker_1"),
+          trader_flags: 0,
+          character_profile: String::from("sim_default_stalker"),
+          community_index: 1,
+          rank: 300,
+          reputation: 0,
+          character_name: String::from("character-name"),
+          dead_body_can_take: 1,
+          dead_body_closed: 0,
+        },
+        equipment_preferences: vec![1, 1, 1, 1, 1],
+        main_weapon_preferences: vec![2, 2, 2, 2],
+      },
+      skeleton: AlifeObjectSkeleton {
+        name: String::from("$editor"),
+        flags: 0,
+        source_id: 65535,
+        bones: None,
+      },
+      start_dialog: String::from("start-dialog"),
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectHumanStalker>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -18,9 +18,21 @@ pub struct AlifeObjectInventoryBox {
 
 impl ChunkReadWrite for AlifeObjectInventoryBox {
   /// Read inventory object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  /// Box state is stored since spawn format version 125, older boxes are open and can be looted.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let base: AlifeObjectDynamicVisual = reader.read_xr::<T, _>()?;
+
+    if !reader.is_version_at_least(125) {
+      return Ok(Self {
+        base,
+        can_take: 1,
+        is_closed: 0,
+        tip: String::new(),
+      });
+    }
+
     Ok(Self {
-      base: reader.read_xr::<T, _>()?,
+      base,
       can_take: reader.read_u8()?,
       is_closed: reader.read_u8()?,
       tip: reader.read_w1251_string()?,
@@ -30,9 +42,12 @@ impl ChunkReadWrite for AlifeObjectInventoryBox {
   /// Write inventory object data into the writer.
   fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
     writer.write_xr::<T, _>(&self.base)?;
-    writer.write_u8(self.can_take)?;
-    writer.write_u8(self.is_closed)?;
-    writer.write_w1251_string(&self.tip)?;
+
+    if writer.is_version_at_least(125) {
+      writer.write_u8(self.can_take)?;
+      writer.write_u8(self.is_closed)?;
+      writer.write_w1251_string(&self.tip)?;
+    }
 
     Ok(())
   }
@@ -73,6 +88,7 @@ impl LtxImportExport for AlifeObjectInventoryBox {
 
 #[cfg(test)]
 mod tests {
+  use crate::constants::SPAWN_VERSION_SOC;
   use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
   use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
   use crate::data::alife::inherited::alife_object_inventory_box::AlifeObjectInventoryBox;
@@ -130,12 +146,55 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 62 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectInventoryBox::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_write_legacy_version() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new_versioned(SPAWN_VERSION_SOC);
+
+    let original: AlifeObjectInventoryBox = AlifeObjectInventoryBox {
+      base: AlifeObjectDynamicVisual {
+        base: AlifeObjectAbstract {
+          game_vertex_id: 2463,
+          distance: 12.0,
+          direct_control: 5634,
+          level_vertex_id: 2533,
+          flags: 64,
+          custom_data: String::from("custom-data"),
+          story_id: 2136,
+          spawn_story_id: 0,
+        },
+        visual_name: String::from("visual-name"),
+        visual_flags: 0,
+      },
+      can_take: 1,
+      is_closed: 0,
+      tip: String::new(),
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 51);
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    reader.version = Some(SPAWN_VERSION_SOC);
 
     assert_eq!(
-      AlifeObjectInventoryBox::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectInventoryBox::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
+    assert!(reader.is_ended());
 
     Ok(())
   }
@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -18,11 +18,16 @@ pub struct AlifeObjectItem {
 
 impl ChunkReadWrite for AlifeObjectItem {
   /// Read ALife item object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  /// Upgrades are stored since spawn format version 124.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let object: Self = Self {
       base: reader.read_xr::<T, _>()?,
       condition: reader.read_f32::<T>()?,
-      upgrades_count: reader.read_u32::<T>()?,
+      upgrades_count: if reader.is_version_at_least(124) {
+        reader.read_u32::<T>()?
+      } else {
+        0
+      },
     };
 
     assert_equal(
@@ -38,7 +43,10 @@ impl ChunkReadWrite for AlifeObjectItem {
   fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
     writer.write_xr::<T, _>(&self.base)?;
     writer.write_f32::<T>(self.condition)?;
-    writer.write_u32::<T>(self.upgrades_count)?;
+
+    if writer.is_version_at_least(124) {
+      writer.write_u32::<T>(self.upgrades_count)?;
+    }
 
     Ok(())
   }
@@ -133,10 +141,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 52 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectItem::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectItem::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -21,7 +21,8 @@ pub struct AlifeObjectItemWeapon {
 
 impl ChunkReadWrite for AlifeObjectItemWeapon {
   /// Read ALife item object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  /// Elapsed grenades count is stored since spawn format version 123.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       ammo_current: reader.read_u16::<T>()?,
@@ -29,7 +30,11 @@ impl ChunkReadWrite for AlifeObjectItemWeapon {
       weapon_state: reader.read_u8()?,
       addon_flags: reader.read_u8()?,
       ammo_type: reader.read_u8()?,
-      elapsed_grenades: reader.read_u8()?,
+      elapsed_grenades: if reader.is_version_at_least(123) {
+        reader.read_u8()?
+      } else {
+        0
+      },
     })
   }
 
@@ -41,7 +46,10 @@ impl ChunkReadWrite for AlifeObjectItemWeapon {
     writer.write_u8(self.weapon_state)?;
     writer.write_u8(self.addon_flags)?;
     writer.write_u8(self.ammo_type)?;
-    writer.write_u8(self.elapsed_grenades)?;
+
+    if writer.is_version_at_least(123) {
+      writer.write_u8(self.elapsed_grenades)?;
+    }
 
     Ok(())
   }
@@ -156,10 +164,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 62 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectItemWeapon::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectItemWeapon::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -2,7 +2,7 @@ use crate::data::alife::inherited::alife_object_item_weapon::AlifeObjectItemWeap
 use crate::export::LtxImportExport;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 use xray_ltx::Ltx;
 
@@ -14,7 +14,7 @@ pub struct AlifeObjectItemWeaponMagazined {
 
 impl ChunkReadWrite for AlifeObjectItemWeaponMagazined {
   /// Read magazined weapon data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
     })
@@ -111,10 +111,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 67 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectItemWeaponMagazinedWgl::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectItemWeaponMagazinedWgl::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -0,0 +1,202 @@
+use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "monster_abstract.")]
+pub struct AlifeObjectMonsterAbstract {
+  #[ltx(inherit)]
+  pub base: AlifeObjectCreature,
+  pub out_space_restrictors: String,
+  pub in_space_restrictors: String,
+  pub smart_terrain_id: u16,
+  pub smart_terrain_task_active: u8,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectMonsterAbstract = AlifeObjectMonsterAbstract {
+      base: AlifeObjectCreature {
+        base: AlifeObjectDynamicVisual {
+          base: AlifeObjectAbstract {
+            game_vertex_id: 1002,
+            distance: 65.25,
+            direct_control: 412421,
+            level_vertex_id: 66231,
+            flags: 33,
+            custom_data: String::from("custom_data"),
+            story_id: 400,
+            spawn_story_id: 25,
+          },
+          visual_name: String::from("visual_name"),
+          visual_flags: 33,
+        },
+        team: 2,
+        squad: 3,
+        group: 4,
+        health: 1.0,
+        dynamic_out_restrictions: vec![1, 2, 3, 4],
+        dynamic_in_restrictions: vec![5, 6, 7],
+        killer_id: 25,
+        game_death_time: 0,
+      },
+      out_space_restrictors: String::from("out-restrictor"),
+      in_space_restrictors: String::from("in-restrictor"),
+      smart_terrain_id: 352,
+      smart_terrain_task_active: 1,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 122);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 122);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 122 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectMonsterAbstract::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectMonsterAbstract = AlifeObjectMonsterAbstract {
+      base: AlifeObjectCreature {
+        base: AlifeObjectDynamicVisual {
+          base: AlifeObjectAbstract {
+            game_vertex_id: 5,
+            distance: 24.5,
+            direct_control: 235,
+            level_vertex_id: 2653,
+            flags: 12,
+            custom_data: String::from("custom-data"),
+            story_id: 253,
+            spawn_story_id: 54,
+          },
+          visual_name: String::from("visual-name"),
+          visual_flags: 13,
+        },
+        team: 5,
+        squad: 6,
+        group: 7,
+        health: 0.75,
+        dynamic_out_restrictions: vec![10, 11],
+        dynamic_in_restrictions: vec![12],
+        killer_id: 13,
+        game_death_time: 14,
+      },
+      out_space_restrictors: String::from("out-restrictor"),
+      in_space_restrictors: String::from("in-restrictor"),
+      smart_terrain_id: 36,
+      smart_terrain_task_active: 0,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(
+      AlifeObjectMonsterAbstract::import("data", &source)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectMonsterAbstract = AlifeObjectMonsterAbstract {
+      base: AlifeObjectCreature {
+        base: AlifeObjectDynamicVisual {
+          base: AlifeObjectAbstract {
+            game_vertex_id: 36,
+            distance: 43.5,
+            direct_control: 235,
+            level_vertex_id: 236,
+            flags: 27,
+            custom_data: String::from("custom-data"),
+            story_id: 78,
+            spawn_story_id: 79,
+          },
+          visual_name: String::from("visual-name"),
+          visual_flags: 80,
+        },
+        team: 81,
+        squad: 82,
+        group: 83,
+        health: 0.5,
+        dynamic_out_restrictions: vec![84],
+        dynamic_in_restrictions: vec![85, 86],
+        killer_id: 87,
+        game_death_time: 88,
+      },
+      out_space_restrictors: String::from("out-restrictor"),
+      in_space_restrictors: String::from("in-restrictor"),
+      smart_terrain_id: 89,
+      smart_terrain_task_active: 1,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(serialized.to_string(), serialized);
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectMonsterAbstract>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,430 @@
+use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_db_derive::LtxImportExport;
+use xray_error::XRayResult;
+use xray_utils::assert_equal;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "monster_rat.")]
+pub struct AlifeObjectMonsterRat {
+  #[ltx(inherit)]
+  pub base: AlifeObjectMonsterAbstract,
+  pub field_of_view: f32,
+  pub eye_range: f32,
+  pub minimum_speed: f32,
+  pub maximum_speed: f32,
+  pub attack_speed: f32,
+  pub pursuit_distance: f32,
+  pub home_distance: f32,
+  pub morale_success_attack_quant: f32,
+  pub morale_death_quant: f32,
+  pub morale_fear_quant: f32,
+  pub morale_restore_quant: f32,
+  pub morale_restore_time_interval: u16,
+  pub morale_minimum_value: f32,
+  pub morale_maximum_value: f32,
+  pub morale_normal_value: f32,
+  pub hit_power: f32,
+  pub hit_interval: u16,
+  pub attack_distance: f32,
+  pub attack_angle: f32,
+  pub attack_success_probability: f32,
+  pub condition: f32,
+  pub upgrades_count: u32,
+}
+
+impl ChunkReadWrite for AlifeObjectMonsterRat {
+  /// Read ALife monster rat object data from the chunk.
+  /// Upgrades list of inventory item part is not parsed, rats are expected to have no upgrades.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let object: Self = Self {
+      base: reader.read_xr::<T, _>()?,
+      field_of_view: reader.read_f32::<T>()?,
+      eye_range: reader.read_f32::<T>()?,
+      minimum_speed: reader.read_f32::<T>()?,
+      maximum_speed: reader.read_f32::<T>()?,
+      attack_speed: reader.read_f32::<T>()?,
+      pursuit_distance: reader.read_f32::<T>()?,
+      home_distance: reader.read_f32::<T>()?,
+      morale_success_attack_quant: reader.read_f32::<T>()?,
+      morale_death_quant: reader.read_f32::<T>()?,
+      morale_fear_quant: reader.read_f32::<T>()?,
+      morale_restore_quant: reader.read_f32::<T>()?,
+      morale_restore_time_interval: reader.read_u16::<T>()?,
+      morale_minimum_value: reader.read_f32::<T>()?,
+      morale_maximum_value: reader.read_f32::<T>()?,
+      morale_normal_value: reader.read_f32::<T>()?,
+      hit_power: reader.read_f32::<T>()?,
+      hit_interval: reader.read_u16::<T>()?,
+      attack_distance: reader.read_f32::<T>()?,
+      attack_angle: reader.read_f32::<T>()?,
+      attack_success_probability: reader.read_f32::<T>()?,
+      condition: reader.read_f32::<T>()?,
+      upgrades_count: reader.read_u32::<T>()?,
+    };
+
+    assert_equal(
+      object.upgrades_count,
+      0,
+      "Unexpected upgraded monster rat provided",
+    )?;
+
+    Ok(object)
+  }
+
+  /// Write ALife monster rat object data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_xr::<T, _>(&self.base)?;
+    writer.write_f32::<T>(self.field_of_view)?;
+    writer.write_f32::<T>(self.eye_range)?;
+    writer.write_f32::<T>(self.minimum_speed)?;
+    writer.write_f32::<T>(self.maximum_speed)?;
+    writer.write_f32::<T>(self.attack_speed)?;
+    writer.write_f32::<T>(self.pursuit_distance)?;
+    writer.write_f32::<T>(self.home_distance)?;
+    writer.write_f32::<T>(self.morale_success_attack_quant)?;
+    writer.write_f32::<T>(self.morale_death_quant)?;
+    writer.write_f32::<T>(self.morale_fear_quant)?;
+    writer.write_f32::<T>(self.morale_restore_quant)?;
+    writer.write_u16::<T>(self.morale_restore_time_interval)?;
+    writer.write_f32::<T>(self.morale_minimum_value)?;
+    writer.write_f32::<T>(self.morale_maximum_value)?;
+    writer.write_f32::<T>(self.morale_normal_value)?;
+    writer.write_f32::<T>(self.hit_power)?;
+    writer.write_u16::<T>(self.hit_interval)?;
+    writer.write_f32::<T>(self.attack_distance)?;
+    writer.write_f32::<T>(self.attack_angle)?;
+    writer.write_f32::<T>(self.attack_success_probability)?;
+    writer.write_f32::<T>(self.condition)?;
+    writer.write_u32::<T>(self.upgrades_count)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+  use crate::data::alife::inherited::alife_object_monster_rat::AlifeObjectMonsterRat;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectMonsterRat = AlifeObjectMonsterRat {
+      base: AlifeObjectMonsterAbstract {
+        base: AlifeObjectCreature {
+          base: AlifeObjectDynamicVisual {
+            base: AlifeObjectAbstract {
+              game_vertex_id: 37,
+              distance: 2.5,
+              direct_control: 1,
+              level_vertex_id: 4562,
+              flags: 63,
+              custom_data: String::from("custom-data"),
+              story_id: 38,
+              spawn_story_id: 39,
+            },
+            visual_name: String::from("monsters\\rat\\rat_1"),
+            visual_flags: 0,
+          },
+          team: 1,
+          squad: 2,
+          group: 3,
+          health: 1.0,
+          dynamic_out_restrictions: vec![],
+          dynamic_in_restrictions: vec![],
+          killer_id: 65535,
+          game_death_time: 0,
+        },
+        out_space_restrictors: String::from("out-restrictor"),
+        in_space_restrictors: String::from("in-restrictor"),
+        smart_terrain_id: 65535,
+        smart_terrain_task_active: 0,
+      },
+      field_of_view: 120.0,
+      eye_range: 10.0,
+      minimum_speed: 0.5,
+      maximum_speed: 2.5,
+      attack_speed: 4.0,
+      pursuit_distance: 25.0,
+      home_distance: 15.0,
+      morale_success_attack_quant: 0.1,
+      morale_death_quant: -0.2,
+      morale_fear_quant: -0.1,
+      morale_restore_quant: 0.05,
+      morale_restore_time_interval: 3000,
+      morale_minimum_value: 0.0,
+      morale_maximum_value: 1.0,
+      morale_normal_value: 0.5,
+      hit_power: 0.25,
+      hit_interval: 1500,
+      attack_distance: 0.5,
+      attack_angle: 0.75,
+      attack_success_probability: 0.5,
+      condition: 1.0,
+      upgrades_count: 0,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 199);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 199);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 199 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectMonsterRat::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_upgraded() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let mut original: AlifeObjectMonsterRat = AlifeObjectMonsterRat {
+      base: AlifeObjectMonsterAbstract {
+        base: AlifeObjectCreature {
+          base: AlifeObjectDynamicVisual {
+            base: AlifeObjectAbstract {
+              game_vertex_id: 38,
+              distance: 2.5,
+              direct_control: 1,
+              level_vertex_id: 4562,
+              flags: 63,
+              custom_data: String::from("custom-data"),
+              story_id: 39,
+              spawn_story_id: 40,
+            },
+            visual_name: String::from("monsters\\rat\\rat_1"),
+            visual_flags: 0,
+          },
+          team: 1,
+          squad: 2,
+          group: 3,
+          health: 1.0,
+          dynamic_out_restrictions: vec![],
+          dynamic_in_restrictions: vec![],
+          killer_id: 65535,
+          game_death_time: 0,
+        },
+        out_space_restrictors: String::from("out-restrictor"),
+        in_space_restrictors: String::from("in-restrictor"),
+        smart_terrain_id: 65535,
+        smart_terrain_task_active: 0,
+      },
+      field_of_view: 120.0,
+      eye_range: 10.0,
+      minimum_speed: 0.5,
+      maximum_speed: 2.5,
+      attack_speed: 4.0,
+      pursuit_distance: 25.0,
+      home_distance: 15.0,
+      morale_success_attack_quant: 0.1,
+      morale_death_quant: -0.2,
+      morale_fear_quant: -0.1,
+      morale_restore_quant: 0.05,
+      morale_restore_time_interval: 3000,
+      morale_minimum_value: 0.0,
+      morale_maximum_value: 1.0,
+      morale_normal_value: 0.5,
+      hit_power: 0.25,
+      hit_interval: 1500,
+      attack_distance: 0.5,
+      attack_angle: 0.75,
+      attack_success_probability: 0.5,
+      condition: 1.0,
+      upgrades_count: 0,
+    };
+
+    original.upgrades_count = 1;
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert!(AlifeObjectMonsterRat::read::<XRayByteOrder, _>(&mut reader).is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectMonsterRat = AlifeObjectMonsterRat {
+      base: AlifeObjectMonsterAbstract {
+        base: AlifeObjectCreature {
+          base: AlifeObjectDynamicVisual {
+            base: AlifeObjectAbstract {
+              game_vertex_id: 25,
+              distance: 2.5,
+              direct_control: 1,
+              level_vertex_id: 4562,
+              flags: 63,
+              custom_data: String::from("custom-data"),
+              story_id: 26,
+              spawn_story_id: 27,
+            },
+            visual_name: String::from("monsters\\rat\\rat_1"),
+            visual_flags: 0,
+          },
+          team: 1,
+          squad: 2,
+          group: 3,
+          health: 1.0,
+          dynamic_out_restrictions: vec![],
+          dynamic_in_restrictions: vec![],
+          killer_id: 65535,
+          game_death_time: 0,
+        },
+        out_space_restrictors: String::from("out-restrictor"),
+        in_space_restrictors: String::from("in-restrictor"),
+        smart_terrain_id: 65535,
+        smart_terrain_task_active: 0,
+      },
+      field_of_view: 120.0,
+      eye_range: 10.0,
+      minimum_speed: 0.5,
+      maximum_speed: 2.5,
+      attack_speed: 4.0,
+      pursuit_distance: 25.0,
+      home_distance: 15.0,
+      morale_success_attack_quant: 0.1,
+      morale_death_quant: -0.2,
+      morale_fear_quant: -0.1,
+      morale_restore_quant: 0.05,
+      morale_restore_time_interval: 3000,
+      morale_minimum_value: 0.0,
+      morale_maximum_value: 1.0,
+      morale_normal_value: 0.5,
+      hit_power: 0.25,
+      hit_interval: 1500,
+      attack_distance: 0.5,
+      attack_angle: 0.75,
+      attack_success_probability: 0.5,
+      condition: 1.0,
+      upgrades_count: 0,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectMonsterRat::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectMonsterRat = AlifeObjectMonsterRat {
+      base: AlifeObjectMonsterAbstract {
+        base: AlifeObjectCreature {
+          base: AlifeObjectDynamicVisual {
+            base: AlifeObjectAbstract {
+              game_vertex_id: 36,
+              distance: 2.5,
+              direct_control: 1,
+              level_vertex_id: 4562,
+              flags: 63,
+              custom_data: String::from("custom-data"),
+              story_id: 37,
+              spawn_story_id: 38,
+            },
+            visual_name: String::from("monsters\\rat\\rat_1"),
+            visual_flags: 0,
+          },
+          team: 1,
+          squad: 2,
+          group: 3,
+          health: 1.0,
+          dynamic_out_restrictions: vec![],
+          dynamic_in_restrictions: vec![],
+          killer_id: 65535,
+          game_death_time: 0,
+        },
+        out_space_restrictors: String::from("out-restrictor"),
+        in_space_restrictors: String::from("in-restrictor"),
+        smart_terrain_id: 65535,
+        smart_terrain_task_active: 0,
+      },
+      field_of_view: 120.0,
+      eye_range: 10.0,
+      minimum_speed: 0.5,
+      maximum_speed: 2.5,
+      attack_speed: 4.0,
+      pursuit_distance: 25.0,
+      home_distance: 15.0,
+      morale_success_attack_quant: 0.1,
+      morale_death_quant: -0.2,
+      morale_fear_quant: -0.1,
+      morale_restore_quant: 0.05,
+      morale_restore_time_interval: 3000,
+      morale_minimum_value: 0.0,
+      morale_maximum_value: 1.0,
+      morale_normal_value: 0.5,
+      hit_power: 0.25,
+      hit_interval: 1500,
+      attack_distance: 0.5,
+      attack_angle: 0.75,
+      attack_success_probability: 0.5,
+      condition: 1.0,
+      upgrades_count: 0,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectMonsterRat>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
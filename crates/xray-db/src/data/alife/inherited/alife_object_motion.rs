@@ -2,7 +2,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -14,7 +14,7 @@ pub struct AlifeObjectMotion {
 
 impl ChunkReadWrite for AlifeObjectMotion {
   /// Read motion object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       motion_name: reader.read_w1251_string()?,
     })
@@ -89,10 +89,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 12 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectMotion::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectMotion::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
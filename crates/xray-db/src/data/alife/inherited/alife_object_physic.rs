@@ -4,7 +4,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -20,7 +20,7 @@ pub struct AlifeObjectPhysic {
 
 impl ChunkReadWrite for AlifeObjectPhysic {
   /// Read ALife physic object from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       skeleton: reader.read_xr::<T, _>()?,
@@ -114,6 +114,7 @@ mod tests {
         name: String::from("skeleton-name"),
         flags: 0,
         source_id: 2153,
+        bones: None,
       },
       physic_type: 6,
       mass: 5.0,
@@ -135,10 +136,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 88 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectPhysic::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectPhysic::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
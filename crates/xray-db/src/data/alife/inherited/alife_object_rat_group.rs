@@ -0,0 +1,291 @@
+use crate::data::alife::inherited::alife_object_group_abstract::AlifeObjectGroupAbstract;
+use crate::data::alife::inherited::alife_object_monster_rat::AlifeObjectMonsterRat;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectRatGroup {
+  #[ltx(inherit)]
+  pub base: AlifeObjectMonsterRat,
+  #[ltx(inherit)]
+  pub group: AlifeObjectGroupAbstract,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_group_abstract::AlifeObjectGroupAbstract;
+  use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+  use crate::data::alife::inherited::alife_object_monster_rat::AlifeObjectMonsterRat;
+  use crate::data::alife::inherited::alife_object_rat_group::AlifeObjectRatGroup;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectRatGroup = AlifeObjectRatGroup {
+      base: AlifeObjectMonsterRat {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 37,
+                distance: 2.5,
+                direct_control: 1,
+                level_vertex_id: 4562,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 38,
+                spawn_story_id: 39,
+              },
+              visual_name: String::from("monsters\\rat\\rat_1"),
+              visual_flags: 0,
+            },
+            team: 1,
+            squad: 2,
+            group: 3,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        field_of_view: 120.0,
+        eye_range: 10.0,
+        minimum_speed: 0.5,
+        maximum_speed: 2.5,
+        attack_speed: 4.0,
+        pursuit_distance: 25.0,
+        home_distance: 15.0,
+        morale_success_attack_quant: 0.1,
+        morale_death_quant: -0.2,
+        morale_fear_quant: -0.1,
+        morale_restore_quant: 0.05,
+        morale_restore_time_interval: 3000,
+        morale_minimum_value: 0.0,
+        morale_maximum_value: 1.0,
+        morale_normal_value: 0.5,
+        hit_power: 0.25,
+        hit_interval: 1500,
+        attack_distance: 0.5,
+        attack_angle: 0.75,
+        attack_success_probability: 0.5,
+        condition: 1.0,
+        upgrades_count: 0,
+      },
+      group: AlifeObjectGroupAbstract {
+        create_spawn_positions: 1,
+        count: 3,
+        members: vec![47, 48, 49],
+      },
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 215);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 215);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 215 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectRatGroup::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectRatGroup = AlifeObjectRatGroup {
+      base: AlifeObjectMonsterRat {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 25,
+                distance: 2.5,
+                direct_control: 1,
+                level_vertex_id: 4562,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 26,
+                spawn_story_id: 27,
+              },
+              visual_name: String::from("monsters\\rat\\rat_1"),
+              visual_flags: 0,
+            },
+            team: 1,
+            squad: 2,
+            group: 3,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        field_of_view: 120.0,
+        eye_range: 10.0,
+        minimum_speed: 0.5,
+        maximum_speed: 2.5,
+        attack_speed: 4.0,
+        pursuit_distance: 25.0,
+        home_distance: 15.0,
+        morale_success_attack_quant: 0.1,
+        morale_death_quant: -0.2,
+        morale_fear_quant: -0.1,
+        morale_restore_quant: 0.05,
+        morale_restore_time_interval: 3000,
+        morale_minimum_value: 0.0,
+        morale_maximum_value: 1.0,
+        morale_normal_value: 0.5,
+        hit_power: 0.25,
+        hit_interval: 1500,
+        attack_distance: 0.5,
+        attack_angle: 0.75,
+        attack_success_probability: 0.5,
+        condition: 1.0,
+        upgrades_count: 0,
+      },
+      group: AlifeObjectGroupAbstract {
+        create_spawn_positions: 1,
+        count: 3,
+        members: vec![35, 36, 37],
+      },
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectRatGroup::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectRatGroup = AlifeObjectRatGroup {
+      base: AlifeObjectMonsterRat {
+        base: AlifeObjectMonsterAbstract {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: AlifeObjectAbstract {
+                game_vertex_id: 36,
+                distance: 2.5,
+                direct_control: 1,
+                level_vertex_id: 4562,
+                flags: 63,
+                custom_data: String::from("custom-data"),
+                story_id: 37,
+                spawn_story_id: 38,
+              },
+              visual_name: String::from("monsters\\rat\\rat_1"),
+              visual_flags: 0,
+            },
+            team: 1,
+            squad: 2,
+            group: 3,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![],
+            killer_id: 65535,
+            game_death_time: 0,
+          },
+          out_space_restrictors: String::from("out-restrictor"),
+          in_space_restrictors: String::from("in-restrictor"),
+          smart_terrain_id: 65535,
+          smart_terrain_task_active: 0,
+        },
+        field_of_view: 120.0,
+        eye_range: 10.0,
+        minimum_speed: 0.5,
+        maximum_speed: 2.5,
+        attack_speed: 4.0,
+        pursuit_distance: 25.0,
+        home_distance: 15.0,
+        morale_success_attack_quant: 0.1,
+        morale_death_quant: -0.2,
+        morale_fear_quant: -0.1,
+        morale_restore_quant: 0.05,
+        morale_restore_time_interval: 3000,
+        morale_minimum_value: 0.0,
+        morale_maximum_value: 1.0,
+        morale_normal_value: 0.5,
+        hit_power: 0.25,
+        hit_interval: 1500,
+        attack_distance: 0.5,
+        attack_angle: 0.75,
+        attack_success_probability: 0.5,
+        condition: 1.0,
+        upgrades_count: 0,
+      },
+      group: AlifeObjectGroupAbstract {
+        create_spawn_positions: 1,
+        count: 3,
+        members: vec![46, 47, 48],
+      },
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectRatGroup>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -3,7 +3,7 @@ use crate::data::generic::shape::Shape;
 use crate::export::LtxImportExport;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -16,10 +16,10 @@ pub struct AlifeObjectShape {
 
 impl ChunkReadWrite for AlifeObjectShape {
   /// Read shape object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
-      shape: Shape::read_list::<T>(reader)?,
+      shape: Shape::read_list::<T, _>(reader)?,
     })
   }
 
@@ -115,10 +115,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 105 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectShape::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectShape::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -1,9 +1,10 @@
 use crate::constants::FLAG_SKELETON_SAVED_DATA;
+use crate::data::alife::inherited::alife_object_skeleton_bones::AlifeObjectSkeletonBones;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -13,19 +14,41 @@ pub struct AlifeObjectSkeleton {
   pub name: String,
   pub flags: u8,
   pub source_id: u16,
+  pub bones: Option<AlifeObjectSkeletonBones>,
+}
+
+impl AlifeObjectSkeleton {
+  /// Whether skeleton stores saved bones state.
+  pub fn has_saved_data(&self) -> bool {
+    self.flags & FLAG_SKELETON_SAVED_DATA != 0
+  }
+
+  /// Check that bones state is set only when saved data flag is set.
+  fn assert_saved_data(&self) -> XRayResult {
+    if self.bones.is_some() == self.has_saved_data() {
+      Ok(())
+    } else {
+      Err(XRayError::new_invalid_error(format!(
+        "Expected skeleton bones to be set only with saved data flag, flags: {}, bones: {}",
+        self.flags,
+        self.bones.is_some()
+      )))
+    }
+  }
 }
 
 impl ChunkReadWrite for AlifeObjectSkeleton {
   /// Read skeleton data from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    let object = Self {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let mut object: Self = Self {
       name: reader.read_w1251_string()?,
       flags: reader.read_u8()?,
       source_id: reader.read_u16::<XRayByteOrder>()?,
+      bones: None,
     };
 
-    if object.flags & FLAG_SKELETON_SAVED_DATA != 0 {
-      todo!("Extend skeleton parsing to include bones based on flag")
+    if object.has_saved_data() {
+      object.bones = Some(reader.read_xr::<T, _>()?);
     }
 
     Ok(object)
@@ -33,10 +56,16 @@ impl ChunkReadWrite for AlifeObjectSkeleton {
 
   /// Write skeleton data into the chunk writer.
   fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    self.assert_saved_data()?;
+
     writer.write_w1251_string(&self.name)?;
     writer.write_u8(self.flags)?;
     writer.write_u16::<XRayByteOrder>(self.source_id)?;
 
+    if let Some(bones) = &self.bones {
+      writer.write_xr::<T, _>(bones)?;
+    }
+
     Ok(())
   }
 }
@@ -52,21 +81,34 @@ impl LtxImportExport for AlifeObjectSkeleton {
       ))
     })?;
 
-    Ok(Self {
+    let mut object: Self = Self {
       name: read_ltx_field("skeleton.name", section)?,
       flags: read_ltx_field("skeleton.flags", section)?,
       source_id: read_ltx_field("skeleton.source_id", section)?,
-    })
+      bones: None,
+    };
+
+    if object.has_saved_data() {
+      object.bones = Some(AlifeObjectSkeletonBones::import(section_name, ltx)?);
+    }
+
+    Ok(object)
   }
 
   /// Export object data into ltx file.
   fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    self.assert_saved_data()?;
+
     ltx
       .with_section(section_name)
       .set("skeleton.name", &self.name)
       .set("skeleton.flags", self.flags.to_string())
       .set("skeleton.source_id", self.source_id.to_string());
 
+    if let Some(bones) = &self.bones {
+      bones.export(section_name, ltx)?;
+    }
+
     Ok(())
   }
 }
@@ -74,6 +116,10 @@ impl LtxImportExport for AlifeObjectSkeleton {
 #[cfg(test)]
 mod tests {
   use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+  use crate::data::alife::inherited::alife_object_skeleton_bones::{
+    AlifeObjectSkeletonBone, AlifeObjectSkeletonBones,
+  };
+  use crate::data::generic::vector_3d::Vector3d;
   use crate::export::LtxImportExport;
   use serde_json::to_string_pretty;
   use std::fs::File;
@@ -97,6 +143,7 @@ mod tests {
       name: String::from("test-name"),
       flags: 33,
       source_id: 753,
+      bones: None,
     };
 
     original.write::<XRayByteOrder>(&mut writer)?;
@@ -114,14 +161,85 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 13 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
-    let read_object: AlifeObjectSkeleton = AlifeObjectSkeleton::read::<XRayByteOrder>(&mut reader)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+    let read_object: AlifeObjectSkeleton =
+      AlifeObjectSkeleton::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read_object, original);
 
     Ok(())
   }
 
+  #[test]
+  fn test_read_write_saved_data() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    let original: AlifeObjectSkeleton = AlifeObjectSkeleton {
+      name: String::from("test-name-saved"),
+      flags: 4,
+      source_id: 65535,
+      bones: Some(AlifeObjectSkeletonBones {
+        bones_mask: 255,
+        root_bone: 0,
+        min: Vector3d::new(-1.0, -1.0, -1.0),
+        max: Vector3d::new(1.0, 1.0, 1.0),
+        bones: vec![AlifeObjectSkeletonBone {
+          position: vec![1, 2, 3],
+          quaternion: vec![4, 5, 6, 7],
+          enabled: 1,
+        }],
+      }),
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 63);
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(
+      AlifeObjectSkeleton::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_write_inconsistent_saved_data() -> XRayResult {
+    let without_bones: AlifeObjectSkeleton = AlifeObjectSkeleton {
+      name: String::from("test-name-without-bones"),
+      flags: 4,
+      source_id: 65535,
+      bones: None,
+    };
+
+    let without_flag: AlifeObjectSkeleton = AlifeObjectSkeleton {
+      name: String::from("test-name-without-flag"),
+      flags: 0,
+      source_id: 65535,
+      bones: Some(AlifeObjectSkeletonBones {
+        bones_mask: 1,
+        root_bone: 0,
+        min: Vector3d::new(-1.0, -1.0, -1.0),
+        max: Vector3d::new(1.0, 1.0, 1.0),
+        bones: vec![],
+      }),
+    };
+
+    assert!(without_bones
+      .write::<XRayByteOrder>(&mut ChunkWriter::new())
+      .is_err());
+    assert!(without_flag
+      .write::<XRayByteOrder>(&mut ChunkWriter::new())
+      .is_err());
+    assert!(without_flag.export("data", &mut Ltx::new()).is_err());
+
+    Ok(())
+  }
+
   #[test]
   fn test_import_export() -> XRayResult {
     let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
@@ -131,12 +249,24 @@ mod tests {
       name: String::from("test-name-first"),
       flags: 33,
       source_id: 753,
+      bones: None,
     };
 
     let second: AlifeObjectSkeleton = AlifeObjectSkeleton {
       name: String::from("test-name-second"),
       flags: 54,
       source_id: 526,
+      bones: Some(AlifeObjectSkeletonBones {
+        bones_mask: 3,
+        root_bone: 0,
+        min: Vector3d::new(-1.0, -1.0, -1.0),
+        max: Vector3d::new(1.0, 1.0, 1.0),
+        bones: vec![AlifeObjectSkeletonBone {
+          position: vec![1, 2, 3],
+          quaternion: vec![4, 5, 6, 7],
+          enabled: 1,
+        }],
+      }),
     };
 
     first.export("first", &mut ltx)?;
@@ -160,6 +290,17 @@ mod tests {
       name: String::from("test-name-serde"),
       flags: 45,
       source_id: 34,
+      bones: Some(AlifeObjectSkeletonBones {
+        bones_mask: 1,
+        root_bone: 0,
+        min: Vector3d::new(-1.0, -1.0, -1.0),
+        max: Vector3d::new(1.0, 1.0, 1.0),
+        bones: vec![AlifeObjectSkeletonBone {
+          position: vec![1, 2, 3],
+          quaternion: vec![4, 5, 6, 7],
+          enabled: 0,
+        }],
+      }),
     };
 
     let mut file: File = overwrite_test_relative_resource_as_file(
@@ -0,0 +1,319 @@
+use crate::data::generic::vector_3d::Vector3d;
+use crate::export::LtxImportExport;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::{assert_equal, vector_from_string, vector_to_string};
+
+/// Saved physic state of single skeleton bone.
+/// Position is quantized into 3 bytes relative to min/max bounds, rotation into 4 bytes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectSkeletonBone {
+  pub position: Vec<u8>,
+  pub quaternion: Vec<u8>,
+  pub enabled: u8,
+}
+
+impl ChunkReadWrite for AlifeObjectSkeletonBone {
+  /// Read skeleton bone state from the chunk reader.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    Ok(Self {
+      position: reader.read_bytes(3)?,
+      quaternion: reader.read_bytes(4)?,
+      enabled: reader.read_u8()?,
+    })
+  }
+
+  /// Write skeleton bone state into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    assert_equal(
+      self.position.len(),
+      3,
+      "Expected 3 quantized skeleton bone position values",
+    )?;
+    assert_equal(
+      self.quaternion.len(),
+      4,
+      "Expected 4 quantized skeleton bone quaternion values",
+    )?;
+
+    writer.write_all(&self.position)?;
+    writer.write_all(&self.quaternion)?;
+    writer.write_u8(self.enabled)?;
+
+    Ok(())
+  }
+}
+
+/// Saved bones data of physic skeleton, stored when skeleton has saved data flag.
+/// Represents SPHBonesData from xray implementation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectSkeletonBones {
+  pub bones_mask: u64,
+  pub root_bone: u16,
+  pub min: Vector3d,
+  pub max: Vector3d,
+  pub bones: Vec<AlifeObjectSkeletonBone>,
+}
+
+impl ChunkReadWrite for AlifeObjectSkeletonBones {
+  /// Read skeleton bones data from the chunk reader.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let bones_mask: u64 = reader.read_u64::<T>()?;
+    let root_bone: u16 = reader.read_u16::<T>()?;
+    let min: Vector3d = reader.read_xr::<T, _>()?;
+    let max: Vector3d = reader.read_xr::<T, _>()?;
+    let count: u16 = reader.read_u16::<T>()?;
+    let mut bones: Vec<AlifeObjectSkeletonBone> = Vec::with_capacity(count as usize);
+
+    for _ in 0..count {
+      bones.push(reader.read_xr::<T, _>()?);
+    }
+
+    Ok(Self {
+      bones_mask,
+      root_bone,
+      min,
+      max,
+      bones,
+    })
+  }
+
+  /// Write skeleton bones data into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u64::<T>(self.bones_mask)?;
+    writer.write_u16::<T>(self.root_bone)?;
+    writer.write_xr::<T, _>(&self.min)?;
+    writer.write_xr::<T, _>(&self.max)?;
+    writer.write_u16::<T>(u16::try_from(self.bones.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected skeleton bones count to fit into u16")
+    })?)?;
+
+    for bone in &self.bones {
+      writer.write_xr::<T, _>(bone)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeObjectSkeletonBones {
+  /// Import skeleton bones data from ltx config section.
+  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
+    let section: &Section = ltx.section(section_name).ok_or_else(|| {
+      XRayError::new_parsing_error(format!(
+        "ALife object '{}' should be defined in ltx file ({})",
+        section_name,
+        file!()
+      ))
+    })?;
+
+    let count: usize = read_ltx_field("skeleton.bones_count", section)?;
+    let mut bones: Vec<AlifeObjectSkeletonBone> = Vec::with_capacity(count);
+
+    for index in 0..count {
+      let prefix: String = format!("skeleton.bone.{index}");
+
+      bones.push(AlifeObjectSkeletonBone {
+        position: vector_from_string(&read_ltx_field::<String>(
+          &format!("{prefix}.position"),
+          section,
+        )?)?,
+        quaternion: vector_from_string(&read_ltx_field::<String>(
+          &format!("{prefix}.quaternion"),
+          section,
+        )?)?,
+        enabled: read_ltx_field(&format!("{prefix}.enabled"), section)?,
+      });
+    }
+
+    Ok(Self {
+      bones_mask: read_ltx_field("skeleton.bones_mask", section)?,
+      root_bone: read_ltx_field("skeleton.root_bone", section)?,
+      min: read_ltx_field("skeleton.bones_min", section)?,
+      max: read_ltx_field("skeleton.bones_max", section)?,
+      bones,
+    })
+  }
+
+  /// Export skeleton bones data into ltx file.
+  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    ltx
+      .with_section(section_name)
+      .set("skeleton.bones_mask", self.bones_mask.to_string())
+      .set("skeleton.root_bone", self.root_bone.to_string())
+      .set("skeleton.bones_min", self.min.to_string())
+      .set("skeleton.bones_max", self.max.to_string())
+      .set("skeleton.bones_count", self.bones.len().to_string());
+
+    for (index, bone) in self.bones.iter().enumerate() {
+      let prefix: String = format!("skeleton.bone.{index}");
+
+      ltx
+        .with_section(section_name)
+        .set(
+          format!("{prefix}.position"),
+          vector_to_string(&bone.position),
+        )
+        .set(
+          format!("{prefix}.quaternion"),
+          vector_to_string(&bone.quaternion),
+        )
+        .set(format!("{prefix}.enabled"), bone.enabled.to_string());
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_skeleton_bones::{
+    AlifeObjectSkeletonBone, AlifeObjectSkeletonBones,
+  };
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectSkeletonBones = AlifeObjectSkeletonBones {
+      bones_mask: u64::MAX - 12,
+      root_bone: 12,
+      min: Vector3d::new(-10.5, -2.0, -8.25),
+      max: Vector3d::new(10.5, 4.0, 8.25),
+      bones: vec![
+        AlifeObjectSkeletonBone {
+          position: vec![12, 127, 255],
+          quaternion: vec![0, 64, 128, 255],
+          enabled: 1,
+        },
+        AlifeObjectSkeletonBone {
+          position: vec![10, 20, 12],
+          quaternion: vec![1, 2, 3, 4],
+          enabled: 0,
+        },
+      ],
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 52);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 52);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 52 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectSkeletonBones::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectSkeletonBones = AlifeObjectSkeletonBones {
+      bones_mask: u64::MAX - 24,
+      root_bone: 24,
+      min: Vector3d::new(-10.5, -2.0, -8.25),
+      max: Vector3d::new(10.5, 4.0, 8.25),
+      bones: vec![
+        AlifeObjectSkeletonBone {
+          position: vec![24, 127, 255],
+          quaternion: vec![0, 64, 128, 255],
+          enabled: 1,
+        },
+        AlifeObjectSkeletonBone {
+          position: vec![10, 20, 24],
+          quaternion: vec![1, 2, 3, 4],
+          enabled: 0,
+        },
+      ],
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectSkeletonBones::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectSkeletonBones = AlifeObjectSkeletonBones {
+      bones_mask: u64::MAX - 36,
+      root_bone: 36,
+      min: Vector3d::new(-10.5, -2.0, -8.25),
+      max: Vector3d::new(10.5, 4.0, 8.25),
+      bones: vec![
+        AlifeObjectSkeletonBone {
+          position: vec![36, 127, 255],
+          quaternion: vec![0, 64, 128, 255],
+          enabled: 1,
+        },
+        AlifeObjectSkeletonBone {
+          position: vec![10, 20, 36],
+          quaternion: vec![1, 2, 3, 4],
+          enabled: 0,
+        },
+      ],
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectSkeletonBones>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
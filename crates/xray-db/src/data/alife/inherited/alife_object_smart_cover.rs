@@ -4,7 +4,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -23,7 +23,7 @@ pub struct AlifeObjectSmartCover {
 
 impl ChunkReadWrite for AlifeObjectSmartCover {
   /// Read smart cover object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       shape: reader.read_xr_list::<T, Shape>()?,
@@ -167,10 +167,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 136 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectSmartCover::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectSmartCover::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
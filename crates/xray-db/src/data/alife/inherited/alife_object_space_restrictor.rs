@@ -2,13 +2,12 @@ use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
 use crate::data::generic::shape::Shape;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
-use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_db_derive::ChunkReadWrite;
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite)]
 #[serde(rename_all = "camelCase")]
 pub struct AlifeObjectSpaceRestrictor {
   pub base: AlifeObjectAbstract,
@@ -16,25 +15,6 @@ pub struct AlifeObjectSpaceRestrictor {
   pub restrictor_type: u8,
 }
 
-impl ChunkReadWrite for AlifeObjectSpaceRestrictor {
-  /// Read generic space restrictor data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    Ok(Self {
-      base: reader.read_xr::<T, _>()?,
-      shape: reader.read_xr_list::<T, Shape>()?,
-      restrictor_type: reader.read_u8()?,
-    })
-  }
-
-  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
-    writer.write_xr::<T, _>(&self.base)?;
-    writer.write_xr_list::<T, Shape>(&self.shape)?;
-    writer.write_u8(self.restrictor_type)?;
-
-    Ok(())
-  }
-}
-
 impl LtxImportExport for AlifeObjectSpaceRestrictor {
   /// Import generic space restrictor data from the chunk.
   fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
@@ -132,10 +112,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 106 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectSpaceRestrictor::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectSpaceRestrictor::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -0,0 +1,72 @@
+use crate::export::LtxImportExport;
+use byteorder::ByteOrder;
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::XRayResult;
+use xray_ltx::Ltx;
+
+/// Spectator object does not store any data except generic object base.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectSpectator {}
+
+impl ChunkReadWrite for AlifeObjectSpectator {
+  fn read<T: ByteOrder, D: ChunkDataSource>(_: &mut ChunkReader<D>) -> XRayResult<Self> {
+    Ok(Self {})
+  }
+
+  fn write<T: ByteOrder>(&self, _: &mut ChunkWriter) -> XRayResult {
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeObjectSpectator {
+  fn import(_: &str, _: &Ltx) -> XRayResult<Self> {
+    Ok(Self {})
+  }
+
+  fn export(&self, _: &str, _: &mut Ltx) -> XRayResult {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_spectator::AlifeObjectSpectator;
+  use crate::export::LtxImportExport;
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let original: AlifeObjectSpectator = AlifeObjectSpectator::default();
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 0);
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(
+      AlifeObjectSpectator::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let mut ltx: Ltx = Ltx::new();
+    let original: AlifeObjectSpectator = AlifeObjectSpectator::default();
+
+    original.export("data", &mut ltx)?;
+
+    assert_eq!(AlifeObjectSpectator::import("data", &ltx)?, original);
+
+    Ok(())
+  }
+}
@@ -3,13 +3,12 @@ use crate::data::alife::inherited::alife_object_motion::AlifeObjectMotion;
 use crate::data::generic::time::Time;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
-use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_db_derive::ChunkReadWrite;
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite)]
 #[serde(rename_all = "camelCase")]
 pub struct AlifeObjectTorridZone {
   pub base: AlifeObjectCustomZone,
@@ -17,26 +16,6 @@ pub struct AlifeObjectTorridZone {
   pub last_spawn_time: Option<Time>,
 }
 
-impl ChunkReadWrite for AlifeObjectTorridZone {
-  /// Read zone object data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    Ok(Self {
-      base: reader.read_xr::<T, _>()?,
-      motion: reader.read_xr::<T, _>()?,
-      last_spawn_time: reader.read_xr_optional::<T, Time>()?,
-    })
-  }
-
-  /// Write zone object data into the writer.
-  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
-    writer.write_xr::<T, _>(&self.base)?;
-    writer.write_xr::<T, _>(&self.motion)?;
-    writer.write_xr_optional::<T, _>(self.last_spawn_time.as_ref())?;
-
-    Ok(())
-  }
-}
-
 impl LtxImportExport for AlifeObjectTorridZone {
   /// Import torrid zone object data from ltx config section.
   fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
@@ -144,10 +123,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 81 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectTorridZone::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectTorridZone::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -2,7 +2,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -23,7 +23,8 @@ pub struct AlifeObjectTraderAbstract {
 
 impl ChunkReadWrite for AlifeObjectTraderAbstract {
   /// Read trader data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  /// Dead body flags are stored since CoP spawn format (128).
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       money: reader.read_u32::<T>()?,
       specific_character: reader.read_w1251_string()?,
@@ -33,8 +34,16 @@ impl ChunkReadWrite for AlifeObjectTraderAbstract {
       rank: reader.read_u32::<T>()?,
       reputation: reader.read_u32::<T>()?,
       character_name: reader.read_w1251_string()?,
-      dead_body_can_take: reader.read_u8()?,
-      dead_body_closed: reader.read_u8()?,
+      dead_body_can_take: if reader.is_version_at_least(128) {
+        reader.read_u8()?
+      } else {
+        1
+      },
+      dead_body_closed: if reader.is_version_at_least(128) {
+        reader.read_u8()?
+      } else {
+        0
+      },
     })
   }
 
@@ -48,8 +57,11 @@ impl ChunkReadWrite for AlifeObjectTraderAbstract {
     writer.write_u32::<T>(self.rank)?;
     writer.write_u32::<T>(self.reputation)?;
     writer.write_w1251_string(&self.character_name)?;
-    writer.write_u8(self.dead_body_can_take)?;
-    writer.write_u8(self.dead_body_closed)?;
+
+    if writer.is_version_at_least(128) {
+      writer.write_u8(self.dead_body_can_take)?;
+      writer.write_u8(self.dead_body_closed)?;
+    }
 
     Ok(())
   }
@@ -146,10 +158,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 74 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectTraderAbstract::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectTraderAbstract::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
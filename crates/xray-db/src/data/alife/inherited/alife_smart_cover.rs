@@ -4,7 +4,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -19,7 +19,7 @@ pub struct AlifeSmartCover {
 
 impl ChunkReadWrite for AlifeSmartCover {
   /// Read smart cover data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let base: AlifeObjectSmartCover = reader.read_xr::<T, _>()?;
 
     let last_description: String = reader.read_w1251_string()?;
@@ -158,10 +158,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 131 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeObjectSmartCover::read::<XRayByteOrder>(&mut reader)?,
+      AlifeObjectSmartCover::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
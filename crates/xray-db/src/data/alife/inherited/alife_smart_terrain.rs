@@ -1,109 +1,108 @@
+use crate::data::alife::inherited::alife_smart_terrain_actor_control::AlifeSmartTerrainActorControl;
+use crate::data::alife::inherited::alife_smart_terrain_dead_object::AlifeSmartTerrainDeadObject;
+use crate::data::alife::inherited::alife_smart_terrain_job::AlifeSmartTerrainJob;
+use crate::data::alife::inherited::alife_smart_terrain_respawn::AlifeSmartTerrainRespawn;
 use crate::data::alife::inherited::alife_smart_zone::AlifeSmartZone;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
-use xray_utils::assert_equal;
+use xray_utils::{assert_equal, vector_from_string, vector_to_string};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AlifeSmartTerrain {
   pub base: AlifeSmartZone,
-  pub arriving_objects_count: u8,
-  pub object_job_descriptors_count: u8,
-  pub dead_objects_infos_count: u8,
-  pub smart_terrain_actor_control: u8,
-  pub respawn_point: u8,
-  pub staying_objects_count: u8,
-  pub save_marker: u16,
+  pub arriving_objects: Vec<u16>,
+  pub object_job_descriptors: Vec<AlifeSmartTerrainJob>,
+  pub dead_objects_infos: Vec<AlifeSmartTerrainDeadObject>,
+  pub actor_control: Option<AlifeSmartTerrainActorControl>,
+  pub respawn: Option<AlifeSmartTerrainRespawn>,
+  pub staying_objects: Vec<u16>,
 }
 
 impl ChunkReadWrite for AlifeSmartTerrain {
   /// Read ALife smart terrain data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    let base: AlifeSmartZone = AlifeSmartZone::read::<T>(reader)?;
+  /// Script data ends with save marker equal to size of the data, it is validated and not stored.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let base: AlifeSmartZone = AlifeSmartZone::read::<T, _>(reader)?;
+    let script_data_start: u64 = reader.read_bytes_len();
 
     let arriving_objects_count: u8 = reader.read_u8()?;
+    let mut arriving_objects: Vec<u16> = Vec::with_capacity(arriving_objects_count as usize);
 
-    assert_equal(
-      arriving_objects_count,
-      0,
-      "Unexpected arriving objects in smart terrain",
-    )?;
-
-    let object_job_descriptors_count: u8 = reader.read_u8()?;
-
-    assert_equal(
-      object_job_descriptors_count,
-      0,
-      "Unexpected job objects in smart terrain",
-    )?;
-
-    let dead_objects_infos_count: u8 = reader.read_u8()?;
-
-    assert_equal(
-      dead_objects_infos_count,
-      0,
-      "Unexpected dead objects in smart terrain",
-    )?;
-
-    let smart_terrain_actor_control: u8 = reader.read_u8()?;
-
-    assert_equal(
-      smart_terrain_actor_control,
-      0,
-      "Unexpected smart terrain actor control",
-    )?;
+    for _ in 0..arriving_objects_count {
+      arriving_objects.push(reader.read_u16::<T>()?);
+    }
 
-    let respawn_point: u8 = reader.read_u8()?;
+    let object_job_descriptors: Vec<AlifeSmartTerrainJob> = reader.read_xr_list::<T, _>()?;
+    let dead_objects_infos: Vec<AlifeSmartTerrainDeadObject> = reader.read_xr_list::<T, _>()?;
 
-    if respawn_point != 0 {
-      return Err(XRayError::new_parsing_error(
-        "Unexpected respawn point handler in smart terrain parser",
-      ));
-    }
+    let actor_control: Option<AlifeSmartTerrainActorControl> = reader.read_xr_optional::<T, _>()?;
+    let respawn: Option<AlifeSmartTerrainRespawn> = reader.read_xr_optional::<T, _>()?;
 
     let staying_objects_count: u8 = reader.read_u8()?;
+    let mut staying_objects: Vec<u16> = Vec::with_capacity(staying_objects_count as usize);
 
-    assert_equal(
-      staying_objects_count,
-      0,
-      "Unexpected smart terrain staying objects",
-    )?;
+    for _ in 0..staying_objects_count {
+      staying_objects.push(reader.read_u16::<T>()?);
+    }
 
+    let script_data_size: u64 = reader.read_bytes_len() - script_data_start;
     let save_marker: u16 = reader.read_u16::<T>()?;
 
     assert_equal(
-      save_marker,
-      6,
+      save_marker as u64,
+      script_data_size,
       "Unexpected data provided with smart terrain save",
     )?;
 
     Ok(Self {
       base,
-      arriving_objects_count,
-      object_job_descriptors_count,
-      dead_objects_infos_count,
-      smart_terrain_actor_control,
-      respawn_point,
-      staying_objects_count,
-      save_marker,
+      arriving_objects,
+      object_job_descriptors,
+      dead_objects_infos,
+      actor_control,
+      respawn,
+      staying_objects,
     })
   }
 
   /// Write smart terrain data into the writer.
+  /// Save marker is computed from size of written script data.
   fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
     writer.write_xr::<T, _>(&self.base)?;
-    writer.write_u8(self.arriving_objects_count)?;
-    writer.write_u8(self.object_job_descriptors_count)?;
-    writer.write_u8(self.dead_objects_infos_count)?;
-    writer.write_u8(self.smart_terrain_actor_control)?;
-    writer.write_u8(self.respawn_point)?;
-    writer.write_u8(self.staying_objects_count)?;
-    writer.write_u16::<T>(self.save_marker)?;
+
+    let script_data_start: usize = writer.bytes_written();
+
+    writer.write_u8(u8::try_from(self.arriving_objects.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected smart terrain arriving objects count to fit into u8")
+    })?)?;
+
+    for object_id in &self.arriving_objects {
+      writer.write_u16::<T>(*object_id)?;
+    }
+
+    writer.write_xr_list::<T, _>(&self.object_job_descriptors)?;
+    writer.write_xr_list::<T, _>(&self.dead_objects_infos)?;
+    writer.write_xr_optional::<T, _>(self.actor_control.as_ref())?;
+    writer.write_xr_optional::<T, _>(self.respawn.as_ref())?;
+    writer.write_u8(u8::try_from(self.staying_objects.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected smart terrain staying objects count to fit into u8")
+    })?)?;
+
+    for object_id in &self.staying_objects {
+      writer.write_u16::<T>(*object_id)?;
+    }
+
+    writer.write_u16::<T>(
+      u16::try_from(writer.bytes_written() - script_data_start).map_err(|_| {
+        XRayError::new_invalid_error("Expected smart terrain script data size to fit into u16")
+      })?,
+    )?;
 
     Ok(())
   }
@@ -122,13 +121,15 @@ impl LtxImportExport for AlifeSmartTerrain {
 
     Ok(Self {
       base: AlifeSmartZone::import(section_name, ltx)?,
-      arriving_objects_count: read_ltx_field("arriving_objects_count", section)?,
-      object_job_descriptors_count: read_ltx_field("object_job_descriptors_count", section)?,
-      dead_objects_infos_count: read_ltx_field("dead_objects_infos_count", section)?,
-      smart_terrain_actor_control: read_ltx_field("smart_terrain_actor_control", section)?,
-      respawn_point: read_ltx_field("respawn_point", section)?,
-      staying_objects_count: read_ltx_field("staying_objects_count", section)?,
-      save_marker: read_ltx_field("save_marker", section)?,
+      arriving_objects: vector_from_string(&read_ltx_field::<String>(
+        "arriving_objects",
+        section,
+      )?)?,
+      object_job_descriptors: AlifeSmartTerrainJob::import_list(section)?,
+      dead_objects_infos: AlifeSmartTerrainDeadObject::import_list(section)?,
+      actor_control: AlifeSmartTerrainActorControl::import_optional(section)?,
+      respawn: AlifeSmartTerrainRespawn::import_optional(section)?,
+      staying_objects: vector_from_string(&read_ltx_field::<String>("staying_objects", section)?)?,
     })
   }
 
@@ -138,28 +139,13 @@ impl LtxImportExport for AlifeSmartTerrain {
 
     ltx
       .with_section(section_name)
-      .set(
-        "arriving_objects_count",
-        self.arriving_objects_count.to_string(),
-      )
-      .set(
-        "object_job_descriptors_count",
-        self.object_job_descriptors_count.to_string(),
-      )
-      .set(
-        "dead_objects_infos_count",
-        self.dead_objects_infos_count.to_string(),
-      )
-      .set(
-        "smart_terrain_actor_control",
-        self.smart_terrain_actor_control.to_string(),
-      )
-      .set("respawn_point", self.respawn_point.to_string())
-      .set(
-        "staying_objects_count",
-        self.staying_objects_count.to_string(),
-      )
-      .set("save_marker", self.save_marker.to_string());
+      .set("arriving_objects", vector_to_string(&self.arriving_objects))
+      .set("staying_objects", vector_to_string(&self.staying_objects));
+
+    AlifeSmartTerrainJob::export_list(&self.object_job_descriptors, section_name, ltx);
+    AlifeSmartTerrainDeadObject::export_list(&self.dead_objects_infos, section_name, ltx);
+    AlifeSmartTerrainActorControl::export_optional(self.actor_control.as_ref(), section_name, ltx);
+    AlifeSmartTerrainRespawn::export_optional(self.respawn.as_ref(), section_name, ltx);
 
     Ok(())
   }
@@ -170,14 +156,21 @@ mod tests {
   use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
   use crate::data::alife::inherited::alife_object_space_restrictor::AlifeObjectSpaceRestrictor;
   use crate::data::alife::inherited::alife_smart_terrain::AlifeSmartTerrain;
+  use crate::data::alife::inherited::alife_smart_terrain_actor_control::AlifeSmartTerrainActorControl;
+  use crate::data::alife::inherited::alife_smart_terrain_dead_object::AlifeSmartTerrainDeadObject;
+  use crate::data::alife::inherited::alife_smart_terrain_job::AlifeSmartTerrainJob;
+  use crate::data::alife::inherited::alife_smart_terrain_respawn::AlifeSmartTerrainRespawn;
   use crate::data::alife::inherited::alife_smart_zone::AlifeSmartZone;
   use crate::data::generic::shape::Shape;
+  use crate::data::generic::time::Time;
   use crate::data::generic::vector_3d::Vector3d;
+  use crate::export::LtxImportExport;
   use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
   use xray_error::XRayResult;
+  use xray_ltx::Ltx;
   use xray_test_utils::utils::{
-    get_relative_test_sample_file_path, open_test_resource_as_slice,
-    overwrite_test_relative_resource_as_file,
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
   };
   use xray_test_utils::FileSlice;
 
@@ -211,13 +204,12 @@ mod tests {
           restrictor_type: 2,
         },
       },
-      arriving_objects_count: 0,
-      object_job_descriptors_count: 0,
-      dead_objects_infos_count: 0,
-      smart_terrain_actor_control: 0,
-      respawn_point: 0,
-      staying_objects_count: 0,
-      save_marker: 6,
+      arriving_objects: vec![],
+      object_job_descriptors: vec![],
+      dead_objects_infos: vec![],
+      actor_control: None,
+      respawn: None,
+      staying_objects: vec![],
     };
 
     original.write::<XRayByteOrder>(&mut writer)?;
@@ -235,13 +227,254 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 114 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeSmartTerrain::read::<XRayByteOrder>(&mut reader)?,
+      AlifeSmartTerrain::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
     Ok(())
   }
+
+  #[test]
+  fn test_read_write_populated() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String =
+      get_relative_test_sample_file_path(file!(), "read_write_populated.chunk");
+
+    let original: AlifeSmartTerrain = AlifeSmartTerrain {
+      base: AlifeSmartZone {
+        base: AlifeObjectSpaceRestrictor {
+          base: AlifeObjectAbstract {
+            game_vertex_id: 1002,
+            distance: 65.25,
+            direct_control: 31231,
+            level_vertex_id: 3213,
+            flags: 34,
+            custom_data: String::from("custom_data"),
+            story_id: 400,
+            spawn_story_id: 25,
+          },
+          shape: vec![
+            Shape::Sphere((Vector3d::new(3.5, -2.5, 11.5), 1.0)),
+            Shape::Box((
+              Vector3d::new(1.5, 1.1, 73.1),
+              Vector3d::new(5.1, 2.2, 3.3),
+              Vector3d::new(4.0, 6.0, 2.4),
+              Vector3d::new(9.2, 4.3, 3.0),
+            )),
+          ],
+          restrictor_type: 2,
+        },
+      },
+      arriving_objects: vec![1024, 2048],
+      object_job_descriptors: vec![
+        AlifeSmartTerrainJob {
+          object_id: 1024,
+          job_priority: 60,
+          job_id: 3,
+          begin_job: 1,
+          need_job: String::from("logic@walker_1"),
+        },
+        AlifeSmartTerrainJob {
+          object_id: 2048,
+          job_priority: 45,
+          job_id: 5,
+          begin_job: 0,
+          need_job: String::from("nil"),
+        },
+      ],
+      dead_objects_infos: vec![AlifeSmartTerrainDeadObject {
+        job_id: 4,
+        time: Time {
+          year: 12,
+          month: 6,
+          day: 24,
+          hour: 13,
+          minute: 45,
+          second: 30,
+          millis: 250,
+        },
+      }],
+      actor_control: Some(AlifeSmartTerrainActorControl {
+        status: 2,
+        idle_time: Time {
+          year: 12,
+          month: 6,
+          day: 24,
+          hour: 14,
+          minute: 5,
+          second: 10,
+          millis: 500,
+        },
+      }),
+      respawn: Some(AlifeSmartTerrainRespawn {
+        already_spawned: vec![
+          (String::from("stalker_novice"), 2),
+          (String::from("stalker_veteran"), 1),
+        ],
+        last_respawn_update: Some(Time {
+          year: 12,
+          month: 6,
+          day: 24,
+          hour: 12,
+          minute: 0,
+          second: 0,
+          millis: 0,
+        }),
+      }),
+      staying_objects: vec![1024, 4096],
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 214);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 214);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 214 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeSmartTerrain::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeSmartTerrain = AlifeSmartTerrain {
+      base: AlifeSmartZone {
+        base: AlifeObjectSpaceRestrictor {
+          base: AlifeObjectAbstract {
+            game_vertex_id: 1002,
+            distance: 65.25,
+            direct_control: 31231,
+            level_vertex_id: 3213,
+            flags: 34,
+            custom_data: String::from("custom_data"),
+            story_id: 400,
+            spawn_story_id: 25,
+          },
+          shape: vec![
+            Shape::Sphere((Vector3d::new(3.5, -2.5, 11.5), 1.0)),
+            Shape::Box((
+              Vector3d::new(1.5, 1.1, 73.1),
+              Vector3d::new(5.1, 2.2, 3.3),
+              Vector3d::new(4.0, 6.0, 2.4),
+              Vector3d::new(9.2, 4.3, 3.0),
+            )),
+          ],
+          restrictor_type: 2,
+        },
+      },
+      arriving_objects: vec![1024, 2048],
+      object_job_descriptors: vec![
+        AlifeSmartTerrainJob {
+          object_id: 1024,
+          job_priority: 60,
+          job_id: 3,
+          begin_job: 1,
+          need_job: String::from("logic@walker_1"),
+        },
+        AlifeSmartTerrainJob {
+          object_id: 2048,
+          job_priority: 45,
+          job_id: 5,
+          begin_job: 0,
+          need_job: String::from("nil"),
+        },
+      ],
+      dead_objects_infos: vec![AlifeSmartTerrainDeadObject {
+        job_id: 4,
+        time: Time {
+          year: 12,
+          month: 6,
+          day: 24,
+          hour: 13,
+          minute: 45,
+          second: 30,
+          millis: 250,
+        },
+      }],
+      actor_control: Some(AlifeSmartTerrainActorControl {
+        status: 2,
+        idle_time: Time {
+          year: 12,
+          month: 6,
+          day: 24,
+          hour: 14,
+          minute: 5,
+          second: 10,
+          millis: 500,
+        },
+      }),
+      respawn: Some(AlifeSmartTerrainRespawn {
+        already_spawned: vec![
+          (String::from("stalker_novice"), 2),
+          (String::from("stalker_veteran"), 1),
+        ],
+        last_respawn_update: Some(Time {
+          year: 12,
+          month: 6,
+          day: 24,
+          hour: 12,
+          minute: 0,
+          second: 0,
+          millis: 0,
+        }),
+      }),
+      staying_objects: vec![1024, 4096],
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    let mut imported: AlifeSmartTerrain = AlifeSmartTerrain::import("data", &source)?;
+
+    assert_eq!(imported, original);
+
+    imported.arriving_objects.push(4096);
+    imported.dead_objects_infos.clear();
+    imported.actor_control = None;
+
+    if let Some(respawn) = imported.respawn.as_mut() {
+      respawn.last_respawn_update = None;
+    }
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    imported.write::<XRayByteOrder>(&mut writer)?;
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(
+      AlifeSmartTerrain::read::<XRayByteOrder, _>(&mut reader)?,
+      imported
+    );
+
+    Ok(())
+  }
 }
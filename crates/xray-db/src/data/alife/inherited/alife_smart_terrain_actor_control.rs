@@ -0,0 +1,109 @@
+use crate::data::generic::time::Time;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{
+  ChunkDataSource, ChunkReadWrite, ChunkReadWriteOptional, ChunkReader, ChunkWriter,
+};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::assert_equal;
+
+/// Actor control state of base smart terrain.
+/// Represents `base_on_actor_control` data saved by smart terrain script.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeSmartTerrainActorControl {
+  pub status: u8,
+  pub idle_time: Time,
+}
+
+impl ChunkReadWrite for AlifeSmartTerrainActorControl {
+  /// Read smart terrain actor control from the chunk reader.
+  /// Data ends with save marker equal to size of the data, it is validated and not stored.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let data_start: u64 = reader.read_bytes_len();
+
+    let status: u8 = reader.read_u8()?;
+    let idle_time: Time = reader.read_xr::<T, _>()?;
+
+    let data_size: u64 = reader.read_bytes_len() - data_start;
+    let save_marker: u16 = reader.read_u16::<T>()?;
+
+    assert_equal(
+      save_marker as u64,
+      data_size,
+      "Unexpected data provided with smart terrain actor control save",
+    )?;
+
+    Ok(Self { status, idle_time })
+  }
+
+  /// Write smart terrain actor control into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    let data_start: usize = writer.bytes_written();
+
+    writer.write_u8(self.status)?;
+    writer.write_xr::<T, _>(&self.idle_time)?;
+    writer.write_u16::<T>(
+      u16::try_from(writer.bytes_written() - data_start).map_err(|_| {
+        XRayError::new_invalid_error("Expected smart terrain actor control size to fit into u16")
+      })?,
+    )?;
+
+    Ok(())
+  }
+}
+
+impl ChunkReadWriteOptional for AlifeSmartTerrainActorControl {
+  /// Read optional actor control, stored after u8 presence flag.
+  fn read_optional<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Option<Self>> {
+    if reader.read_u8()? == 1 {
+      Ok(Some(Self::read::<T, _>(reader)?))
+    } else {
+      Ok(None)
+    }
+  }
+
+  /// Write optional actor control with u8 presence flag.
+  fn write_optional<T: ByteOrder>(writer: &mut ChunkWriter, control: Option<&Self>) -> XRayResult {
+    if let Some(control) = control {
+      writer.write_u8(1)?;
+      control.write::<T>(writer)?;
+    } else {
+      writer.write_u8(0)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl AlifeSmartTerrainActorControl {
+  /// Import optional actor control from ltx config section.
+  pub fn import_optional(section: &Section) -> XRayResult<Option<Self>> {
+    if !read_ltx_field::<bool>("has_actor_control", section)? {
+      return Ok(None);
+    }
+
+    Ok(Some(Self {
+      status: read_ltx_field("actor_control.status", section)?,
+      idle_time: read_ltx_field("actor_control.idle_time", section)?,
+    }))
+  }
+
+  /// Export optional actor control to target ltx file section.
+  pub fn export_optional(control: Option<&Self>, section_name: &str, ltx: &mut Ltx) {
+    ltx
+      .with_section(section_name)
+      .set("has_actor_control", control.is_some().to_string());
+
+    if let Some(control) = control {
+      ltx
+        .with_section(section_name)
+        .set("actor_control.status", control.status.to_string())
+        .set("actor_control.idle_time", control.idle_time.to_string());
+    }
+  }
+}
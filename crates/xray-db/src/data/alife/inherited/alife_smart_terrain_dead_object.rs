@@ -0,0 +1,98 @@
+use crate::data::generic::time::Time;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+
+/// Death time of object linked to smart terrain job.
+/// Represents `dead_time` records saved by smart terrain script.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeSmartTerrainDeadObject {
+  pub job_id: u8,
+  pub time: Time,
+}
+
+impl ChunkReadWrite for AlifeSmartTerrainDeadObject {
+  /// Read smart terrain dead object info from the chunk reader.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    Ok(Self {
+      job_id: reader.read_u8()?,
+      time: reader.read_xr::<T, _>()?,
+    })
+  }
+
+  /// Write smart terrain dead object info into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u8(self.job_id)?;
+    writer.write_xr::<T, _>(&self.time)?;
+
+    Ok(())
+  }
+}
+
+impl ChunkReadWriteList for AlifeSmartTerrainDeadObject {
+  /// Read list of dead object infos, stored as u8 count followed by records.
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
+    let count: u8 = reader.read_u8()?;
+    let mut objects: Vec<Self> = Vec::with_capacity(count as usize);
+
+    for _ in 0..count {
+      objects.push(Self::read::<T, _>(reader)?);
+    }
+
+    Ok(objects)
+  }
+
+  /// Write list of dead object infos into the chunk writer.
+  fn write_list<T: ByteOrder>(writer: &mut ChunkWriter, objects: &[Self]) -> XRayResult {
+    writer.write_u8(u8::try_from(objects.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected smart terrain dead objects count to fit into u8")
+    })?)?;
+
+    for object in objects {
+      object.write::<T>(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl AlifeSmartTerrainDeadObject {
+  /// Import dead object infos from ltx config section.
+  pub fn import_list(section: &Section) -> XRayResult<Vec<Self>> {
+    let count: usize = read_ltx_field("dead_objects_infos_count", section)?;
+    let mut objects: Vec<Self> = Vec::with_capacity(count);
+
+    for index in 0..count {
+      let prefix: String = format!("dead_object_info.{index}");
+
+      objects.push(Self {
+        job_id: read_ltx_field(&format!("{prefix}.job_id"), section)?,
+        time: read_ltx_field(&format!("{prefix}.time"), section)?,
+      });
+    }
+
+    Ok(objects)
+  }
+
+  /// Export dead object infos to target ltx file section.
+  pub fn export_list(objects: &[Self], section_name: &str, ltx: &mut Ltx) {
+    ltx
+      .with_section(section_name)
+      .set("dead_objects_infos_count", objects.len().to_string());
+
+    for (index, object) in objects.iter().enumerate() {
+      let prefix: String = format!("dead_object_info.{index}");
+
+      ltx
+        .with_section(section_name)
+        .set(format!("{prefix}.job_id"), object.job_id.to_string())
+        .set(format!("{prefix}.time"), object.time.to_string());
+    }
+  }
+}
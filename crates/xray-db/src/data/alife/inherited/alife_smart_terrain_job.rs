@@ -0,0 +1,115 @@
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+
+/// Job descriptor of object assigned to smart terrain.
+/// Represents `npc_info` records saved by smart terrain script.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeSmartTerrainJob {
+  pub object_id: u16,
+  pub job_priority: u8,
+  pub job_id: u8,
+  pub begin_job: u8,
+  pub need_job: String,
+}
+
+impl ChunkReadWrite for AlifeSmartTerrainJob {
+  /// Read smart terrain job descriptor from the chunk reader.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    Ok(Self {
+      object_id: reader.read_u16::<T>()?,
+      job_priority: reader.read_u8()?,
+      job_id: reader.read_u8()?,
+      begin_job: reader.read_u8()?,
+      need_job: reader.read_w1251_string()?,
+    })
+  }
+
+  /// Write smart terrain job descriptor into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u16::<T>(self.object_id)?;
+    writer.write_u8(self.job_priority)?;
+    writer.write_u8(self.job_id)?;
+    writer.write_u8(self.begin_job)?;
+    writer.write_w1251_string(&self.need_job)?;
+
+    Ok(())
+  }
+}
+
+impl ChunkReadWriteList for AlifeSmartTerrainJob {
+  /// Read list of job descriptors, stored as u8 count followed by records.
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
+    let count: u8 = reader.read_u8()?;
+    let mut jobs: Vec<Self> = Vec::with_capacity(count as usize);
+
+    for _ in 0..count {
+      jobs.push(Self::read::<T, _>(reader)?);
+    }
+
+    Ok(jobs)
+  }
+
+  /// Write list of job descriptors into the chunk writer.
+  fn write_list<T: ByteOrder>(writer: &mut ChunkWriter, jobs: &[Self]) -> XRayResult {
+    writer.write_u8(u8::try_from(jobs.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected smart terrain job descriptors count to fit into u8")
+    })?)?;
+
+    for job in jobs {
+      job.write::<T>(writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl AlifeSmartTerrainJob {
+  /// Import job descriptors from ltx config section.
+  pub fn import_list(section: &Section) -> XRayResult<Vec<Self>> {
+    let count: usize = read_ltx_field("object_job_descriptors_count", section)?;
+    let mut jobs: Vec<Self> = Vec::with_capacity(count);
+
+    for index in 0..count {
+      let prefix: String = format!("object_job_descriptor.{index}");
+
+      jobs.push(Self {
+        object_id: read_ltx_field(&format!("{prefix}.object_id"), section)?,
+        job_priority: read_ltx_field(&format!("{prefix}.job_priority"), section)?,
+        job_id: read_ltx_field(&format!("{prefix}.job_id"), section)?,
+        begin_job: read_ltx_field(&format!("{prefix}.begin_job"), section)?,
+        need_job: read_ltx_field(&format!("{prefix}.need_job"), section)?,
+      });
+    }
+
+    Ok(jobs)
+  }
+
+  /// Export job descriptors to target ltx file section.
+  pub fn export_list(jobs: &[Self], section_name: &str, ltx: &mut Ltx) {
+    ltx
+      .with_section(section_name)
+      .set("object_job_descriptors_count", jobs.len().to_string());
+
+    for (index, job) in jobs.iter().enumerate() {
+      let prefix: String = format!("object_job_descriptor.{index}");
+
+      ltx
+        .with_section(section_name)
+        .set(format!("{prefix}.object_id"), job.object_id.to_string())
+        .set(
+          format!("{prefix}.job_priority"),
+          job.job_priority.to_string(),
+        )
+        .set(format!("{prefix}.job_id"), job.job_id.to_string())
+        .set(format!("{prefix}.begin_job"), job.begin_job.to_string())
+        .set(format!("{prefix}.need_job"), &job.need_job);
+    }
+  }
+}
@@ -0,0 +1,135 @@
+use crate::data::generic::time::Time;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{
+  ChunkDataSource, ChunkReadWrite, ChunkReadWriteOptional, ChunkReader, ChunkWriter,
+};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+
+/// Respawn state of smart terrain marked as respawn point.
+/// Represents `already_spawned` counts by section and `last_respawn_update` saved by smart
+/// terrain script.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeSmartTerrainRespawn {
+  pub already_spawned: Vec<(String, u8)>,
+  pub last_respawn_update: Option<Time>,
+}
+
+impl ChunkReadWrite for AlifeSmartTerrainRespawn {
+  /// Read smart terrain respawn state from the chunk reader.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let already_spawned_count: u8 = reader.read_u8()?;
+    let mut already_spawned: Vec<(String, u8)> = Vec::with_capacity(already_spawned_count as usize);
+
+    for _ in 0..already_spawned_count {
+      already_spawned.push((reader.read_w1251_string()?, reader.read_u8()?));
+    }
+
+    Ok(Self {
+      already_spawned,
+      last_respawn_update: reader.read_xr_optional::<T, Time>()?,
+    })
+  }
+
+  /// Write smart terrain respawn state into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u8(u8::try_from(self.already_spawned.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected smart terrain spawned sections count to fit into u8")
+    })?)?;
+
+    for (section, count) in &self.already_spawned {
+      writer.write_w1251_string(section)?;
+      writer.write_u8(*count)?;
+    }
+
+    writer.write_xr_optional::<T, Time>(self.last_respawn_update.as_ref())?;
+
+    Ok(())
+  }
+}
+
+impl ChunkReadWriteOptional for AlifeSmartTerrainRespawn {
+  /// Read optional respawn state, stored after u8 respawn point flag.
+  fn read_optional<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Option<Self>> {
+    if reader.read_u8()? == 1 {
+      Ok(Some(Self::read::<T, _>(reader)?))
+    } else {
+      Ok(None)
+    }
+  }
+
+  /// Write optional respawn state with u8 respawn point flag.
+  fn write_optional<T: ByteOrder>(writer: &mut ChunkWriter, respawn: Option<&Self>) -> XRayResult {
+    if let Some(respawn) = respawn {
+      writer.write_u8(1)?;
+      respawn.write::<T>(writer)?;
+    } else {
+      writer.write_u8(0)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl AlifeSmartTerrainRespawn {
+  /// Import optional respawn state from ltx config section.
+  pub fn import_optional(section: &Section) -> XRayResult<Option<Self>> {
+    if !read_ltx_field::<bool>("is_respawn_point", section)? {
+      return Ok(None);
+    }
+
+    let count: usize = read_ltx_field("respawn.already_spawned_count", section)?;
+    let mut already_spawned: Vec<(String, u8)> = Vec::with_capacity(count);
+
+    for index in 0..count {
+      let prefix: String = format!("respawn.already_spawned.{index}");
+
+      already_spawned.push((
+        read_ltx_field(&format!("{prefix}.section"), section)?,
+        read_ltx_field(&format!("{prefix}.count"), section)?,
+      ));
+    }
+
+    Ok(Some(Self {
+      already_spawned,
+      last_respawn_update: Time::from_str_optional(&read_ltx_field::<String>(
+        "respawn.last_respawn_update",
+        section,
+      )?)?,
+    }))
+  }
+
+  /// Export optional respawn state to target ltx file section.
+  pub fn export_optional(respawn: Option<&Self>, section_name: &str, ltx: &mut Ltx) {
+    ltx
+      .with_section(section_name)
+      .set("is_respawn_point", respawn.is_some().to_string());
+
+    if let Some(respawn) = respawn {
+      ltx
+        .with_section(section_name)
+        .set(
+          "respawn.already_spawned_count",
+          respawn.already_spawned.len().to_string(),
+        )
+        .set(
+          "respawn.last_respawn_update",
+          Time::export_to_string(respawn.last_respawn_update.as_ref()),
+        );
+
+      for (index, (spawned_section, count)) in respawn.already_spawned.iter().enumerate() {
+        let prefix: String = format!("respawn.already_spawned.{index}");
+
+        ltx
+          .with_section(section_name)
+          .set(format!("{prefix}.section"), spawned_section.clone())
+          .set(format!("{prefix}.count"), count.to_string());
+      }
+    }
+  }
+}
@@ -0,0 +1,536 @@
+use crate::data::alife::inherited::alife_object_human_stalker::AlifeObjectHumanStalker;
+use crate::export::LtxImportExport;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::assert_equal;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeStalker {
+  pub base: AlifeObjectHumanStalker,
+  pub old_level_vertex_id: String,
+  pub death_dropped: u8,
+  pub save_marker: u16,
+}
+
+impl ChunkReadWrite for AlifeStalker {
+  /// Read stalker data from the chunk.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let base: AlifeObjectHumanStalker = reader.read_xr::<T, _>()?;
+    let script_data_start: u64 = reader.read_bytes_len();
+
+    let old_level_vertex_id: String = reader.read_w1251_string()?;
+    let death_dropped: u8 = reader.read_u8()?;
+
+    let script_data_size: u64 = reader.read_bytes_len() - script_data_start;
+    let save_marker: u16 = reader.read_u16::<T>()?;
+
+    assert_equal(
+      save_marker as u64,
+      script_data_size,
+      "Unexpected script data provided for stalker",
+    )?;
+
+    Ok(Self {
+      base,
+      old_level_vertex_id,
+      death_dropped,
+      save_marker,
+    })
+  }
+
+  /// Write stalker data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_xr::<T, _>(&self.base)?;
+    writer.write_w1251_string(&self.old_level_vertex_id)?;
+    writer.write_u8(self.death_dropped)?;
+    writer.write_u16::<T>(self.save_marker)?;
+
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeStalker {
+  /// Import stalker data from ltx config section.
+  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
+    let section: &Section = ltx.section(section_name).ok_or_else(|| {
+      XRayError::new_parsing_error(format!(
+        "ALife object '{}' should be defined in ltx file ({})",
+        section_name,
+        file!()
+      ))
+    })?;
+
+    Ok(Self {
+      base: AlifeObjectHumanStalker::import(section_name, ltx)?,
+      old_level_vertex_id: read_ltx_field("stalker.old_level_vertex_id", section)?,
+      death_dropped: read_ltx_field("stalker.death_dropped", section)?,
+      save_marker: read_ltx_field("stalker.save_marker", section)?,
+    })
+  }
+
+  /// Export object data into ltx file.
+  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    self.base.export(section_name, ltx)?;
+
+    ltx
+      .with_section(section_name)
+      .set("stalker.old_level_vertex_id", &self.old_level_vertex_id)
+      .set("stalker.death_dropped", self.death_dropped.to_string())
+      .set("stalker.save_marker", self.save_marker.to_string());
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_human_abstract::AlifeObjectHumanAbstract;
+  use crate::data::alife::inherited::alife_object_human_stalker::AlifeObjectHumanStalker;
+  use crate::data::alife::inherited::alife_object_monster_abstract::AlifeObjectMonsterAbstract;
+  use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+  use crate::data::alife::inherited::alife_object_trader_abstract::AlifeObjectTraderAbstract;
+  use crate::data::alife::inherited::alife_stalker::AlifeStalker;
+  use crate::export::LtxImportExport;
+  use byteorder::WriteBytesExt;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeStalker = AlifeStalker {
+      base: AlifeObjectHumanStalker {
+        base: AlifeObjectHumanAbstract {
+          base: AlifeObjectMonsterAbstract {
+            base: AlifeObjectCreature {
+              base: AlifeObjectDynamicVisual {
+                base: AlifeObjectAbstract {
+                  game_vertex_id: 1200,
+                  distance: 0.5,
+                  direct_control: 1,
+                  level_vertex_id: 124153,
+                  flags: 63,
+                  custom_data: String::from("[logic]\\nactive = walker@base"),
+                  story_id: 1201,
+                  spawn_story_id: 1202,
+                },
+                visual_name: String::from("actors\\stalker_bandit\\stalker_bandit_3"),
+                visual_flags: 0,
+              },
+              team: 0,
+              squad: 0,
+              group: 0,
+              health: 1.0,
+              dynamic_out_restrictions: vec![],
+              dynamic_in_restrictions: vec![],
+              killer_id: 65535,
+              game_death_time: 0,
+            },
+            out_space_restrictors: String::from("out-restrictor"),
+            in_space_restrictors: String::from("in-restrictor"),
+            smart_terrain_id: 65535,
+            smart_terrain_task_active: 0,
+          },
+          trader: AlifeObjectTraderAbstract {
+            money: 1200,
+            specific_character: String::from("zat_b7_bandit_boss_sultan"),
+            trader_flags: 0,
+            character_profile: String::from("zat_b7_bandit_boss_sultan"),
+            community_index: 3,
+            rank: 700,
+            reputation: 0,
+            character_name: String::from("character-name"),
+            dead_body_can_take: 1,
+            dead_body_closed: 0,
+          },
+          equipment_preferences: vec![0, 0, 0, 0, 0],
+          main_weapon_preferences: vec![0, 0, 0, 0],
+        },
+        skeleton: AlifeObjectSkeleton {
+          name: String::from("$editor"),
+          flags: 0,
+          source_id: 65535,
+          bones: None,
+        },
+        start_dialog: String::from("start-dialog"),
+      },
+      old_level_vertex_id: String::from("124153"),
+      death_dropped: 0,
+      save_marker: 8,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 293);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 293);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 293 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeStalker::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  /// Stalker spawn packet data written field by field in order of engine `STATE_Write`:
+  /// trader abstract, object, visual, creature, monster abstract, human abstract, skeleton,
+  /// human stalker and se_stalker script data.
+  fn get_packet() -> XRayResult<Vec<u8>> {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    // CSE_ALifeTraderAbstract:
+    writer.write_u32::<XRayByteOrder>(3500)?;
+    writer.write_w1251_string("zat_b30_owl_stalker_trader")?;
+    writer.write_u32::<XRayByteOrder>(1)?;
+    writer.write_w1251_string("zat_b30_owl_stalker_trader")?;
+    writer.write_i32::<XRayByteOrder>(2)?;
+    writer.write_i32::<XRayByteOrder>(450)?;
+    writer.write_i32::<XRayByteOrder>(0)?;
+    writer.write_w1251_string("")?;
+    writer.write_u8(0)?;
+    writer.write_u8(1)?;
+    // CSE_ALifeObject:
+    writer.write_u16::<XRayByteOrder>(1542)?;
+    writer.write_f32::<XRayByteOrder>(12.25)?;
+    writer.write_u32::<XRayByteOrder>(1)?;
+    writer.write_u32::<XRayByteOrder>(305514)?;
+    writer.write_u32::<XRayByteOrder>(7)?;
+    writer.write_w1251_string("[logic]\nactive = walker@guard")?;
+    writer.write_u32::<XRayByteOrder>(u32::MAX)?;
+    writer.write_u32::<XRayByteOrder>(u32::MAX)?;
+    // CSE_Visual:
+    writer.write_w1251_string("actors\\stalker_neutral\\stalker_neutral_1")?;
+    writer.write_u8(0)?;
+    // CSE_ALifeCreatureAbstract:
+    writer.write_u8(0)?;
+    writer.write_u8(1)?;
+    writer.write_u8(2)?;
+    writer.write_f32::<XRayByteOrder>(1.0)?;
+    writer.write_u32::<XRayByteOrder>(1)?;
+    writer.write_u16::<XRayByteOrder>(812)?;
+    writer.write_u32::<XRayByteOrder>(0)?;
+    writer.write_u16::<XRayByteOrder>(u16::MAX)?;
+    writer.write_u64::<XRayByteOrder>(0)?;
+    // CSE_ALifeMonsterAbstract:
+    writer.write_w1251_string("zat_b30_restrictor")?;
+    writer.write_w1251_string("")?;
+    writer.write_u16::<XRayByteOrder>(u16::MAX)?;
+    writer.write_u8(0)?;
+    // CSE_ALifeHumanAbstract:
+    writer.write_u32::<XRayByteOrder>(5)?;
+    writer.write_all(&[1, 2, 3, 4, 5])?;
+    writer.write_u32::<XRayByteOrder>(4)?;
+    writer.write_all(&[4, 3, 2, 1])?;
+    // CSE_PHSkeleton:
+    writer.write_w1251_string("$editor")?;
+    writer.write_u8(0)?;
+    writer.write_u16::<XRayByteOrder>(u16::MAX)?;
+    // CSE_ALifeHumanStalker:
+    writer.write_w1251_string("")?;
+    // se_stalker:
+    writer.write_w1251_string("305514")?;
+    writer.write_u8(0)?;
+    writer.write_u16::<XRayByteOrder>(8)?;
+
+    writer.flush_raw_into_buffer()
+  }
+
+  #[test]
+  fn test_read_packet() -> XRayResult {
+    let data: Vec<u8> = get_packet()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    let stalker: AlifeStalker = AlifeStalker::read::<XRayByteOrder, _>(&mut reader)?;
+
+    assert!(reader.is_ended());
+
+    let human: &AlifeObjectHumanAbstract = &stalker.base.base;
+
+    assert_eq!(human.trader.money, 3500);
+    assert_eq!(human.trader.character_profile, "zat_b30_owl_stalker_trader");
+    assert_eq!(human.trader.rank, 450);
+    assert_eq!(human.trader.dead_body_closed, 1);
+    assert_eq!(human.base.base.base.base.game_vertex_id, 1542);
+    assert_eq!(human.base.base.base.base.level_vertex_id, 305514);
+    assert_eq!(human.base.base.squad, 1);
+    assert_eq!(human.base.base.dynamic_out_restrictions, vec![812]);
+    assert_eq!(human.base.out_space_restrictors, "zat_b30_restrictor");
+    assert_eq!(human.equipment_preferences, vec![1, 2, 3, 4, 5]);
+    assert_eq!(human.main_weapon_preferences, vec![4, 3, 2, 1]);
+    assert_eq!(stalker.base.skeleton.name, "$editor");
+    assert_eq!(stalker.old_level_vertex_id, "305514");
+    assert_eq!(stalker.save_marker, 8);
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    stalker.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.flush_raw_into_buffer()?, data);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_invalid_save_marker() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let mut original: AlifeStalker = AlifeStalker {
+      base: AlifeObjectHumanStalker {
+        base: AlifeObjectHumanAbstract {
+          base: AlifeObjectMonsterAbstract {
+            base: AlifeObjectCreature {
+              base: AlifeObjectDynamicVisual {
+                base: AlifeObjectAbstract {
+                  game_vertex_id: 1200,
+                  distance: 0.5,
+                  direct_control: 1,
+                  level_vertex_id: 124153,
+                  flags: 63,
+                  custom_data: String::from("[logic]\\nactive = walker@base"),
+                  story_id: 1201,
+                  spawn_story_id: 1202,
+                },
+                visual_name: String::from("actors\\stalker_bandit\\stalker_bandit_3"),
+                visual_flags: 0,
+              },
+              team: 0,
+              squad: 0,
+              group: 0,
+              health: 1.0,
+              dynamic_out_restrictions: vec![],
+              dynamic_in_restrictions: vec![],
+              killer_id: 65535,
+              game_death_time: 0,
+            },
+            out_space_restrictors: String::from("out-restrictor"),
+            in_space_restrictors: String::from("in-restrictor"),
+            smart_terrain_id: 65535,
+            smart_terrain_task_active: 0,
+          },
+          trader: AlifeObjectTraderAbstract {
+            money: 1200,
+            specific_character: String::from("zat_b7_bandit_boss_sultan"),
+            trader_flags: 0,
+            character_profile: String::from("zat_b7_bandit_boss_sultan"),
+            community_index: 3,
+            rank: 700,
+            reputation: 0,
+            character_name: String::from("character-name"),
+            dead_body_can_take: 1,
+            dead_body_closed: 0,
+          },
+          equipment_preferences: vec![0, 0, 0, 0, 0],
+          main_weapon_preferences: vec![0, 0, 0, 0],
+        },
+        skeleton: AlifeObjectSkeleton {
+          name: String::from("$editor"),
+          flags: 0,
+          source_id: 65535,
+          bones: None,
+        },
+        start_dialog: String::from("start-dialog"),
+      },
+      old_level_vertex_id: String::from("124153"),
+      death_dropped: 0,
+      save_marker: 8,
+    };
+
+    original.save_marker = 7;
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert!(AlifeStalker::read::<XRayByteOrder, _>(&mut reader).is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeStalker = AlifeStalker {
+      base: AlifeObjectHumanStalker {
+        base: AlifeObjectHumanAbstract {
+          base: AlifeObjectMonsterAbstract {
+            base: AlifeObjectCreature {
+              base: AlifeObjectDynamicVisual {
+                base: AlifeObjectAbstract {
+                  game_vertex_id: 25,
+                  distance: 0.5,
+                  direct_control: 1,
+                  level_vertex_id: 124153,
+                  flags: 63,
+                  custom_data: String::from("[logic]\\nactive = walker@base"),
+                  story_id: 26,
+                  spawn_story_id: 27,
+                },
+                visual_name: String::from("actors\\stalker_bandit\\stalker_bandit_3"),
+                visual_flags: 0,
+              },
+              team: 0,
+              squad: 0,
+              group: 0,
+              health: 1.0,
+              dynamic_out_restrictions: vec![],
+              dynamic_in_restrictions: vec![],
+              killer_id: 65535,
+              game_death_time: 0,
+            },
+            out_space_restrictors: String::from("out-restrictor"),
+            in_space_restrictors: String::from("in-restrictor"),
+            smart_terrain_id: 65535,
+            smart_terrain_task_active: 0,
+          },
+          trader: AlifeObjectTraderAbstract {
+            money: 1200,
+            specific_character: String::from("zat_b7_bandit_boss_sultan"),
+            trader_flags: 0,
+            character_profile: String::from("zat_b7_bandit_boss_sultan"),
+            community_index: 3,
+            rank: 700,
+            reputation: 0,
+            character_name: String::from("character-name"),
+            dead_body_can_take: 1,
+            dead_body_closed: 0,
+          },
+          equipment_preferences: vec![0, 0, 0, 0, 0],
+          main_weapon_preferences: vec![0, 0, 0, 0],
+        },
+        skeleton: AlifeObjectSkeleton {
+          name: String::from("$editor"),
+          flags: 0,
+          source_id: 65535,
+          bones: None,
+        },
+        start_dialog: String::from("start-dialog"),
+      },
+      old_level_vertex_id: String::from("124153"),
+      death_dropped: 0,
+      save_marker: 8,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeStalker::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeStalker = AlifeStalker {
+      base: AlifeObjectHumanStalker {
+        base: AlifeObjectHumanAbstract {
+          base: AlifeObjectMonsterAbstract {
+            base: AlifeObjectCreature {
+              base: AlifeObjectDynamicVisual {
+                base: AlifeObjectAbstract {
+                  game_vertex_id: 36,
+                  distance: 0.5,
+                  direct_control: 1,
+                  level_vertex_id: 124153,
+                  flags: 63,
+                  custom_data: String::from("[logic]\\nactive = walker@base"),
+                  story_id: 37,
+                  spawn_story_id: 38,
+                },
+                visual_name: String::from("actors\\stalker_bandit\\stalker_bandit_3"),
+                visual_flags: 0,
+              },
+              team: 0,
+              squad: 0,
+              group: 0,
+              health: 1.0,
+              dynamic_out_restrictions: vec![],
+              dynamic_in_restrictions: vec![],
+              killer_id: 65535,
+              game_death_time: 0,
+            },
+            out_space_restrictors: String::from("out-restrictor"),
+            in_space_restrictors: String::from("in-restrictor"),
+            smart_terrain_id: 65535,
+            smart_terrain_task_active: 0,
+          },
+          trader: AlifeObjectTraderAbstract {
+            money: 1200,
+            specific_character: String::from("zat_b7_bandit_boss_sultan"),
+            trader_flags: 0,
+            character_profile: String::from("zat_b7_bandit_boss_sultan"),
+            community_index: 3,
+            rank: 700,
+            reputation: 0,
+            character_name: String::from("character-name"),
+            dead_body_can_take: 1,
+            dead_body_closed: 0,
+          },
+          equipment_preferences: vec![0, 0, 0, 0, 0],
+          main_weapon_preferences: vec![0, 0, 0, 0],
+        },
+        skeleton: AlifeObjectSkeleton {
+          name: String::from("$editor"),
+          flags: 0,
+          source_id: 65535,
+          bones: None,
+        },
+        start_dialog: String::from("start-dialog"),
+      },
+      old_level_vertex_id: String::from("124153"),
+      death_dropped: 0,
+      save_marker: 8,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(serde_json::from_str::<AlifeStalker>(&serialized)?, original);
+
+    Ok(())
+  }
+}
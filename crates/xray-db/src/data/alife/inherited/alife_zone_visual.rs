@@ -5,7 +5,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -21,7 +21,7 @@ pub struct AlifeZoneVisual {
 
 impl ChunkReadWrite for AlifeZoneVisual {
   /// Read visual zone data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       base: reader.read_xr::<T, _>()?,
       visual: reader.read_xr::<T, _>()?,
@@ -173,10 +173,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 145 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeZoneVisual::read::<XRayByteOrder>(&mut reader)?,
+      AlifeZoneVisual::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -247,10 +248,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 190 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      AlifeZoneVisual::read::<XRayByteOrder>(&mut reader)?,
+      AlifeZoneVisual::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
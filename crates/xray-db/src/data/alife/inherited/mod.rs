@@ -2,23 +2,30 @@ pub(crate) mod alife_actor;
 pub(crate) mod alife_anomalous_zone;
 pub(crate) mod alife_graph_point;
 pub(crate) mod alife_level_changer;
+pub(crate) mod alife_monster;
 pub(crate) mod alife_object_abstract;
 pub(crate) mod alife_object_actor;
 pub(crate) mod alife_object_anomaly_zone;
 pub(crate) mod alife_object_breakable;
+pub(crate) mod alife_object_car;
 pub(crate) mod alife_object_climable;
 pub(crate) mod alife_object_creature;
 pub(crate) mod alife_object_custom_zone;
 pub(crate) mod alife_object_dynamic;
 pub(crate) mod alife_object_dynamic_visual;
+pub(crate) mod alife_object_flesh_group;
+pub(crate) mod alife_object_group_abstract;
 pub(crate) mod alife_object_hanging_lamp;
 pub(crate) mod alife_object_helicopter;
+pub(crate) mod alife_object_human_abstract;
+pub(crate) mod alife_object_human_stalker;
 pub(crate) mod alife_object_inventory_box;
 pub(crate) mod alife_object_item;
 pub(crate) mod alife_object_item_ammo;
 pub(crate) mod alife_object_item_artefact;
 pub(crate) mod alife_object_item_custom_outfit;
 pub(crate) mod alife_object_item_detector;
+pub(crate) mod alife_object_item_document;
 pub(crate) mod alife_object_item_explosive;
 pub(crate) mod alife_object_item_grenade;
 pub(crate) mod alife_object_item_helmet;
@@ -27,17 +34,32 @@ pub(crate) mod alife_object_item_weapon;
 pub(crate) mod alife_object_item_weapon_magazined;
 pub(crate) mod alife_object_item_weapon_magazined_wgl;
 pub(crate) mod alife_object_item_weapon_shotgun;
+pub(crate) mod alife_object_monster_abstract;
+pub(crate) mod alife_object_monster_base;
+pub(crate) mod alife_object_monster_rat;
 pub(crate) mod alife_object_motion;
+pub(crate) mod alife_object_ph_skeleton;
 pub(crate) mod alife_object_physic;
+pub(crate) mod alife_object_rat_group;
 pub(crate) mod alife_object_shape;
 pub(crate) mod alife_object_skeleton;
+pub(crate) mod alife_object_skeleton_bones;
 pub(crate) mod alife_object_smart_cover;
 pub(crate) mod alife_object_space_restrictor;
+pub(crate) mod alife_object_spawn_group;
+pub(crate) mod alife_object_spectator;
+pub(crate) mod alife_object_team_base_zone;
 pub(crate) mod alife_object_torrid_zone;
+pub(crate) mod alife_object_trader;
 pub(crate) mod alife_object_trader_abstract;
 pub(crate) mod alife_object_visual;
 pub(crate) mod alife_smart_cover;
 pub(crate) mod alife_smart_cover_loophole;
 pub(crate) mod alife_smart_terrain;
+pub(crate) mod alife_smart_terrain_actor_control;
+pub(crate) mod alife_smart_terrain_dead_object;
+pub(crate) mod alife_smart_terrain_job;
+pub(crate) mod alife_smart_terrain_respawn;
 pub(crate) mod alife_smart_zone;
+pub(crate) mod alife_stalker;
 pub(crate) mod alife_zone_visual;
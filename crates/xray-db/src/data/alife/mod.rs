@@ -1,3 +1,5 @@
 pub(crate) mod alife_object;
 pub(crate) mod alife_object_inherited;
+pub(crate) mod alife_object_update;
 pub(crate) mod inherited;
+pub(crate) mod update;
@@ -0,0 +1,136 @@
+use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+/// Outfit and helmet condition is quantized into single byte.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "update.item_custom_outfit.")]
+pub struct AlifeObjectItemCustomOutfitUpdate {
+  #[ltx(inherit)]
+  pub base: AlifeObjectPhysicStateUpdate,
+  pub condition: u8,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::update::alife_object_item_custom_outfit_update::AlifeObjectItemCustomOutfitUpdate;
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectItemCustomOutfitUpdate = AlifeObjectItemCustomOutfitUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      condition: 37,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 2);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 2);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 2 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectItemCustomOutfitUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectItemCustomOutfitUpdate = AlifeObjectItemCustomOutfitUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      condition: 25,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(
+      AlifeObjectItemCustomOutfitUpdate::import("data", &source)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectItemCustomOutfitUpdate = AlifeObjectItemCustomOutfitUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      condition: 36,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectItemCustomOutfitUpdate>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,135 @@
+use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "update.item_torch.")]
+pub struct AlifeObjectItemTorchUpdate {
+  #[ltx(inherit)]
+  pub base: AlifeObjectPhysicStateUpdate,
+  pub flags: u8,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::update::alife_object_item_torch_update::AlifeObjectItemTorchUpdate;
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectItemTorchUpdate = AlifeObjectItemTorchUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      flags: 5,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 2);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 2);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 2 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectItemTorchUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectItemTorchUpdate = AlifeObjectItemTorchUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      flags: 1,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(
+      AlifeObjectItemTorchUpdate::import("data", &source)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectItemTorchUpdate = AlifeObjectItemTorchUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      flags: 4,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectItemTorchUpdate>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,248 @@
+use crate::data::alife::update::alife_object_item_weapon_magazined_update::AlifeObjectItemWeaponMagazinedUpdate;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_db_derive::LtxImportExport;
+use xray_error::XRayResult;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "update.item_weapon_magazined_wgl.")]
+pub struct AlifeObjectItemWeaponMagazinedWglUpdate {
+  #[ltx(inherit)]
+  pub base: AlifeObjectItemWeaponMagazinedUpdate,
+  pub grenade_mode: u8,
+}
+
+impl ChunkReadWrite for AlifeObjectItemWeaponMagazinedWglUpdate {
+  /// Read weapon with grenade launcher update data from the chunk.
+  /// Grenade mode is stored before inherited weapon data.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let grenade_mode: u8 = reader.read_u8()?;
+
+    Ok(Self {
+      base: reader.read_xr::<T, _>()?,
+      grenade_mode,
+    })
+  }
+
+  /// Write weapon with grenade launcher update data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u8(self.grenade_mode)?;
+    writer.write_xr::<T, _>(&self.base)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::update::alife_object_item_weapon_magazined_update::AlifeObjectItemWeaponMagazinedUpdate;
+  use crate::data::alife::update::alife_object_item_weapon_magazined_wgl_update::AlifeObjectItemWeaponMagazinedWglUpdate;
+  use crate::data::alife::update::alife_object_item_weapon_update::AlifeObjectItemWeaponUpdate;
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectItemWeaponMagazinedWglUpdate =
+      AlifeObjectItemWeaponMagazinedWglUpdate {
+        base: AlifeObjectItemWeaponMagazinedUpdate {
+          base: AlifeObjectItemWeaponUpdate {
+            base: AlifeObjectPhysicStateUpdate {
+              num_items: 0,
+              position: None,
+              quaternion: vec![],
+              angular_velocity: vec![],
+              linear_velocity: vec![],
+            },
+            condition: 255,
+            weapon_flags: 0,
+            ammo_elapsed: 37,
+            addon_flags: 2,
+            ammo_type: 1,
+            weapon_state: 0,
+            weapon_zoom: 0,
+          },
+          current_fire_mode: 1,
+        },
+        grenade_mode: 1,
+      };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 11);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 11);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 11 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectItemWeaponMagazinedWglUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_packet() -> XRayResult {
+    // Grenade mode, inventory item states count, condition (q8), weapon flags, ammo elapsed,
+    // addon flags, ammo type, weapon state, zoom and current fire mode.
+    let data: Vec<u8> = vec![1, 0, 255, 0, 30, 0, 2, 1, 0, 0, 3];
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    let mut expected: AlifeObjectItemWeaponMagazinedWglUpdate =
+      AlifeObjectItemWeaponMagazinedWglUpdate {
+        base: AlifeObjectItemWeaponMagazinedUpdate {
+          base: AlifeObjectItemWeaponUpdate {
+            base: AlifeObjectPhysicStateUpdate {
+              num_items: 0,
+              position: None,
+              quaternion: vec![],
+              angular_velocity: vec![],
+              linear_velocity: vec![],
+            },
+            condition: 255,
+            weapon_flags: 0,
+            ammo_elapsed: 30,
+            addon_flags: 2,
+            ammo_type: 1,
+            weapon_state: 0,
+            weapon_zoom: 0,
+          },
+          current_fire_mode: 1,
+        },
+        grenade_mode: 1,
+      };
+
+    expected.base.current_fire_mode = 3;
+
+    assert_eq!(
+      AlifeObjectItemWeaponMagazinedWglUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      expected
+    );
+    assert!(reader.is_ended());
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    expected.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.flush_raw_into_buffer()?, data);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectItemWeaponMagazinedWglUpdate =
+      AlifeObjectItemWeaponMagazinedWglUpdate {
+        base: AlifeObjectItemWeaponMagazinedUpdate {
+          base: AlifeObjectItemWeaponUpdate {
+            base: AlifeObjectPhysicStateUpdate {
+              num_items: 0,
+              position: None,
+              quaternion: vec![],
+              angular_velocity: vec![],
+              linear_velocity: vec![],
+            },
+            condition: 255,
+            weapon_flags: 0,
+            ammo_elapsed: 25,
+            addon_flags: 2,
+            ammo_type: 1,
+            weapon_state: 0,
+            weapon_zoom: 0,
+          },
+          current_fire_mode: 1,
+        },
+        grenade_mode: 1,
+      };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(
+      AlifeObjectItemWeaponMagazinedWglUpdate::import("data", &source)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectItemWeaponMagazinedWglUpdate =
+      AlifeObjectItemWeaponMagazinedWglUpdate {
+        base: AlifeObjectItemWeaponMagazinedUpdate {
+          base: AlifeObjectItemWeaponUpdate {
+            base: AlifeObjectPhysicStateUpdate {
+              num_items: 0,
+              position: None,
+              quaternion: vec![],
+              angular_velocity: vec![],
+              linear_velocity: vec![],
+            },
+            condition: 255,
+            weapon_flags: 0,
+            ammo_elapsed: 36,
+            addon_flags: 2,
+            ammo_type: 1,
+            weapon_state: 0,
+            weapon_zoom: 0,
+          },
+          current_fire_mode: 1,
+        },
+        grenade_mode: 1,
+      };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectItemWeaponMagazinedWglUpdate>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,235 @@
+use crate::data::alife::update::alife_object_item_weapon_magazined_update::AlifeObjectItemWeaponMagazinedUpdate;
+use crate::export::LtxImportExport;
+use crate::file_import::read_ltx_field;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::{vector_from_string, vector_to_string};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectItemWeaponShotgunUpdate {
+  pub base: AlifeObjectItemWeaponMagazinedUpdate,
+  pub ammo_ids: Vec<u8>,
+}
+
+impl ChunkReadWrite for AlifeObjectItemWeaponShotgunUpdate {
+  /// Read shotgun update data from the chunk, loaded ammo types are stored as u8 count + u8 list.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let base: AlifeObjectItemWeaponMagazinedUpdate = reader.read_xr::<T, _>()?;
+    let count: u8 = reader.read_u8()?;
+
+    Ok(Self {
+      base,
+      ammo_ids: reader.read_bytes(count as usize)?,
+    })
+  }
+
+  /// Write shotgun update data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_xr::<T, _>(&self.base)?;
+    writer.write_u8(u8::try_from(self.ammo_ids.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected shotgun ammo ids count to fit into u8")
+    })?)?;
+    writer.write_all(&self.ammo_ids)?;
+
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeObjectItemWeaponShotgunUpdate {
+  /// Import shotgun update data from ltx config section.
+  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
+    let section: &Section = ltx.section(section_name).ok_or_else(|| {
+      XRayError::new_parsing_error(format!(
+        "ALife object update '{}' should be defined in ltx file ({})",
+        section_name,
+        file!()
+      ))
+    })?;
+
+    Ok(Self {
+      base: AlifeObjectItemWeaponMagazinedUpdate::import(section_name, ltx)?,
+      ammo_ids: vector_from_string(&read_ltx_field::<String>(
+        "update.item_weapon_shotgun.ammo_ids",
+        section,
+      )?)?,
+    })
+  }
+
+  /// Export shotgun update data into ltx file.
+  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    self.base.export(section_name, ltx)?;
+
+    ltx.with_section(section_name).set(
+      "update.item_weapon_shotgun.ammo_ids",
+      vector_to_string(&self.ammo_ids),
+    );
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::update::alife_object_item_weapon_magazined_update::AlifeObjectItemWeaponMagazinedUpdate;
+  use crate::data::alife::update::alife_object_item_weapon_shotgun_update::AlifeObjectItemWeaponShotgunUpdate;
+  use crate::data::alife::update::alife_object_item_weapon_update::AlifeObjectItemWeaponUpdate;
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectItemWeaponShotgunUpdate = AlifeObjectItemWeaponShotgunUpdate {
+      base: AlifeObjectItemWeaponMagazinedUpdate {
+        base: AlifeObjectItemWeaponUpdate {
+          base: AlifeObjectPhysicStateUpdate {
+            num_items: 0,
+            position: None,
+            quaternion: vec![],
+            angular_velocity: vec![],
+            linear_velocity: vec![],
+          },
+          condition: 255,
+          weapon_flags: 0,
+          ammo_elapsed: 37,
+          addon_flags: 2,
+          ammo_type: 1,
+          weapon_state: 0,
+          weapon_zoom: 0,
+        },
+        current_fire_mode: 1,
+      },
+      ammo_ids: vec![0, 1, 1, 0],
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 15);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 15);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 15 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectItemWeaponShotgunUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectItemWeaponShotgunUpdate = AlifeObjectItemWeaponShotgunUpdate {
+      base: AlifeObjectItemWeaponMagazinedUpdate {
+        base: AlifeObjectItemWeaponUpdate {
+          base: AlifeObjectPhysicStateUpdate {
+            num_items: 0,
+            position: None,
+            quaternion: vec![],
+            angular_velocity: vec![],
+            linear_velocity: vec![],
+          },
+          condition: 255,
+          weapon_flags: 0,
+          ammo_elapsed: 25,
+          addon_flags: 2,
+          ammo_type: 1,
+          weapon_state: 0,
+          weapon_zoom: 0,
+        },
+        current_fire_mode: 1,
+      },
+      ammo_ids: vec![0, 1, 1, 0],
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(
+      AlifeObjectItemWeaponShotgunUpdate::import("data", &source)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectItemWeaponShotgunUpdate = AlifeObjectItemWeaponShotgunUpdate {
+      base: AlifeObjectItemWeaponMagazinedUpdate {
+        base: AlifeObjectItemWeaponUpdate {
+          base: AlifeObjectPhysicStateUpdate {
+            num_items: 0,
+            position: None,
+            quaternion: vec![],
+            angular_velocity: vec![],
+            linear_velocity: vec![],
+          },
+          condition: 255,
+          weapon_flags: 0,
+          ammo_elapsed: 36,
+          addon_flags: 2,
+          ammo_type: 1,
+          weapon_state: 0,
+          weapon_zoom: 0,
+        },
+        current_fire_mode: 1,
+      },
+      ammo_ids: vec![0, 1, 1, 0],
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectItemWeaponShotgunUpdate>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,159 @@
+use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "update.item_weapon.")]
+pub struct AlifeObjectItemWeaponUpdate {
+  #[ltx(inherit)]
+  pub base: AlifeObjectPhysicStateUpdate,
+  pub condition: u8,
+  pub weapon_flags: u8,
+  pub ammo_elapsed: u16,
+  pub addon_flags: u8,
+  pub ammo_type: u8,
+  pub weapon_state: u8,
+  pub weapon_zoom: u8,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::update::alife_object_item_weapon_update::AlifeObjectItemWeaponUpdate;
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectItemWeaponUpdate = AlifeObjectItemWeaponUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      condition: 255,
+      weapon_flags: 0,
+      ammo_elapsed: 37,
+      addon_flags: 2,
+      ammo_type: 1,
+      weapon_state: 0,
+      weapon_zoom: 0,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 9);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 9);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 9 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectItemWeaponUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectItemWeaponUpdate = AlifeObjectItemWeaponUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      condition: 255,
+      weapon_flags: 0,
+      ammo_elapsed: 25,
+      addon_flags: 2,
+      ammo_type: 1,
+      weapon_state: 0,
+      weapon_zoom: 0,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(
+      AlifeObjectItemWeaponUpdate::import("data", &source)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectItemWeaponUpdate = AlifeObjectItemWeaponUpdate {
+      base: AlifeObjectPhysicStateUpdate {
+        num_items: 0,
+        position: None,
+        quaternion: vec![],
+        angular_velocity: vec![],
+        linear_velocity: vec![],
+      },
+      condition: 255,
+      weapon_flags: 0,
+      ammo_elapsed: 36,
+      addon_flags: 2,
+      ammo_type: 1,
+      weapon_state: 0,
+      weapon_zoom: 0,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectItemWeaponUpdate>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
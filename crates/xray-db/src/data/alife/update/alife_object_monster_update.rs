@@ -0,0 +1,163 @@
+use crate::data::alife::update::alife_object_creature_update::AlifeObjectCreatureUpdate;
+use serde::{Deserialize, Serialize};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite, LtxImportExport)]
+#[serde(rename_all = "camelCase")]
+#[ltx(prefix = "update.monster.")]
+pub struct AlifeObjectMonsterUpdate {
+  #[ltx(inherit)]
+  pub base: AlifeObjectCreatureUpdate,
+  pub next_game_vertex_id: u16,
+  pub previous_game_vertex_id: u16,
+  pub distance_from_point: f32,
+  pub distance_to_point: f32,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::update::alife_object_creature_update::AlifeObjectCreatureUpdate;
+  use crate::data::alife::update::alife_object_monster_update::AlifeObjectMonsterUpdate;
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectMonsterUpdate = AlifeObjectMonsterUpdate {
+      base: AlifeObjectCreatureUpdate {
+        health: 0.75,
+        timestamp: 3700,
+        flags: 0,
+        position: Vector3d::new(10.5, -2.25, 64.0),
+        model_yaw: 1.5,
+        torso_yaw: 1.25,
+        torso_pitch: 0.0,
+        torso_roll: 0.0,
+        team: 1,
+        squad: 2,
+        group: 3,
+      },
+      next_game_vertex_id: 37,
+      previous_game_vertex_id: 38,
+      distance_from_point: 2.5,
+      distance_to_point: 12.5,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 52);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 52);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 52 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectMonsterUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let original: AlifeObjectMonsterUpdate = AlifeObjectMonsterUpdate {
+      base: AlifeObjectCreatureUpdate {
+        health: 0.75,
+        timestamp: 2500,
+        flags: 0,
+        position: Vector3d::new(10.5, -2.25, 64.0),
+        model_yaw: 1.5,
+        torso_yaw: 1.25,
+        torso_pitch: 0.0,
+        torso_roll: 0.0,
+        team: 1,
+        squad: 2,
+        group: 3,
+      },
+      next_game_vertex_id: 25,
+      previous_game_vertex_id: 26,
+      distance_from_point: 2.5,
+      distance_to_point: 12.5,
+    };
+
+    original.export("data", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(AlifeObjectMonsterUpdate::import("data", &source)?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectMonsterUpdate = AlifeObjectMonsterUpdate {
+      base: AlifeObjectCreatureUpdate {
+        health: 0.75,
+        timestamp: 3600,
+        flags: 0,
+        position: Vector3d::new(10.5, -2.25, 64.0),
+        model_yaw: 1.5,
+        torso_yaw: 1.25,
+        torso_pitch: 0.0,
+        torso_roll: 0.0,
+        team: 1,
+        squad: 2,
+        group: 3,
+      },
+      next_game_vertex_id: 36,
+      previous_game_vertex_id: 37,
+      distance_from_point: 2.5,
+      distance_to_point: 12.5,
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectMonsterUpdate>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
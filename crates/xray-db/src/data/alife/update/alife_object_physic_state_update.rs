@@ -0,0 +1,328 @@
+use crate::constants::{FLAG_PHYSIC_STATE_ANGULAR_NULL, FLAG_PHYSIC_STATE_LINEAR_NULL};
+use crate::data::generic::vector_3d::Vector3d;
+use crate::export::LtxImportExport;
+use crate::file_import::{read_ini_optional_field, read_ltx_field};
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_ltx::{Ltx, Section};
+use xray_utils::{assert_equal, vector_from_string, vector_to_string};
+
+/// Physic state of inventory items and physic objects.
+/// Items count byte stores count in low 5 bits and state mask in high 3 bits.
+/// Rest of state is serialized only if count is not zero, rotation and velocities are quantized.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlifeObjectPhysicStateUpdate {
+  pub num_items: u8,
+  pub position: Option<Vector3d>,
+  pub quaternion: Vec<u8>,
+  pub angular_velocity: Vec<u8>,
+  pub linear_velocity: Vec<u8>,
+}
+
+impl AlifeObjectPhysicStateUpdate {
+  /// Create empty physic state, as written for objects not simulated yet.
+  pub fn new() -> Self {
+    Self {
+      num_items: 0,
+      position: None,
+      quaternion: Vec::new(),
+      angular_velocity: Vec::new(),
+      linear_velocity: Vec::new(),
+    }
+  }
+
+  pub fn has_state(&self) -> bool {
+    self.num_items != 0
+  }
+
+  pub fn has_angular_velocity(&self) -> bool {
+    self.has_state() && self.num_items & FLAG_PHYSIC_STATE_ANGULAR_NULL == 0
+  }
+
+  pub fn has_linear_velocity(&self) -> bool {
+    self.has_state() && self.num_items & FLAG_PHYSIC_STATE_LINEAR_NULL == 0
+  }
+}
+
+impl ChunkReadWrite for AlifeObjectPhysicStateUpdate {
+  /// Read physic state update data from the chunk.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let mut object: Self = Self {
+      num_items: reader.read_u8()?,
+      ..Self::new()
+    };
+
+    if object.has_state() {
+      object.position = Some(reader.read_xr::<T, _>()?);
+      object.quaternion = reader.read_bytes(4)?;
+    }
+
+    if object.has_angular_velocity() {
+      object.angular_velocity = reader.read_bytes(3)?;
+    }
+
+    if object.has_linear_velocity() {
+      object.linear_velocity = reader.read_bytes(3)?;
+    }
+
+    Ok(object)
+  }
+
+  /// Write physic state update data into the writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u8(self.num_items)?;
+
+    if self.has_state() {
+      writer.write_xr::<T, _>(self.position.as_ref().ok_or_else(|| {
+        XRayError::new_invalid_error("Expected physic state position to be set for items")
+      })?)?;
+
+      assert_equal(
+        self.quaternion.len(),
+        4,
+        "Expected 4 quantized physic state quaternion values",
+      )?;
+
+      writer.write_all(&self.quaternion)?;
+    }
+
+    if self.has_angular_velocity() {
+      assert_equal(
+        self.angular_velocity.len(),
+        3,
+        "Expected 3 quantized physic state angular velocity values",
+      )?;
+
+      writer.write_all(&self.angular_velocity)?;
+    }
+
+    if self.has_linear_velocity() {
+      assert_equal(
+        self.linear_velocity.len(),
+        3,
+        "Expected 3 quantized physic state linear velocity values",
+      )?;
+
+      writer.write_all(&self.linear_velocity)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl LtxImportExport for AlifeObjectPhysicStateUpdate {
+  /// Import physic state update data from ltx config section.
+  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
+    let section: &Section = ltx.section(section_name).ok_or_else(|| {
+      XRayError::new_parsing_error(format!(
+        "ALife object update '{}' should be defined in ltx file ({})",
+        section_name,
+        file!()
+      ))
+    })?;
+
+    Ok(Self {
+      num_items: read_ltx_field("update.physic_state.num_items", section)?,
+      position: read_ini_optional_field("update.physic_state.position", section)?,
+      quaternion: vector_from_string(&read_ltx_field::<String>(
+        "update.physic_state.quaternion",
+        section,
+      )?)?,
+      angular_velocity: vector_from_string(&read_ltx_field::<String>(
+        "update.physic_state.angular_velocity",
+        section,
+      )?)?,
+      linear_velocity: vector_from_string(&read_ltx_field::<String>(
+        "update.physic_state.linear_velocity",
+        section,
+      )?)?,
+    })
+  }
+
+  /// Export physic state update data into ltx file.
+  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
+    ltx
+      .with_section(section_name)
+      .set("update.physic_state.num_items", self.num_items.to_string())
+      .set(
+        "update.physic_state.quaternion",
+        vector_to_string(&self.quaternion),
+      )
+      .set(
+        "update.physic_state.angular_velocity",
+        vector_to_string(&self.angular_velocity),
+      )
+      .set(
+        "update.physic_state.linear_velocity",
+        vector_to_string(&self.linear_velocity),
+      );
+
+    if let Some(position) = &self.position {
+      ltx
+        .with_section(section_name)
+        .set("update.physic_state.position", position.to_string());
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::export::LtxImportExport;
+  use serde_json::to_string_pretty;
+  use std::fs::File;
+  use std::io::{Seek, SeekFrom, Write};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_ltx::Ltx;
+  use xray_test_utils::file::read_file_as_string;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+    open_test_resource_as_slice, overwrite_test_relative_resource_as_file,
+  };
+  use xray_test_utils::FileSlice;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+    let filename: String = get_relative_test_sample_file_path(file!(), "read_write.chunk");
+
+    let original: AlifeObjectPhysicStateUpdate = AlifeObjectPhysicStateUpdate {
+      num_items: 1,
+      position: Some(Vector3d::new(1.5, -2.5, 3.0)),
+      quaternion: vec![0, 127, 255, 64],
+      angular_velocity: vec![1, 2, 3],
+      linear_velocity: vec![4, 5, 6],
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 23);
+
+    let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
+      &mut overwrite_test_relative_resource_as_file(&filename)?,
+      0,
+    )?;
+
+    assert_eq!(bytes_written, 23);
+
+    let file: FileSlice = open_test_resource_as_slice(&filename)?;
+
+    assert_eq!(file.bytes_remaining(), 23 + 8);
+
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+
+    assert_eq!(
+      AlifeObjectPhysicStateUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_write_masked() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    let original: AlifeObjectPhysicStateUpdate = AlifeObjectPhysicStateUpdate {
+      num_items: 1 | 64 | 128,
+      position: Some(Vector3d::new(1.0, 2.0, 3.0)),
+      quaternion: vec![1, 2, 3, 4],
+      angular_velocity: vec![],
+      linear_velocity: vec![],
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 17);
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(
+      AlifeObjectPhysicStateUpdate::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_import_export() -> XRayResult {
+    let ltx_filename: String = get_relative_test_sample_file_path(file!(), "import_export.ltx");
+    let mut ltx: Ltx = Ltx::new();
+
+    let first: AlifeObjectPhysicStateUpdate = AlifeObjectPhysicStateUpdate {
+      num_items: 1,
+      position: Some(Vector3d::new(4.5, 2.5, 3.0)),
+      quaternion: vec![10, 20, 30, 40],
+      angular_velocity: vec![5, 6, 7],
+      linear_velocity: vec![8, 9, 10],
+    };
+
+    let second: AlifeObjectPhysicStateUpdate = AlifeObjectPhysicStateUpdate {
+      num_items: 0,
+      position: None,
+      quaternion: vec![],
+      angular_velocity: vec![],
+      linear_velocity: vec![],
+    };
+
+    first.export("first", &mut ltx)?;
+    second.export("second", &mut ltx)?;
+
+    ltx.write_to(&mut overwrite_test_relative_resource_as_file(
+      &ltx_filename,
+    )?)?;
+
+    let source: Ltx = Ltx::read_from_path(get_absolute_test_resource_path(&ltx_filename))?;
+
+    assert_eq!(
+      AlifeObjectPhysicStateUpdate::import("first", &source)?,
+      first
+    );
+    assert_eq!(
+      AlifeObjectPhysicStateUpdate::import("second", &source)?,
+      second
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_serialize_deserialize() -> XRayResult {
+    let original: AlifeObjectPhysicStateUpdate = AlifeObjectPhysicStateUpdate {
+      num_items: 1,
+      position: Some(Vector3d::new(1.5, 2.5, 3.5)),
+      quaternion: vec![1, 1, 1, 1],
+      angular_velocity: vec![2, 2, 2],
+      linear_velocity: vec![3, 3, 3],
+    };
+
+    let mut file: File = overwrite_test_relative_resource_as_file(
+      &get_relative_test_sample_file_path(file!(), "serialize_deserialize.json"),
+    )?;
+
+    file.write_all(to_string_pretty(&original)?.as_bytes())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let serialized: String = read_file_as_string(&mut file)?;
+
+    assert_eq!(serialized.to_string(), serialized);
+
+    assert_eq!(
+      serde_json::from_str::<AlifeObjectPhysicStateUpdate>(&serialized)?,
+      original
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,14 @@
+pub(crate) mod alife_object_actor_update;
+pub(crate) mod alife_object_creature_update;
+pub(crate) mod alife_object_human_stalker_update;
+pub(crate) mod alife_object_item_ammo_update;
+pub(crate) mod alife_object_item_custom_outfit_update;
+pub(crate) mod alife_object_item_torch_update;
+pub(crate) mod alife_object_item_weapon_magazined_update;
+pub(crate) mod alife_object_item_weapon_magazined_wgl_update;
+pub(crate) mod alife_object_item_weapon_shotgun_update;
+pub(crate) mod alife_object_item_weapon_update;
+pub(crate) mod alife_object_monster_rat_update;
+pub(crate) mod alife_object_monster_update;
+pub(crate) mod alife_object_physic_state_update;
+pub(crate) mod alife_object_stationary_mgun_update;
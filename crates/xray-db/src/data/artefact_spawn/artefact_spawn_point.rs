@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -17,7 +17,7 @@ pub struct ArtefactSpawnPoint {
 
 impl ChunkReadWrite for ArtefactSpawnPoint {
   /// Read artefact spawn point from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       position: reader.read_xr::<T, _>()?,
       level_vertex_id: reader.read_u32::<T>()?,
@@ -111,11 +111,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 20 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ArtefactSpawnPoint::read::<XRayByteOrder>(&mut reader)?,
+      ArtefactSpawnPoint::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -1,8 +1,9 @@
+use crate::data::generic::vector_3d::Vector3d;
 use crate::file_import::read_ltx_field;
 use crate::types::{Matrix3d, Sphere3d};
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -14,9 +15,28 @@ pub enum Shape {
   Box(Matrix3d),
 }
 
+impl Shape {
+  /// Check whether point in object local space is inside of the shape.
+  /// Box is represented with axes scaled by box size and center offset, same as xray shape data.
+  pub fn contains(&self, point: &Vector3d) -> bool {
+    match self {
+      Self::Sphere((center, radius)) => point.distance_squared_to(center) <= radius * radius,
+      Self::Box((i, j, k, center)) => {
+        let offset: Vector3d = point - center;
+
+        [i, j, k].iter().all(|axis| {
+          let length_squared: f32 = axis.dot(axis);
+
+          length_squared > 0.0 && (offset.dot(axis) / length_squared).abs() <= 0.5
+        })
+      }
+    }
+  }
+}
+
 impl ChunkReadWrite for Shape {
   /// Read shape from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let shape_type: u8 = reader.read_u8().expect("Shape type to be read");
 
     Ok(match shape_type {
@@ -61,12 +81,14 @@ impl ChunkReadWrite for Shape {
 
 impl ChunkReadWriteList for Shape {
   /// Read list of shapes from the chunk reader.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let mut shapes: Vec<Self> = Vec::new();
     let count: u8 = reader.read_u8().expect("Count flag to be read");
 
     for _ in 0..count {
-      shapes.push(Self::read::<T>(reader)?);
+      shapes.push(Self::read::<T, _>(reader)?);
     }
 
     assert_equal(
@@ -228,9 +250,10 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 67 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
-    assert_eq!(Shape::read_list::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(Shape::read_list::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -264,9 +287,10 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 17 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
-    assert_eq!(Shape::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(Shape::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -314,13 +338,31 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 49 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
-    assert_eq!(Shape::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(Shape::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
 
+  #[test]
+  fn test_contains() {
+    let sphere: Shape = Shape::Sphere((Vector3d::new(0.0, 1.0, 0.0), 2.0));
+    let cube: Shape = Shape::Box((
+      Vector3d::new(4.0, 0.0, 0.0),
+      Vector3d::new(0.0, 2.0, 0.0),
+      Vector3d::new(0.0, 0.0, 1.0),
+      Vector3d::new(10.0, 0.0, 0.0),
+    ));
+
+    assert!(sphere.contains(&Vector3d::new(0.0, 2.5, 1.0)));
+    assert!(!sphere.contains(&Vector3d::new(2.0, 2.0, 0.0)));
+    assert!(cube.contains(&Vector3d::new(11.5, 0.9, -0.4)));
+    assert!(!cube.contains(&Vector3d::new(12.5, 0.0, 0.0)));
+    assert!(!cube.contains(&Vector3d::new(10.0, 0.0, 0.6)));
+  }
+
   #[test]
   fn test_import_export() -> XRayResult {
     let config_path: &Path = &get_absolute_test_sample_file_path(file!(), "test_import_export.ltx");
@@ -3,7 +3,9 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
-use xray_chunk::{ChunkReadWrite, ChunkReadWriteOptional, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  ChunkDataSource, ChunkReadWrite, ChunkReadWriteOptional, ChunkReader, ChunkWriter,
+};
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Display)]
@@ -21,9 +23,11 @@ pub struct Time {
 
 impl ChunkReadWriteOptional for Time {
   /// Read optional time object from the chunk.
-  fn read_optional<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Option<Self>> {
+  fn read_optional<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Option<Self>> {
     if reader.read_u8()? == 1 {
-      Ok(Some(Self::read::<T>(reader)?))
+      Ok(Some(Self::read::<T, _>(reader)?))
     } else {
       Ok(None)
     }
@@ -45,7 +49,7 @@ impl ChunkReadWriteOptional for Time {
 
 impl ChunkReadWrite for Time {
   /// Read time object from chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let year: u8 = reader.read_u8()?;
     let month: u8 = reader.read_u8()?;
     let day: u8 = reader.read_u8()?;
@@ -198,9 +202,10 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 8 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
-    assert_eq!(Time::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(Time::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -236,10 +241,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 9 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      Time::read_optional::<XRayByteOrder>(&mut reader)?,
+      Time::read_optional::<XRayByteOrder, _>(&mut reader)?,
       Some(original)
     );
 
@@ -267,9 +273,10 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 1 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
-    assert_eq!(Time::read_optional::<XRayByteOrder>(&mut reader)?, None);
+    assert_eq!(Time::read_optional::<XRayByteOrder, _>(&mut reader)?, None);
 
     Ok(())
   }
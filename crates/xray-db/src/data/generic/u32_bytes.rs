@@ -2,7 +2,7 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_utils::vector_from_string_sized;
 
@@ -10,7 +10,7 @@ use xray_utils::vector_from_string_sized;
 pub struct U32Bytes(pub u8, pub u8, pub u8, pub u8);
 
 impl ChunkReadWrite for U32Bytes {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(U32Bytes(
       reader.read_u8()?,
       reader.read_u8()?,
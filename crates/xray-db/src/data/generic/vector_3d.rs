@@ -1,8 +1,9 @@
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use std::ops::Sub;
 use std::str::FromStr;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Eq, Display)]
@@ -18,11 +19,36 @@ impl Vector3d<f32> {
   pub fn new(x: f32, y: f32, z: f32) -> Self {
     Self { x, y, z }
   }
+
+  /// Get dot product of two vectors.
+  pub fn dot(&self, other: &Self) -> f32 {
+    self.x * other.x + self.y * other.y + self.z * other.z
+  }
+
+  /// Get squared distance between two points, cheaper for comparisons.
+  pub fn distance_squared_to(&self, other: &Self) -> f32 {
+    let delta: Self = self - other;
+
+    delta.dot(&delta)
+  }
+
+  /// Get distance between two points.
+  pub fn distance_to(&self, other: &Self) -> f32 {
+    self.distance_squared_to(other).sqrt()
+  }
+}
+
+impl Sub for &Vector3d<f32> {
+  type Output = Vector3d<f32>;
+
+  fn sub(self, other: Self) -> Self::Output {
+    Vector3d::new(self.x - other.x, self.y - other.y, self.z - other.z)
+  }
 }
 
 impl ChunkReadWrite for Vector3d<f32> {
   /// Read vector coordinates from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       x: reader.read_f32::<T>()?,
       y: reader.read_f32::<T>()?,
@@ -139,10 +165,10 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 12 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    assert_eq!(Vector3d::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(Vector3d::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -161,6 +187,17 @@ mod tests {
     Ok(())
   }
 
+  #[test]
+  fn test_distance() {
+    let first: Vector3d = Vector3d::new(1.0, 2.0, 3.0);
+    let second: Vector3d = Vector3d::new(4.0, 6.0, 3.0);
+
+    assert_eq!(&second - &first, Vector3d::new(3.0, 4.0, 0.0));
+    assert_eq!(first.dot(&second), 25.0);
+    assert_eq!(first.distance_squared_to(&second), 25.0);
+    assert_eq!(first.distance_to(&second), 5.0);
+  }
+
   #[test]
   fn test_serialize_deserialize() -> XRayResult {
     let original: Vector3d = Vector3d {
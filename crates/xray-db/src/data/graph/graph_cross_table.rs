@@ -4,8 +4,8 @@ use std::fs::File;
 use std::io::Write;
 use uuid::Uuid;
 use xray_chunk::{
-  assert_chunk_read, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkSizePackedIterator,
-  ChunkWriter,
+  assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader,
+  ChunkSizePackedIterator, ChunkWriter,
 };
 use xray_error::XRayResult;
 
@@ -27,9 +27,9 @@ impl GraphCrossTable {
   pub fn import_list<T: ByteOrder>(file: &mut File) -> XRayResult<Vec<Self>> {
     let mut cross_tables: Vec<Self> = Vec::new();
 
-    for mut cross_table_reader in
-      ChunkSizePackedIterator::from_current(&mut ChunkReader::from_file(file.try_clone().unwrap())?)
-    {
+    for mut cross_table_reader in ChunkSizePackedIterator::<T>::from_current(
+      &mut ChunkReader::from_file(file.try_clone().unwrap())?,
+    ) {
       cross_tables.push(cross_table_reader.read_xr::<T, _>()?);
       assert_chunk_read(&cross_table_reader, "Expect cross table chunk to be ended")?;
     }
@@ -53,14 +53,34 @@ impl GraphCrossTable {
 
     Ok(())
   }
+
+  /// Get game vertex ID and distance to it for provided level vertex (AI node).
+  /// Cells are stored for each level node as u16 game vertex ID followed by f32 distance.
+  pub fn get_cell<T: ByteOrder>(&self, level_vertex_id: u32) -> Option<(u16, f32)> {
+    if self.nodes_count == 0 || level_vertex_id >= self.nodes_count {
+      return None;
+    }
+
+    let cell_size: usize = self.data.len() / self.nodes_count as usize;
+
+    if cell_size < 6 {
+      return None;
+    }
+
+    let cell: &[u8] = &self.data[level_vertex_id as usize * cell_size..][..cell_size];
+
+    Some((T::read_u16(cell), T::read_f32(&cell[cell_size - 4..])))
+  }
 }
 
 impl ChunkReadWriteList for GraphCrossTable {
   /// Read cross tables list data from the chunk.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let mut cross_tables: Vec<Self> = Vec::new();
 
-    for mut cross_table_reader in ChunkSizePackedIterator::from_current(reader) {
+    for mut cross_table_reader in ChunkSizePackedIterator::<T, D>::from_current(reader) {
       cross_tables.push(cross_table_reader.read_xr::<T, _>()?);
       assert_chunk_read(&cross_table_reader, "Expect cross table chunk to be ended")?;
     }
@@ -84,7 +104,7 @@ impl ChunkReadWriteList for GraphCrossTable {
 
 impl ChunkReadWrite for GraphCrossTable {
   /// Read cross table data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       version: reader.read_u32::<T>()?,
       nodes_count: reader.read_u32::<T>()?,
@@ -111,6 +131,7 @@ impl ChunkReadWrite for GraphCrossTable {
 #[cfg(test)]
 mod tests {
   use crate::data::graph::graph_cross_table::GraphCrossTable;
+  use byteorder::WriteBytesExt;
   use serde_json::to_string_pretty;
   use std::fs::File;
   use std::io::{Seek, SeekFrom, Write};
@@ -178,17 +199,42 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 166 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      GraphCrossTable::read_list::<XRayByteOrder>(&mut reader)?,
+      GraphCrossTable::read_list::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
     Ok(())
   }
 
+  #[test]
+  fn test_get_cell() -> XRayResult {
+    let mut data: Vec<u8> = Vec::new();
+
+    for (game_vertex_id, distance) in [(10u16, 1.5f32), (11, 0.0), (12, 7.25)] {
+      data.write_u16::<XRayByteOrder>(game_vertex_id)?;
+      data.write_f32::<XRayByteOrder>(distance)?;
+    }
+
+    let cross_table: GraphCrossTable = GraphCrossTable {
+      version: 10,
+      nodes_count: 3,
+      vertices_count: 3,
+      level_guid: uuid!("89e55023-10b1-426f-9247-bb680e5fe0b7"),
+      game_guid: uuid!("89e55023-10b1-426f-9247-bb680e5fe0b8"),
+      data,
+    };
+
+    assert_eq!(cross_table.get_cell::<XRayByteOrder>(0), Some((10, 1.5)));
+    assert_eq!(cross_table.get_cell::<XRayByteOrder>(2), Some((12, 7.25)));
+    assert_eq!(cross_table.get_cell::<XRayByteOrder>(3), None);
+
+    Ok(())
+  }
+
   #[test]
   fn test_read_write() -> XRayResult {
     let filename: String = String::from("read_write.chunk");
@@ -223,11 +269,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 55 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      GraphCrossTable::read::<XRayByteOrder>(&mut reader)?,
+      GraphCrossTable::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -2,7 +2,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -15,7 +15,7 @@ pub struct GraphEdge {
 
 impl ChunkReadWrite for GraphEdge {
   /// Read edge from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       game_vertex_id: reader.read_u16::<T>()?,
       distance: reader.read_f32::<T>()?,
@@ -107,10 +107,10 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 6 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    assert_eq!(GraphEdge::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(GraphEdge::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -3,7 +3,7 @@ use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -20,7 +20,7 @@ pub struct GraphHeader {
 
 impl ChunkReadWrite for GraphHeader {
   /// Read header data from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       version: reader.read_u8()?,
       vertices_count: reader.read_u16::<T>()?,
@@ -133,10 +133,13 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 28 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    assert_eq!(GraphHeader::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(
+      GraphHeader::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
 
     Ok(())
   }
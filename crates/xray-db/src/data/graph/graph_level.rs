@@ -4,7 +4,7 @@ use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -21,7 +21,7 @@ pub struct GraphLevel {
 
 impl ChunkReadWrite for GraphLevel {
   /// Read graph level data from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       name: reader.read_w1251_string()?,
       offset: reader.read_xr::<T, _>()?,
@@ -131,10 +131,10 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 59 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    assert_eq!(GraphLevel::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(GraphLevel::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -2,19 +2,19 @@ use crate::data::generic::u32_bytes::U32Bytes;
 use crate::data::generic::vector_3d::Vector3d;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
-use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_db_derive::ChunkReadWrite;
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::vector_to_string;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ChunkReadWrite)]
 #[serde(rename_all = "camelCase")]
 pub struct GraphVertex {
   pub level_point: Vector3d<f32>,
   pub game_point: Vector3d<f32>,
   pub level_id: u8,
+  #[chunk(u24)]
   pub level_vertex_id: u32,
   pub vertex_type: U32Bytes,
   pub edges_offset: u32,
@@ -23,38 +23,6 @@ pub struct GraphVertex {
   pub level_points_count: u8,
 }
 
-impl ChunkReadWrite for GraphVertex {
-  /// Read graph vertex data from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    Ok(Self {
-      level_point: reader.read_xr::<T, _>()?,
-      game_point: reader.read_xr::<T, _>()?,
-      level_id: reader.read_u8()?,
-      level_vertex_id: reader.read_u24::<T>()?,
-      vertex_type: reader.read_xr::<T, U32Bytes>()?,
-      edges_offset: reader.read_u32::<T>()?,
-      level_points_offset: reader.read_u32::<T>()?,
-      edges_count: reader.read_u8()?,
-      level_points_count: reader.read_u8()?,
-    })
-  }
-
-  /// Write graph vertex data into chunk writer.
-  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
-    writer.write_xr::<T, _>(&self.level_point)?;
-    writer.write_xr::<T, _>(&self.game_point)?;
-    writer.write_u8(self.level_id)?;
-    writer.write_u24::<T>(self.level_vertex_id)?;
-    writer.write_xr::<T, U32Bytes>(&self.vertex_type)?;
-    writer.write_u32::<T>(self.edges_offset)?;
-    writer.write_u32::<T>(self.level_points_offset)?;
-    writer.write_u8(self.edges_count)?;
-    writer.write_u8(self.level_points_count)?;
-
-    Ok(())
-  }
-}
-
 impl LtxImportExport for GraphVertex {
   /// Import graph vertex from ltx file.
   fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
@@ -161,10 +129,13 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 42 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    assert_eq!(GraphVertex::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(
+      GraphVertex::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
 
     Ok(())
   }
@@ -0,0 +1,100 @@
+use crate::data::generic::vector_3d::Vector3d;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::XRayResult;
+
+/// Header of level AI mesh (level.ai), bounding box is stored as min and max corners.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelAiHeader {
+  pub version: u32,
+  pub vertices_count: u32,
+  pub cell_size: f32,
+  pub factor_y: f32,
+  pub min: Vector3d,
+  pub max: Vector3d,
+  pub guid: Uuid,
+}
+
+impl LevelAiHeader {
+  /// Get count of cells in single row of the mesh grid (along Z axis).
+  pub fn get_row_length(&self) -> u32 {
+    ((self.max.z - self.min.z) / self.cell_size + 0.001 + 1.5).floor() as u32
+  }
+
+  /// Get count of cells in single column of the mesh grid (along X axis).
+  pub fn get_column_length(&self) -> u32 {
+    ((self.max.x - self.min.x) / self.cell_size + 0.001 + 1.5).floor() as u32
+  }
+}
+
+impl ChunkReadWrite for LevelAiHeader {
+  /// Read level AI header data from the chunk reader.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    Ok(Self {
+      version: reader.read_u32::<T>()?,
+      vertices_count: reader.read_u32::<T>()?,
+      cell_size: reader.read_f32::<T>()?,
+      factor_y: reader.read_f32::<T>()?,
+      min: reader.read_xr::<T, _>()?,
+      max: reader.read_xr::<T, _>()?,
+      guid: Uuid::from_u128(reader.read_u128::<T>()?),
+    })
+  }
+
+  /// Write level AI header data into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    writer.write_u32::<T>(self.version)?;
+    writer.write_u32::<T>(self.vertices_count)?;
+    writer.write_f32::<T>(self.cell_size)?;
+    writer.write_f32::<T>(self.factor_y)?;
+    writer.write_xr::<T, _>(&self.min)?;
+    writer.write_xr::<T, _>(&self.max)?;
+    writer.write_u128::<T>(self.guid.as_u128())?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::level_ai::level_ai_header::LevelAiHeader;
+  use uuid::uuid;
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    let original: LevelAiHeader = LevelAiHeader {
+      version: 10,
+      vertices_count: 4,
+      cell_size: 0.7,
+      factor_y: 12.5,
+      min: Vector3d::new(-1.4, -2.5, 0.0),
+      max: Vector3d::new(1.4, 10.0, 2.1),
+      guid: uuid!("89e55023-10b1-426f-9247-bb680e5fe0a1"),
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 56);
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(
+      LevelAiHeader::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+    assert!(reader.is_ended());
+    assert_eq!(original.get_row_length(), 4);
+    assert_eq!(original.get_column_length(), 5);
+
+    Ok(())
+  }
+}
@@ -0,0 +1,153 @@
+use crate::data::generic::vector_3d::Vector3d;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_error::XRayResult;
+
+/// Compressed AI node of level.ai mesh.
+///
+/// Four links to neighbour nodes are packed as 23-bit values into first 12 bytes,
+/// light is stored in 4 upper bits of the last byte of links data.
+/// Position is stored as packed XZ cell index (u24) and Y quantized into u16 by header factor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelAiVertex {
+  pub links: [u32; 4],
+  pub light: u8,
+  pub cover_high: u16,
+  pub cover_low: u16,
+  pub plane: u16,
+  pub packed_xz: u32,
+  pub packed_y: u16,
+}
+
+impl LevelAiVertex {
+  pub const LINK_BITS: u32 = 23;
+  pub const INVALID_LINK: u32 = (1 << Self::LINK_BITS) - 1;
+
+  /// Get unit normal of node plane, decompressed same way as xray `pvDecompress`.
+  pub fn get_plane_normal(&self) -> Vector3d {
+    let mut x: i32 = ((self.plane & 0x1f80) >> 7) as i32;
+    let mut y: i32 = (self.plane & 0x007f) as i32;
+
+    if x + y >= 127 {
+      x = 127 - x;
+      y = 127 - y;
+    }
+
+    let z: i32 = 126 - x - y;
+    let adjustment: f32 = 1.0 / ((x * x + y * y + z * z) as f32).sqrt();
+    let sign = |mask: u16| if self.plane & mask != 0 { -1.0 } else { 1.0 };
+
+    Vector3d::new(
+      sign(0x8000) * adjustment * x as f32,
+      sign(0x4000) * adjustment * y as f32,
+      sign(0x2000) * adjustment * z as f32,
+    )
+  }
+}
+
+impl ChunkReadWrite for LevelAiVertex {
+  /// Read level AI vertex data from the chunk reader.
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let data: Vec<u8> = reader.read_bytes(12)?;
+    let bits: u128 = data
+      .iter()
+      .rev()
+      .fold(0u128, |bits, byte| (bits << 8) | *byte as u128);
+
+    Ok(Self {
+      links: [0, 1, 2, 3]
+        .map(|index| ((bits >> (index * Self::LINK_BITS)) as u32) & Self::INVALID_LINK),
+      light: data[11] >> 4,
+      cover_high: reader.read_u16::<T>()?,
+      cover_low: reader.read_u16::<T>()?,
+      plane: reader.read_u16::<T>()?,
+      packed_xz: reader.read_u24::<T>()?,
+      packed_y: reader.read_u16::<T>()?,
+    })
+  }
+
+  /// Write level AI vertex data into the chunk writer.
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
+    let bits: u128 = self
+      .links
+      .iter()
+      .enumerate()
+      .fold(0u128, |bits, (index, link)| {
+        bits | ((*link & Self::INVALID_LINK) as u128) << (index as u32 * Self::LINK_BITS)
+      })
+      | ((self.light & 0xf) as u128) << 92;
+
+    for index in 0..12 {
+      writer.write_u8((bits >> (index * 8)) as u8)?;
+    }
+
+    writer.write_u16::<T>(self.cover_high)?;
+    writer.write_u16::<T>(self.cover_low)?;
+    writer.write_u16::<T>(self.plane)?;
+    writer.write_u24::<T>(self.packed_xz)?;
+    writer.write_u16::<T>(self.packed_y)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::level_ai::level_ai_vertex::LevelAiVertex;
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    let original: LevelAiVertex = LevelAiVertex {
+      links: [1, LevelAiVertex::INVALID_LINK, 4_000_000, 0],
+      light: 11,
+      cover_high: 0x1234,
+      cover_low: 0x4321,
+      plane: 0x0fc0,
+      packed_xz: 0x00ab_cdef,
+      packed_y: 65535,
+    };
+
+    original.write::<XRayByteOrder>(&mut writer)?;
+
+    assert_eq!(writer.bytes_written(), 23);
+
+    let data: Vec<u8> = writer.flush_raw_into_buffer()?;
+
+    assert_eq!(&data[0..3], &[1, 0, 128]);
+    assert_eq!(data[11] >> 4, 11);
+
+    let mut reader: ChunkReader<_> = ChunkReader::from_bytes(&data)?;
+
+    assert_eq!(
+      LevelAiVertex::read::<XRayByteOrder, _>(&mut reader)?,
+      original
+    );
+    assert!(reader.is_ended());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_plane_normal() {
+    let flat: LevelAiVertex = LevelAiVertex {
+      links: [LevelAiVertex::INVALID_LINK; 4],
+      light: 0,
+      cover_high: 0,
+      cover_low: 0,
+      plane: 126,
+      packed_xz: 0,
+      packed_y: 0,
+    };
+
+    let normal: Vector3d = flat.get_plane_normal();
+
+    assert_eq!((normal.x, normal.y, normal.z), (0.0, 1.0, 0.0));
+  }
+}
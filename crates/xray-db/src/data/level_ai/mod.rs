@@ -0,0 +1,2 @@
+pub(crate) mod level_ai_header;
+pub(crate) mod level_ai_vertex;
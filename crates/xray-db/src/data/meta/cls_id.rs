@@ -39,6 +39,7 @@ pub enum ClsId {
   ODstrS,
   OPhysS,
   OSearch,
+  OnOffS,
   PSkelet,
   SActor,
   SExplo,
@@ -157,6 +157,7 @@ lazy_static! {
     "notebook" => ClsId::ODstrS,
     "novice_outfit" => ClsId::EStlk,
     "ognetushitel" => ClsId::ODstrS,
+    "online_offline_group" => ClsId::OnOffS,
     "ph_skeleton_object" => ClsId::PSkelet,
     "physic_destroyable_object" => ClsId::ODstrS,
     "physic_object" => ClsId::OPhysS,
@@ -628,6 +629,7 @@ lazy_static! {
     ClsId::ODstrS => AlifeClass::CseAlifeObjectPhysic,
     ClsId::OPhysS => AlifeClass::CseAlifeObjectPhysic,
     ClsId::OSearch => AlifeClass::CseAlifeObjectProjector,
+    ClsId::OnOffS => AlifeClass::SimSquadScripted,
     ClsId::PSkelet => AlifeClass::CseAlifePhSkeletonObject,
     ClsId::SActor => AlifeClass::SeActor,
     ClsId::SExplo => AlifeClass::CseAlifeItemExplosive,
@@ -2,6 +2,7 @@ pub(crate) mod alife;
 pub(crate) mod artefact_spawn;
 pub(crate) mod generic;
 pub(crate) mod graph;
+pub(crate) mod level_ai;
 pub(crate) mod meta;
 pub(crate) mod ogf;
 pub(crate) mod particles;
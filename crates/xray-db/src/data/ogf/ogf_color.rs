@@ -1,7 +1,7 @@
 use crate::data::generic::rgb_color::RgbColor;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -13,7 +13,7 @@ pub struct OgfColor {
 }
 
 impl ChunkReadWrite for OgfColor {
-  fn read<T: ByteOrder>(_: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(_: &mut ChunkReader<D>) -> XRayResult<Self> {
     todo!("Implement")
   }
 
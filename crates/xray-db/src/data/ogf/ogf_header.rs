@@ -1,6 +1,6 @@
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -8,7 +8,7 @@ use xray_error::XRayResult;
 pub struct OgfHeader {}
 
 impl ChunkReadWrite for OgfHeader {
-  fn read<T: ByteOrder>(_: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(_: &mut ChunkReader<D>) -> XRayResult<Self> {
     todo!("Implement")
   }
 
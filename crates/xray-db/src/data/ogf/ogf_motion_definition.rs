@@ -1,7 +1,9 @@
 use crate::data::ogf::ogf_motion_mark::OgfMotionMark;
 use byteorder::{ByteOrder, ReadBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_vector_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_vector_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter,
+};
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -22,12 +24,15 @@ pub struct OgfMotionDefinition {
 // todo: Version based switcher?
 // todo: Version based switcher?
 impl OgfMotionDefinition {
-  pub fn read_list<T: ByteOrder>(reader: &mut ChunkReader, version: u16) -> XRayResult<Vec<Self>> {
+  pub fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+    version: u16,
+  ) -> XRayResult<Vec<Self>> {
     let count: u16 = reader.read_u16::<T>()?;
     let mut definitions: Vec<Self> = Vec::with_capacity(count as usize);
 
     for _ in 0..count {
-      definitions.push(Self::read::<T>(reader, version).map_err(|error| {
+      definitions.push(Self::read::<T, _>(reader, version).map_err(|error| {
         XRayError::new_read_error(format!("Failed to read ogf motion: {error}"))
       })?);
     }
@@ -41,7 +46,10 @@ impl OgfMotionDefinition {
     Ok(definitions)
   }
 
-  pub fn read<T: ByteOrder>(reader: &mut ChunkReader, version: u16) -> XRayResult<Self> {
+  pub fn read<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+    version: u16,
+  ) -> XRayResult<Self> {
     let name: String = reader.read_w1251_string()?;
     let flags: u32 = reader.read_u32::<T>()?;
     let bone_or_part: u16 = reader.read_u16::<T>()?;
@@ -56,7 +64,7 @@ impl OgfMotionDefinition {
       let mut marks: Vec<OgfMotionMark> = Vec::with_capacity(count as usize);
 
       for _ in 0..count {
-        marks.push(OgfMotionMark::read::<T>(reader).map_err(|error| {
+        marks.push(OgfMotionMark::read::<T, _>(reader).map_err(|error| {
           XRayError::new_read_error(format!("Failed to read ogf motion mark: {error}"))
         })?);
       }
@@ -1,6 +1,8 @@
 use byteorder::{ByteOrder, ReadBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_vector_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_vector_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter,
+};
 use xray_error::XRayResult;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -11,7 +13,7 @@ pub struct OgfMotionMark {
 }
 
 impl ChunkReadWrite for OgfMotionMark {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let name: String = reader.read_w1251_rn_string()?;
 
     let count: u32 = reader.read_u32::<T>()?;
@@ -1,7 +1,8 @@
 use byteorder::{ByteOrder, ReadBytesExt};
 use serde::{Deserialize, Serialize};
 use xray_chunk::{
-  assert_chunk_vector_read, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter,
+  assert_chunk_vector_read, ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader,
+  ChunkWriter,
 };
 use xray_error::{XRayError, XRayResult};
 
@@ -23,13 +24,15 @@ impl OgfPart {
 }
 
 impl ChunkReadWriteList for OgfPart {
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let count: u16 = reader.read_u16::<T>()?;
     let mut parts: Vec<Self> = Vec::with_capacity(count as usize);
 
     for _ in 0..count {
       parts.push(
-        Self::read::<T>(reader).map_err(|error| {
+        Self::read::<T, _>(reader).map_err(|error| {
           XRayError::new_read_error(format!("Failed to read ogf part: {error}"))
         })?,
       );
@@ -50,7 +53,7 @@ impl ChunkReadWriteList for OgfPart {
 }
 
 impl ChunkReadWrite for OgfPart {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let name: String = reader.read_w1251_string()?;
     let count: u16 = reader.read_u16::<T>()?;
 
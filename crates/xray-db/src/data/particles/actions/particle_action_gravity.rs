@@ -1,14 +1,9 @@
 use crate::data::generic::vector_3d::Vector3d;
 use crate::data::particles::particle_action_type::ParticleActionType;
-use crate::export::LtxImportExport;
-use crate::file_import::read_ltx_field;
-use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
-use xray_error::{XRayError, XRayResult};
-use xray_ltx::{Ltx, Section};
+use xray_db_derive::{ChunkReadWrite, LtxImportExport};
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ChunkReadWrite, LtxImportExport)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleActionGravity {
   pub action_flags: u32,
@@ -16,52 +11,6 @@ pub struct ParticleActionGravity {
   pub direction: Vector3d,
 }
 
-impl ChunkReadWrite for ParticleActionGravity {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    Ok(Self {
-      action_flags: reader.read_u32::<T>()?,
-      action_type: reader.read_xr::<T, _>()?,
-      direction: reader.read_xr::<T, _>()?,
-    })
-  }
-
-  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
-    writer.write_u32::<T>(self.action_flags)?;
-    writer.write_xr::<T, _>(&self.action_type)?;
-    writer.write_xr::<T, _>(&self.direction)?;
-
-    Ok(())
-  }
-}
-
-impl LtxImportExport for ParticleActionGravity {
-  fn import(section_name: &str, ltx: &Ltx) -> XRayResult<Self> {
-    let section: &Section = ltx.section(section_name).ok_or_else(|| {
-      XRayError::new_parsing_error(format!(
-        "Particle action section '{}' should be defined in ltx file ({})",
-        section_name,
-        file!()
-      ))
-    })?;
-
-    Ok(Self {
-      action_flags: read_ltx_field("action_flags", section)?,
-      action_type: read_ltx_field("action_type", section)?,
-      direction: read_ltx_field("direction", section)?,
-    })
-  }
-
-  fn export(&self, section_name: &str, ltx: &mut Ltx) -> XRayResult {
-    ltx
-      .with_section(section_name)
-      .set("action_flags", self.action_flags.to_string())
-      .set("action_type", self.action_type.to_string())
-      .set("direction", self.direction.to_string());
-
-    Ok(())
-  }
-}
-
 #[cfg(test)]
 mod tests {
   use crate::data::generic::vector_3d::Vector3d;
@@ -107,10 +56,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 20 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      ParticleActionGravity::read::<XRayByteOrder>(&mut reader)?,
+      ParticleActionGravity::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -4,7 +4,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -18,7 +18,9 @@ pub struct ParticleActionSinkVelocity {
 }
 
 impl ChunkReadWrite for ParticleActionSinkVelocity {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<ParticleActionSinkVelocity> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<ParticleActionSinkVelocity> {
     Ok(Self {
       action_flags: reader.read_u32::<T>()?,
       action_type: reader.read_xr::<T, _>()?,
@@ -113,10 +115,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 80 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      ParticleActionSinkVelocity::read::<XRayByteOrder>(&mut reader)?,
+      ParticleActionSinkVelocity::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
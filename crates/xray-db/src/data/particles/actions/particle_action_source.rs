@@ -5,7 +5,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -28,7 +28,9 @@ pub struct ParticleActionSource {
 }
 
 impl ChunkReadWrite for ParticleActionSource {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<ParticleActionSource> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<ParticleActionSource> {
     Ok(Self {
       action_flags: reader.read_u32::<T>()?,
       action_type: reader.read_xr::<T, _>()?,
@@ -169,10 +171,11 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 380 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
 
     assert_eq!(
-      ParticleActionSource::read::<XRayByteOrder>(&mut reader)?,
+      ParticleActionSource::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -35,7 +35,9 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 use std::str::FromStr;
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter,
+};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -80,7 +82,9 @@ impl ParticleAction {
 
 impl ChunkReadWriteList for ParticleAction {
   /// Read list of particle action data from chunk reader.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let count: u32 = reader.read_u32::<T>()?;
 
     let mut actions: Vec<Self> = Vec::with_capacity(count as usize);
@@ -114,7 +118,7 @@ impl ChunkReadWriteList for ParticleAction {
 }
 
 impl ChunkReadWrite for ParticleAction {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let action_type: ParticleActionType = ParticleActionType::from(reader.read_u32::<T>()?);
 
     Ok(match action_type {
@@ -3,7 +3,7 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -20,7 +20,7 @@ pub struct ParticleDomain {
 
 impl ChunkReadWrite for ParticleDomain {
   /// Read particle domain from chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     Ok(Self {
       domain_type: reader.read_u32::<T>()?,
       coordinates: (reader.read_xr::<T, _>()?, reader.read_xr::<T, _>()?),
@@ -267,11 +267,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 68 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ParticleDomain::read::<XRayByteOrder>(&mut reader)?,
+      ParticleDomain::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
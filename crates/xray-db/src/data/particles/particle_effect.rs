@@ -12,8 +12,8 @@ use byteorder::{ByteOrder, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use xray_chunk::{
   assert_chunk_read, find_optional_chunk_by_id, find_required_chunk_by_id, read_f32_chunk,
-  read_f32_vector_chunk, read_u16_chunk, read_u32_chunk, read_w1251_string_chunk, ChunkReadWrite,
-  ChunkReader, ChunkWriter,
+  read_f32_vector_chunk, read_u16_chunk, read_u32_chunk, read_w1251_string_chunk, ChunkDataSource,
+  ChunkReadWrite, ChunkReader, ChunkWriter,
 };
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
@@ -87,12 +87,12 @@ impl ParticleEffect {
 impl ChunkReadWrite for ParticleEffect {
   /// Read effects by position descriptor.
   /// Parses binary data into version chunk representation object.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    let chunks: Vec<ChunkReader> = reader.read_children();
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let chunks: Vec<ChunkReader<D>> = reader.read_children::<T>()?;
 
     let effect: Self = {
       Self {
-        version: read_u16_chunk::<T>(
+        version: read_u16_chunk::<T, _>(
           &mut find_optional_chunk_by_id(&chunks, Self::VERSION_CHUNK_ID)
             .expect("Particle name chunk not found"),
         )
@@ -106,7 +106,7 @@ impl ChunkReadWrite for ParticleEffect {
         .map_err(|error| {
           XRayError::new_parsing_error(format!("Failed to read particle name chunk: {}", error))
         })?,
-        max_particles: read_u32_chunk::<T>(
+        max_particles: read_u32_chunk::<T, _>(
           &mut find_optional_chunk_by_id(&chunks, Self::MAX_PARTICLES_CHUNK_ID)
             .expect("Particle max particles chunk not found"),
         )
@@ -124,7 +124,7 @@ impl ChunkReadWrite for ParticleEffect {
               error
             ))
           })?,
-        flags: read_u32_chunk::<T>(
+        flags: read_u32_chunk::<T, _>(
           &mut find_optional_chunk_by_id(&chunks, Self::FLAGS_CHUNK_ID)
             .expect("Particle flags chunk not found"),
         )
@@ -141,7 +141,7 @@ impl ChunkReadWrite for ParticleEffect {
             XRayError::new_parsing_error(format!("Failed to read particle sprite chunk: {}", error))
           })?,
         time_limit: find_optional_chunk_by_id(&chunks, Self::TIME_LIMIT_CHUNK_ID).map(|mut it| {
-          read_f32_chunk::<T>(&mut it)
+          read_f32_chunk::<T, _>(&mut it)
             .expect("Invalid frame time limit chunk data in particle effect")
         }),
         collision: find_optional_chunk_by_id(&chunks, Self::COLLISION_CHUNK_ID).map(|mut it| {
@@ -150,7 +150,7 @@ impl ChunkReadWrite for ParticleEffect {
         }),
         velocity_scale: find_optional_chunk_by_id(&chunks, Self::VELOCITY_SCALE_CHUNK_ID).map(
           |mut it| {
-            read_f32_vector_chunk::<T>(&mut it)
+            read_f32_vector_chunk::<T, _>(&mut it)
               .expect("Invalid velocity scale chunk data in particle effect")
               .into()
           },
@@ -162,7 +162,7 @@ impl ChunkReadWrite for ParticleEffect {
           },
         ),
         rotation: find_optional_chunk_by_id(&chunks, Self::ROTATION_CHUNK_ID).map(|mut it| {
-          read_f32_vector_chunk::<T>(&mut it)
+          read_f32_vector_chunk::<T, _>(&mut it)
             .expect("Invalid rotation chunk data in particle effect")
             .into()
         }),
@@ -468,10 +468,10 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 343 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    let read: ParticleEffect = ParticleEffect::read::<XRayByteOrder>(&mut reader)?;
+    let read: ParticleEffect = ParticleEffect::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read, original);
 
@@ -5,7 +5,8 @@ use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 use xray_chunk::{
-  assert_chunk_read, read_till_end_binary_chunk, ChunkReadWrite, ChunkReader, ChunkWriter,
+  assert_chunk_read, read_till_end_binary_chunk, ChunkDataSource, ChunkReadWrite, ChunkReader,
+  ChunkWriter,
 };
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
@@ -23,7 +24,7 @@ impl ParticleEffectEditorData {
 
 impl ChunkReadWrite for ParticleEffectEditorData {
   /// Read particle effect editor data data from chunk redder.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let particle_description: Self = Self {
       value: read_till_end_binary_chunk(reader)?,
     };
@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 
@@ -23,7 +23,7 @@ impl ParticleEffectFrame {
 
 impl ChunkReadWrite for ParticleEffectFrame {
   /// Read frame data from chunk redder.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let particle_frame: Self = Self {
       texture_size: (reader.read_f32::<T>()?, reader.read_f32::<T>()?),
       reserved: (reader.read_f32::<T>()?, reader.read_f32::<T>()?),
@@ -187,10 +187,10 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 28 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    let read: ParticleEffectFrame = ParticleEffectFrame::read::<XRayByteOrder>(&mut reader)?;
+    let read: ParticleEffectFrame = ParticleEffectFrame::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read, original);
 
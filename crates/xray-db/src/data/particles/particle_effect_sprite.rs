@@ -3,7 +3,7 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -21,7 +21,7 @@ impl ParticleEffectSprite {
 
 impl ChunkReadWrite for ParticleEffectSprite {
   /// Read effect sprite data from chunk redder.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let particle_sprite: Self = Self {
       shader_name: reader.read_w1251_string()?,
       texture_name: reader.read_w1251_string()?,
@@ -126,11 +126,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 25 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     let read_sprite: ParticleEffectSprite =
-      ParticleEffectSprite::read::<XRayByteOrder>(&mut reader)?;
+      ParticleEffectSprite::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read_sprite, original);
 
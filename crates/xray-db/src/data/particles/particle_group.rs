@@ -8,7 +8,8 @@ use byteorder::{ByteOrder, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use xray_chunk::{
   find_optional_chunk_by_id, find_required_chunk_by_id, read_f32_chunk, read_u16_chunk,
-  read_u32_chunk, read_w1251_string_chunk, ChunkReadWrite, ChunkReader, ChunkWriter,
+  read_u32_chunk, read_w1251_string_chunk, ChunkDataSource, ChunkReadWrite, ChunkReader,
+  ChunkWriter,
 };
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
@@ -46,11 +47,11 @@ impl ParticleGroup {
 
 impl ChunkReadWrite for ParticleGroup {
   /// Read group from chunk reader binary data.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    let chunks: Vec<ChunkReader> = reader.read_children();
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let chunks: Vec<ChunkReader<D>> = reader.read_children::<T>()?;
 
     let particle_group: Self = Self {
-      version: read_u16_chunk::<T>(&mut find_required_chunk_by_id(
+      version: read_u16_chunk::<T, _>(&mut find_required_chunk_by_id(
         &chunks,
         Self::VERSION_CHUNK_ID,
       )?)?,
@@ -58,18 +59,18 @@ impl ChunkReadWrite for ParticleGroup {
         &chunks,
         Self::NAME_CHUNK_ID,
       )?)?,
-      flags: read_u32_chunk::<T>(&mut find_required_chunk_by_id(
+      flags: read_u32_chunk::<T, _>(&mut find_required_chunk_by_id(
         &chunks,
         Self::FLAGS_CHUNK_ID,
       )?)?,
       effects: find_required_chunk_by_id(&chunks, Self::EFFECTS_CHUNK_ID)?
         .read_xr_list::<T, _>()?,
-      time_limit: read_f32_chunk::<T>(&mut find_required_chunk_by_id(
+      time_limit: read_f32_chunk::<T, _>(&mut find_required_chunk_by_id(
         &chunks,
         Self::TIME_LIMIT_CHUNK_ID,
       )?)?,
       description: find_optional_chunk_by_id(&chunks, Self::DESCRIPTION_CHUNK_ID).map(|mut it| {
-        ParticleDescription::read::<T>(&mut it).expect("Invalid description chunk data")
+        ParticleDescription::read::<T, _>(&mut it).expect("Invalid description chunk data")
       }),
       effects_old: find_optional_chunk_by_id(&chunks, Self::EFFECTS2_CHUNK_ID).map(|mut it| {
         it.read_xr_list::<T, _>()
@@ -2,7 +2,9 @@ use crate::constants::META_TYPE_FIELD;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter,
+};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -31,13 +33,15 @@ impl ParticleGroupEffect {
 
 impl ChunkReadWriteList for ParticleGroupEffect {
   /// Read list of effect groups data from chunk reader.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let count: u32 = reader.read_u32::<T>()?;
 
     let mut effects: Vec<Self> = Vec::with_capacity(count as usize);
 
     for _ in 0..count {
-      effects.push(Self::read::<T>(reader)?);
+      effects.push(Self::read::<T, _>(reader)?);
     }
 
     assert_equal(
@@ -64,7 +68,7 @@ impl ChunkReadWriteList for ParticleGroupEffect {
 
 impl ChunkReadWrite for ParticleGroupEffect {
   /// Read group effect from chunk reader binary data.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let particle_group = Self {
       name: reader.read_w1251_string()?,
       on_play_child_name: reader.read_w1251_string()?,
@@ -245,11 +249,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 370 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ParticleGroupEffect::read_list::<XRayByteOrder>(&mut reader)?,
+      ParticleGroupEffect::read_list::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -291,11 +295,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 103 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ParticleGroupEffect::read::<XRayByteOrder>(&mut reader)?,
+      ParticleGroupEffect::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
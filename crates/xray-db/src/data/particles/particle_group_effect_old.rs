@@ -2,7 +2,9 @@ use crate::constants::META_TYPE_FIELD;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter,
+};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -29,13 +31,15 @@ impl ParticleGroupEffectOld {
 
 impl ChunkReadWriteList for ParticleGroupEffectOld {
   /// Read list of old effect groups data from chunk reader.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let count: u32 = reader.read_u32::<T>()?;
 
     let mut effects: Vec<Self> = Vec::with_capacity(count as usize);
 
     for _ in 0..count {
-      effects.push(Self::read::<T>(reader)?);
+      effects.push(Self::read::<T, _>(reader)?);
     }
 
     assert_equal(
@@ -62,7 +66,7 @@ impl ChunkReadWriteList for ParticleGroupEffectOld {
 
 impl ChunkReadWrite for ParticleGroupEffectOld {
   /// Read old group effect from chunk reader binary data.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let particle_group = Self {
       name: reader.read_w1251_string()?,
       on_play_child_name: reader.read_w1251_string()?,
@@ -230,11 +234,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 200 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ParticleGroupEffectOld::read_list::<XRayByteOrder>(&mut reader)?,
+      ParticleGroupEffectOld::read_list::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -274,11 +278,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 58 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ParticleGroupEffectOld::read::<XRayByteOrder>(&mut reader)?,
+      ParticleGroupEffectOld::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
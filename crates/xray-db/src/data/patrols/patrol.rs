@@ -6,7 +6,8 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 use xray_chunk::{
-  assert_chunk_read, ChunkIterator, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter,
+  assert_chunk_read, ChunkDataSource, ChunkIterator, ChunkReadWrite, ChunkReadWriteList,
+  ChunkReader, ChunkWriter,
 };
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
@@ -42,11 +43,13 @@ impl Patrol {
 
 impl ChunkReadWriteList for Patrol {
   /// Read chunk as list of patrol samples.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let mut patrols: Vec<Self> = Vec::new();
 
-    for mut patrol_reader in ChunkIterator::from_start(reader) {
-      patrols.push(Self::read::<T>(&mut patrol_reader)?);
+    for patrol_reader in ChunkIterator::<T, D>::from_start(reader) {
+      patrols.push(Self::read::<T, _>(&mut patrol_reader?)?);
     }
 
     assert_chunk_read(reader, "Chunk data should be read for patrols list")?;
@@ -70,16 +73,16 @@ impl ChunkReadWriteList for Patrol {
 
 impl ChunkReadWrite for Patrol {
   /// Read chunk as patrol.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    let mut meta_reader: ChunkReader = reader.read_child_by_index(Self::META_CHUNK_ID)?;
-    let mut data_reader: ChunkReader = reader.read_child_by_index(Self::DATA_CHUNK_ID)?;
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let mut meta_reader: ChunkReader<D> = reader.read_child_by_index::<T>(Self::META_CHUNK_ID)?;
+    let mut data_reader: ChunkReader<D> = reader.read_child_by_index::<T>(Self::DATA_CHUNK_ID)?;
 
-    let mut point_count_reader: ChunkReader =
-      data_reader.read_child_by_index(Self::DATA_POINT_COUNT_CHUNK_ID)?;
-    let mut points_reader: ChunkReader =
-      data_reader.read_child_by_index(Self::DATA_POINT_DATA_CHUNK_ID)?;
-    let mut links_reader: ChunkReader =
-      data_reader.read_child_by_index(Self::DATA_LIST_CHUNK_ID)?;
+    let mut point_count_reader: ChunkReader<D> =
+      data_reader.read_child_by_index::<T>(Self::DATA_POINT_COUNT_CHUNK_ID)?;
+    let mut points_reader: ChunkReader<D> =
+      data_reader.read_child_by_index::<T>(Self::DATA_POINT_DATA_CHUNK_ID)?;
+    let mut links_reader: ChunkReader<D> =
+      data_reader.read_child_by_index::<T>(Self::DATA_LIST_CHUNK_ID)?;
 
     let name: String = meta_reader.read_w1251_string()?;
 
@@ -290,7 +293,9 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 210 + 8);
     assert_eq!(
-      Patrol::read::<XRayByteOrder>(&mut ChunkReader::from_slice(file)?.read_child_by_index(0)?)?,
+      Patrol::read::<XRayByteOrder, _>(
+        &mut ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?
+      )?,
       original
     );
 
@@ -366,8 +371,8 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 430 + 8);
     assert_eq!(
-      Patrol::read_list::<XRayByteOrder>(
-        &mut ChunkReader::from_slice(file)?.read_child_by_index(0)?
+      Patrol::read_list::<XRayByteOrder, _>(
+        &mut ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?
       )?,
       original
     );
@@ -2,7 +2,9 @@ use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter,
+};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::assert_equal;
@@ -16,11 +18,13 @@ pub struct PatrolLink {
 
 impl ChunkReadWriteList for PatrolLink {
   /// Read links from chunk file.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let mut links: Vec<Self> = Vec::new();
 
     while reader.has_data() {
-      links.push(Self::read::<T>(reader)?);
+      links.push(Self::read::<T, _>(reader)?);
     }
 
     assert_chunk_read(reader, "Chunk data should be read for patrol links")?;
@@ -40,7 +44,7 @@ impl ChunkReadWriteList for PatrolLink {
 
 impl ChunkReadWrite for PatrolLink {
   /// Read patrol link from chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let index: u32 = reader.read_u32::<T>()?;
     let count: u32 = reader.read_u32::<T>()?;
 
@@ -173,10 +177,10 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 32 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    assert_eq!(PatrolLink::read::<XRayByteOrder>(&mut reader)?, original);
+    assert_eq!(PatrolLink::read::<XRayByteOrder, _>(&mut reader)?, original);
 
     Ok(())
   }
@@ -213,11 +217,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 48 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      PatrolLink::read_list::<XRayByteOrder>(&mut reader)?,
+      PatrolLink::read_list::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
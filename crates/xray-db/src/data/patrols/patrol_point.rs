@@ -5,7 +5,8 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 use xray_chunk::{
-  assert_chunk_read, ChunkIterator, ChunkReadWrite, ChunkReadWriteList, ChunkReader, ChunkWriter,
+  assert_chunk_read, ChunkDataSource, ChunkIterator, ChunkReadWrite, ChunkReadWriteList,
+  ChunkReader, ChunkWriter,
 };
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
@@ -29,12 +30,17 @@ impl PatrolPoint {
 
 impl ChunkReadWriteList for PatrolPoint {
   /// Read points from the chunk reader.
-  fn read_list<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Vec<Self>> {
+  fn read_list<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Vec<Self>> {
     let mut points: Vec<Self> = Vec::new();
 
-    for (index, mut point_reader) in ChunkIterator::from_start(reader).enumerate() {
-      let mut index_reader: ChunkReader = point_reader.read_child_by_index(Self::INDEX_CHUNK_ID)?;
-      let mut data_reader: ChunkReader = point_reader.read_child_by_index(Self::DATA_CHUNK_ID)?;
+    for (index, point_reader) in ChunkIterator::<T, D>::from_start(reader).enumerate() {
+      let mut point_reader: ChunkReader<D> = point_reader?;
+      let mut index_reader: ChunkReader<D> =
+        point_reader.read_child_by_index::<T>(Self::INDEX_CHUNK_ID)?;
+      let mut data_reader: ChunkReader<D> =
+        point_reader.read_child_by_index::<T>(Self::DATA_CHUNK_ID)?;
 
       assert_equal(
         index,
@@ -42,7 +48,7 @@ impl ChunkReadWriteList for PatrolPoint {
         "Expect correct patrol point index",
       )?;
 
-      points.push(Self::read::<T>(&mut data_reader)?);
+      points.push(Self::read::<T, _>(&mut data_reader)?);
 
       assert_chunk_read(&index_reader, "Patrol point index chunk should be read")?;
       assert_chunk_read(&point_reader, "Patrol point data chunk should be read")?;
@@ -78,7 +84,7 @@ impl ChunkReadWriteList for PatrolPoint {
 
 impl ChunkReadWrite for PatrolPoint {
   /// Read patrol point data from the chunk reader.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let point: Self = Self {
       name: reader.read_w1251_string()?,
       position: reader.read_xr::<T, _>()?,
@@ -208,8 +214,8 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 274 + 8);
     assert_eq!(
-      PatrolPoint::read_list::<XRayByteOrder>(
-        &mut ChunkReader::from_slice(file)?.read_child_by_index(0)?
+      PatrolPoint::read_list::<XRayByteOrder, _>(
+        &mut ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?
       )?,
       original
     );
@@ -245,8 +251,8 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 39 + 8);
     assert_eq!(
-      PatrolPoint::read::<XRayByteOrder>(
-        &mut ChunkReader::from_slice(file)?.read_child_by_index(0)?
+      PatrolPoint::read::<XRayByteOrder, _>(
+        &mut ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?
       )?,
       original
     );
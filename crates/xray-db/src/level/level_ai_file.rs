@@ -0,0 +1,373 @@
+use crate::data::generic::vector_3d::Vector3d;
+use crate::data::level_ai::level_ai_header::LevelAiHeader;
+use crate::data::level_ai::level_ai_vertex::LevelAiVertex;
+use byteorder::ByteOrder;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use xray_chunk::{ChunkDataSource, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_utils::{assert_equal, open_export_file};
+
+/// Descriptor of level.ai file (level AI mesh) compiled by xrAI.
+///
+/// File is not chunked: header is followed by compressed AI nodes.
+/// Nodes are sorted by packed XZ cell index, several nodes can share same cell on different heights.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelAiFile {
+  pub header: LevelAiHeader,
+  pub vertices: Vec<LevelAiVertex>,
+}
+
+impl LevelAiFile {
+  pub const SUPPORTED_VERSION: u32 = 10;
+
+  /// Read level AI file from provided path.
+  pub fn read_from_path<T: ByteOrder, P: AsRef<Path>>(path: &P) -> XRayResult<Self> {
+    Self::read_from_file::<T>(File::open(path).map_err(|error| {
+      XRayError::new_not_found_error(format!(
+        "Level AI file was not read: {}, error: {}",
+        path.as_ref().display(),
+        error
+      ))
+    })?)
+  }
+
+  /// Read level AI file from file.
+  pub fn read_from_file<T: ByteOrder>(file: File) -> XRayResult<Self> {
+    Self::read::<T, _>(&mut ChunkReader::from_file(file)?)
+  }
+
+  /// Read level AI file data from the chunk reader.
+  pub fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let header: LevelAiHeader = reader.read_xr::<T, _>()?;
+
+    assert_equal(
+      header.version,
+      Self::SUPPORTED_VERSION,
+      "Expect supported version of level AI file",
+    )?;
+
+    let mut vertices: Vec<LevelAiVertex> = Vec::with_capacity(header.vertices_count as usize);
+
+    for _ in 0..header.vertices_count {
+      vertices.push(reader.read_xr::<T, _>()?);
+    }
+
+    if !reader.is_ended() {
+      return Err(XRayError::new_parsing_error(
+        "Expect level AI file to be read after all vertices",
+      ));
+    }
+
+    if !vertices.is_sorted_by_key(|vertex| vertex.packed_xz) {
+      return Err(XRayError::new_invalid_error(
+        "Expect level AI vertices to be sorted by packed XZ position",
+      ));
+    }
+
+    log::info!("Read level AI file, {} vertices", vertices.len());
+
+    Ok(Self { header, vertices })
+  }
+
+  /// Write level AI file data to the file by provided path.
+  pub fn write_to_path<T: ByteOrder, P: AsRef<Path>>(&self, path: &P) -> XRayResult {
+    fs::create_dir_all(
+      path
+        .as_ref()
+        .parent()
+        .expect("Level AI file parent directory"),
+    )?;
+    self.write_to::<T>(&mut BufWriter::new(open_export_file(path)?))
+  }
+
+  /// Write level AI file data to the writer.
+  pub fn write_to<T: ByteOrder>(&self, writer: &mut dyn Write) -> XRayResult {
+    assert_equal(
+      self.header.vertices_count as usize,
+      self.vertices.len(),
+      "Expect level AI header to match vertices count",
+    )?;
+
+    let mut file_writer: ChunkWriter = ChunkWriter::new();
+
+    file_writer.write_xr::<T, _>(&self.header)?;
+
+    for vertex in &self.vertices {
+      file_writer.write_xr::<T, _>(vertex)?;
+    }
+
+    file_writer.flush_raw_into(writer)?;
+
+    Ok(())
+  }
+
+  /// Get world position of AI node center, same as xray `CLevelGraph::vertex_position`.
+  pub fn get_vertex_position(&self, vertex: &LevelAiVertex) -> Vector3d {
+    let row_length: u32 = self.header.get_row_length();
+
+    Vector3d::new(
+      (vertex.packed_xz / row_length) as f32 * self.header.cell_size + self.header.min.x,
+      vertex.packed_y as f32 / 65535.0 * self.header.factor_y + self.header.min.y,
+      (vertex.packed_xz % row_length) as f32 * self.header.cell_size + self.header.min.z,
+    )
+  }
+
+  /// Get height of AI node plane at provided XZ world coordinates.
+  pub fn get_vertex_plane_y(&self, vertex: &LevelAiVertex, x: f32, z: f32) -> f32 {
+    let center: Vector3d = self.get_vertex_position(vertex);
+    let normal: Vector3d = vertex.get_plane_normal();
+
+    if normal.y == 0.0 {
+      center.y
+    } else {
+      center.y - (normal.x * (x - center.x) + normal.z * (z - center.z)) / normal.y
+    }
+  }
+
+  /// Get grid cell index of world coordinate along one axis, rounded to the closest cell center.
+  fn get_cell_index(&self, coordinate: f32, min: f32) -> i64 {
+    ((coordinate - min) / self.header.cell_size + 0.5).floor() as i64
+  }
+
+  /// Get range of vertices placed in provided packed XZ cell.
+  fn get_cell_vertices(&self, x: u32, z_from: u32, z_to: u32) -> (usize, usize) {
+    let row_length: u32 = self.header.get_row_length();
+    let from: u32 = x * row_length + z_from;
+    let to: u32 = x * row_length + z_to;
+
+    (
+      self
+        .vertices
+        .partition_point(|vertex| vertex.packed_xz < from),
+      self
+        .vertices
+        .partition_point(|vertex| vertex.packed_xz <= to),
+    )
+  }
+
+  /// Find ID of AI node containing provided position, same as xray `CLevelGraph::vertex_id`.
+  ///
+  /// Among nodes of the position cell the closest node under position is preferred,
+  /// if every node is over position the lowest one is picked.
+  pub fn find_vertex_id(&self, position: &Vector3d) -> Option<u32> {
+    let x: i64 = self.get_cell_index(position.x, self.header.min.x);
+    let z: i64 = self.get_cell_index(position.z, self.header.min.z);
+
+    if x < 0
+      || z < 0
+      || x >= self.header.get_column_length() as i64
+      || z >= self.header.get_row_length() as i64
+    {
+      return None;
+    }
+
+    let (from, to) = self.get_cell_vertices(x as u32, z as u32, z as u32);
+    let mut best: Option<(usize, f32)> = None;
+
+    for index in from..to {
+      let y: f32 = self.get_vertex_plane_y(&self.vertices[index], position.x, position.z);
+
+      best = match best {
+        Some((_, best_y))
+          if (best_y <= position.y
+            && (y > position.y || position.y - y >= position.y - best_y))
+            || (best_y > position.y && y > position.y && y - position.y >= best_y - position.y) =>
+        {
+          best
+        }
+        _ => Some((index, y)),
+      };
+    }
+
+    best.map(|(index, _)| index as u32)
+  }
+
+  /// Find ID of AI node closest to provided position by node center.
+  pub fn find_nearest_vertex_id(&self, position: &Vector3d) -> Option<u32> {
+    self
+      .vertices
+      .iter()
+      .enumerate()
+      .map(|(index, vertex)| {
+        (
+          index,
+          self
+            .get_vertex_position(vertex)
+            .distance_squared_to(position),
+        )
+      })
+      .min_by(|(_, first), (_, second)| first.total_cmp(second))
+      .map(|(index, _)| index as u32)
+  }
+
+  /// Get IDs of AI nodes with cells inside of provided XZ world bounds.
+  pub fn get_vertices_in_bounds(&self, min: &Vector3d, max: &Vector3d) -> Vec<u32> {
+    let column_end: i64 = self.header.get_column_length() as i64 - 1;
+    let row_end: i64 = self.header.get_row_length() as i64 - 1;
+
+    let x_from: i64 = self.get_cell_index(min.x, self.header.min.x).max(0);
+    let x_to: i64 = self
+      .get_cell_index(max.x, self.header.min.x)
+      .min(column_end);
+    let z_from: i64 = self.get_cell_index(min.z, self.header.min.z).max(0);
+    let z_to: i64 = self.get_cell_index(max.z, self.header.min.z).min(row_end);
+
+    if x_from > x_to || z_from > z_to {
+      return Vec::new();
+    }
+
+    (x_from..=x_to)
+      .flat_map(|x| {
+        let (from, to) = self.get_cell_vertices(x as u32, z_from as u32, z_to as u32);
+
+        from as u32..to as u32
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::level_ai::level_ai_header::LevelAiHeader;
+  use crate::data::level_ai::level_ai_vertex::LevelAiVertex;
+  use crate::level::level_ai_file::LevelAiFile;
+  use uuid::uuid;
+  use xray_chunk::{ChunkReader, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+  };
+
+  fn get_vertex(packed_xz: u32, packed_y: u16) -> LevelAiVertex {
+    LevelAiVertex {
+      links: [LevelAiVertex::INVALID_LINK; 4],
+      light: 15,
+      cover_high: 0,
+      cover_low: 0,
+      plane: 126,
+      packed_xz,
+      packed_y,
+    }
+  }
+
+  /// Mesh of 3x3 cells with 1m size, center cell has ground and upper floor nodes.
+  fn get_level_ai() -> LevelAiFile {
+    LevelAiFile {
+      header: LevelAiHeader {
+        version: 10,
+        vertices_count: 10,
+        cell_size: 1.0,
+        factor_y: 10.0,
+        min: Vector3d::new(0.0, 0.0, 0.0),
+        max: Vector3d::new(2.0, 10.0, 2.0),
+        guid: uuid!("89e55023-10b1-426f-9247-bb680e5fe0a1"),
+      },
+      vertices: vec![
+        get_vertex(0, 0),
+        get_vertex(1, 0),
+        get_vertex(2, 0),
+        get_vertex(3, 0),
+        get_vertex(4, 0),
+        get_vertex(4, 32767),
+        get_vertex(5, 0),
+        get_vertex(6, 0),
+        get_vertex(7, 0),
+        get_vertex(8, 0),
+      ],
+    }
+  }
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let filename: String = get_relative_test_sample_file_path(file!(), "level.ai");
+    let original: LevelAiFile = get_level_ai();
+
+    original.write_to_path::<XRayByteOrder, _>(&get_absolute_test_resource_path(&filename))?;
+
+    let read: LevelAiFile =
+      LevelAiFile::read_from_path::<XRayByteOrder, _>(&get_absolute_test_resource_path(&filename))?;
+
+    assert_eq!(read.header, original.header);
+    assert_eq!(read.vertices, original.vertices);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_unsorted() -> XRayResult {
+    let mut original: LevelAiFile = get_level_ai();
+    let mut data: Vec<u8> = Vec::new();
+
+    original.vertices.swap(0, 1);
+    original.write_to::<XRayByteOrder>(&mut data)?;
+
+    assert!(LevelAiFile::read::<XRayByteOrder, _>(&mut ChunkReader::from_bytes(&data)?).is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_vertex_position() {
+    let level_ai: LevelAiFile = get_level_ai();
+
+    assert_eq!(
+      level_ai.get_vertex_position(&level_ai.vertices[7]),
+      Vector3d::new(2.0, 0.0, 0.0)
+    );
+    assert_eq!(level_ai.get_vertex_position(&level_ai.vertices[5]).x, 1.0);
+    assert!((level_ai.get_vertex_position(&level_ai.vertices[5]).y - 5.0).abs() < 0.001);
+  }
+
+  #[test]
+  fn test_find_vertex_id() {
+    let level_ai: LevelAiFile = get_level_ai();
+
+    assert_eq!(
+      level_ai.find_vertex_id(&Vector3d::new(0.1, 0.0, 1.9)),
+      Some(2)
+    );
+    assert_eq!(
+      level_ai.find_vertex_id(&Vector3d::new(1.2, 1.0, 0.8)),
+      Some(4)
+    );
+    assert_eq!(
+      level_ai.find_vertex_id(&Vector3d::new(1.2, 7.0, 0.8)),
+      Some(5)
+    );
+    assert_eq!(
+      level_ai.find_vertex_id(&Vector3d::new(1.2, -1.0, 0.8)),
+      Some(4)
+    );
+    assert_eq!(level_ai.find_vertex_id(&Vector3d::new(5.0, 0.0, 0.0)), None);
+    assert_eq!(
+      level_ai.find_nearest_vertex_id(&Vector3d::new(5.0, 0.0, 0.1)),
+      Some(7)
+    );
+  }
+
+  #[test]
+  fn test_vertices_in_bounds() {
+    let level_ai: LevelAiFile = get_level_ai();
+
+    assert_eq!(
+      level_ai.get_vertices_in_bounds(
+        &Vector3d::new(0.6, 0.0, -5.0),
+        &Vector3d::new(1.4, 0.0, 0.4)
+      ),
+      vec![3]
+    );
+    assert_eq!(
+      level_ai.get_vertices_in_bounds(&Vector3d::new(0.6, 0.0, 0.6), &Vector3d::new(5.0, 0.0, 1.4)),
+      vec![4, 5, 8]
+    );
+    assert!(level_ai
+      .get_vertices_in_bounds(&Vector3d::new(3.0, 0.0, 0.0), &Vector3d::new(5.0, 0.0, 1.0))
+      .is_empty());
+  }
+}
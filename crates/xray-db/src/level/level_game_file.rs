@@ -0,0 +1,287 @@
+use crate::data::generic::vector_3d::Vector3d;
+use crate::data::patrols::patrol::Patrol;
+use crate::data::patrols::patrol_link::PatrolLink;
+use crate::data::patrols::patrol_point::PatrolPoint;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use xray_chunk::{
+  assert_chunk_read, find_optional_chunk_by_id, find_required_chunk_by_id, ChunkDataSource,
+  ChunkReader, ChunkWriter,
+};
+use xray_error::{XRayError, XRayResult};
+use xray_utils::{assert_equal, open_export_file};
+
+/// Descriptor of level.game file exported by level editor.
+///
+/// Only way objects chunk is parsed, each way object is a patrol path with raw points.
+/// Navigation data of points is not stored in level.game and is resolved when spawn is built,
+/// until then level and game vertex IDs are set to invalid values.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelGameFile {
+  pub patrols: Vec<Patrol>,
+}
+
+impl LevelGameFile {
+  pub const PATROLS_CHUNK_ID: u32 = 0x1000;
+
+  pub const WAY_VERSION_CHUNK_ID: u32 = 0x0001;
+  pub const WAY_POINTS_CHUNK_ID: u32 = 0x0002;
+  pub const WAY_LINKS_CHUNK_ID: u32 = 0x0003;
+  pub const WAY_NAME_CHUNK_ID: u32 = 0x0005;
+
+  pub const WAY_VERSION: u16 = 0x0013;
+
+  /// Read level game file from provided path.
+  pub fn read_from_path<T: ByteOrder, P: AsRef<Path>>(path: &P) -> XRayResult<Self> {
+    Self::read_from_file::<T>(File::open(path).map_err(|error| {
+      XRayError::new_not_found_error(format!(
+        "Level game file was not read: {}, error: {}",
+        path.as_ref().display(),
+        error
+      ))
+    })?)
+  }
+
+  /// Read level game file from file.
+  pub fn read_from_file<T: ByteOrder>(file: File) -> XRayResult<Self> {
+    Self::read_from_chunks::<T, _>(&ChunkReader::from_file(file)?.read_children::<T>()?)
+  }
+
+  /// Read level game file from chunks, levels without way objects have no patrols chunk.
+  pub fn read_from_chunks<T: ByteOrder, D: ChunkDataSource>(
+    chunks: &[ChunkReader<D>],
+  ) -> XRayResult<Self> {
+    let mut patrols: Vec<Patrol> = Vec::new();
+
+    if let Some(mut patrols_reader) = find_optional_chunk_by_id(chunks, Self::PATROLS_CHUNK_ID) {
+      for mut patrol_reader in patrols_reader.read_children::<T>()? {
+        patrols.push(Self::read_patrol::<T, _>(
+          &patrol_reader.read_children::<T>()?,
+        )?);
+      }
+    }
+
+    log::info!("Read level game file, {} patrols", patrols.len());
+
+    Ok(Self { patrols })
+  }
+
+  /// Read single way object as patrol.
+  fn read_patrol<T: ByteOrder, D: ChunkDataSource>(
+    chunks: &[ChunkReader<D>],
+  ) -> XRayResult<Patrol> {
+    let mut version_reader: ChunkReader<D> =
+      find_required_chunk_by_id(chunks, Self::WAY_VERSION_CHUNK_ID)?;
+    let mut name_reader: ChunkReader<D> =
+      find_required_chunk_by_id(chunks, Self::WAY_NAME_CHUNK_ID)?;
+    let mut points_reader: ChunkReader<D> =
+      find_required_chunk_by_id(chunks, Self::WAY_POINTS_CHUNK_ID)?;
+    let mut links_reader: ChunkReader<D> =
+      find_required_chunk_by_id(chunks, Self::WAY_LINKS_CHUNK_ID)?;
+
+    assert_equal(
+      version_reader.read_u16::<T>()?,
+      Self::WAY_VERSION,
+      "Expect supported version of way object in level game file",
+    )?;
+
+    let name: String = name_reader.read_w1251_string()?;
+
+    let points_count: u16 = points_reader.read_u16::<T>()?;
+    let mut points: Vec<PatrolPoint> = Vec::with_capacity(points_count as usize);
+
+    for _ in 0..points_count {
+      let position: Vector3d = points_reader.read_xr::<T, _>()?;
+      let flags: u32 = points_reader.read_u32::<T>()?;
+
+      points.push(PatrolPoint {
+        name: points_reader.read_w1251_string()?,
+        position,
+        flags,
+        level_vertex_id: u32::MAX,
+        game_vertex_id: u16::MAX,
+      });
+    }
+
+    let links_count: u16 = links_reader.read_u16::<T>()?;
+    let mut links: BTreeMap<u32, Vec<(u32, f32)>> = BTreeMap::new();
+
+    for _ in 0..links_count {
+      let from: u16 = links_reader.read_u16::<T>()?;
+      let to: u16 = links_reader.read_u16::<T>()?;
+      let probability: f32 = links_reader.read_f32::<T>()?;
+
+      links
+        .entry(from as u32)
+        .or_default()
+        .push((to as u32, probability));
+    }
+
+    assert_chunk_read(
+      &version_reader,
+      "Expect way object version chunk to be read",
+    )?;
+    assert_chunk_read(&name_reader, "Expect way object name chunk to be read")?;
+    assert_chunk_read(&points_reader, "Expect way object points chunk to be read")?;
+    assert_chunk_read(&links_reader, "Expect way object links chunk to be read")?;
+
+    Ok(Patrol {
+      name,
+      points,
+      links: links
+        .into_iter()
+        .map(|(index, links)| PatrolLink { index, links })
+        .collect(),
+    })
+  }
+
+  /// Write level game file data to the file by provided path.
+  pub fn write_to_path<T: ByteOrder, P: AsRef<Path>>(&self, path: &P) -> XRayResult {
+    fs::create_dir_all(
+      path
+        .as_ref()
+        .parent()
+        .expect("Level game file parent directory"),
+    )?;
+    self.write_to::<T>(&mut BufWriter::new(open_export_file(path)?))
+  }
+
+  /// Write level game file data to the writer.
+  pub fn write_to<T: ByteOrder>(&self, writer: &mut dyn Write) -> XRayResult {
+    let mut patrols_writer: ChunkWriter = ChunkWriter::new();
+
+    for (index, patrol) in self.patrols.iter().enumerate() {
+      let mut patrol_writer: ChunkWriter = ChunkWriter::new();
+
+      Self::write_patrol::<T>(patrol, &mut patrol_writer)?;
+      patrol_writer.flush_chunk_into::<T>(&mut patrols_writer, index as u32)?;
+    }
+
+    patrols_writer.flush_chunk_into::<T>(writer, Self::PATROLS_CHUNK_ID)?;
+
+    Ok(())
+  }
+
+  /// Write single patrol as way object.
+  fn write_patrol<T: ByteOrder>(patrol: &Patrol, writer: &mut ChunkWriter) -> XRayResult {
+    let mut version_writer: ChunkWriter = ChunkWriter::new();
+    let mut points_writer: ChunkWriter = ChunkWriter::new();
+    let mut links_writer: ChunkWriter = ChunkWriter::new();
+    let mut name_writer: ChunkWriter = ChunkWriter::new();
+
+    version_writer.write_u16::<T>(Self::WAY_VERSION)?;
+
+    points_writer.write_u16::<T>(u16::try_from(patrol.points.len()).map_err(|_| {
+      XRayError::new_invalid_error("Expected way object points count to fit into u16")
+    })?)?;
+
+    for point in &patrol.points {
+      points_writer.write_xr::<T, _>(&point.position)?;
+      points_writer.write_u32::<T>(point.flags)?;
+      points_writer.write_w1251_string(&point.name)?;
+    }
+
+    let links_count: usize = patrol.links.iter().map(|link| link.links.len()).sum();
+
+    links_writer.write_u16::<T>(u16::try_from(links_count).map_err(|_| {
+      XRayError::new_invalid_error("Expected way object links count to fit into u16")
+    })?)?;
+
+    for link in &patrol.links {
+      for (to, probability) in &link.links {
+        links_writer.write_u16::<T>(link.index as u16)?;
+        links_writer.write_u16::<T>(*to as u16)?;
+        links_writer.write_f32::<T>(*probability)?;
+      }
+    }
+
+    name_writer.write_w1251_string(&patrol.name)?;
+
+    version_writer.flush_chunk_into::<T>(writer, Self::WAY_VERSION_CHUNK_ID)?;
+    points_writer.flush_chunk_into::<T>(writer, Self::WAY_POINTS_CHUNK_ID)?;
+    links_writer.flush_chunk_into::<T>(writer, Self::WAY_LINKS_CHUNK_ID)?;
+    name_writer.flush_chunk_into::<T>(writer, Self::WAY_NAME_CHUNK_ID)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::patrols::patrol::Patrol;
+  use crate::data::patrols::patrol_link::PatrolLink;
+  use crate::data::patrols::patrol_point::PatrolPoint;
+  use crate::level::level_game_file::LevelGameFile;
+  use xray_chunk::XRayByteOrder;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+  };
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let filename: String = get_relative_test_sample_file_path(file!(), "level.game");
+
+    let original: LevelGameFile = LevelGameFile {
+      patrols: vec![
+        Patrol {
+          name: String::from("test_patrol_walk"),
+          points: vec![
+            PatrolPoint {
+              name: String::from("wp00"),
+              position: Vector3d::new(1.5, 0.0, -2.5),
+              flags: 1,
+              level_vertex_id: u32::MAX,
+              game_vertex_id: u16::MAX,
+            },
+            PatrolPoint {
+              name: String::from("wp01"),
+              position: Vector3d::new(4.0, 0.5, 3.25),
+              flags: 0,
+              level_vertex_id: u32::MAX,
+              game_vertex_id: u16::MAX,
+            },
+          ],
+          links: vec![
+            PatrolLink {
+              index: 0,
+              links: vec![(1, 1.0)],
+            },
+            PatrolLink {
+              index: 1,
+              links: vec![(0, 0.5)],
+            },
+          ],
+        },
+        Patrol {
+          name: String::from("test_patrol_look"),
+          points: vec![PatrolPoint {
+            name: String::from("wp00"),
+            position: Vector3d::new(-6.0, 1.0, 0.0),
+            flags: 4,
+            level_vertex_id: u32::MAX,
+            game_vertex_id: u16::MAX,
+          }],
+          links: Vec::new(),
+        },
+      ],
+    };
+
+    original.write_to_path::<XRayByteOrder, _>(&get_absolute_test_resource_path(&filename))?;
+
+    let read: LevelGameFile = LevelGameFile::read_from_path::<XRayByteOrder, _>(
+      &get_absolute_test_resource_path(&filename),
+    )?;
+
+    assert_eq!(read.patrols, original.patrols);
+
+    Ok(())
+  }
+}
@@ -0,0 +1,174 @@
+use crate::data::alife::alife_object::AlifeObject;
+use byteorder::ByteOrder;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use xray_chunk::{ChunkDataSource, ChunkReader, ChunkWriter};
+use xray_error::{XRayError, XRayResult};
+use xray_utils::open_export_file;
+
+/// Descriptor of level.spawn file exported by level editor.
+///
+/// Each root chunk stores single spawn packet, chunk IDs are sequential object indexes.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelSpawnFile {
+  pub objects: Vec<AlifeObject>,
+}
+
+impl LevelSpawnFile {
+  /// Read level spawn file from provided path.
+  pub fn read_from_path<T: ByteOrder, P: AsRef<Path>>(path: &P) -> XRayResult<Self> {
+    Self::read_from_file::<T>(File::open(path).map_err(|error| {
+      XRayError::new_not_found_error(format!(
+        "Level spawn file was not read: {}, error: {}",
+        path.as_ref().display(),
+        error
+      ))
+    })?)
+  }
+
+  /// Read level spawn file from file.
+  pub fn read_from_file<T: ByteOrder>(file: File) -> XRayResult<Self> {
+    Self::read_from_chunks::<T, _>(&ChunkReader::from_file(file)?.read_children::<T>()?)
+  }
+
+  /// Read level spawn file from chunks.
+  pub fn read_from_chunks<T: ByteOrder, D: ChunkDataSource>(
+    chunks: &[ChunkReader<D>],
+  ) -> XRayResult<Self> {
+    let mut objects: Vec<AlifeObject> = Vec::with_capacity(chunks.len());
+
+    for chunk in chunks {
+      let mut object_reader: ChunkReader<D> = chunk.clone();
+
+      objects.push(
+        AlifeObject::read_spawn_packet::<T, _>(&mut object_reader)
+          .map_err(|error| chunk.with_location(error))?,
+      );
+    }
+
+    log::info!("Read level spawn file, {} objects", objects.len());
+
+    Ok(Self { objects })
+  }
+
+  /// Write level spawn file data to the file by provided path.
+  pub fn write_to_path<T: ByteOrder, P: AsRef<Path>>(&self, path: &P) -> XRayResult {
+    fs::create_dir_all(
+      path
+        .as_ref()
+        .parent()
+        .expect("Level spawn file parent directory"),
+    )?;
+    self.write_to::<T>(&mut BufWriter::new(open_export_file(path)?))
+  }
+
+  /// Write level spawn file data to the writer.
+  pub fn write_to<T: ByteOrder>(&self, writer: &mut dyn Write) -> XRayResult {
+    for (index, object) in self.objects.iter().enumerate() {
+      let mut object_writer: ChunkWriter = ChunkWriter::new();
+
+      object.write_spawn_packet::<T>(&mut object_writer)?;
+      object_writer.flush_chunk_into::<T>(writer, index as u32)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::alife_object::AlifeObject;
+  use crate::data::alife::alife_object_inherited::AlifeObjectInherited;
+  use crate::data::alife::alife_object_update::AlifeObjectUpdate;
+  use crate::data::alife::inherited::alife_graph_point::AlifeGraphPoint;
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_space_restrictor::AlifeObjectSpaceRestrictor;
+  use crate::data::generic::shape::Shape;
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::meta::cls_id::ClsId;
+  use crate::level::level_spawn_file::LevelSpawnFile;
+  use xray_chunk::XRayByteOrder;
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_resource_path, get_relative_test_sample_file_path,
+  };
+
+  fn get_object(section: &str, name: &str, inherited: AlifeObjectInherited) -> AlifeObject {
+    AlifeObject {
+      id: u16::MAX,
+      net_action: 1,
+      section: String::from(section),
+      clsid: ClsId::from_section(section),
+      name: String::from(name),
+      script_game_id: 0,
+      script_rp: 0,
+      position: Vector3d::new(10.5, 2.0, -4.25),
+      direction: Vector3d::new(0.0, 1.5, 0.0),
+      respawn_time: 0,
+      parent_id: u16::MAX,
+      phantom_id: u16::MAX,
+      script_flags: 32,
+      version: 128,
+      game_type: 1,
+      script_version: 12,
+      client_data_size: 0,
+      spawn_id: u16::MAX,
+      inherited,
+      update: AlifeObjectUpdate::None,
+    }
+  }
+
+  #[test]
+  fn test_read_write() -> XRayResult {
+    let filename: String = get_relative_test_sample_file_path(file!(), "level.spawn");
+
+    let original: LevelSpawnFile = LevelSpawnFile {
+      objects: vec![
+        get_object(
+          "space_restrictor",
+          "test_restrictor",
+          AlifeObjectInherited::CseAlifeSpaceRestrictor(Box::new(AlifeObjectSpaceRestrictor {
+            base: AlifeObjectAbstract {
+              game_vertex_id: u16::MAX,
+              distance: 0.0,
+              direct_control: 1,
+              level_vertex_id: u32::MAX,
+              flags: 0,
+              custom_data: String::from("[logic]\ncfg = test.ltx"),
+              story_id: u32::MAX,
+              spawn_story_id: u32::MAX,
+            },
+            shape: vec![Shape::Sphere((Vector3d::new(0.0, 0.0, 0.0), 4.5))],
+            restrictor_type: 3,
+          })),
+        ),
+        get_object(
+          "graph_point",
+          "test_graph_point",
+          AlifeObjectInherited::CseAlifeGraphPoint(Box::new(AlifeGraphPoint {
+            connection_point_name: String::from("test_connection_point"),
+            connection_level_name: String::from("test_connection_level"),
+            location0: 1,
+            location1: 2,
+            location2: 3,
+            location3: 4,
+          })),
+        ),
+      ],
+    };
+
+    original.write_to_path::<XRayByteOrder, _>(&get_absolute_test_resource_path(&filename))?;
+
+    let read: LevelSpawnFile = LevelSpawnFile::read_from_path::<XRayByteOrder, _>(
+      &get_absolute_test_resource_path(&filename),
+    )?;
+
+    assert_eq!(read.objects, original.objects);
+
+    Ok(())
+  }
+}
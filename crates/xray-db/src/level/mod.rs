@@ -0,0 +1,3 @@
+pub(crate) mod level_ai_file;
+pub(crate) mod level_game_file;
+pub(crate) mod level_spawn_file;
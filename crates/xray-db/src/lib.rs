@@ -2,15 +2,20 @@ pub(crate) mod constants;
 pub(crate) mod data;
 pub(crate) mod export;
 pub(crate) mod file_import;
+pub(crate) mod level;
 pub(crate) mod ogf;
 pub(crate) mod omf;
 pub(crate) mod particles;
 pub(crate) mod spawn;
 pub(crate) mod types;
 
+pub use crate::level::level_ai_file::*;
+pub use crate::level::level_game_file::*;
+pub use crate::level::level_spawn_file::*;
 pub use crate::ogf::ogf_file::*;
 pub use crate::omf::omf_file::*;
 pub use crate::particles::particles_file::*;
+pub use crate::spawn::builder::spawn_builder::*;
 pub use crate::spawn::spawn_file::*;
 pub use crate::types::*;
 pub use xray_chunk::XRayByteOrder;
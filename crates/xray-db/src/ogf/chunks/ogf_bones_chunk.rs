@@ -2,7 +2,8 @@ use crate::data::ogf::ogf_bone::OgfBone;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use xray_chunk::{
-  assert_chunk_read, assert_chunk_vector_read, ChunkReadWrite, ChunkReader, ChunkWriter,
+  assert_chunk_read, assert_chunk_vector_read, ChunkDataSource, ChunkReadWrite, ChunkReader,
+  ChunkWriter,
 };
 use xray_error::XRayResult;
 
@@ -24,7 +25,7 @@ impl OgfBonesChunk {
 }
 
 impl ChunkReadWrite for OgfBonesChunk {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!("Reading bones chunk: {} bytes", reader.read_bytes_remain());
 
     let count: u32 = reader.read_u32::<T>()?;
@@ -1,7 +1,9 @@
 use crate::OgfFile;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_read, ChunkIterator, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_read, ChunkDataSource, ChunkIterator, ChunkReadWrite, ChunkReader, ChunkWriter,
+};
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,7 +16,7 @@ impl OgfChildrenChunk {
 }
 
 impl ChunkReadWrite for OgfChildrenChunk {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!(
       "Reading children chunk: {} bytes",
       reader.read_bytes_remain()
@@ -22,7 +24,9 @@ impl ChunkReadWrite for OgfChildrenChunk {
 
     let mut children: Vec<OgfFile> = Vec::new();
 
-    for (index, mut object_reader) in (0..).zip(ChunkIterator::from_start(reader)) {
+    for (index, object_reader) in (0..).zip(ChunkIterator::<T, D>::from_start(reader)) {
+      let mut object_reader: ChunkReader<D> = object_reader?;
+
       if object_reader.id != index {
         return Err(XRayError::new_unexpected_error(format!(
           "Invalid data in OGF children chunk, expected index {}, got {}",
@@ -30,7 +34,7 @@ impl ChunkReadWrite for OgfChildrenChunk {
         )));
       }
 
-      children.push(OgfFile::read_from_chunk::<T>(&mut object_reader)?);
+      children.push(OgfFile::read_from_chunk::<T, _>(&mut object_reader)?);
     }
 
     assert_chunk_read(reader, "Expect all data to be read from ogf children")?;
@@ -1,6 +1,6 @@
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_read, ChunkReader, ChunkWriter};
+use xray_chunk::{assert_chunk_read, ChunkDataSource, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,7 +18,10 @@ impl OgfKinematicsChunk {
 // todo: Conditional read + implement chunk RW.
 // todo: Conditional read + implement chunk RW.
 impl OgfKinematicsChunk {
-  pub fn read<T: ByteOrder>(reader: &mut ChunkReader, chunk_id: u32) -> XRayResult<Self> {
+  pub fn read<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+    chunk_id: u32,
+  ) -> XRayResult<Self> {
     log::info!(
       "Reading motion refs chunk: {} bytes, chunk id {} ",
       reader.read_bytes_remain(),
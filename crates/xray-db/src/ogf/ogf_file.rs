@@ -10,7 +10,7 @@ use std::fs::File;
 use std::path::Path;
 use xray_chunk::{
   find_one_of_optional_chunk_by_id, find_one_of_required_chunks_by_id, find_optional_chunk_by_id,
-  find_required_chunk_by_id, ChunkReader,
+  find_required_chunk_by_id, ChunkDataSource, ChunkReader,
 };
 use xray_error::{XRayError, XRayResult};
 
@@ -37,14 +37,18 @@ impl OgfFile {
   }
 
   pub fn read_from_file<T: ByteOrder>(file: File) -> XRayResult<Self> {
-    Self::read_from_chunk::<T>(&mut ChunkReader::from_file(file)?)
+    Self::read_from_chunk::<T, _>(&mut ChunkReader::from_file(file)?)
   }
 
-  pub fn read_from_chunk<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    Self::read_from_chunks::<T>(&reader.read_children())
+  pub fn read_from_chunk<T: ByteOrder, D: ChunkDataSource>(
+    reader: &mut ChunkReader<D>,
+  ) -> XRayResult<Self> {
+    Self::read_from_chunks::<T, _>(&reader.read_children::<T>()?)
   }
 
-  pub fn read_from_chunks<T: ByteOrder>(chunks: &[ChunkReader]) -> XRayResult<Self> {
+  pub fn read_from_chunks<T: ByteOrder, D: ChunkDataSource>(
+    chunks: &[ChunkReader<D>],
+  ) -> XRayResult<Self> {
     Ok(Self {
       header: find_required_chunk_by_id(chunks, OgfHeaderChunk::CHUNK_ID)?.read_xr::<T, _>()?,
       texture: match find_optional_chunk_by_id(chunks, OgfTextureChunk::CHUNK_ID) {
@@ -70,7 +74,7 @@ impl OgfFile {
           OgfKinematicsChunk::CHUNK_ID_OLD,
         ],
       ) {
-        Some((id, mut it)) => Some(OgfKinematicsChunk::read::<T>(&mut it, id)?),
+        Some((id, mut it)) => Some(OgfKinematicsChunk::read::<T, _>(&mut it, id)?),
         None => None,
       },
     })
@@ -86,7 +90,7 @@ impl OgfFile {
   /// Read only list of motion refs specifically and skip other data parts.
   pub fn read_motions_refs_from_file<T: ByteOrder>(file: File) -> XRayResult<Vec<String>> {
     let mut reader: ChunkReader = ChunkReader::from_file(file)?;
-    let chunks: Vec<ChunkReader> = reader.read_children();
+    let chunks: Vec<ChunkReader> = reader.read_children::<T>()?;
 
     log::info!(
       "Reading ogf file motion refs, {} chunks, {} bytes",
@@ -102,6 +106,6 @@ impl OgfFile {
       ],
     )?;
 
-    Ok(OgfKinematicsChunk::read::<T>(&mut chunk, chunk_id)?.motion_refs)
+    Ok(OgfKinematicsChunk::read::<T, _>(&mut chunk, chunk_id)?.motion_refs)
   }
 }
@@ -1,7 +1,7 @@
 use crate::data::ogf::ogf_motion::OgfMotion;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xray_chunk::{read_u32_chunk, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{read_u32_chunk, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,15 +14,15 @@ impl OmfMotionsChunk {
 }
 
 impl ChunkReadWrite for OmfMotionsChunk {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!(
       "Reading motions chunk: {} bytes",
       reader.read_bytes_remain()
     );
 
-    let mut chunks: Vec<ChunkReader> = reader.read_children();
+    let mut chunks: Vec<ChunkReader<D>> = reader.read_children::<T>()?;
 
-    let bones_motions_count: u32 = read_u32_chunk::<T>(
+    let bones_motions_count: u32 = read_u32_chunk::<T, _>(
       chunks
         .first_mut()
         .expect("Correct omf motions chunk with count definitions"),
@@ -3,7 +3,7 @@ use crate::data::ogf::ogf_part::OgfPart;
 use crate::OmfFile;
 use byteorder::{ByteOrder, ReadBytesExt};
 use serde::{Deserialize, Serialize};
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -22,7 +22,7 @@ impl OmfParametersChunk {
 }
 
 impl ChunkReadWrite for OmfParametersChunk {
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!(
       "Reading parameters chunk: {} bytes",
       reader.read_bytes_remain()
@@ -42,7 +42,7 @@ impl ChunkReadWrite for OmfParametersChunk {
       .read_xr_list::<T, _>()
       .map_err(|error| XRayError::new_read_error(format!("Failed to read ogf parts: {error}")))?;
 
-    let motions: Vec<OgfMotionDefinition> = OgfMotionDefinition::read_list::<T>(reader, version)
+    let motions: Vec<OgfMotionDefinition> = OgfMotionDefinition::read_list::<T, _>(reader, version)
       .map_err(|error| {
         XRayError::new_read_error(format!("Failed to read ogf motion definitions: {}", error))
       })?;
@@ -4,7 +4,7 @@ use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::path::Path;
-use xray_chunk::{find_required_chunk_by_id, ChunkReader};
+use xray_chunk::{find_required_chunk_by_id, ChunkDataSource, ChunkReader};
 use xray_error::{XRayError, XRayResult};
 
 // c++ CKinematicsAnimated
@@ -28,10 +28,12 @@ impl OmfFile {
   }
 
   pub fn read_from_file<T: ByteOrder>(file: File) -> XRayResult<Self> {
-    Self::read_from_chunks::<T>(&ChunkReader::from_file(file)?.read_children())
+    Self::read_from_chunks::<T, _>(&ChunkReader::from_file(file)?.read_children::<T>()?)
   }
 
-  pub fn read_from_chunks<T: ByteOrder>(chunks: &[ChunkReader]) -> XRayResult<Self> {
+  pub fn read_from_chunks<T: ByteOrder, D: ChunkDataSource>(
+    chunks: &[ChunkReader<D>],
+  ) -> XRayResult<Self> {
     assert_eq!(
       chunks.len(),
       2,
@@ -72,7 +74,7 @@ impl OmfFile {
 
   pub fn read_motions_from_file<T: ByteOrder>(file: File) -> XRayResult<Vec<String>> {
     let mut reader: ChunkReader = ChunkReader::from_file(file)?;
-    let chunks: Vec<ChunkReader> = reader.read_children();
+    let chunks: Vec<ChunkReader> = reader.read_children::<T>()?;
 
     log::info!(
       "Reading omf file motions, {} chunks, {} bytes",
@@ -5,7 +5,7 @@ use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 use std::path::Path;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 use xray_ltx::Ltx;
 use xray_utils::{assert, open_export_file};
@@ -23,8 +23,8 @@ impl ParticlesEffectsChunk {
 impl ChunkReadWrite for ParticlesEffectsChunk {
   /// Read effects chunk by position descriptor.
   /// Parses binary data into version chunk representation object.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
-    let chunks: Vec<ChunkReader> = reader.read_children();
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
+    let chunks: Vec<ChunkReader<D>> = reader.read_children::<T>()?;
     let mut effects: Vec<ParticleEffect> = Vec::new();
 
     log::info!(
@@ -34,7 +34,7 @@ impl ChunkReadWrite for ParticlesEffectsChunk {
     );
 
     for mut chunk in chunks {
-      effects.push(ParticleEffect::read::<T>(&mut chunk)?);
+      effects.push(ParticleEffect::read::<T, _>(&mut chunk)?);
     }
 
     effects.sort_by(|first, second| first.name.cmp(&second.name));
@@ -4,7 +4,7 @@ use crate::file_import::read_ltx_field;
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::{XRayError, XRayResult};
 use xray_ltx::{Ltx, Section};
 use xray_utils::open_export_file;
@@ -23,7 +23,7 @@ impl ParticlesHeaderChunk {
 impl ChunkReadWrite for ParticlesHeaderChunk {
   /// Read version chunk by position descriptor.
   /// Parses binary data into version chunk representation object.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     let header_chunk: Self = Self {
       version: reader.read_u16::<T>()?,
     };
@@ -138,11 +138,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 2 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ParticlesHeaderChunk::read::<XRayByteOrder>(&mut reader)
+      ParticlesHeaderChunk::read::<XRayByteOrder, _>(&mut reader)
         .unwrap_err()
         .to_string(),
       "Not implemented error: Unknown version in particles header chunk, expected v1 only",
@@ -175,11 +175,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 2 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      ParticlesHeaderChunk::read::<XRayByteOrder>(&mut reader)?,
+      ParticlesHeaderChunk::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -9,7 +9,7 @@ use std::fs;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
-use xray_chunk::{find_required_chunk_by_id, ChunkReader, ChunkWriter};
+use xray_chunk::{find_required_chunk_by_id, ChunkDataSource, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 use xray_utils::{assert, assert_equal, open_export_file};
 
@@ -29,11 +29,13 @@ impl ParticlesFile {
 
   /// Read particles from file.
   pub fn read_from_file<T: ByteOrder>(file: File) -> XRayResult<Self> {
-    Self::read_from_chunks::<T>(&ChunkReader::from_file(file)?.read_children())
+    Self::read_from_chunks::<T, _>(&ChunkReader::from_file(file)?.read_children::<T>()?)
   }
 
   /// Read particles from chunks.
-  pub fn read_from_chunks<T: ByteOrder>(chunks: &[ChunkReader]) -> XRayResult<Self> {
+  pub fn read_from_chunks<T: ByteOrder, D: ChunkDataSource>(
+    chunks: &[ChunkReader<D>],
+  ) -> XRayResult<Self> {
     assert(
       !chunks
         .iter()
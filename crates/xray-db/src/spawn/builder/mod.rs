@@ -0,0 +1,2 @@
+pub(crate) mod spawn_builder;
+pub(crate) mod spawn_level_locator;
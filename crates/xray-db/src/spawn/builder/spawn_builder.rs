@@ -0,0 +1,1069 @@
+use crate::data::alife::alife_object::AlifeObject;
+use crate::data::alife::alife_object_inherited::AlifeObjectInherited;
+use crate::data::alife::alife_object_update::AlifeObjectUpdate;
+use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+use crate::data::alife::inherited::alife_object_anomaly_zone::AlifeObjectAnomalyZone;
+use crate::data::artefact_spawn::artefact_spawn_point::ArtefactSpawnPoint;
+use crate::data::generic::vector_3d::Vector3d;
+use crate::data::graph::graph_level::GraphLevel;
+use crate::data::meta::alife_class::AlifeClass;
+use crate::data::patrols::patrol::Patrol;
+use crate::level::level_ai_file::LevelAiFile;
+use crate::level::level_game_file::LevelGameFile;
+use crate::level::level_spawn_file::LevelSpawnFile;
+use crate::spawn::builder::spawn_level_locator::{SpawnLevelLocator, SpawnLocation};
+use crate::spawn::chunks::spawn_alife_spawns_chunk::SpawnALifeSpawnsChunk;
+use crate::spawn::chunks::spawn_artefact_spawns_chunk::SpawnArtefactSpawnsChunk;
+use crate::spawn::chunks::spawn_graphs_chunk::SpawnGraphsChunk;
+use crate::spawn::chunks::spawn_header_chunk::SpawnHeaderChunk;
+use crate::spawn::chunks::spawn_patrols_chunk::SpawnPatrolsChunk;
+use crate::spawn::spawn_file::SpawnFile;
+use byteorder::ByteOrder;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+use xray_chunk::ChunkReader;
+use xray_error::{XRayError, XRayResult};
+
+/// Sources of single level exported by level editor and AI mesh compiled for it.
+#[derive(Debug)]
+pub struct SpawnBuilderLevel {
+  pub name: String,
+  pub spawn: LevelSpawnFile,
+  pub game: LevelGameFile,
+  pub ai: LevelAiFile,
+}
+
+/// Builder of all.spawn file from level editor exports and game graph.
+/// Represents spawn compilation step of xrAI (`-s` flag):
+///
+/// - objects of all levels get sequential IDs
+/// - objects and patrol points get AI nodes resolved with level AI mesh and game vertices
+///   resolved with cross tables of game graph
+/// - level changers get destination vertices resolved with graph points of destination level
+/// - anomaly zones get artefact spawn nodes baked from AI nodes inside of zone shapes
+/// - single actor is expected across all levels
+///
+/// Update packets are created from spawn state of objects.
+/// Graph points are only used to resolve level changers and are not included into spawn.
+#[derive(Debug)]
+pub struct SpawnBuilder {
+  pub graphs: SpawnGraphsChunk,
+  pub levels: Vec<SpawnBuilderLevel>,
+}
+
+impl SpawnBuilder {
+  pub const LEVEL_SPAWN_FILE_NAME: &'static str = "level.spawn";
+  pub const LEVEL_GAME_FILE_NAME: &'static str = "level.game";
+  pub const LEVEL_AI_FILE_NAME: &'static str = "level.ai";
+
+  pub fn new(graphs: SpawnGraphsChunk) -> Self {
+    Self {
+      graphs,
+      levels: Vec::new(),
+    }
+  }
+
+  /// Read game graph and sources of each graph level from provided paths.
+  /// Levels are expected in `<levels_path>/<level_name>/` folders, level.game is optional.
+  /// AI mesh (level.ai) is required to resolve navigation data of objects.
+  pub fn read_from_path<T: ByteOrder, P: AsRef<Path>>(
+    graph_path: &P,
+    levels_path: &P,
+  ) -> XRayResult<Self> {
+    let mut builder: Self = Self::new(
+      ChunkReader::from_file(File::open(graph_path).map_err(|error| {
+        XRayError::new_not_found_error(format!(
+          "Game graph file was not read: {}, error: {}",
+          graph_path.as_ref().display(),
+          error
+        ))
+      })?)?
+      .read_xr::<T, _>()?,
+    );
+
+    let names: Vec<String> = builder
+      .graphs
+      .levels
+      .iter()
+      .map(|level| level.name.clone())
+      .collect();
+
+    for name in names {
+      let level_path: PathBuf = levels_path.as_ref().join(&name);
+      let game_path: PathBuf = level_path.join(Self::LEVEL_GAME_FILE_NAME);
+
+      log::info!("Reading level '{}' from {}", name, level_path.display());
+
+      builder.add_level(SpawnBuilderLevel {
+        spawn: LevelSpawnFile::read_from_path::<T, _>(
+          &level_path.join(Self::LEVEL_SPAWN_FILE_NAME),
+        )?,
+        game: if game_path.is_file() {
+          LevelGameFile::read_from_path::<T, _>(&game_path)?
+        } else {
+          LevelGameFile {
+            patrols: Vec::new(),
+          }
+        },
+        ai: LevelAiFile::read_from_path::<T, _>(&level_path.join(Self::LEVEL_AI_FILE_NAME))?,
+        name,
+      });
+    }
+
+    Ok(builder)
+  }
+
+  /// Add sources of level to build, level should be registered in game graph.
+  pub fn add_level(&mut self, level: SpawnBuilderLevel) {
+    self.levels.push(level);
+  }
+
+  /// Build spawn file from added levels.
+  pub fn build<T: ByteOrder>(self) -> XRayResult<SpawnFile> {
+    let Self { graphs, levels } = self;
+
+    let mut sources: Vec<(String, LevelSpawnFile, LevelGameFile)> = Vec::new();
+    let mut meshes: Vec<(String, LevelAiFile)> = Vec::new();
+
+    for level in levels {
+      sources.push((level.name.clone(), level.spawn, level.game));
+      meshes.push((level.name, level.ai));
+    }
+
+    let (objects, nodes, patrols) = {
+      let locators: HashMap<&str, SpawnLevelLocator> = meshes
+        .iter()
+        .map(|(name, ai)| {
+          let level: &GraphLevel = graphs
+            .levels
+            .iter()
+            .find(|level| level.name == *name)
+            .ok_or_else(|| {
+              XRayError::new_invalid_error(format!(
+                "Level '{}' is not registered in game graph",
+                name
+              ))
+            })?;
+
+          Ok((name.as_str(), SpawnLevelLocator::new(&graphs, level, ai)?))
+        })
+        .collect::<XRayResult<_>>()?;
+
+      let graph_points: HashMap<(String, String), Vector3d> = sources
+        .iter()
+        .flat_map(|(name, spawn, _)| {
+          spawn
+            .objects
+            .iter()
+            .filter(|object| {
+              matches!(
+                object.inherited,
+                AlifeObjectInherited::CseAlifeGraphPoint(_)
+              )
+            })
+            .map(|object| ((name.clone(), object.name.clone()), object.position.clone()))
+        })
+        .collect();
+
+      let mut objects: Vec<AlifeObject> = Vec::new();
+      let mut nodes: Vec<ArtefactSpawnPoint> = Vec::new();
+      let mut patrols: Vec<Patrol> = Vec::new();
+      let mut patrol_names: HashSet<String> = HashSet::new();
+
+      for (name, spawn, game) in sources {
+        let locator: &SpawnLevelLocator = &locators[name.as_str()];
+
+        log::info!(
+          "Building level '{}', {} objects, {} patrols",
+          name,
+          spawn.objects.len(),
+          game.patrols.len()
+        );
+
+        for mut object in spawn.objects {
+          if matches!(
+            object.inherited,
+            AlifeObjectInherited::CseAlifeGraphPoint(_)
+          ) {
+            continue;
+          }
+
+          let location: SpawnLocation = locator.locate::<T>(&object.position)?;
+
+          if let Some(base) = object.inherited.get_abstract_mut() {
+            Self::set_location(base, &location);
+          }
+
+          if let Some(zone) = object.inherited.get_anomaly_zone_mut() {
+            Self::bake_artefact_spawns::<T>(
+              locator,
+              zone,
+              &object.position,
+              &object.direction,
+              &mut nodes,
+            )?;
+          }
+
+          if let AlifeObjectInherited::SeLevelChanger(changer) = &mut object.inherited {
+            match (
+              graph_points.get(&(
+                changer.dest_level_name.clone(),
+                changer.dest_graph_point.clone(),
+              )),
+              locators.get(changer.dest_level_name.as_str()),
+            ) {
+              (Some(position), Some(destination)) => {
+                let location: SpawnLocation = destination.locate::<T>(position)?;
+
+                changer.dest_game_vertex_id = location.game_vertex_id;
+                changer.dest_level_vertex_id =
+                  graphs.vertices[location.game_vertex_id as usize].level_vertex_id;
+              }
+              _ => {
+                return Err(XRayError::new_invalid_error(format!(
+                  "Destination '{}' of level changer '{}' was not found on level '{}'",
+                  changer.dest_graph_point, object.name, changer.dest_level_name
+                )))
+              }
+            }
+          }
+
+          let id: u16 = u16::try_from(objects.len())
+            .ok()
+            .filter(|id| *id != u16::MAX)
+            .ok_or_else(|| {
+              XRayError::new_invalid_error("Expected objects count to fit into u16")
+            })?;
+
+          object.id = id;
+          object.spawn_id = id;
+          object.update = AlifeObjectUpdate::from_spawn(
+            &AlifeClass::from_cls_id(&object.clsid),
+            &object.inherited,
+            &object.position,
+            &object.direction,
+          );
+
+          objects.push(object);
+        }
+
+        for mut patrol in game.patrols {
+          if !patrol_names.insert(patrol.name.clone()) {
+            return Err(XRayError::new_invalid_error(format!(
+              "Patrol '{}' of level '{}' is already defined",
+              patrol.name, name
+            )));
+          }
+
+          for point in &mut patrol.points {
+            let location: SpawnLocation = locator.locate::<T>(&point.position)?;
+
+            point.game_vertex_id = location.game_vertex_id;
+            point.level_vertex_id = location.level_vertex_id;
+          }
+
+          patrols.push(patrol);
+        }
+      }
+
+      let actors_count: usize = objects
+        .iter()
+        .filter(|object| matches!(object.inherited, AlifeObjectInherited::SeActor(_)))
+        .count();
+
+      if actors_count != 1 {
+        return Err(XRayError::new_invalid_error(format!(
+          "Expected single actor in spawn, got {actors_count}"
+        )));
+      }
+
+      (objects, nodes, patrols)
+    };
+
+    log::info!(
+      "Built spawn with {} objects, {} artefact spawn nodes, {} patrols",
+      objects.len(),
+      nodes.len(),
+      patrols.len()
+    );
+
+    Ok(SpawnFile {
+      header: SpawnHeaderChunk {
+        version: graphs.header.version as u32,
+        guid: Uuid::new_v4(),
+        graph_guid: graphs.header.guid,
+        objects_count: objects.len() as u32,
+        levels_count: graphs.header.levels_count as u32,
+      },
+      alife_spawn: SpawnALifeSpawnsChunk { objects },
+      artefact_spawn: SpawnArtefactSpawnsChunk { nodes },
+      patrols: SpawnPatrolsChunk { patrols },
+      graphs,
+    })
+  }
+
+  /// Write resolved navigation data into object base.
+  fn set_location(base: &mut AlifeObjectAbstract, location: &SpawnLocation) {
+    base.game_vertex_id = location.game_vertex_id;
+    base.level_vertex_id = location.level_vertex_id;
+    base.distance = location.distance;
+  }
+
+  /// Pick artefact spawn nodes from AI nodes inside of zone shapes.
+  /// Requested count of artefact spawns is limited by count of AI nodes inside of the zone.
+  ///
+  /// xrAI picks random nodes of the zone, here closest to zone position nodes go first
+  /// to keep built spawn reproducible.
+  fn bake_artefact_spawns<T: ByteOrder>(
+    locator: &SpawnLevelLocator,
+    zone: &mut AlifeObjectAnomalyZone,
+    position: &Vector3d,
+    direction: &Vector3d,
+    nodes: &mut Vec<ArtefactSpawnPoint>,
+  ) -> XRayResult {
+    let candidates: Vec<u32> =
+      locator.get_vertices_in_shapes(position, direction, &zone.base.base.shape);
+    let count: usize = candidates.len().min(zone.artefact_spawn_count as usize);
+
+    if count < zone.artefact_spawn_count as usize {
+      log::warn!(
+        "Anomaly zone at {} requested {} artefact spawns, {} nodes available",
+        position,
+        zone.artefact_spawn_count,
+        count
+      );
+    }
+
+    zone.artefact_position_offset = nodes.len() as u32;
+    zone.artefact_spawn_count = count as u16;
+
+    for level_vertex_id in candidates.into_iter().take(count) {
+      nodes.push(ArtefactSpawnPoint {
+        position: locator
+          .ai
+          .get_vertex_position(&locator.ai.vertices[level_vertex_id as usize]),
+        level_vertex_id,
+        distance: locator.locate_vertex::<T>(level_vertex_id)?.distance,
+      });
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::alife::alife_object::AlifeObject;
+  use crate::data::alife::alife_object_inherited::AlifeObjectInherited;
+  use crate::data::alife::alife_object_update::AlifeObjectUpdate;
+  use crate::data::alife::inherited::alife_actor::AlifeActor;
+  use crate::data::alife::inherited::alife_anomalous_zone::AlifeAnomalousZone;
+  use crate::data::alife::inherited::alife_graph_point::AlifeGraphPoint;
+  use crate::data::alife::inherited::alife_level_changer::AlifeLevelChanger;
+  use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
+  use crate::data::alife::inherited::alife_object_actor::AlifeObjectActor;
+  use crate::data::alife::inherited::alife_object_anomaly_zone::AlifeObjectAnomalyZone;
+  use crate::data::alife::inherited::alife_object_creature::AlifeObjectCreature;
+  use crate::data::alife::inherited::alife_object_custom_zone::AlifeObjectCustomZone;
+  use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
+  use crate::data::alife::inherited::alife_object_skeleton::AlifeObjectSkeleton;
+  use crate::data::alife::inherited::alife_object_space_restrictor::AlifeObjectSpaceRestrictor;
+  use crate::data::alife::inherited::alife_object_trader_abstract::AlifeObjectTraderAbstract;
+  use crate::data::artefact_spawn::artefact_spawn_point::ArtefactSpawnPoint;
+  use crate::data::generic::shape::Shape;
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::graph::graph_cross_table::GraphCrossTable;
+  use crate::data::graph::graph_header::GraphHeader;
+  use crate::data::graph::graph_level::GraphLevel;
+  use crate::data::graph::graph_vertex::GraphVertex;
+  use crate::data::level_ai::level_ai_header::LevelAiHeader;
+  use crate::data::level_ai::level_ai_vertex::LevelAiVertex;
+  use crate::data::meta::cls_id::ClsId;
+  use crate::data::patrols::patrol::Patrol;
+  use crate::data::patrols::patrol_point::PatrolPoint;
+  use crate::level::level_ai_file::LevelAiFile;
+  use crate::level::level_game_file::LevelGameFile;
+  use crate::level::level_spawn_file::LevelSpawnFile;
+  use crate::spawn::builder::spawn_builder::{SpawnBuilder, SpawnBuilderLevel};
+  use crate::spawn::chunks::spawn_graphs_chunk::SpawnGraphsChunk;
+  use crate::spawn::spawn_file::SpawnFile;
+  use byteorder::WriteBytesExt;
+  use std::f32::consts::FRAC_PI_2;
+  use std::io::Cursor;
+  use std::path::PathBuf;
+  use uuid::{uuid, Uuid};
+  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_error::XRayResult;
+  use xray_test_utils::utils::{
+    get_absolute_test_sample_file_path, get_relative_test_sample_file_path,
+    overwrite_test_relative_resource_as_file,
+  };
+
+  const LEVEL_A_GUID: Uuid = uuid!("89e55023-10b1-426f-9247-bb680e5fe0a1");
+  const LEVEL_B_GUID: Uuid = uuid!("89e55023-10b1-426f-9247-bb680e5fe0b2");
+
+  fn get_cross_table(level_guid: Uuid, cells: &[(u16, f32)]) -> XRayResult<GraphCrossTable> {
+    let mut data: Vec<u8> = Vec::new();
+
+    for (game_vertex_id, distance) in cells {
+      data.write_u16::<XRayByteOrder>(*game_vertex_id)?;
+      data.write_f32::<XRayByteOrder>(*distance)?;
+    }
+
+    Ok(GraphCrossTable {
+      version: 10,
+      nodes_count: cells.len() as u32,
+      vertices_count: 3,
+      level_guid,
+      game_guid: uuid!("78e55023-10b1-426f-9247-bb680e5fe0b7"),
+      data,
+    })
+  }
+
+  fn get_vertex(level_point: Vector3d, level_id: u8, level_vertex_id: u32) -> GraphVertex {
+    GraphVertex {
+      level_point: level_point.clone(),
+      game_point: level_point,
+      level_id,
+      level_vertex_id,
+      vertex_type: (0, 0, 0, 0).into(),
+      edges_offset: 0,
+      level_points_offset: 0,
+      edges_count: 0,
+      level_points_count: 0,
+    }
+  }
+
+  /// Flat AI mesh with 1m cells covering provided bounds, nodes are placed in every cell.
+  fn get_level_ai(guid: Uuid, min: Vector3d, max: Vector3d) -> LevelAiFile {
+    let mut level_ai: LevelAiFile = LevelAiFile {
+      header: LevelAiHeader {
+        version: 10,
+        vertices_count: 0,
+        cell_size: 1.0,
+        factor_y: 1.0,
+        min,
+        max,
+        guid,
+      },
+      vertices: Vec::new(),
+    };
+
+    let count: u32 = level_ai.header.get_row_length() * level_ai.header.get_column_length();
+
+    level_ai.header.vertices_count = count;
+    level_ai.vertices = (0..count)
+      .map(|packed_xz| LevelAiVertex {
+        links: [LevelAiVertex::INVALID_LINK; 4],
+        light: 15,
+        cover_high: 0,
+        cover_low: 0,
+        plane: 126,
+        packed_xz,
+        packed_y: 0,
+      })
+      .collect();
+
+    level_ai
+  }
+
+  /// AI mesh of level_a: 21x3 cells from (0,0,0) to (20,0,2), node ID is `x * 3 + z`.
+  fn get_level_a_ai() -> LevelAiFile {
+    get_level_ai(
+      LEVEL_A_GUID,
+      Vector3d::new(0.0, 0.0, 0.0),
+      Vector3d::new(20.0, 1.0, 2.0),
+    )
+  }
+
+  /// AI mesh of level_b: 2x2 cells from (100,0,100) to (101,0,101), node ID is `x * 2 + z`.
+  fn get_level_b_ai() -> LevelAiFile {
+    get_level_ai(
+      LEVEL_B_GUID,
+      Vector3d::new(100.0, 0.0, 100.0),
+      Vector3d::new(101.0, 1.0, 101.0),
+    )
+  }
+
+  /// Game graph with two levels:
+  /// - level_a with vertices at (0,0,0) and (20,0,0), nodes with x < 10 belong to the first one
+  /// - level_b with single vertex at (101,0,101)
+  fn get_graphs() -> XRayResult<SpawnGraphsChunk> {
+    Ok(SpawnGraphsChunk {
+      header: GraphHeader {
+        version: 10,
+        vertices_count: 3,
+        edges_count: 0,
+        points_count: 0,
+        guid: uuid!("78e55023-10b1-426f-9247-bb680e5fe0b7"),
+        levels_count: 2,
+      },
+      levels: vec![
+        GraphLevel {
+          name: String::from("level_a"),
+          offset: Vector3d::new(0.0, 0.0, 0.0),
+          id: 0,
+          section: String::from("level_a"),
+          guid: LEVEL_A_GUID,
+        },
+        GraphLevel {
+          name: String::from("level_b"),
+          offset: Vector3d::new(0.0, 0.0, 0.0),
+          id: 1,
+          section: String::from("level_b"),
+          guid: LEVEL_B_GUID,
+        },
+      ],
+      vertices: vec![
+        get_vertex(Vector3d::new(0.0, 0.0, 0.0), 0, 0),
+        get_vertex(Vector3d::new(20.0, 0.0, 0.0), 0, 60),
+        get_vertex(Vector3d::new(101.0, 0.0, 101.0), 1, 3),
+      ],
+      edges: vec![],
+      points: vec![],
+      cross_tables: vec![
+        get_cross_table(
+          LEVEL_A_GUID,
+          &(0..63)
+            .map(|index| (if index < 30 { 0 } else { 1 }, index as f32 * 0.5))
+            .collect::<Vec<_>>(),
+        )?,
+        get_cross_table(LEVEL_B_GUID, &[(2, 1.5), (2, 1.0), (2, 0.5), (2, 0.0)])?,
+      ],
+    })
+  }
+
+  fn get_abstract() -> AlifeObjectAbstract {
+    AlifeObjectAbstract {
+      game_vertex_id: u16::MAX,
+      distance: 0.0,
+      direct_control: 1,
+      level_vertex_id: u32::MAX,
+      flags: 0,
+      custom_data: String::new(),
+      story_id: u32::MAX,
+      spawn_story_id: u32::MAX,
+    }
+  }
+
+  fn get_restrictor(shape: Vec<Shape>) -> AlifeObjectSpaceRestrictor {
+    AlifeObjectSpaceRestrictor {
+      base: get_abstract(),
+      shape,
+      restrictor_type: 3,
+    }
+  }
+
+  fn get_object(
+    section: &str,
+    name: &str,
+    position: Vector3d,
+    inherited: AlifeObjectInherited,
+  ) -> AlifeObject {
+    AlifeObject {
+      id: u16::MAX,
+      net_action: 1,
+      section: String::from(section),
+      clsid: ClsId::from_section(section),
+      name: String::from(name),
+      script_game_id: 0,
+      script_rp: 0,
+      position,
+      direction: Vector3d::new(0.0, 0.0, 0.0),
+      respawn_time: 0,
+      parent_id: u16::MAX,
+      phantom_id: u16::MAX,
+      script_flags: 32,
+      version: 128,
+      game_type: 1,
+      script_version: 12,
+      client_data_size: 0,
+      spawn_id: u16::MAX,
+      inherited,
+      update: AlifeObjectUpdate::None,
+    }
+  }
+
+  fn get_actor(name: &str, position: Vector3d) -> AlifeObject {
+    get_object(
+      "actor",
+      name,
+      position,
+      AlifeObjectInherited::SeActor(Box::new(AlifeActor {
+        base: AlifeObjectActor {
+          base: AlifeObjectCreature {
+            base: AlifeObjectDynamicVisual {
+              base: get_abstract(),
+              visual_name: String::from("actors\\stalker_hero\\stalker_hero_1"),
+              visual_flags: 0,
+            },
+            team: 0,
+            squad: 0,
+            group: 0,
+            health: 1.0,
+            dynamic_out_restrictions: vec![],
+            dynamic_in_restrictions: vec![],
+            killer_id: u16::MAX,
+            game_death_time: 0,
+          },
+          trader: AlifeObjectTraderAbstract {
+            money: 500,
+            specific_character: String::new(),
+            trader_flags: 0,
+            character_profile: String::from("actor"),
+            community_index: u32::MAX,
+            rank: 0,
+            reputation: 0,
+            character_name: String::new(),
+            dead_body_can_take: 1,
+            dead_body_closed: 0,
+          },
+          skeleton: AlifeObjectSkeleton {
+            name: String::from("$editor"),
+            flags: 0,
+            source_id: u16::MAX,
+            bones: None,
+          },
+          holder_id: u16::MAX,
+        },
+        start_position_filled: 0,
+        save_marker: 1,
+      })),
+    )
+  }
+
+  fn get_zone(
+    name: &str,
+    position: Vector3d,
+    direction: Vector3d,
+    shape: Shape,
+    artefact_spawn_count: u16,
+  ) -> AlifeObject {
+    let mut object: AlifeObject = get_object(
+      "zone_buzz",
+      name,
+      position,
+      AlifeObjectInherited::SeZoneAnom(Box::new(AlifeAnomalousZone {
+        base: AlifeObjectAnomalyZone {
+          base: AlifeObjectCustomZone {
+            base: get_restrictor(vec![shape]),
+            max_power: 1.0,
+            owner_id: u32::MAX,
+            enabled_time: 0,
+            disabled_time: 0,
+            start_time_shift: 0,
+          },
+          offline_interactive_radius: 30.0,
+          artefact_spawn_count,
+          artefact_position_offset: 0,
+        },
+        last_spawn_time: None,
+      })),
+    );
+
+    object.direction = direction;
+
+    object
+  }
+
+  fn get_patrol(name: &str, position: Vector3d) -> Patrol {
+    Patrol {
+      name: String::from(name),
+      points: vec![PatrolPoint {
+        name: String::from("wp00"),
+        position,
+        flags: 0,
+        level_vertex_id: u32::MAX,
+        game_vertex_id: u16::MAX,
+      }],
+      links: vec![],
+    }
+  }
+
+  fn get_level_a() -> SpawnBuilderLevel {
+    SpawnBuilderLevel {
+      name: String::from("level_a"),
+      spawn: LevelSpawnFile {
+        objects: vec![
+          get_object(
+            "space_restrictor",
+            "level_a_restrictor",
+            Vector3d::new(19.0, 0.0, 0.0),
+            AlifeObjectInherited::CseAlifeSpaceRestrictor(Box::new(get_restrictor(vec![
+              Shape::Sphere((Vector3d::new(0.0, 0.0, 0.0), 2.0)),
+            ]))),
+          ),
+          get_zone(
+            "level_a_zone_buzz",
+            Vector3d::new(0.0, 0.0, 0.0),
+            Vector3d::new(0.0, 0.0, 0.0),
+            Shape::Sphere((Vector3d::new(0.0, 0.0, 0.0), 1.5)),
+            5,
+          ),
+          get_object(
+            "level_changer",
+            "level_a_to_level_b",
+            Vector3d::new(4.0, 0.0, 0.0),
+            AlifeObjectInherited::SeLevelChanger(Box::new(AlifeLevelChanger {
+              base: get_restrictor(vec![Shape::Sphere((Vector3d::new(0.0, 0.0, 0.0), 1.0))]),
+              dest_game_vertex_id: u16::MAX,
+              dest_level_vertex_id: u32::MAX,
+              dest_position: Vector3d::new(100.9, 0.0, 101.2),
+              dest_direction: Vector3d::new(0.0, 0.0, 0.0),
+              angle_y: 0.0,
+              dest_level_name: String::from("level_b"),
+              dest_graph_point: String::from("level_b_entry"),
+              silent_mode: 0,
+              enabled: 1,
+              hint: String::from("level_b_hint"),
+              save_marker: 26,
+            })),
+          ),
+          get_actor("actor", Vector3d::new(2.1, 0.0, 1.2)),
+          get_zone(
+            "level_a_zone_buzz_rotated",
+            Vector3d::new(10.0, 0.0, 1.0),
+            Vector3d::new(0.0, FRAC_PI_2, 0.0),
+            Shape::Box((
+              Vector3d::new(4.0, 0.0, 0.0),
+              Vector3d::new(0.0, 1.0, 0.0),
+              Vector3d::new(0.0, 0.0, 1.0),
+              Vector3d::new(0.0, 0.0, 0.0),
+            )),
+            2,
+          ),
+        ],
+      },
+      game: LevelGameFile {
+        patrols: vec![get_patrol("level_a_walk", Vector3d::new(18.0, 0.0, 0.0))],
+      },
+      ai: get_level_a_ai(),
+    }
+  }
+
+  fn get_level_b() -> SpawnBuilderLevel {
+    SpawnBuilderLevel {
+      name: String::from("level_b"),
+      spawn: LevelSpawnFile {
+        objects: vec![
+          get_object(
+            "graph_point",
+            "level_b_entry",
+            Vector3d::new(100.9, 0.0, 101.2),
+            AlifeObjectInherited::CseAlifeGraphPoint(Box::new(AlifeGraphPoint {
+              connection_point_name: String::new(),
+              connection_level_name: String::new(),
+              location0: 0,
+              location1: 0,
+              location2: 0,
+              location3: 0,
+            })),
+          ),
+          get_object(
+            "space_restrictor",
+            "level_b_restrictor",
+            Vector3d::new(100.2, 0.0, 100.1),
+            AlifeObjectInherited::CseAlifeSpaceRestrictor(Box::new(get_restrictor(vec![
+              Shape::Sphere((Vector3d::new(0.0, 0.0, 0.0), 2.0)),
+            ]))),
+          ),
+        ],
+      },
+      game: LevelGameFile { patrols: vec![] },
+      ai: get_level_b_ai(),
+    }
+  }
+
+  fn get_location(object: &AlifeObject) -> (u16, u32, f32) {
+    match &object.inherited {
+      AlifeObjectInherited::CseAlifeSpaceRestrictor(it) => (
+        it.base.game_vertex_id,
+        it.base.level_vertex_id,
+        it.base.distance,
+      ),
+      AlifeObjectInherited::SeZoneAnom(it) => (
+        it.base.base.base.base.game_vertex_id,
+        it.base.base.base.base.level_vertex_id,
+        it.base.base.base.base.distance,
+      ),
+      AlifeObjectInherited::SeLevelChanger(it) => (
+        it.base.base.game_vertex_id,
+        it.base.base.level_vertex_id,
+        it.base.base.distance,
+      ),
+      AlifeObjectInherited::SeActor(it) => (
+        it.base.base.base.base.game_vertex_id,
+        it.base.base.base.base.level_vertex_id,
+        it.base.base.base.base.distance,
+      ),
+      _ => panic!("Unexpected object in spawn"),
+    }
+  }
+
+  fn get_artefact_spawn(level_vertex_id: u32) -> ArtefactSpawnPoint {
+    ArtefactSpawnPoint {
+      position: Vector3d::new(
+        (level_vertex_id / 3) as f32,
+        0.0,
+        (level_vertex_id % 3) as f32,
+      ),
+      level_vertex_id,
+      distance: level_vertex_id as f32 * 0.5,
+    }
+  }
+
+  #[test]
+  fn test_build() -> XRayResult {
+    let mut builder: SpawnBuilder = SpawnBuilder::new(get_graphs()?);
+
+    builder.add_level(get_level_a());
+    builder.add_level(get_level_b());
+
+    let spawn: SpawnFile = builder.build::<XRayByteOrder>()?;
+
+    assert_eq!(spawn.header.version, 10);
+    assert_eq!(spawn.header.objects_count, 6);
+    assert_eq!(spawn.header.levels_count, 2);
+    assert_eq!(
+      spawn.header.graph_guid,
+      uuid!("78e55023-10b1-426f-9247-bb680e5fe0b7")
+    );
+
+    let objects: &Vec<AlifeObject> = &spawn.alife_spawn.objects;
+
+    assert_eq!(
+      objects
+        .iter()
+        .map(|object| (object.id, object.spawn_id, object.name.as_str()))
+        .collect::<Vec<_>>(),
+      vec![
+        (0, 0, "level_a_restrictor"),
+        (1, 1, "level_a_zone_buzz"),
+        (2, 2, "level_a_to_level_b"),
+        (3, 3, "actor"),
+        (4, 4, "level_a_zone_buzz_rotated"),
+        (5, 5, "level_b_restrictor"),
+      ]
+    );
+
+    assert_eq!(get_location(&objects[0]), (1, 57, 28.5));
+    assert_eq!(get_location(&objects[1]), (0, 0, 0.0));
+    assert_eq!(get_location(&objects[2]), (0, 12, 6.0));
+    assert_eq!(get_location(&objects[3]), (0, 7, 3.5));
+    assert_eq!(get_location(&objects[4]), (1, 31, 15.5));
+    assert_eq!(get_location(&objects[5]), (2, 0, 1.5));
+
+    for (object, count, offset) in [(&objects[1], 4, 0), (&objects[4], 2, 4)] {
+      match &object.inherited {
+        AlifeObjectInherited::SeZoneAnom(zone) => {
+          assert_eq!(zone.base.artefact_spawn_count, count);
+          assert_eq!(zone.base.artefact_position_offset, offset);
+        }
+        _ => panic!("Expected anomalous zone"),
+      }
+    }
+
+    assert_eq!(
+      spawn.artefact_spawn.nodes,
+      [0, 1, 3, 4, 31, 30]
+        .into_iter()
+        .map(get_artefact_spawn)
+        .collect::<Vec<_>>()
+    );
+
+    match &objects[2].inherited {
+      AlifeObjectInherited::SeLevelChanger(changer) => {
+        assert_eq!(changer.dest_game_vertex_id, 2);
+        assert_eq!(changer.dest_level_vertex_id, 3);
+      }
+      _ => panic!("Expected level changer"),
+    }
+
+    assert_eq!(spawn.patrols.patrols.len(), 1);
+    assert_eq!(spawn.patrols.patrols[0].points[0].game_vertex_id, 1);
+    assert_eq!(spawn.patrols.patrols[0].points[0].level_vertex_id, 54);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_build_outside_of_ai_mesh() -> XRayResult {
+    let mut builder: SpawnBuilder = SpawnBuilder::new(get_graphs()?);
+    let mut level_b: SpawnBuilderLevel = get_level_b();
+
+    level_b.spawn.objects[1].position = Vector3d::new(90.0, 0.0, 101.2);
+
+    builder.add_level(get_level_a());
+    builder.add_level(level_b);
+
+    let spawn: SpawnFile = builder.build::<XRayByteOrder>()?;
+
+    assert_eq!(get_location(&spawn.alife_spawn.objects[5]), (2, 1, 1.0));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_build_actors_count() -> XRayResult {
+    let mut without_actor: SpawnBuilder = SpawnBuilder::new(get_graphs()?);
+    let mut level_a: SpawnBuilderLevel = get_level_a();
+
+    level_a.spawn.objects.remove(3);
+    without_actor.add_level(level_a);
+    without_actor.add_level(get_level_b());
+
+    assert_eq!(
+      without_actor
+        .build::<XRayByteOrder>()
+        .unwrap_err()
+        .to_string(),
+      "Invalid error: Expected single actor in spawn, got 0"
+    );
+
+    let mut with_actors: SpawnBuilder = SpawnBuilder::new(get_graphs()?);
+    let mut level_b: SpawnBuilderLevel = get_level_b();
+
+    level_b
+      .spawn
+      .objects
+      .push(get_actor("actor_second", Vector3d::new(100.0, 0.0, 100.0)));
+    with_actors.add_level(get_level_a());
+    with_actors.add_level(level_b);
+
+    assert_eq!(
+      with_actors
+        .build::<XRayByteOrder>()
+        .unwrap_err()
+        .to_string(),
+      "Invalid error: Expected single actor in spawn, got 2"
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_build_mismatched_ai() -> XRayResult {
+    let mut builder: SpawnBuilder = SpawnBuilder::new(get_graphs()?);
+    let mut level_b: SpawnBuilderLevel = get_level_b();
+
+    level_b.ai.vertices.pop();
+    level_b.ai.header.vertices_count -= 1;
+
+    builder.add_level(get_level_a());
+    builder.add_level(level_b);
+
+    assert_eq!(
+      builder.build::<XRayByteOrder>().unwrap_err().to_string(),
+      "Invalid error: Expected cross table of level 'level_b' to match AI mesh, got 4 nodes, expected 3"
+    );
+
+    let mut builder: SpawnBuilder = SpawnBuilder::new(get_graphs()?);
+    let mut level_b: SpawnBuilderLevel = get_level_b();
+
+    level_b.ai.header.guid = LEVEL_A_GUID;
+
+    builder.add_level(get_level_a());
+    builder.add_level(level_b);
+
+    assert!(builder.build::<XRayByteOrder>().is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_build_foreign_game_vertex() -> XRayResult {
+    let mut graphs: SpawnGraphsChunk = get_graphs()?;
+
+    graphs.cross_tables[1] =
+      get_cross_table(LEVEL_B_GUID, &[(0, 1.5), (2, 1.0), (2, 0.5), (2, 0.0)])?;
+
+    let mut builder: SpawnBuilder = SpawnBuilder::new(graphs);
+
+    builder.add_level(get_level_a());
+    builder.add_level(get_level_b());
+
+    assert_eq!(
+      builder.build::<XRayByteOrder>().unwrap_err().to_string(),
+      "Invalid error: Expected game vertex 0 of node 0 to belong to level 'level_b'"
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_build_duplicate_patrols() -> XRayResult {
+    let mut builder: SpawnBuilder = SpawnBuilder::new(get_graphs()?);
+    let mut level_b: SpawnBuilderLevel = get_level_b();
+
+    level_b
+      .game
+      .patrols
+      .push(get_patrol("level_a_walk", Vector3d::new(100.0, 0.0, 100.0)));
+
+    builder.add_level(get_level_a());
+    builder.add_level(level_b);
+
+    assert_eq!(
+      builder.build::<XRayByteOrder>().unwrap_err().to_string(),
+      "Invalid error: Patrol 'level_a_walk' of level 'level_b' is already defined"
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_build_from_path() -> XRayResult {
+    let graph_path: String = get_relative_test_sample_file_path(file!(), "game.graph");
+    let levels_path: PathBuf = get_absolute_test_sample_file_path(file!(), "levels");
+
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    get_graphs()?.write::<XRayByteOrder>(&mut writer)?;
+    writer.flush_raw_into(&mut overwrite_test_relative_resource_as_file(&graph_path)?)?;
+
+    for level in [get_level_a(), get_level_b()] {
+      let level_path: PathBuf = levels_path.join(&level.name);
+
+      level
+        .spawn
+        .write_to_path::<XRayByteOrder, _>(&level_path.join(SpawnBuilder::LEVEL_SPAWN_FILE_NAME))?;
+      level
+        .game
+        .write_to_path::<XRayByteOrder, _>(&level_path.join(SpawnBuilder::LEVEL_GAME_FILE_NAME))?;
+      level
+        .ai
+        .write_to_path::<XRayByteOrder, _>(&level_path.join(SpawnBuilder::LEVEL_AI_FILE_NAME))?;
+    }
+
+    let builder: SpawnBuilder = SpawnBuilder::read_from_path::<XRayByteOrder, _>(
+      &get_absolute_test_sample_file_path(file!(), "game.graph"),
+      &levels_path,
+    )?;
+
+    assert_eq!(builder.levels.len(), 2);
+    assert_eq!(builder.levels[0].spawn.objects.len(), 5);
+    assert_eq!(builder.levels[1].spawn.objects.len(), 2);
+    assert_eq!(builder.levels[0].game.patrols.len(), 1);
+    assert_eq!(builder.levels[0].ai.vertices.len(), 63);
+    assert_eq!(builder.levels[1].ai.vertices.len(), 4);
+
+    let spawn: SpawnFile = builder.build::<XRayByteOrder>()?;
+    let mut data: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+    spawn.write_to::<XRayByteOrder, _>(&mut data)?;
+
+    let read: SpawnFile = SpawnFile::read_from_chunks::<XRayByteOrder, _>(
+      &ChunkReader::from_bytes(data.get_ref())?.read_children::<XRayByteOrder>()?,
+    )?;
+
+    assert_eq!(read.header, spawn.header);
+    assert_eq!(read.alife_spawn.objects, spawn.alife_spawn.objects);
+    assert_eq!(read.artefact_spawn.nodes, spawn.artefact_spawn.nodes);
+    assert_eq!(read.patrols.patrols, spawn.patrols.patrols);
+    assert_eq!(read.graphs, spawn.graphs);
+
+    Ok(())
+  }
+}
@@ -0,0 +1,205 @@
+use crate::data::generic::shape::Shape;
+use crate::data::generic::vector_3d::Vector3d;
+use crate::data::graph::graph_cross_table::GraphCrossTable;
+use crate::data::graph::graph_level::GraphLevel;
+use crate::level::level_ai_file::LevelAiFile;
+use crate::spawn::chunks::spawn_graphs_chunk::SpawnGraphsChunk;
+use byteorder::ByteOrder;
+use std::collections::HashSet;
+use xray_error::{XRayError, XRayResult};
+
+/// Resolved navigation data of object placed on level.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpawnLocation {
+  pub game_vertex_id: u16,
+  pub level_vertex_id: u32,
+  pub distance: f32,
+}
+
+/// Locator of navigation data for single level of game graph.
+///
+/// AI node of a position is found in level AI mesh same way as xray does, game vertex and
+/// distance to it are taken from level cross table stored in game graph.
+#[derive(Debug)]
+pub struct SpawnLevelLocator<'a> {
+  pub graphs: &'a SpawnGraphsChunk,
+  pub level: &'a GraphLevel,
+  pub ai: &'a LevelAiFile,
+  pub cross_table: &'a GraphCrossTable,
+}
+
+impl<'a> SpawnLevelLocator<'a> {
+  /// Create locator for provided level of game graph and AI mesh of the level.
+  pub fn new(
+    graphs: &'a SpawnGraphsChunk,
+    level: &'a GraphLevel,
+    ai: &'a LevelAiFile,
+  ) -> XRayResult<Self> {
+    let cross_table: &'a GraphCrossTable = graphs
+      .cross_tables
+      .iter()
+      .find(|cross_table| cross_table.level_guid == level.guid)
+      .ok_or_else(|| {
+        XRayError::new_not_found_error(format!(
+          "Expected game graph to contain cross table of level '{}'",
+          level.name
+        ))
+      })?;
+
+    if ai.header.guid != level.guid {
+      return Err(XRayError::new_invalid_error(format!(
+        "Expected AI mesh of level '{}' to match game graph, got guid {}, expected {}",
+        level.name, ai.header.guid, level.guid
+      )));
+    }
+
+    if cross_table.nodes_count as usize != ai.vertices.len() {
+      return Err(XRayError::new_invalid_error(format!(
+        "Expected cross table of level '{}' to match AI mesh, got {} nodes, expected {}",
+        level.name,
+        cross_table.nodes_count,
+        ai.vertices.len()
+      )));
+    }
+
+    Ok(Self {
+      graphs,
+      level,
+      ai,
+      cross_table,
+    })
+  }
+
+  /// Get ID of AI node containing provided position.
+  /// Positions outside of AI mesh get closest node, same as xray does for misplaced objects.
+  pub fn get_vertex_id(&self, position: &Vector3d) -> XRayResult<u32> {
+    if let Some(level_vertex_id) = self.ai.find_vertex_id(position) {
+      return Ok(level_vertex_id);
+    }
+
+    let level_vertex_id: u32 = self.ai.find_nearest_vertex_id(position).ok_or_else(|| {
+      XRayError::new_invalid_error(format!(
+        "Expected AI mesh of level '{}' to contain vertices",
+        self.level.name
+      ))
+    })?;
+
+    log::warn!(
+      "Position {} is outside of AI mesh of level '{}', closest node {} is used",
+      position,
+      self.level.name,
+      level_vertex_id
+    );
+
+    Ok(level_vertex_id)
+  }
+
+  /// Get IDs of AI nodes inside of object shapes, closest to the object position go first.
+  ///
+  /// Node centers are transformed into object local space with object position and rotation
+  /// before checking shapes, same as xray does for zone shapes.
+  pub fn get_vertices_in_shapes(
+    &self,
+    position: &Vector3d,
+    direction: &Vector3d,
+    shapes: &[Shape],
+  ) -> Vec<u32> {
+    let (i, j, k) = Self::get_rotation(direction);
+    let mut visited: HashSet<u32> = HashSet::new();
+    let mut vertices: Vec<(u32, f32)> = Vec::new();
+
+    for shape in shapes {
+      let (center, radius) = match shape {
+        Shape::Sphere((center, radius)) => (center, *radius),
+        Shape::Box((axis_i, axis_j, axis_k, center)) => (
+          center,
+          0.5 * (axis_i.dot(axis_i).sqrt() + axis_j.dot(axis_j).sqrt() + axis_k.dot(axis_k).sqrt()),
+        ),
+      };
+
+      let world_center: Vector3d = Vector3d::new(
+        position.x + center.x * i.x + center.y * j.x + center.z * k.x,
+        position.y + center.x * i.y + center.y * j.y + center.z * k.y,
+        position.z + center.x * i.z + center.y * j.z + center.z * k.z,
+      );
+
+      for level_vertex_id in self.ai.get_vertices_in_bounds(
+        &Vector3d::new(world_center.x - radius, 0.0, world_center.z - radius),
+        &Vector3d::new(world_center.x + radius, 0.0, world_center.z + radius),
+      ) {
+        if visited.contains(&level_vertex_id) {
+          continue;
+        }
+
+        let vertex_position: Vector3d = self
+          .ai
+          .get_vertex_position(&self.ai.vertices[level_vertex_id as usize]);
+        let offset: Vector3d = &vertex_position - position;
+        let local: Vector3d = Vector3d::new(offset.dot(&i), offset.dot(&j), offset.dot(&k));
+
+        if shape.contains(&local) {
+          visited.insert(level_vertex_id);
+          vertices.push((
+            level_vertex_id,
+            vertex_position.distance_squared_to(position),
+          ));
+        }
+      }
+    }
+
+    vertices.sort_by(|(first_id, first), (second_id, second)| {
+      first.total_cmp(second).then(first_id.cmp(second_id))
+    });
+
+    vertices.into_iter().map(|(id, _)| id).collect()
+  }
+
+  /// Get rotation axes of object direction angles, same as xray `Fmatrix::setXYZ`.
+  fn get_rotation(direction: &Vector3d) -> (Vector3d, Vector3d, Vector3d) {
+    let (sh, ch) = direction.y.sin_cos();
+    let (sp, cp) = direction.x.sin_cos();
+    let (sb, cb) = direction.z.sin_cos();
+
+    let cc: f32 = ch * cb;
+    let cs: f32 = ch * sb;
+    let sc: f32 = sh * cb;
+    let ss: f32 = sh * sb;
+
+    (
+      Vector3d::new(cc - sp * ss, -cp * sb, sp * cs + sc),
+      Vector3d::new(sp * sc + cs, cp * cb, ss - sp * cc),
+      Vector3d::new(-cp * sh, sp, cp * ch),
+    )
+  }
+
+  /// Resolve navigation data of provided position.
+  pub fn locate<T: ByteOrder>(&self, position: &Vector3d) -> XRayResult<SpawnLocation> {
+    self.locate_vertex::<T>(self.get_vertex_id(position)?)
+  }
+
+  /// Resolve navigation data of provided AI node with cross table of the level.
+  pub fn locate_vertex<T: ByteOrder>(&self, level_vertex_id: u32) -> XRayResult<SpawnLocation> {
+    let (game_vertex_id, distance) =
+      self
+        .cross_table
+        .get_cell::<T>(level_vertex_id)
+        .ok_or_else(|| {
+          XRayError::new_invalid_error(format!(
+            "Expected cross table of level '{}' to contain node {}",
+            self.level.name, level_vertex_id
+          ))
+        })?;
+
+    match self.graphs.vertices.get(game_vertex_id as usize) {
+      Some(vertex) if vertex.level_id == self.level.id => Ok(SpawnLocation {
+        game_vertex_id,
+        level_vertex_id,
+        distance,
+      }),
+      _ => Err(XRayError::new_invalid_error(format!(
+        "Expected game vertex {} of node {} to belong to level '{}'",
+        game_vertex_id, level_vertex_id, self.level.name
+      ))),
+    }
+  }
+}
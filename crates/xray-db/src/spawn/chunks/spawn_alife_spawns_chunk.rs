@@ -3,9 +3,12 @@ use crate::export::{FileImportExport, LtxImportExport};
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::io::Write;
+use std::io::{Cursor, Seek, Write};
 use std::path::Path;
-use xray_chunk::{assert_chunk_read, ChunkIterator, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{
+  assert_chunk_read, ChunkDataSource, ChunkIterator, ChunkReadWrite, ChunkReader,
+  ChunkStreamWriter, ChunkWriter,
+};
 use xray_error::XRayResult;
 use xray_ltx::Ltx;
 use xray_utils::{assert_equal, open_export_file};
@@ -28,26 +31,57 @@ impl SpawnALifeSpawnsChunk {
 
   pub const OBJECT_INDEX_CHUNK_ID: u32 = 0;
   pub const OBJECT_DATA_CHUNK_ID: u32 = 1;
+
+  /// Write ALife chunk data into the stream, only single object is buffered at a time.
+  pub fn write_into_stream<T: ByteOrder, W: Write + Seek>(
+    &self,
+    stream: &mut ChunkStreamWriter<W>,
+  ) -> XRayResult {
+    stream.write_chunk::<T, _>(Self::COUNT_CHUNK_ID, |stream| {
+      Ok(stream.write_u32::<T>(self.objects.len() as u32)?)
+    })?;
+
+    stream.write_chunk::<T, _>(Self::OBJECTS_CHUNK_ID, |stream| {
+      for (index, object) in self.objects.iter().enumerate() {
+        stream.write_chunk::<T, _>(index as u32, |stream| {
+          stream.write_chunk::<T, _>(Self::OBJECT_INDEX_CHUNK_ID, |stream| {
+            Ok(stream.write_u16::<T>(index as u16)?)
+          })?;
+          stream.write_xr_chunk::<T, _>(Self::OBJECT_DATA_CHUNK_ID, object)?;
+
+          Ok(())
+        })?;
+      }
+
+      Ok(())
+    })?;
+
+    stream.write_chunk::<T, _>(Self::VERTEX_CHUNK_ID, |_| Ok(()))?;
+
+    Ok(())
+  }
 }
 
 impl ChunkReadWrite for SpawnALifeSpawnsChunk {
   /// Read spawns chunk by position descriptor from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!(
       "Reading ALife spawns chunk, {} bytes",
       reader.read_bytes_remain()
     );
 
-    let mut count_reader: ChunkReader = reader.read_child_by_index(Self::COUNT_CHUNK_ID)?;
-    let mut objects_reader: ChunkReader = reader.read_child_by_index(Self::OBJECTS_CHUNK_ID)?;
-    let vertex_reader: ChunkReader = reader.read_child_by_index(Self::VERTEX_CHUNK_ID)?;
+    let mut count_reader: ChunkReader<D> = reader.read_child_by_index::<T>(Self::COUNT_CHUNK_ID)?;
+    let mut objects_reader: ChunkReader<D> =
+      reader.read_child_by_index::<T>(Self::OBJECTS_CHUNK_ID)?;
+    let vertex_reader: ChunkReader<D> = reader.read_child_by_index::<T>(Self::VERTEX_CHUNK_ID)?;
 
     let count: u32 = count_reader.read_u32::<T>()?;
     let mut objects: Vec<AlifeObject> = Vec::with_capacity(count as usize);
 
-    for mut object_reader in ChunkIterator::from_start(&mut objects_reader) {
-      let mut index_reader: ChunkReader =
-        object_reader.read_child_by_index(Self::OBJECT_INDEX_CHUNK_ID)?;
+    for object_reader in ChunkIterator::<T, D>::from_start(&mut objects_reader) {
+      let mut object_reader: ChunkReader<D> = object_reader?;
+      let mut index_reader: ChunkReader<D> =
+        object_reader.read_child_by_index::<T>(Self::OBJECT_INDEX_CHUNK_ID)?;
       let index: u16 = index_reader.read_u16::<T>()?;
 
       assert_equal(
@@ -57,8 +91,8 @@ impl ChunkReadWrite for SpawnALifeSpawnsChunk {
       )?;
       assert_chunk_read(&index_reader, "Expect ALife object index to be read")?;
 
-      let mut data_reader: ChunkReader =
-        object_reader.read_child_by_index(Self::OBJECT_DATA_CHUNK_ID)?;
+      let mut data_reader: ChunkReader<D> =
+        object_reader.read_child_by_index::<T>(Self::OBJECT_DATA_CHUNK_ID)?;
       let data: AlifeObject = data_reader.read_xr::<T, _>()?;
 
       objects.push(data);
@@ -80,53 +114,12 @@ impl ChunkReadWrite for SpawnALifeSpawnsChunk {
 
   /// Write ALife chunk data into the writer.
   fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XRayResult {
-    let mut count_writer: ChunkWriter = ChunkWriter::new();
-    let mut objects_writer: ChunkWriter = ChunkWriter::new();
-    let mut vertex_writer: ChunkWriter = ChunkWriter::new();
-
-    count_writer.write_u32::<T>(self.objects.len() as u32)?;
+    let mut stream: ChunkStreamWriter<Cursor<Vec<u8>>> =
+      ChunkStreamWriter::new(Cursor::new(Vec::new()))?;
 
-    for (index, object) in self.objects.iter().enumerate() {
-      let mut object_writer: ChunkWriter = ChunkWriter::new();
-
-      let mut index_writer: ChunkWriter = ChunkWriter::new();
-      index_writer.write_u16::<T>(index as u16)?;
-      object_writer.write_all(
-        index_writer
-          .flush_chunk_into_buffer::<T>(Self::OBJECT_INDEX_CHUNK_ID)?
-          .as_slice(),
-      )?;
+    self.write_into_stream::<T, _>(&mut stream)?;
 
-      let mut data_writer: ChunkWriter = ChunkWriter::new();
-      object.write::<T>(&mut data_writer)?;
-      object_writer.write_all(
-        data_writer
-          .flush_chunk_into_buffer::<T>(Self::OBJECT_DATA_CHUNK_ID)?
-          .as_slice(),
-      )?;
-
-      objects_writer.write_all(
-        object_writer
-          .flush_chunk_into_buffer::<T>(index as u32)?
-          .as_slice(),
-      )?;
-    }
-
-    writer.write_all(
-      count_writer
-        .flush_chunk_into_buffer::<T>(Self::COUNT_CHUNK_ID)?
-        .as_slice(),
-    )?;
-    writer.write_all(
-      objects_writer
-        .flush_chunk_into_buffer::<T>(Self::OBJECTS_CHUNK_ID)?
-        .as_slice(),
-    )?;
-    writer.write_all(
-      vertex_writer
-        .flush_chunk_into_buffer::<T>(Self::VERTEX_CHUNK_ID)?
-        .as_slice(),
-    )?;
+    writer.write_all(stream.into_inner()?.get_ref())?;
 
     log::info!(
       "Written ALife spawns chunk, {} bytes",
@@ -184,15 +177,18 @@ impl fmt::Debug for SpawnALifeSpawnsChunk {
 mod tests {
   use crate::data::alife::alife_object::AlifeObject;
   use crate::data::alife::alife_object_inherited::AlifeObjectInherited;
+  use crate::data::alife::alife_object_update::AlifeObjectUpdate;
   use crate::data::alife::inherited::alife_object_abstract::AlifeObjectAbstract;
   use crate::data::alife::inherited::alife_object_dynamic_visual::AlifeObjectDynamicVisual;
   use crate::data::alife::inherited::alife_object_item::AlifeObjectItem;
   use crate::data::alife::inherited::alife_object_item_custom_outfit::AlifeObjectItemCustomOutfit;
   use crate::data::alife::inherited::alife_object_space_restrictor::AlifeObjectSpaceRestrictor;
+  use crate::data::alife::update::alife_object_item_custom_outfit_update::AlifeObjectItemCustomOutfitUpdate;
+  use crate::data::alife::update::alife_object_physic_state_update::AlifeObjectPhysicStateUpdate;
   use crate::data::generic::vector_3d::Vector3d;
   use crate::data::meta::cls_id::ClsId;
   use crate::spawn::chunks::spawn_alife_spawns_chunk::SpawnALifeSpawnsChunk;
-  use xray_chunk::{ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+  use xray_chunk::{assert_chunks_equal, ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
   use xray_error::XRayResult;
   use xray_test_utils::utils::{
     get_relative_test_sample_file_path, open_test_resource_as_slice,
@@ -224,10 +220,10 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 28 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    let read: SpawnALifeSpawnsChunk = SpawnALifeSpawnsChunk::read::<XRayByteOrder>(&mut reader)?;
+    let read: SpawnALifeSpawnsChunk = SpawnALifeSpawnsChunk::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read.objects.len(), original.objects.len());
 
@@ -281,7 +277,18 @@ mod tests {
               },
             },
           )),
-          update_data: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+          update: AlifeObjectUpdate::ItemCustomOutfit(Box::new(
+            AlifeObjectItemCustomOutfitUpdate {
+              base: AlifeObjectPhysicStateUpdate {
+                num_items: 0,
+                position: None,
+                quaternion: vec![],
+                angular_velocity: vec![],
+                linear_velocity: vec![],
+              },
+              condition: 255,
+            },
+          )),
         },
         AlifeObject {
           id: 2335,
@@ -318,7 +325,7 @@ mod tests {
               restrictor_type: 0,
             },
           )),
-          update_data: vec![0, 1, 2, 3, 4, 5, 6, 7, 8],
+          update: AlifeObjectUpdate::None,
         },
       ],
     };
@@ -327,24 +334,24 @@ mod tests {
 
     original.write::<XRayByteOrder>(&mut writer)?;
 
-    assert_eq!(writer.bytes_written(), 419);
+    assert_eq!(writer.bytes_written(), 395);
 
     let bytes_written: usize = writer.flush_chunk_into::<XRayByteOrder>(
       &mut overwrite_test_relative_resource_as_file(&filename)?,
       0,
     )?;
 
-    assert_eq!(bytes_written, 419);
+    assert_eq!(bytes_written, 395);
 
     let file: FileSlice = open_test_resource_as_slice(&filename)?;
 
-    assert_eq!(file.bytes_remaining(), 419 + 8);
+    assert_eq!(file.bytes_remaining(), 395 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
-    let read: SpawnALifeSpawnsChunk = SpawnALifeSpawnsChunk::read::<XRayByteOrder>(&mut reader)?;
+    let read: SpawnALifeSpawnsChunk = SpawnALifeSpawnsChunk::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read.objects.len(), original.objects.len());
 
@@ -352,6 +359,12 @@ mod tests {
       assert_eq!(object, original.objects.get(index).unwrap());
     }
 
+    let mut repacked: ChunkWriter = ChunkWriter::new();
+
+    read.write::<XRayByteOrder>(&mut repacked)?;
+
+    assert_chunks_equal(&writer.buffer, &repacked.buffer);
+
     Ok(())
   }
 }
@@ -4,7 +4,7 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::path::Path;
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 use xray_ltx::Ltx;
 use xray_utils::{assert_equal, open_export_file};
@@ -24,7 +24,7 @@ impl SpawnArtefactSpawnsChunk {
 impl ChunkReadWrite for SpawnArtefactSpawnsChunk {
   /// Read header chunk by position descriptor.
   /// Parses binary data into artefact spawns chunk representation object.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!(
       "Reading artefacts spawns chunk: {} bytes",
       reader.read_bytes_remain()
@@ -35,7 +35,7 @@ impl ChunkReadWrite for SpawnArtefactSpawnsChunk {
 
     // Parsing CLevelPoint structure, 20 bytes per one.
     for _ in 0..count {
-      nodes.push(ArtefactSpawnPoint::read::<T>(reader)?);
+      nodes.push(ArtefactSpawnPoint::read::<T, _>(reader)?);
     }
 
     assert_equal(
@@ -160,11 +160,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 44 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      SpawnArtefactSpawnsChunk::read::<XRayByteOrder>(&mut reader)?,
+      SpawnArtefactSpawnsChunk::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
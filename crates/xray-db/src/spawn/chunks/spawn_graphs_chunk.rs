@@ -10,7 +10,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fs::File;
 use std::path::Path;
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder};
+use xray_chunk::{
+  assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter, XRayByteOrder,
+};
 use xray_error::XRayResult;
 use xray_ltx::Ltx;
 use xray_utils::{assert_equal, open_export_file};
@@ -33,7 +35,7 @@ impl SpawnGraphsChunk {
 
 impl ChunkReadWrite for SpawnGraphsChunk {
   /// Read graphs chunk by position descriptor.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!("Reading graphs chunk, bytes {}", reader.read_bytes_remain());
 
     let header: GraphHeader = reader.read_xr::<T, _>()?;
@@ -327,11 +329,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 28 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      SpawnGraphsChunk::read::<XRayByteOrder>(&mut reader)?,
+      SpawnGraphsChunk::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -468,11 +470,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 430 + 8);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      SpawnGraphsChunk::read::<XRayByteOrder>(&mut reader)?,
+      SpawnGraphsChunk::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
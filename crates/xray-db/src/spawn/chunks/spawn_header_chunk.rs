@@ -4,7 +4,7 @@ use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use uuid::Uuid;
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 use xray_ltx::{Ltx, Section};
 use xray_utils::open_export_file;
@@ -26,7 +26,7 @@ impl SpawnHeaderChunk {
 impl ChunkReadWrite for SpawnHeaderChunk {
   /// Read header chunk by position descriptor.
   /// Parses binary data into header chunk representation object.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!("Parsing header chunk, {} bytes", reader.read_bytes_remain());
 
     let header: Self = Self {
@@ -120,10 +120,10 @@ mod tests {
     let mut reader: ChunkReader = ChunkReader::from_slice(open_test_resource_as_slice(
       &get_relative_test_sample_file_path(file!(), "read_empty.chunk"),
     )?)?
-    .read_child_by_index(0)?;
+    .read_child_by_index::<XRayByteOrder>(0)?;
 
     let original: XRayResult<SpawnHeaderChunk> =
-      SpawnHeaderChunk::read::<XRayByteOrder>(&mut reader);
+      SpawnHeaderChunk::read::<XRayByteOrder, _>(&mut reader);
 
     assert!(original.is_err(), "Expected failure with empty chunk");
 
@@ -160,11 +160,11 @@ mod tests {
     assert_eq!(file.bytes_remaining(), 52);
 
     let mut reader: ChunkReader = ChunkReader::from_slice(file)?
-      .read_child_by_index(0)
+      .read_child_by_index::<XRayByteOrder>(0)
       .expect("0 index chunk to exist");
 
     assert_eq!(
-      SpawnHeaderChunk::read::<XRayByteOrder>(&mut reader)?,
+      SpawnHeaderChunk::read::<XRayByteOrder, _>(&mut reader)?,
       original
     );
 
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::io::Write;
 use std::path::Path;
-use xray_chunk::{assert_chunk_read, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xray_chunk::{assert_chunk_read, ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
 use xray_error::XRayResult;
 use xray_ltx::Ltx;
 use xray_utils::{assert_equal, open_export_file};
@@ -24,14 +24,16 @@ impl SpawnPatrolsChunk {
 
 impl ChunkReadWrite for SpawnPatrolsChunk {
   /// Read patrols list from the chunk.
-  fn read<T: ByteOrder>(reader: &mut ChunkReader) -> XRayResult<Self> {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XRayResult<Self> {
     log::info!(
       "Reading patrols chunk, bytes {}",
       reader.read_bytes_remain()
     );
 
-    let mut meta_reader: ChunkReader = reader.read_child_by_index(Self::META_NESTED_CHUNK_ID)?;
-    let mut data_reader: ChunkReader = reader.read_child_by_index(Self::DATA_NESTED_CHUNK_ID)?;
+    let mut meta_reader: ChunkReader<D> =
+      reader.read_child_by_index::<T>(Self::META_NESTED_CHUNK_ID)?;
+    let mut data_reader: ChunkReader<D> =
+      reader.read_child_by_index::<T>(Self::DATA_NESTED_CHUNK_ID)?;
 
     let count: u32 = meta_reader.read_u32::<T>()?;
     let patrols: Vec<Patrol> = data_reader.read_xr_list::<T, _>()?;
@@ -223,8 +225,9 @@ mod tests {
 
     assert_eq!(file.bytes_remaining(), 450 + 8);
 
-    let mut reader: ChunkReader = ChunkReader::from_slice(file)?.read_child_by_index(0)?;
-    let read: SpawnPatrolsChunk = SpawnPatrolsChunk::read::<XRayByteOrder>(&mut reader)?;
+    let mut reader: ChunkReader =
+      ChunkReader::from_slice(file)?.read_child_by_index::<XRayByteOrder>(0)?;
+    let read: SpawnPatrolsChunk = SpawnPatrolsChunk::read::<XRayByteOrder, _>(&mut reader)?;
 
     assert_eq!(read, original);
 
@@ -1,2 +1,3 @@
+pub(crate) mod builder;
 pub(crate) mod chunks;
 pub(crate) mod spawn_file;
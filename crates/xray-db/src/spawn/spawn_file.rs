@@ -8,9 +8,9 @@ use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufWriter, Seek, Write};
 use std::path::Path;
-use xray_chunk::{find_required_chunk_by_id, ChunkReader, ChunkWriter};
+use xray_chunk::{find_required_chunk_by_id, ChunkDataSource, ChunkReader, ChunkStreamWriter};
 use xray_error::XRayResult;
 use xray_utils::{assert_equal, open_export_file};
 
@@ -40,11 +40,13 @@ impl SpawnFile {
 
   /// Read spawn file from file.
   pub fn read_from_file<T: ByteOrder>(file: File) -> XRayResult<Self> {
-    Self::read_from_chunks::<T>(&ChunkReader::from_file(file)?.read_children())
+    Self::read_from_chunks::<T, _>(&ChunkReader::from_file(file)?.read_children::<T>()?)
   }
 
   /// Read spawn file from chunks.
-  pub fn read_from_chunks<T: ByteOrder>(chunks: &[ChunkReader]) -> XRayResult<Self> {
+  pub fn read_from_chunks<T: ByteOrder, D: ChunkDataSource>(
+    chunks: &[ChunkReader<D>],
+  ) -> XRayResult<Self> {
     assert_equal(
       chunks.len(),
       5,
@@ -82,31 +84,24 @@ impl SpawnFile {
   /// Write spawn file data to the file by provided path.
   pub fn write_to_path<T: ByteOrder, P: AsRef<Path>>(&self, path: &P) -> XRayResult {
     fs::create_dir_all(path.as_ref().parent().expect("Spawn file parent directory"))?;
-    self.write_to::<T>(&mut open_export_file(path)?)
+    self.write_to::<T, _>(&mut BufWriter::new(open_export_file(path)?))
   }
 
   /// Write spawn file data to the writer.
-  pub fn write_to<T: ByteOrder>(&self, writer: &mut dyn Write) -> XRayResult {
-    let mut header_chunk_writer: ChunkWriter = ChunkWriter::new();
-    header_chunk_writer.write_xr::<T, _>(&self.header)?;
-    header_chunk_writer.flush_chunk_into::<T>(writer, SpawnHeaderChunk::CHUNK_ID)?;
-
-    let mut alife_spawn_chunk_writer: ChunkWriter = ChunkWriter::new();
-    alife_spawn_chunk_writer.write_xr::<T, _>(&self.alife_spawn)?;
-    alife_spawn_chunk_writer.flush_chunk_into::<T>(writer, SpawnALifeSpawnsChunk::CHUNK_ID)?;
-
-    let mut artefact_spawn_chunk_writer: ChunkWriter = ChunkWriter::new();
-    artefact_spawn_chunk_writer.write_xr::<T, _>(&self.artefact_spawn)?;
-    artefact_spawn_chunk_writer
-      .flush_chunk_into::<T>(writer, SpawnArtefactSpawnsChunk::CHUNK_ID)?;
-
-    let mut patrols_chunk_writer: ChunkWriter = ChunkWriter::new();
-    patrols_chunk_writer.write_xr::<T, _>(&self.patrols)?;
-    patrols_chunk_writer.flush_chunk_into::<T>(writer, SpawnPatrolsChunk::CHUNK_ID)?;
-
-    let mut graphs_chunk_writer: ChunkWriter = ChunkWriter::new();
-    graphs_chunk_writer.write_xr::<T, _>(&self.graphs)?;
-    graphs_chunk_writer.flush_chunk_into::<T>(writer, SpawnGraphsChunk::CHUNK_ID)?;
+  ///
+  /// Chunks are streamed into the writer and sizes are patched after payload is written.
+  pub fn write_to<T: ByteOrder, W: Write + Seek>(&self, writer: &mut W) -> XRayResult {
+    let mut stream: ChunkStreamWriter<&mut W> = ChunkStreamWriter::new(writer)?;
+
+    stream.write_xr_chunk::<T, _>(SpawnHeaderChunk::CHUNK_ID, &self.header)?;
+    stream.write_chunk::<T, _>(SpawnALifeSpawnsChunk::CHUNK_ID, |stream| {
+      self.alife_spawn.write_into_stream::<T, _>(stream)
+    })?;
+    stream.write_xr_chunk::<T, _>(SpawnArtefactSpawnsChunk::CHUNK_ID, &self.artefact_spawn)?;
+    stream.write_xr_chunk::<T, _>(SpawnPatrolsChunk::CHUNK_ID, &self.patrols)?;
+    stream.write_xr_chunk::<T, _>(SpawnGraphsChunk::CHUNK_ID, &self.graphs)?;
+
+    stream.into_inner()?;
 
     Ok(())
   }
@@ -1,6 +1,6 @@
 //! # Bit-stream tools.
 use core::mem;
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 
 type BitBuf = usize;
 const BITBUF_BYTESIZE: usize = mem::size_of::<BitBuf>();
@@ -152,6 +152,58 @@ impl<R: Read> BitRead for BitStream<R> {
   }
 }
 
+/// A simple bit-stream writer, wrapped over a writable stream.
+///
+/// Bits are being written to an each consecutive byte, starting from its highest bit.
+#[derive(Debug)]
+pub struct BitWriter<W> {
+  inner: W,
+  bits_buf: u64,
+  bits_len: u32,
+}
+
+impl<W: Write> BitWriter<W> {
+  /// Creates a new `BitWriter<W>`.
+  pub fn new(inner: W) -> BitWriter<W> {
+    BitWriter {
+      inner,
+      bits_buf: 0,
+      bits_len: 0,
+    }
+  }
+
+  /// Writes `n` lowest bits of `bits` to the stream, starting from the highest one.
+  ///
+  /// # Panics
+  /// Panics if `n` exceeds 32.
+  pub fn write_bits(&mut self, bits: u32, n: u32) -> io::Result<()> {
+    assert!(n <= 32, "too many bits written");
+    if n == 0 {
+      return Ok(());
+    }
+    self.bits_buf = self.bits_buf << n | (bits as u64 & ((1u64 << n) - 1));
+    self.bits_len += n;
+    while self.bits_len >= 8 {
+      self.bits_len -= 8;
+      self
+        .inner
+        .write_all(&[(self.bits_buf >> self.bits_len) as u8])?;
+    }
+    self.bits_buf &= (1u64 << self.bits_len) - 1;
+    Ok(())
+  }
+
+  /// Pads the last incomplete byte with zero bits, writes it and returns the underlying writer.
+  pub fn finish(mut self) -> io::Result<W> {
+    if self.bits_len > 0 {
+      let byte = (self.bits_buf << (8 - self.bits_len)) as u8;
+      self.inner.write_all(&[byte])?;
+    }
+    self.inner.flush()?;
+    Ok(self.inner)
+  }
+}
+
 #[inline(always)]
 const fn bitsize<T>() -> u32 {
   mem::size_of::<T>() as u32 * 8
@@ -221,4 +273,23 @@ mod tests {
       _ => unimplemented!(),
     }
   }
+
+  #[test]
+  fn bit_writer_works() {
+    let mut bwr = BitWriter::new(Vec::new());
+    for n in 1..16 {
+      bwr.write_bits(0, n).unwrap();
+      bwr.write_bits(!0, n).unwrap();
+    }
+    bwr.write_bits(0b101, 3).unwrap();
+    let somebits = bwr.finish().unwrap();
+    let mut rd: &[u8] = &somebits;
+    let mut brdr = BitStream::new(&mut rd);
+    for n in 1..16 {
+      assert_eq!(brdr.read_bits::<u16>(n).unwrap(), 0);
+      assert_eq!(brdr.read_bits::<u16>(n).unwrap(), (1 << n) - 1);
+    }
+    assert_eq!(brdr.read_bits::<u8>(3).unwrap(), 0b101);
+    assert_eq!(brdr.read_bits::<u8>(1).unwrap(), 0);
+  }
 }
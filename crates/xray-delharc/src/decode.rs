@@ -7,7 +7,7 @@ use crate::crc::Crc16;
 use crate::header::{CompressionMethod, LhaHeader};
 
 #[cfg(feature = "lh1")]
-mod lhv1;
+pub(crate) mod lhv1;
 mod lhv2;
 #[cfg(feature = "lz")]
 mod lz5;
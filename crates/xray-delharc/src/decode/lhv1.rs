@@ -4,7 +4,7 @@ use crate::ringbuf::*;
 use core::num::NonZeroU16;
 use std::io::{self, Read};
 
-mod dyntree;
+pub(crate) mod dyntree;
 use dyntree::*;
 
 const RING_BUFFER_SIZE: usize = 4096;
@@ -1,8 +1,8 @@
 //! # Dynamic Huffman Coding.
-use crate::bitstream::BitRead;
+use crate::bitstream::{BitRead, BitWriter};
 use crate::statictree::entry::*;
 use core::fmt;
-use std::io;
+use std::io::{self, Write};
 
 #[derive(Clone)]
 pub struct DynHuffTree {
@@ -475,6 +475,33 @@ impl DynHuffTree {
       }
     }
   }
+
+  /// Writes the path to the leaf of `value` and updates the tree the same way [DynHuffTree::read_entry] does.
+  pub fn write_entry<W: Write>(&mut self, value: u16, writer: &mut BitWriter<W>) -> io::Result<()> {
+    let mut path: u64 = 0;
+    let mut path_len: u32 = 0;
+    let mut node_index = self.leaves.get_leaf_node_index(value);
+    // walk up from leaf, reading decodes child index as `index - bit`
+    while node_index != 0 {
+      let parent_index = self.nodes[node_index].parent as usize;
+      match self.nodes[parent_index].entry.as_type() {
+        NodeType::Branch(child_index) => {
+          path |= ((child_index as usize - node_index) as u64) << path_len;
+          path_len += 1;
+        }
+        NodeType::Leaf(_) => unreachable!(),
+      }
+      node_index = parent_index;
+    }
+    debug_assert!(path_len <= 64);
+    if path_len > 32 {
+      writer.write_bits((path >> 32) as u32, path_len - 32)?;
+      path_len = 32;
+    }
+    writer.write_bits(path as u32, path_len)?;
+    self.increment_for_value(value);
+    Ok(())
+  }
 }
 
 impl fmt::Display for DynHuffTree {
@@ -0,0 +1,6 @@
+//! # Encoding algorithms.
+#[cfg(feature = "lh1")]
+mod lhv1;
+
+#[cfg(feature = "lh1")]
+pub use lhv1::*;
@@ -0,0 +1,175 @@
+use crate::bitstream::BitWriter;
+use crate::decode::lhv1::dyntree::DynHuffTree;
+use std::io::{self, Write};
+
+const RING_BUFFER_SIZE: usize = 4096;
+/// Longest match that can be expressed with a single command.
+const MAX_MATCH: usize = 60;
+/// Shortest match worth encoding instead of literals.
+const MIN_MATCH: usize = 3;
+/// Original encoders never reference the look-ahead part of the ring buffer.
+const MAX_DISTANCE: usize = RING_BUFFER_SIZE - MAX_MATCH;
+const MAX_CHAIN: usize = 256;
+const HASH_BITS: u32 = 13;
+const NO_POS: usize = usize::MAX;
+
+/// An encoder for `-lh1-` compression method.
+///
+/// Produces streams readable by [crate::decode::Lh1Decoder], matches are looked up only in the
+/// data previously passed to the encoder.
+#[derive(Debug)]
+pub struct Lh1Encoder<W> {
+  bit_writer: BitWriter<W>,
+  command_tree: Box<DynHuffTree>,
+}
+
+impl<W: Write> Lh1Encoder<W> {
+  pub fn new(wr: W) -> Lh1Encoder<W> {
+    Lh1Encoder {
+      bit_writer: BitWriter::new(wr),
+      command_tree: Box::new(DynHuffTree::new()),
+    }
+  }
+
+  /// Encodes the whole `data` buffer.
+  pub fn encode(&mut self, data: &[u8]) -> io::Result<()> {
+    let mut head = vec![NO_POS; 1 << HASH_BITS];
+    let mut prev = vec![NO_POS; data.len()];
+    let mut pos = 0;
+
+    while pos < data.len() {
+      let (length, distance) = find_match(data, pos, &head, &prev);
+      let length = if length >= MIN_MATCH {
+        self.write_command((length - MIN_MATCH + 0x100) as u16)?;
+        self.write_offset((distance - 1) as u16)?;
+        length
+      } else {
+        self.write_command(data[pos] as u16)?;
+        1
+      };
+      for index in pos..pos + length {
+        insert_hash(data, index, &mut head, &mut prev);
+      }
+      pos += length;
+    }
+    Ok(())
+  }
+
+  /// Flushes pending bits and returns the underlying writer.
+  pub fn finish(self) -> io::Result<W> {
+    self.bit_writer.finish()
+  }
+
+  #[inline]
+  fn write_command(&mut self, command: u16) -> io::Result<()> {
+    self.command_tree.write_entry(command, &mut self.bit_writer)
+  }
+
+  /// Inverse of the offset decoding, upper 6 bits are written with fixed length prefix codes.
+  #[inline]
+  fn write_offset(&mut self, offset: u16) -> io::Result<()> {
+    let (base, bits) = match offset {
+      0..=63 => (0, 0),
+      64..=255 => (0b000100000, 1),
+      256..=767 => (0b001100000, 2),
+      768..=1535 => (0b011000000, 3),
+      1536..=3071 => (0b100100000, 4),
+      _ => (0b110000000, 5),
+    };
+    self
+      .bit_writer
+      .write_bits(((offset >> bits) + base) as u32, 9)?;
+    self
+      .bit_writer
+      .write_bits((offset & ((1 << bits) - 1)) as u32, bits)
+  }
+}
+
+#[inline]
+fn get_hash(data: &[u8], pos: usize) -> usize {
+  let value = (data[pos] as usize) << 16 | (data[pos + 1] as usize) << 8 | data[pos + 2] as usize;
+  (value.wrapping_mul(2654435761) >> (32 - HASH_BITS)) & ((1 << HASH_BITS) - 1)
+}
+
+#[inline]
+fn insert_hash(data: &[u8], pos: usize, head: &mut [usize], prev: &mut [usize]) {
+  if pos + MIN_MATCH <= data.len() {
+    let hash = get_hash(data, pos);
+    prev[pos] = head[hash];
+    head[hash] = pos;
+  }
+}
+
+/// Returns (length, distance) of the longest match found for `pos`.
+fn find_match(data: &[u8], pos: usize, head: &[usize], prev: &[usize]) -> (usize, usize) {
+  let max_length = MAX_MATCH.min(data.len() - pos);
+  if max_length < MIN_MATCH {
+    return (0, 0);
+  }
+
+  let mut best = (0, 0);
+  let mut candidate = head[get_hash(data, pos)];
+  let mut chain = 0;
+
+  while candidate != NO_POS && chain < MAX_CHAIN {
+    let distance = pos - candidate;
+    if distance > MAX_DISTANCE {
+      break;
+    }
+    let length = data[candidate..]
+      .iter()
+      .zip(&data[pos..pos + max_length])
+      .take_while(|(a, b)| a == b)
+      .count();
+    if length > best.0 {
+      best = (length, distance);
+      if length == max_length {
+        break;
+      }
+    }
+    candidate = prev[candidate];
+    chain += 1;
+  }
+  best
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::decode::{Decoder, Lh1Decoder};
+  use rand::{thread_rng, Rng};
+
+  fn encode_decode(data: &[u8]) -> Vec<u8> {
+    let mut encoder = Lh1Encoder::new(Vec::new());
+    encoder.encode(data).unwrap();
+    let encoded = encoder.finish().unwrap();
+    let mut decoder = Lh1Decoder::new(encoded.as_slice());
+    let mut decoded = vec![0u8; data.len()];
+    decoder.fill_buffer(&mut decoded).unwrap();
+    decoded
+  }
+
+  #[test]
+  fn lhav1_encoder_works() {
+    assert_eq!(encode_decode(&[]), &[] as &[u8]);
+    assert_eq!(encode_decode(b"a"), b"a");
+
+    let text = b"abcabcabcabcabcabc the quick brown fox jumps over the lazy dog ".repeat(500);
+    assert_eq!(encode_decode(&text), text);
+
+    let mut encoder = Lh1Encoder::new(Vec::new());
+    encoder.encode(&text).unwrap();
+    assert!(encoder.finish().unwrap().len() < text.len() / 10);
+
+    let repeated = vec![7u8; 100_000];
+    assert_eq!(encode_decode(&repeated), repeated);
+
+    let mut rng = thread_rng();
+    let mut random = vec![0u8; 200_000];
+    rng.fill(&mut random[..]);
+    assert_eq!(encode_decode(&random), random);
+
+    let skewed: Vec<u8> = (0..300_000).map(|_| rng.gen_range(0..8u8) * 3).collect();
+    assert_eq!(encode_decode(&skewed), skewed);
+  }
+}
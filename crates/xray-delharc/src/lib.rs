@@ -46,6 +46,8 @@ features = ["lh1"] # select desired features
 | `-pm1-`    | unsupported        | N/A     | PMarc, 8 Kb sliding window, static huffman
 | `-pm2-`    | unsupported        | N/A     | PMarc, 4 Kb sliding window, static huffman
 
+Raw `-lh1-` streams can also be produced with [encode::Lh1Encoder] (`lh1` feature).
+
 ## Example
 
 ```no_run
@@ -92,6 +94,7 @@ fn extract_to_stdout<P: AsRef<Path>>(
 pub(crate) mod bitstream;
 pub mod crc;
 pub mod decode;
+pub mod encode;
 pub mod header;
 pub(crate) mod ringbuf;
 pub(crate) mod statictree;
@@ -75,6 +75,11 @@ pub enum XRayError {
   #[constructor]
   #[error("Serde error: {message}")]
   Serde { message: String },
+  #[error("{error}, at {location}")]
+  ChunkLocated {
+    location: String,
+    error: Box<XRayError>,
+  },
   #[error("IO error: {message}")]
   Io {
     message: String,
@@ -134,6 +139,36 @@ impl XRayError {
     }
   }
 
+  /// Attach chunk location context to error, innermost known location is preserved.
+  pub fn with_chunk_location<T>(self, location: T) -> Self
+  where
+    T: Into<String>,
+  {
+    match self {
+      Self::ChunkLocated { .. } => self,
+      error => Self::ChunkLocated {
+        location: location.into(),
+        error: Box::new(error),
+      },
+    }
+  }
+
+  /// Get chunk location of error, if it is known.
+  pub fn get_chunk_location(&self) -> Option<&str> {
+    match self {
+      Self::ChunkLocated { location, .. } => Some(location),
+      _ => None,
+    }
+  }
+
+  /// Get original error without chunk location context.
+  pub fn get_cause(&self) -> &Self {
+    match self {
+      Self::ChunkLocated { error, .. } => error,
+      error => error,
+    }
+  }
+
   pub fn new_io_error<T>(message: T, kind: ErrorKind) -> Self
   where
     T: Into<String>,